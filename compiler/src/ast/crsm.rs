@@ -6,10 +6,12 @@
 //! - manifold ::= "manifold" IDENT "{" m_body "}"
 //! - m_body ::= state hamiltonian constraint*
 
+use crate::diagnostics::Span;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 /// A complete CRSM manifold program
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CrsmProgram {
     pub manifolds: Vec<Manifold>,
 }
@@ -33,13 +35,16 @@ impl CrsmProgram {
 }
 
 /// A manifold definition in 7dCRSM lang
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Manifold {
     pub name: String,
     pub state: State,
     pub hamiltonian: Hamiltonian,
     pub constraints: Vec<Constraint>,
     pub operators: Vec<String>,
+    /// Source location of the manifold name
+    #[serde(default)]
+    pub span: Span,
 }
 
 impl Manifold {
@@ -50,26 +55,18 @@ impl Manifold {
             hamiltonian: Hamiltonian::default(),
             constraints: Vec::new(),
             operators: Vec::new(),
+            span: Span::default(),
         }
     }
 }
 
 /// State definition: state IDENT = (vars...)
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct State {
     pub name: String,
     pub variables: Vec<String>,
 }
 
-impl Default for State {
-    fn default() -> Self {
-        Self {
-            name: String::new(),
-            variables: Vec::new(),
-        }
-    }
-}
-
 impl State {
     pub fn new(name: &str, variables: Vec<String>) -> Self {
         Self {
@@ -80,21 +77,12 @@ impl State {
 }
 
 /// Hamiltonian law definition
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct Hamiltonian {
     pub name: String,
     pub terms: Vec<HamiltonianTerm>,
 }
 
-impl Default for Hamiltonian {
-    fn default() -> Self {
-        Self {
-            name: String::new(),
-            terms: Vec::new(),
-        }
-    }
-}
-
 impl Hamiltonian {
     pub fn new(name: &str) -> Self {
         Self {
@@ -105,7 +93,7 @@ impl Hamiltonian {
 }
 
 /// Hamiltonian term types
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub enum HamiltonianTerm {
     /// Positive term: +IDENT IDENT
     Product(String, String),
@@ -116,13 +104,13 @@ pub enum HamiltonianTerm {
 }
 
 /// Constraint definition with integral
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Constraint {
     pub integral: Integral,
 }
 
 /// Integral constraint: ∫ IDENT IDENT IDENT = NUMBER
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Integral {
     pub domain: String,
     pub integrand: String,
@@ -4,13 +4,18 @@
 //!
 //! Grammar reference:
 //! - program ::= organism*
-//! - organism ::= "organism" IDENT "{" body "}"
-//! - body ::= (field | gene | evolve | collapse)*
+//! - organism ::= annotation* "organism" IDENT "{" body "}"
+//! - body ::= (field | annotation* gene | evolve | collapse)*
+//! - annotation ::= "@" IDENT "(" value ")"
 
+use crate::ast::payload::Payload;
+use crate::diagnostics::Span;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// A complete DNA program consisting of organisms
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DnaProgram {
     pub organisms: Vec<Organism>,
 }
@@ -34,13 +39,19 @@ impl DnaProgram {
 }
 
 /// An organism definition in DNA lang
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Organism {
     pub name: String,
     pub fields: Vec<Field>,
     pub genes: Vec<Gene>,
     pub evolve: Option<Evolve>,
     pub collapse: Option<Collapse>,
+    /// `@key(value)` annotations (provenance, authorship, tuning hints)
+    #[serde(default)]
+    pub annotations: BTreeMap<String, String>,
+    /// Source location of the organism name
+    #[serde(default)]
+    pub span: Span,
 }
 
 impl Organism {
@@ -51,15 +62,20 @@ impl Organism {
             genes: Vec::new(),
             evolve: None,
             collapse: None,
+            annotations: BTreeMap::new(),
+            span: Span::default(),
         }
     }
 }
 
 /// Field definition: field IDENT : IDENT
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Field {
     pub name: String,
     pub field_type: String,
+    /// Source location of the field name
+    #[serde(default)]
+    pub span: Span,
 }
 
 impl Field {
@@ -67,15 +83,22 @@ impl Field {
         Self {
             name: name.to_string(),
             field_type: field_type.to_string(),
+            span: Span::default(),
         }
     }
 }
 
 /// Gene definition with body expressions
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Gene {
     pub name: String,
     pub body: Vec<Expr>,
+    /// `@key(value)` annotations (provenance, authorship, tuning hints)
+    #[serde(default)]
+    pub annotations: BTreeMap<String, String>,
+    /// Source location of the gene name
+    #[serde(default)]
+    pub span: Span,
 }
 
 impl Gene {
@@ -83,14 +106,16 @@ impl Gene {
         Self {
             name: name.to_string(),
             body: Vec::new(),
+            annotations: BTreeMap::new(),
+            span: Span::default(),
         }
     }
 }
 
 /// Expression types in gene bodies
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub enum Expr {
-    Emit(String),
+    Emit(Payload),
     Bifurcate(String),
     Sovereign,
     Call(String, Vec<Expr>),
@@ -98,7 +123,7 @@ pub enum Expr {
 }
 
 /// Evolution block with ODEs
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Evolve {
     pub odes: Vec<Ode>,
 }
@@ -116,7 +141,7 @@ impl Evolve {
 }
 
 /// Ordinary differential equation: ∂τ (vars) = rhs
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Ode {
     pub state_vars: Vec<String>,
     pub rhs_func: String,
@@ -124,7 +149,7 @@ pub struct Ode {
 }
 
 /// Collapse block with rules
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Collapse {
     pub rules: Vec<CollapseRule>,
 }
@@ -142,14 +167,14 @@ impl Collapse {
 }
 
 /// Collapse rule: if condition then action
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct CollapseRule {
     pub condition: CollapseCondition,
     pub action: String,
 }
 
 /// Collapse condition types
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub enum CollapseCondition {
     LessOrEqual(String, String),
     TendsTo(String, f64),
@@ -178,7 +203,7 @@ mod tests {
     #[test]
     fn test_gene_with_expressions() {
         let mut gene = Gene::new("main");
-        gene.body.push(Expr::Emit("Hello".to_string()));
+        gene.body.push(Expr::Emit(Payload::from("Hello")));
         gene.body.push(Expr::Bifurcate("psi".to_string()));
         gene.body.push(Expr::Sovereign);
         assert_eq!(gene.body.len(), 3);
@@ -4,6 +4,8 @@
 
 pub mod crsm;
 pub mod dna;
+pub mod payload;
 
 pub use crsm::{Constraint, CrsmProgram, Hamiltonian, HamiltonianTerm, Integral, Manifold, State};
+pub use payload::{Encoded, Encoding, Payload};
 pub use dna::{Collapse, CollapseCondition, CollapseRule, DnaProgram, Evolve, Expr, Field, Gene, Ode, Organism};
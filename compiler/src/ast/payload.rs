@@ -0,0 +1,177 @@
+//! Emit Payloads
+//!
+//! Typed data carried by `emit`, from the AST through the IR to runtime
+//! sinks:
+//! - emit "text"            → Payload::Text
+//! - emit bytes "00ff"      → Payload::Bytes (hex in source)
+//! - emit json "{\"a\": 1}" → Payload::Json
+//!
+//! Sinks list the encodings they accept; `Payload::negotiate` picks one and
+//! converts losslessly (bytes become hex text or a JSON array of octets).
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Typed emit payload
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub enum Payload {
+    Text(String),
+    Bytes(Vec<u8>),
+    Json(Value),
+}
+
+/// Wire encoding a sink can accept
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Encoding {
+    Text,
+    Json,
+    Binary,
+}
+
+/// A payload converted to a negotiated encoding
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub enum Encoded {
+    Text(String),
+    Json(Value),
+    Binary(Vec<u8>),
+}
+
+impl Payload {
+    /// Payload type name
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Payload::Text(_) => "text",
+            Payload::Bytes(_) => "bytes",
+            Payload::Json(_) => "json",
+        }
+    }
+
+    /// Encoding that represents the payload without conversion
+    pub fn natural_encoding(&self) -> Encoding {
+        match self {
+            Payload::Text(_) => Encoding::Text,
+            Payload::Bytes(_) => Encoding::Binary,
+            Payload::Json(_) => Encoding::Json,
+        }
+    }
+
+    /// Text content, if this is a text payload
+    pub fn as_text(&self) -> Option<&str> {
+        match self {
+            Payload::Text(text) => Some(text),
+            _ => None,
+        }
+    }
+
+    /// Convert to the given encoding
+    pub fn encode(&self, encoding: Encoding) -> Encoded {
+        match (self, encoding) {
+            (Payload::Text(text), Encoding::Text) => Encoded::Text(text.clone()),
+            (Payload::Text(text), Encoding::Json) => Encoded::Json(Value::String(text.clone())),
+            (Payload::Text(text), Encoding::Binary) => Encoded::Binary(text.as_bytes().to_vec()),
+            (Payload::Bytes(bytes), Encoding::Text) => Encoded::Text(to_hex(bytes)),
+            (Payload::Bytes(bytes), Encoding::Json) => Encoded::Json(Value::from(bytes.clone())),
+            (Payload::Bytes(bytes), Encoding::Binary) => Encoded::Binary(bytes.clone()),
+            (Payload::Json(value), Encoding::Text) => Encoded::Text(value.to_string()),
+            (Payload::Json(value), Encoding::Json) => Encoded::Json(value.clone()),
+            (Payload::Json(value), Encoding::Binary) => {
+                Encoded::Binary(value.to_string().into_bytes())
+            }
+        }
+    }
+
+    /// Pick an encoding from `accepted` (natural one first) and convert
+    pub fn negotiate(&self, accepted: &[Encoding]) -> Option<Encoded> {
+        let natural = self.natural_encoding();
+        let encoding = if accepted.contains(&natural) {
+            natural
+        } else {
+            *accepted.first()?
+        };
+        Some(self.encode(encoding))
+    }
+}
+
+impl From<&str> for Payload {
+    fn from(text: &str) -> Self {
+        Payload::Text(text.to_string())
+    }
+}
+
+impl From<String> for Payload {
+    fn from(text: String) -> Self {
+        Payload::Text(text)
+    }
+}
+
+/// Lowercase hex encoding of bytes
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Decode hex text (whitespace ignored); `None` if malformed
+pub fn from_hex(text: &str) -> Option<Vec<u8>> {
+    let digits: Vec<u32> = text
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_digit(16))
+        .collect::<Option<_>>()?;
+    if !digits.len().is_multiple_of(2) {
+        return None;
+    }
+    Some(
+        digits
+            .chunks(2)
+            .map(|pair| (pair[0] * 16 + pair[1]) as u8)
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_hex_roundtrip() {
+        let bytes = vec![0, 1, 0x7f, 0xff];
+        assert_eq!(to_hex(&bytes), "00017fff");
+        assert_eq!(from_hex("00 01 7F ff"), Some(bytes));
+        assert_eq!(from_hex("abc"), None);
+        assert_eq!(from_hex("zz"), None);
+    }
+
+    #[test]
+    fn test_negotiate_prefers_natural_encoding() {
+        let payload = Payload::Json(json!({ "xi": 8.2 }));
+        let accepted = [Encoding::Text, Encoding::Json];
+        assert_eq!(
+            payload.negotiate(&accepted),
+            Some(Encoded::Json(json!({ "xi": 8.2 })))
+        );
+        assert_eq!(
+            payload.negotiate(&[Encoding::Text]),
+            Some(Encoded::Text("{\"xi\":8.2}".to_string()))
+        );
+        assert_eq!(payload.negotiate(&[]), None);
+    }
+
+    #[test]
+    fn test_bytes_are_binary_safe() {
+        let payload = Payload::Bytes(vec![0, 0xc3, 0x28]);
+        assert_eq!(
+            payload.encode(Encoding::Binary),
+            Encoded::Binary(vec![0, 0xc3, 0x28])
+        );
+        assert_eq!(
+            payload.encode(Encoding::Text),
+            Encoded::Text("00c328".to_string())
+        );
+        assert_eq!(
+            payload.encode(Encoding::Json),
+            Encoded::Json(json!([0, 195, 40]))
+        );
+    }
+}
@@ -0,0 +1,34 @@
+//! dnalang-lsp
+//!
+//! Language server for dna::}{::lang / 7dCRSM::}{::lang over stdio.
+//! A message that cannot be parsed gets a JSON-RPC parse error and the
+//! server keeps reading; it stops at end of input or on an I/O error.
+
+use dnalang_compiler::lsp::{parse_error, read_message, write_message, LanguageServer};
+use std::io;
+
+fn main() -> io::Result<()> {
+    let stdin = io::stdin();
+    let mut input = stdin.lock();
+    let mut output = io::stdout().lock();
+    let mut server = LanguageServer::new();
+
+    loop {
+        let message = match read_message(&mut input) {
+            Ok(Some(message)) => message,
+            Ok(None) => break,
+            Err(err) if err.kind() == io::ErrorKind::InvalidData => {
+                write_message(&mut output, &parse_error(&err))?;
+                continue;
+            }
+            Err(err) => return Err(err),
+        };
+        for reply in server.handle(&message) {
+            write_message(&mut output, &reply)?;
+        }
+        if server.has_exited() {
+            std::process::exit(server.exit_code());
+        }
+    }
+    Ok(())
+}
@@ -17,9 +17,12 @@
 //!     ΛΦ = max → Ω∞.seal()
 
 use crate::ast::{CrsmProgram, DnaProgram, Expr};
+use crate::builtins::fold_builtin;
+use crate::coefficients::HamiltonianCoefficients;
+use crate::inline_pass::InlinePass;
 use crate::ir::{
     CollapseActionIR, CollapseConditionIR, CollapseRuleIR, EvolutionIR, FieldCoord, GeneOp,
-    GeneOpType, HamiltonianTermIR, OmegaIR, Z3StateIR,
+    GeneOpType, MetricIR, OmegaIR, Z3StateIR,
 };
 use serde::{Deserialize, Serialize};
 
@@ -130,6 +133,11 @@ impl Z3State {
         result
     }
 
+    /// H_CRSM = DΛ − KΓ + sin θ
+    pub fn hamiltonian(&self) -> f64 {
+        crate::builtins::hamiltonian(self.lambda, self.gamma, self.theta)
+    }
+
     /// Check if sovereignty conditions are met
     /// Ξ ≥ 8 and Γ ≤ εΓ
     pub fn check_sovereignty(&self) -> bool {
@@ -228,22 +236,36 @@ pub fn omega_bind(program_dna: &DnaProgram, program_crsm: &CrsmProgram) -> Z3Sta
 
 /// Generate Omega IR from bound programs
 pub fn generate_omega_ir(program_dna: &DnaProgram, program_crsm: &CrsmProgram) -> OmegaIR {
+    generate_omega_ir_with(program_dna, program_crsm, &HamiltonianCoefficients::new())
+}
+
+/// Generate Omega IR after `pass` has inlined small gene-to-gene calls, so
+/// the runtime dispatches fewer `Call` ops
+pub fn generate_omega_ir_inlined(
+    program_dna: &DnaProgram,
+    program_crsm: &CrsmProgram,
+    pass: &mut InlinePass,
+) -> OmegaIR {
+    generate_omega_ir(&pass.run(program_dna), program_crsm)
+}
+
+/// Generate Omega IR with the given Hamiltonian coefficients
+pub fn generate_omega_ir_with(
+    program_dna: &DnaProgram,
+    program_crsm: &CrsmProgram,
+    coefficients: &HamiltonianCoefficients,
+) -> OmegaIR {
     let mut ir = OmegaIR::new();
+    if let Some(organism) = program_dna.organisms.first() {
+        ir.organism = organism.name.clone();
+    }
 
     // Convert Z3 state
     let z3_state = omega_bind(program_dna, program_crsm);
     ir.z3_state = Z3StateIR {
         psi_real: z3_state.psi_real,
         psi_imag: z3_state.psi_imag,
-        metric_diag: [
-            z3_state.metric[0][0],
-            z3_state.metric[1][1],
-            z3_state.metric[2][2],
-            z3_state.metric[3][3],
-            z3_state.metric[4][4],
-            z3_state.metric[5][5],
-            z3_state.metric[6][6],
-        ],
+        metric: MetricIR::from_matrix(z3_state.metric),
         nabla_7d: z3_state.nabla_7d,
         gamma: z3_state.gamma,
         lambda: z3_state.lambda,
@@ -254,26 +276,34 @@ pub fn generate_omega_ir(program_dna: &DnaProgram, program_crsm: &CrsmProgram) -
     // Map genes to operations
     for organism in &program_dna.organisms {
         for (idx, gene) in organism.genes.iter().enumerate() {
-            let op_type = if gene.body.is_empty() {
-                GeneOpType::Sovereign
-            } else {
-                match &gene.body[0] {
-                    Expr::Emit(s) => GeneOpType::Emit(s.clone()),
-                    Expr::Bifurcate(_) => GeneOpType::Bifurcate,
-                    Expr::Sovereign => GeneOpType::Sovereign,
-                    Expr::Call(name, _) => GeneOpType::Call(name.clone(), vec![]),
-                    Expr::Ident(name) => GeneOpType::Call(name.clone(), vec![]),
-                };
-                GeneOpType::Sovereign
+            let op_type = match gene.body.first() {
+                None | Some(Expr::Sovereign) => GeneOpType::Sovereign,
+                Some(Expr::Emit(s)) => GeneOpType::Emit(s.clone()),
+                Some(Expr::Bifurcate(_)) => GeneOpType::Bifurcate,
+                // Builtins with known arguments are evaluated here
+                Some(Expr::Call(name, args)) => match fold_builtin(name, args, &z3_state) {
+                    Some(value) => GeneOpType::Folded {
+                        builtin: name.clone(),
+                        value,
+                    },
+                    None => GeneOpType::Call(name.clone(), args.iter().map(arg_name).collect()),
+                },
+                Some(Expr::Ident(name)) => GeneOpType::Call(name.clone(), vec![]),
             };
 
             ir.gene_ops.push(GeneOp {
                 name: gene.name.clone(),
                 connection_index: idx,
                 op_type,
+                annotations: gene.annotations.clone(),
             });
         }
 
+        if !organism.annotations.is_empty() {
+            ir.organism_annotations
+                .insert(organism.name.clone(), organism.annotations.clone());
+        }
+
         // Map fields to coordinates
         for (idx, field) in organism.fields.iter().enumerate() {
             ir.field_coords.push(FieldCoord {
@@ -286,15 +316,7 @@ pub fn generate_omega_ir(program_dna: &DnaProgram, program_crsm: &CrsmProgram) -
 
     // Generate Hamiltonian terms for evolution
     ir.evolution = EvolutionIR {
-        hamiltonian_terms: vec![
-            HamiltonianTermIR::CoherenceGradient { coefficient: 1.0 },
-            HamiltonianTermIR::DecoherenceSuppression { coefficient: 0.1 },
-            HamiltonianTermIR::DualityTorsion {
-                coefficient: 1.0,
-                theta: THETA_CRITICAL,
-            },
-            HamiltonianTermIR::Sovereignty { threshold: XI_THRESHOLD },
-        ],
+        hamiltonian_terms: coefficients.hamiltonian_terms(),
         dt: 0.01,
     };
 
@@ -315,10 +337,19 @@ pub fn generate_omega_ir(program_dna: &DnaProgram, program_crsm: &CrsmProgram) -
     ir
 }
 
+/// Name recorded in the IR for a call argument
+fn arg_name(arg: &Expr) -> String {
+    match arg {
+        Expr::Ident(name) | Expr::Call(name, _) => name.clone(),
+        _ => String::new(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ast::{Field, Gene, Manifold, Organism};
+    use crate::ast::Manifold;
+    use crate::builder::{FieldKind, OrganismBuilder};
 
     #[test]
     fn test_z3_state_creation() {
@@ -347,11 +378,10 @@ mod tests {
 
     #[test]
     fn test_omega_bind() {
-        let mut dna = DnaProgram::new();
-        let mut organism = Organism::new("Test");
-        organism.fields.push(Field::new("lambda", "coherence"));
-        organism.genes.push(Gene::new("main"));
-        dna.add_organism(organism);
+        let dna = OrganismBuilder::new("Test")
+            .field("lambda", FieldKind::Coherence)
+            .gene("main", |g| g)
+            .program();
 
         let mut crsm = CrsmProgram::new();
         crsm.add_manifold(Manifold::new("CRSM7"));
@@ -360,6 +390,53 @@ mod tests {
         assert!(state.lambda > 0.0);
     }
 
+    #[test]
+    fn test_builtin_calls_folded_into_ir() {
+        let dna = OrganismBuilder::new("Test")
+            .gene("energy", |g| g.call("hamiltonian", &[]))
+            .gene("grow", |g| g.call("emergence", &["x"]))
+            .program();
+
+        let ir = generate_omega_ir(&dna, &CrsmProgram::new());
+        let state = omega_bind(&dna, &CrsmProgram::new());
+        assert!(matches!(
+            &ir.gene_ops[0].op_type,
+            GeneOpType::Folded { builtin, value } if builtin == "hamiltonian" && *value == state.hamiltonian()
+        ));
+        assert!(matches!(
+            &ir.gene_ops[1].op_type,
+            GeneOpType::Call(name, args) if name == "emergence" && args == &["x".to_string()]
+        ));
+    }
+
+    #[test]
+    fn test_annotations_preserved_in_ir() {
+        let dna = OrganismBuilder::new("Test")
+            .annotate("author", "ENKI-420")
+            .gene("main", |g| g.annotate("tuning", "K=0.1"))
+            .program();
+
+        let ir = generate_omega_ir(&dna, &CrsmProgram::new());
+        assert_eq!(ir.organism_annotations["Test"]["author"], "ENKI-420");
+        assert_eq!(ir.gene_ops[0].annotations["tuning"], "K=0.1");
+    }
+
+    #[test]
+    fn test_configured_coefficients() {
+        let coefficients = HamiltonianCoefficients {
+            decoherence_suppression: 0.5,
+            ..HamiltonianCoefficients::new()
+        };
+        let ir = generate_omega_ir_with(&DnaProgram::new(), &CrsmProgram::new(), &coefficients);
+        assert_eq!(ir.evolution.hamiltonian_terms, coefficients.hamiltonian_terms());
+
+        let default_ir = generate_omega_ir(&DnaProgram::new(), &CrsmProgram::new());
+        assert_eq!(
+            default_ir.evolution.hamiltonian_terms,
+            HamiltonianCoefficients::new().hamiltonian_terms()
+        );
+    }
+
     #[test]
     fn test_sovereignty_check() {
         let mut state = Z3State::new();
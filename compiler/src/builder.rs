@@ -0,0 +1,204 @@
+//! Program Builder
+//!
+//! Fluent construction of DNA programs from Rust, for tests, examples and
+//! tools that generate organisms without going through source text:
+//!
+//! ```
+//! use dnalang_compiler::builder::{FieldKind, OrganismBuilder};
+//!
+//! let program = OrganismBuilder::new("Demo")
+//!     .field("lambda", FieldKind::Coherence)
+//!     .gene("main", |g| g.emit("hi").bifurcate("lambda").sovereign())
+//!     .program();
+//! assert_eq!(program.organisms[0].genes[0].body.len(), 3);
+//! ```
+//!
+//! Field kinds are an enum, so an unknown field type is a compile error
+//! rather than an E0001 diagnostic.
+
+use crate::ast::{DnaProgram, Expr, Field, Gene, Organism, Payload};
+use crate::semantic::{COORDINATE_SYMBOLS, FIELD_TYPES};
+
+/// Field types that map onto a coordinate of M⁷
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    Coherence,
+    Decoherence,
+    Information,
+    Emergence,
+    Polarity,
+    Torsion,
+    Epoch,
+}
+
+impl FieldKind {
+    pub const ALL: [FieldKind; 7] = [
+        FieldKind::Coherence,
+        FieldKind::Decoherence,
+        FieldKind::Information,
+        FieldKind::Emergence,
+        FieldKind::Polarity,
+        FieldKind::Torsion,
+        FieldKind::Epoch,
+    ];
+
+    /// Source spelling of the type (`coherence`, ...)
+    pub fn as_str(self) -> &'static str {
+        FIELD_TYPES[self as usize]
+    }
+
+    /// Coordinate symbol (Λ, Γ, ...)
+    pub fn symbol(self) -> &'static str {
+        COORDINATE_SYMBOLS[self as usize]
+    }
+}
+
+/// Builds the body of one gene
+#[derive(Debug, Clone)]
+pub struct GeneBuilder {
+    gene: Gene,
+}
+
+impl GeneBuilder {
+    pub fn new(name: &str) -> Self {
+        Self {
+            gene: Gene::new(name),
+        }
+    }
+
+    pub fn emit(mut self, payload: impl Into<Payload>) -> Self {
+        self.gene.body.push(Expr::Emit(payload.into()));
+        self
+    }
+
+    pub fn bifurcate(mut self, field: &str) -> Self {
+        self.gene.body.push(Expr::Bifurcate(field.to_string()));
+        self
+    }
+
+    pub fn sovereign(mut self) -> Self {
+        self.gene.body.push(Expr::Sovereign);
+        self
+    }
+
+    /// Call a gene or builtin with identifier arguments
+    pub fn call(mut self, name: &str, args: &[&str]) -> Self {
+        let args = args.iter().map(|a| Expr::Ident(a.to_string())).collect();
+        self.gene.body.push(Expr::Call(name.to_string(), args));
+        self
+    }
+
+    pub fn annotate(mut self, key: &str, value: &str) -> Self {
+        self.gene
+            .annotations
+            .insert(key.to_string(), value.to_string());
+        self
+    }
+
+    pub fn build(self) -> Gene {
+        self.gene
+    }
+}
+
+/// Builds an organism
+#[derive(Debug, Clone)]
+pub struct OrganismBuilder {
+    organism: Organism,
+}
+
+impl OrganismBuilder {
+    pub fn new(name: &str) -> Self {
+        Self {
+            organism: Organism::new(name),
+        }
+    }
+
+    pub fn field(mut self, name: &str, kind: FieldKind) -> Self {
+        self.organism.fields.push(Field::new(name, kind.as_str()));
+        self
+    }
+
+    /// Add a gene whose body is built by `body`
+    pub fn gene(mut self, name: &str, body: impl FnOnce(GeneBuilder) -> GeneBuilder) -> Self {
+        self.organism
+            .genes
+            .push(body(GeneBuilder::new(name)).build());
+        self
+    }
+
+    pub fn annotate(mut self, key: &str, value: &str) -> Self {
+        self.organism
+            .annotations
+            .insert(key.to_string(), value.to_string());
+        self
+    }
+
+    pub fn build(self) -> Organism {
+        self.organism
+    }
+
+    /// A program containing only this organism
+    pub fn program(self) -> DnaProgram {
+        let mut program = DnaProgram::new();
+        program.add_organism(self.build());
+        program
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::CrsmProgram;
+    use crate::parser::parse;
+    use crate::semantic::analyze;
+
+    #[test]
+    fn test_builder_matches_parsed_source() {
+        let built = OrganismBuilder::new("Demo")
+            .annotate("author", "ENKI-420")
+            .field("lambda", FieldKind::Coherence)
+            .field("gamma", FieldKind::Decoherence)
+            .gene("main", |g| g.emit("hi").bifurcate("lambda").sovereign())
+            .gene("grow", |g| {
+                g.call("emergence", &["lambda"]).annotate("hint", "fast")
+            })
+            .program();
+        let parsed = parse(
+            r#"@author("ENKI-420")
+organism Demo {
+    field lambda: coherence
+    field gamma: decoherence
+    gene main { emit "hi" bifurcate lambda sovereign }
+    @hint("fast")
+    gene grow { emergence(lambda) }
+}"#,
+        );
+        assert!(!parsed.has_errors(), "{:?}", parsed.diagnostics);
+
+        let strip = |program: &DnaProgram| {
+            let mut value = serde_json::to_value(program).unwrap();
+            strip_spans(&mut value);
+            value
+        };
+        assert_eq!(strip(&built), strip(&parsed.dna));
+        assert!(analyze(&built, &CrsmProgram::new()).is_empty());
+    }
+
+    fn strip_spans(value: &mut serde_json::Value) {
+        match value {
+            serde_json::Value::Object(map) => {
+                map.remove("span");
+                map.values_mut().for_each(strip_spans);
+            }
+            serde_json::Value::Array(items) => items.iter_mut().for_each(strip_spans),
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn test_field_kinds_follow_field_types() {
+        let names: Vec<&str> = FieldKind::ALL.iter().map(|k| k.as_str()).collect();
+        assert_eq!(names, FIELD_TYPES);
+        assert_eq!(FieldKind::Torsion.symbol(), "θ");
+    }
+}
@@ -0,0 +1,127 @@
+//! Builtin Functions
+//!
+//! Compile-time evaluation of the builtin calls `emergence()`,
+//! `hamiltonian()` and `seal()` against the Ω_bind state:
+//! - emergence()            → Ξ = ΛΦ/Γ
+//! - emergence(Λ, Φ, Γ)     → ΛΦ/Γ
+//! - hamiltonian()          → H_CRSM = Λ − Γ + sin θ
+//! - hamiltonian(Λ, Γ, θ)   → Λ − Γ + sin θ
+//! - seal()                 → 1 if Ω∞.seal() succeeds on the bound state, else 0
+//!
+//! Arguments are known when they name a coordinate of M⁷ (`lambda`/`Λ`, ...)
+//! or are themselves foldable builtin calls. Anything else is left as a call
+//! for the runtime.
+
+use crate::ast::Expr;
+use crate::binding::{Z3State, GAMMA_TOLERANCE};
+
+/// Names of the builtin functions
+pub const BUILTINS: [&str; 3] = ["emergence", "hamiltonian", "seal"];
+
+/// Whether `name` is a builtin function
+pub fn is_builtin(name: &str) -> bool {
+    BUILTINS.contains(&name)
+}
+
+/// Value of a coordinate of M⁷ by name or symbol
+pub fn coordinate_value(state: &Z3State, name: &str) -> Option<f64> {
+    match name {
+        "lambda" | "Λ" => Some(state.lambda),
+        "gamma" | "Γ" => Some(state.gamma),
+        "phi" | "Φ" => Some(state.phi),
+        "xi" | "Ξ" => Some(state.xi),
+        "rho" | "ρ" | "ρ±" => Some(state.rho),
+        "theta" | "θ" => Some(state.theta),
+        "tau" | "τ" => Some(state.tau),
+        _ => None,
+    }
+}
+
+/// Evaluate an expression at compile time, if all of its inputs are known
+pub fn eval_const(expr: &Expr, state: &Z3State) -> Option<f64> {
+    match expr {
+        Expr::Ident(name) => coordinate_value(state, name),
+        Expr::Call(name, args) => fold_builtin(name, args, state),
+        _ => None,
+    }
+}
+
+/// Fold a builtin call; `None` if it is not a builtin or an argument is unknown
+pub fn fold_builtin(name: &str, args: &[Expr], state: &Z3State) -> Option<f64> {
+    let values = args
+        .iter()
+        .map(|arg| eval_const(arg, state))
+        .collect::<Option<Vec<f64>>>()?;
+
+    match (name, values.as_slice()) {
+        ("emergence", []) => Some(emergence(state.lambda, state.phi, state.gamma)),
+        ("emergence", [lambda, phi, gamma]) => Some(emergence(*lambda, *phi, *gamma)),
+        ("hamiltonian", []) => Some(state.hamiltonian()),
+        ("hamiltonian", [lambda, gamma, theta]) => Some(hamiltonian(*lambda, *gamma, *theta)),
+        ("seal", []) => {
+            let mut sealed = state.clone();
+            sealed.compute_emergence();
+            sealed.seal();
+            Some(if sealed.sealed { 1.0 } else { 0.0 })
+        }
+        _ => None,
+    }
+}
+
+/// Ξ = ΛΦ/Γ, capped like `Z3State::compute_emergence`
+fn emergence(lambda: f64, phi: f64, gamma: f64) -> f64 {
+    if gamma > GAMMA_TOLERANCE {
+        lambda * phi / gamma
+    } else {
+        1e12
+    }
+}
+
+/// H_CRSM = DΛ − KΓ + sin θ
+pub(crate) fn hamiltonian(lambda: f64, gamma: f64, theta: f64) -> f64 {
+    lambda - gamma + theta.to_radians().sin()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(name: &str, args: &[&str]) -> Expr {
+        Expr::Call(
+            name.to_string(),
+            args.iter().map(|a| Expr::Ident(a.to_string())).collect(),
+        )
+    }
+
+    #[test]
+    fn test_fold_emergence() {
+        let state = Z3State::new();
+        let folded = eval_const(&call("emergence", &[]), &state).unwrap();
+        assert!((folded - 0.869 * 7.6901 / 0.012).abs() < 1e-9);
+
+        let explicit = eval_const(&call("emergence", &["Λ", "Φ", "gamma"]), &state).unwrap();
+        assert_eq!(folded, explicit);
+    }
+
+    #[test]
+    fn test_fold_hamiltonian_and_seal() {
+        let mut state = Z3State::new();
+        let h = eval_const(&call("hamiltonian", &["lambda", "gamma", "theta"]), &state);
+        assert_eq!(h, Some(state.hamiltonian()));
+        assert_eq!(eval_const(&call("seal", &[]), &state), Some(0.0));
+
+        state.gamma = 1e-10;
+        assert_eq!(eval_const(&call("seal", &[]), &state), Some(1.0));
+    }
+
+    #[test]
+    fn test_unknown_arguments_not_folded() {
+        let state = Z3State::new();
+        assert_eq!(
+            eval_const(&call("emergence", &["x", "phi", "gamma"]), &state),
+            None
+        );
+        assert_eq!(eval_const(&call("hamiltonian", &["lambda"]), &state), None);
+        assert_eq!(eval_const(&call("mutate", &[]), &state), None);
+    }
+}
@@ -0,0 +1,203 @@
+//! Hamiltonian Coefficients
+//!
+//! Coefficients of the H_CRSM terms emitted by `generate_omega_ir`, loadable
+//! from a TOML table:
+//!
+//! ```toml
+//! [hamiltonian]
+//! coherence_gradient = 1.0       # DΛ∇7D
+//! decoherence_suppression = 0.1  # KΓ
+//! duality_torsion = 1.0          # Π±Jθ
+//! sovereignty_threshold = 8.0    # Ω∞ (Ξ threshold)
+//! ```
+//!
+//! Omitted keys keep their defaults. The `[hamiltonian]` header is optional:
+//! a document without it is read as the table itself.
+
+use crate::binding::{THETA_CRITICAL, XI_THRESHOLD};
+use crate::diagnostics::{Diagnostic, Span};
+use crate::ir::HamiltonianTermIR;
+use serde::{Deserialize, Serialize};
+
+/// Keys accepted in the coefficient table
+pub const COEFFICIENT_KEYS: [&str; 4] = [
+    "coherence_gradient",
+    "decoherence_suppression",
+    "duality_torsion",
+    "sovereignty_threshold",
+];
+
+/// Coefficients of the H_CRSM terms
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HamiltonianCoefficients {
+    /// DΛ∇7D
+    pub coherence_gradient: f64,
+    /// KΓ
+    pub decoherence_suppression: f64,
+    /// Π±Jθ coupling
+    pub duality_torsion: f64,
+    /// Ξ threshold of the Ω∞ term
+    pub sovereignty_threshold: f64,
+}
+
+impl Default for HamiltonianCoefficients {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HamiltonianCoefficients {
+    pub fn new() -> Self {
+        Self {
+            coherence_gradient: 1.0,
+            decoherence_suppression: 0.1,
+            duality_torsion: 1.0,
+            sovereignty_threshold: XI_THRESHOLD,
+        }
+    }
+
+    /// Parse and validate a TOML coefficient table
+    pub fn from_toml(source: &str) -> Result<Self, Vec<Diagnostic>> {
+        let document: toml::Table = source.parse().map_err(|e: toml::de::Error| {
+            let mut diagnostic = Diagnostic::error(
+                "E0300",
+                format!("invalid coefficient TOML: {}", e.message()),
+            );
+            if let Some(range) = e.span() {
+                diagnostic = diagnostic.with_span(span_at(source, range.start, range.end));
+            }
+            vec![diagnostic]
+        })?;
+
+        let table = match document.get("hamiltonian") {
+            Some(toml::Value::Table(table)) => table,
+            _ => &document,
+        };
+
+        let mut coefficients = Self::new();
+        let mut diagnostics = Vec::new();
+        for (key, value) in table {
+            let slot = match key.as_str() {
+                "coherence_gradient" => &mut coefficients.coherence_gradient,
+                "decoherence_suppression" => &mut coefficients.decoherence_suppression,
+                "duality_torsion" => &mut coefficients.duality_torsion,
+                "sovereignty_threshold" => &mut coefficients.sovereignty_threshold,
+                _ => {
+                    diagnostics.push(
+                        Diagnostic::error("E0301", format!("unknown Hamiltonian term `{}`", key))
+                            .with_note(format!("expected one of: {}", COEFFICIENT_KEYS.join(", "))),
+                    );
+                    continue;
+                }
+            };
+            match value {
+                toml::Value::Float(v) => *slot = *v,
+                toml::Value::Integer(v) => *slot = *v as f64,
+                other => diagnostics.push(Diagnostic::error(
+                    "E0302",
+                    format!("`{}` must be a number, found {}", key, other.type_str()),
+                )),
+            }
+        }
+
+        diagnostics.extend(coefficients.validate());
+        if diagnostics.is_empty() {
+            Ok(coefficients)
+        } else {
+            Err(diagnostics)
+        }
+    }
+
+    /// Check that every coefficient is finite and in range
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        let values = [
+            ("coherence_gradient", self.coherence_gradient),
+            ("decoherence_suppression", self.decoherence_suppression),
+            ("duality_torsion", self.duality_torsion),
+        ];
+        for (key, value) in values {
+            if !value.is_finite() || value < 0.0 {
+                diagnostics.push(Diagnostic::error(
+                    "E0302",
+                    format!(
+                        "`{}` must be a finite non-negative number, found {}",
+                        key, value
+                    ),
+                ));
+            }
+        }
+        if !self.sovereignty_threshold.is_finite() || self.sovereignty_threshold <= 0.0 {
+            diagnostics.push(Diagnostic::error(
+                "E0302",
+                format!(
+                    "`sovereignty_threshold` must be a finite positive number, found {}",
+                    self.sovereignty_threshold
+                ),
+            ));
+        }
+        diagnostics
+    }
+
+    /// H_CRSM terms with these coefficients
+    pub fn hamiltonian_terms(&self) -> Vec<HamiltonianTermIR> {
+        vec![
+            HamiltonianTermIR::CoherenceGradient {
+                coefficient: self.coherence_gradient,
+            },
+            HamiltonianTermIR::DecoherenceSuppression {
+                coefficient: self.decoherence_suppression,
+            },
+            HamiltonianTermIR::DualityTorsion {
+                coefficient: self.duality_torsion,
+                theta: THETA_CRITICAL,
+            },
+            HamiltonianTermIR::Sovereignty {
+                threshold: self.sovereignty_threshold,
+            },
+        ]
+    }
+}
+
+/// Span of a byte range, with 1-based line and column of its start
+fn span_at(source: &str, start: usize, end: usize) -> Span {
+    let before = &source[..start.min(source.len())];
+    let line = before.matches('\n').count() + 1;
+    let column = before.rsplit('\n').next().map_or(0, |l| l.chars().count()) + 1;
+    Span::new(start, end, line, column)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_table_keeps_defaults() {
+        let coefficients = HamiltonianCoefficients::from_toml(
+            "[hamiltonian]\ndecoherence_suppression = 0.25\nduality_torsion = 2\n",
+        )
+        .unwrap();
+        assert_eq!(coefficients.decoherence_suppression, 0.25);
+        assert_eq!(coefficients.duality_torsion, 2.0);
+        assert_eq!(coefficients.coherence_gradient, 1.0);
+        assert_eq!(coefficients.sovereignty_threshold, XI_THRESHOLD);
+
+        // Header is optional
+        let bare = HamiltonianCoefficients::from_toml("coherence_gradient = 0.5").unwrap();
+        assert_eq!(bare.coherence_gradient, 0.5);
+    }
+
+    #[test]
+    fn test_validation_errors() {
+        let errors = HamiltonianCoefficients::from_toml(
+            "kgamma = 1.0\nduality_torsion = \"high\"\ncoherence_gradient = -1.0\nsovereignty_threshold = 0\n",
+        )
+        .unwrap_err();
+        let codes: Vec<&str> = errors.iter().map(|d| d.code.as_str()).collect();
+        assert_eq!(codes, ["E0302", "E0301", "E0302", "E0302"]);
+
+        let syntax = HamiltonianCoefficients::from_toml("coherence_gradient = ").unwrap_err();
+        assert_eq!(syntax[0].code, "E0300");
+        assert_eq!(syntax[0].spans[0].line, 1);
+    }
+}
@@ -0,0 +1,166 @@
+//! IR Contract Fixtures
+//!
+//! Shared fixtures for the contract between the compiler (IR producer) and
+//! the runtime (IR consumer). Each fixture is a small program whose compiled
+//! IR exercises specific constructs; together they cover every gene op type,
+//! Hamiltonian term and collapse rule. The compiler's tests check that the
+//! fixtures compile and cover `all_constructs()`; the runtime's tests check
+//! that it executes every fixture.
+//!
+//! Construct names are derived by exhaustive matches, so adding an IR variant
+//! fails to compile here until it is named and given a fixture.
+
+use crate::ir::{CollapseActionIR, CollapseConditionIR, GeneOpType, HamiltonianTermIR, OmegaIR};
+use crate::parser::parse;
+use std::collections::BTreeSet;
+
+/// A named source program used as contract fixture
+#[derive(Debug, Clone, Copy)]
+pub struct ContractFixture {
+    pub name: &'static str,
+    pub source: &'static str,
+}
+
+/// Every contract fixture
+pub const FIXTURES: &[ContractFixture] = &[
+    ContractFixture {
+        name: "emit_payloads",
+        source: r#"organism Emitter {
+    gene text { emit "hello" }
+    gene bytes { emit bytes "00ff" }
+    gene json { emit json "{\"xi\": 8}" }
+}"#,
+    },
+    ContractFixture {
+        name: "bifurcate_and_seal",
+        source: r#"organism Dual {
+    field psi: coherence
+    gene split { bifurcate psi }
+    gene seal { sovereign }
+    gene empty { }
+}"#,
+    },
+    ContractFixture {
+        name: "calls",
+        source: r#"organism Caller {
+    field psi: coherence
+    gene folded { hamiltonian() }
+    gene runtime { emergence(psi, phi, gamma) }
+    gene target { sovereign }
+    gene gene_call { target }
+    gene builtin_call { seal }
+}"#,
+    },
+];
+
+impl ContractFixture {
+    /// Compile the fixture to IR; panics if the fixture does not parse
+    pub fn compile(&self) -> OmegaIR {
+        let parsed = parse(self.source);
+        assert!(
+            !parsed.has_errors(),
+            "contract fixture `{}` does not parse: {:?}",
+            self.name,
+            parsed.diagnostics
+        );
+        crate::binding::generate_omega_ir(&parsed.dna, &parsed.crsm)
+    }
+}
+
+/// Construct name of a gene op
+pub fn gene_op_construct(op: &GeneOpType) -> &'static str {
+    match op {
+        GeneOpType::Emit(_) => "gene_op:emit",
+        GeneOpType::Bifurcate => "gene_op:bifurcate",
+        GeneOpType::Sovereign => "gene_op:sovereign",
+        GeneOpType::Call(..) => "gene_op:call",
+        GeneOpType::Folded { .. } => "gene_op:folded",
+    }
+}
+
+/// Construct name of a Hamiltonian term
+pub fn hamiltonian_construct(term: &HamiltonianTermIR) -> &'static str {
+    match term {
+        HamiltonianTermIR::CoherenceGradient { .. } => "hamiltonian:coherence_gradient",
+        HamiltonianTermIR::DecoherenceSuppression { .. } => "hamiltonian:decoherence_suppression",
+        HamiltonianTermIR::DualityTorsion { .. } => "hamiltonian:duality_torsion",
+        HamiltonianTermIR::Sovereignty { .. } => "hamiltonian:sovereignty",
+    }
+}
+
+/// Construct names of a collapse rule's condition and action
+pub fn collapse_constructs(
+    condition: &CollapseConditionIR,
+    action: &CollapseActionIR,
+) -> [&'static str; 2] {
+    let condition = match condition {
+        CollapseConditionIR::GammaToZero { .. } => "collapse:gamma_to_zero",
+        CollapseConditionIR::LambdaPhiMax { .. } => "collapse:lambda_phi_max",
+    };
+    let action = match action {
+        CollapseActionIR::ApplyProjector => "collapse:apply_projector",
+        CollapseActionIR::SealSovereignty => "collapse:seal_sovereignty",
+    };
+    [condition, action]
+}
+
+/// Constructs used by an IR
+pub fn constructs(ir: &OmegaIR) -> BTreeSet<&'static str> {
+    let mut used = BTreeSet::new();
+    used.extend(ir.gene_ops.iter().map(|op| gene_op_construct(&op.op_type)));
+    used.extend(
+        ir.evolution
+            .hamiltonian_terms
+            .iter()
+            .map(hamiltonian_construct),
+    );
+    for rule in &ir.collapse_rules {
+        used.extend(collapse_constructs(&rule.condition, &rule.action));
+    }
+    used
+}
+
+/// Every construct the IR can express
+pub fn all_constructs() -> BTreeSet<&'static str> {
+    [
+        "gene_op:emit",
+        "gene_op:bifurcate",
+        "gene_op:sovereign",
+        "gene_op:call",
+        "gene_op:folded",
+        "hamiltonian:coherence_gradient",
+        "hamiltonian:decoherence_suppression",
+        "hamiltonian:duality_torsion",
+        "hamiltonian:sovereignty",
+        "collapse:gamma_to_zero",
+        "collapse:lambda_phi_max",
+        "collapse:apply_projector",
+        "collapse:seal_sovereignty",
+    ]
+    .into_iter()
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fixtures_cover_every_construct() {
+        let covered: BTreeSet<&str> = FIXTURES
+            .iter()
+            .flat_map(|fixture| constructs(&fixture.compile()))
+            .collect();
+        assert_eq!(covered, all_constructs());
+    }
+
+    #[test]
+    fn test_fixtures_roundtrip_json() {
+        for fixture in FIXTURES {
+            let ir = fixture.compile();
+            let json = serde_json::to_string(&ir).unwrap();
+            let back: OmegaIR = serde_json::from_str(&json).unwrap();
+            assert!(ir.diff(&back).is_empty(), "{}", fixture.name);
+        }
+    }
+}
@@ -0,0 +1,235 @@
+//! Compiler Diagnostics
+//!
+//! Structured diagnostics produced while compiling dna::}{::lang and
+//! 7dCRSM::}{::lang programs.
+//!
+//! Every diagnostic carries a stable code, a severity, a message, the source
+//! spans it refers to and optional fix-its. Diagnostics can be rendered as
+//! plain text or, with `DiagnosticFormat::Json`, as a JSON document that IDE
+//! plugins and CI wrappers can consume directly.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Diagnostic severity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+    Note,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Note => "note",
+        }
+    }
+}
+
+/// Source span: byte offsets plus 1-based line/column of the start
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize, line: usize, column: usize) -> Self {
+        Self {
+            start,
+            end,
+            line,
+            column,
+        }
+    }
+
+    /// True for spans of nodes that were not produced by the parser
+    pub fn is_dummy(&self) -> bool {
+        self.line == 0
+    }
+}
+
+/// Suggested source edit attached to a diagnostic
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct FixIt {
+    /// Human readable description of the edit
+    pub message: String,
+    /// Text to insert in place of `span`
+    pub replacement: String,
+    /// Region to replace (None when the AST carries no source location)
+    pub span: Option<Span>,
+}
+
+/// A single compiler diagnostic
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Diagnostic {
+    /// Stable diagnostic code, e.g. `E0001`
+    pub code: String,
+    pub severity: Severity,
+    pub message: String,
+    /// Source regions the diagnostic refers to (primary span first)
+    pub spans: Vec<Span>,
+    pub fixits: Vec<FixIt>,
+    /// Additional explanatory notes
+    pub notes: Vec<String>,
+}
+
+impl Diagnostic {
+    pub fn new(code: &str, severity: Severity, message: impl Into<String>) -> Self {
+        Self {
+            code: code.to_string(),
+            severity,
+            message: message.into(),
+            spans: Vec::new(),
+            fixits: Vec::new(),
+            notes: Vec::new(),
+        }
+    }
+
+    pub fn error(code: &str, message: impl Into<String>) -> Self {
+        Self::new(code, Severity::Error, message)
+    }
+
+    pub fn warning(code: &str, message: impl Into<String>) -> Self {
+        Self::new(code, Severity::Warning, message)
+    }
+
+    pub fn with_span(mut self, span: Span) -> Self {
+        self.spans.push(span);
+        self
+    }
+
+    pub fn with_fixit(mut self, fixit: FixIt) -> Self {
+        self.fixits.push(fixit);
+        self
+    }
+
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+
+    /// Render as a single plain-text block
+    pub fn to_text(&self) -> String {
+        let mut out = format!(
+            "{}[{}]: {}",
+            self.severity.as_str(),
+            self.code,
+            self.message
+        );
+        if let Some(span) = self.spans.first() {
+            out.push_str(&format!("\n  --> {}:{}", span.line, span.column));
+        }
+        for note in &self.notes {
+            out.push_str(&format!("\n  = note: {}", note));
+        }
+        for fixit in &self.fixits {
+            out.push_str(&format!("\n  = help: {}", fixit.message));
+        }
+        out
+    }
+}
+
+/// Output format for rendered diagnostics
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DiagnosticFormat {
+    /// Plain text, one block per diagnostic
+    #[default]
+    Text,
+    /// JSON document: `{ "diagnostics": [...], "errors": n, "warnings": n }`
+    Json,
+}
+
+/// JSON envelope for machine consumers
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DiagnosticReport {
+    pub diagnostics: Vec<Diagnostic>,
+    pub errors: usize,
+    pub warnings: usize,
+}
+
+impl DiagnosticReport {
+    pub fn new(diagnostics: &[Diagnostic]) -> Self {
+        let count = |severity| {
+            diagnostics
+                .iter()
+                .filter(|d| d.severity == severity)
+                .count()
+        };
+        Self {
+            errors: count(Severity::Error),
+            warnings: count(Severity::Warning),
+            diagnostics: diagnostics.to_vec(),
+        }
+    }
+}
+
+/// Render diagnostics in the requested format
+pub fn render_diagnostics(diagnostics: &[Diagnostic], format: DiagnosticFormat) -> String {
+    match format {
+        DiagnosticFormat::Text => diagnostics
+            .iter()
+            .map(Diagnostic::to_text)
+            .collect::<Vec<_>>()
+            .join("\n\n"),
+        DiagnosticFormat::Json => serde_json::to_string(&DiagnosticReport::new(diagnostics))
+            .expect("diagnostics are always serializable"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Diagnostic {
+        Diagnostic::error("E0001", "unknown field type `coherense`")
+            .with_span(Span::new(10, 19, 2, 15))
+            .with_fixit(FixIt {
+                message: "did you mean `coherence`?".to_string(),
+                replacement: "coherence".to_string(),
+                span: Some(Span::new(10, 19, 2, 15)),
+            })
+    }
+
+    #[test]
+    fn test_text_rendering() {
+        let text = render_diagnostics(&[sample()], DiagnosticFormat::Text);
+        assert!(text.starts_with("error[E0001]: unknown field type"));
+        assert!(text.contains("--> 2:15"));
+        assert!(text.contains("help: did you mean `coherence`?"));
+    }
+
+    #[test]
+    fn test_json_rendering() {
+        let diagnostics = vec![sample(), Diagnostic::warning("W0001", "unbound field")];
+        let json = render_diagnostics(&diagnostics, DiagnosticFormat::Json);
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(value["errors"], 1);
+        assert_eq!(value["warnings"], 1);
+        assert_eq!(value["diagnostics"][0]["code"], "E0001");
+        assert_eq!(value["diagnostics"][0]["severity"], "error");
+        assert_eq!(value["diagnostics"][0]["spans"][0]["line"], 2);
+        assert_eq!(
+            value["diagnostics"][0]["fixits"][0]["replacement"],
+            "coherence"
+        );
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let json = render_diagnostics(&[sample()], DiagnosticFormat::Json);
+        let report: DiagnosticReport = serde_json::from_str(&json).unwrap();
+        assert_eq!(report.diagnostics[0], sample());
+    }
+}
@@ -102,12 +102,14 @@ impl DualityPass {
                         ops.push(GeneOp {
                             name: gene.name.clone(),
                             connection_index: idx,
+                            annotations: gene.annotations.clone(),
                             op_type: GeneOpType::Bifurcate,
                         });
 
                         ops.push(GeneOp {
                             name: format!("{}:bifurcate:{}", gene.name, target),
                             connection_index: idx,
+                            annotations: gene.annotations.clone(),
                             op_type: GeneOpType::Bifurcate,
                         });
                     }
@@ -115,6 +117,7 @@ impl DualityPass {
                         ops.push(GeneOp {
                             name: gene.name.clone(),
                             connection_index: idx,
+                            annotations: gene.annotations.clone(),
                             op_type: GeneOpType::Sovereign,
                         });
                     }
@@ -122,6 +125,7 @@ impl DualityPass {
                         ops.push(GeneOp {
                             name: gene.name.clone(),
                             connection_index: idx,
+                            annotations: gene.annotations.clone(),
                             op_type: GeneOpType::Emit(s.clone()),
                         });
                     }
@@ -136,6 +140,7 @@ impl DualityPass {
                         ops.push(GeneOp {
                             name: gene.name.clone(),
                             connection_index: idx,
+                            annotations: gene.annotations.clone(),
                             op_type: GeneOpType::Call(func.clone(), arg_names),
                         });
                     }
@@ -176,6 +181,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn test_involution_j_squared() {
         let psi = 3.14159;
         assert_eq!(involution_j(involution_j(psi)), psi);
@@ -0,0 +1,198 @@
+//! Gene Inlining Pass
+//!
+//! Replaces a call to another gene of the same organism with the callee's
+//! body when that body (after its own inlining) has at most `max_size`
+//! expressions:
+//!
+//! ```text
+//! gene main { step() }        gene main { emit "tick" sovereign }
+//! gene step { emit "tick"  →  gene step { emit "tick"
+//!             sovereign }                 sovereign }
+//! ```
+//!
+//! Calls with arguments, builtin calls and recursive cycles are left as
+//! they are. `@inline("never")` on a gene keeps every call to it;
+//! `@inline("always")` inlines it regardless of size.
+
+use crate::ast::{DnaProgram, Expr, Gene, Organism};
+use crate::builtins::is_builtin;
+use std::collections::{HashMap, HashSet};
+
+/// Inlining pass state
+#[derive(Debug, Clone)]
+pub struct InlinePass {
+    /// Largest callee body (in expressions) that is inlined
+    pub max_size: usize,
+    /// Inlined call sites as (organism, caller, callee)
+    pub inlined: Vec<(String, String, String)>,
+}
+
+impl Default for InlinePass {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InlinePass {
+    pub fn new() -> Self {
+        Self {
+            max_size: 8,
+            inlined: Vec::new(),
+        }
+    }
+
+    pub fn with_max_size(mut self, max_size: usize) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Run the pass, returning the transformed program
+    pub fn run(&mut self, program: &DnaProgram) -> DnaProgram {
+        let mut result = program.clone();
+        for organism in &mut result.organisms {
+            self.process_organism(organism);
+        }
+        result
+    }
+
+    fn process_organism(&mut self, organism: &mut Organism) {
+        let genes: HashMap<&str, &Gene> = organism
+            .genes
+            .iter()
+            .map(|gene| (gene.name.as_str(), gene))
+            .collect();
+
+        let mut expanded = HashMap::new();
+        let mut sites = Vec::new();
+        for gene in &organism.genes {
+            let mut stack = HashSet::new();
+            self.expand(&gene.name, &genes, &mut expanded, &mut stack, &mut sites);
+        }
+
+        let bodies: Vec<Vec<Expr>> = organism
+            .genes
+            .iter()
+            .map(|gene| expanded[gene.name.as_str()].clone())
+            .collect();
+        for (gene, body) in organism.genes.iter_mut().zip(bodies) {
+            gene.body = body;
+        }
+        self.inlined.extend(
+            sites
+                .into_iter()
+                .map(|(caller, callee)| (organism.name.clone(), caller, callee)),
+        );
+    }
+
+    /// Inlined body of `name`, memoized; `stack` holds the genes being expanded
+    fn expand<'a>(
+        &self,
+        name: &'a str,
+        genes: &HashMap<&'a str, &'a Gene>,
+        expanded: &mut HashMap<&'a str, Vec<Expr>>,
+        stack: &mut HashSet<&'a str>,
+        sites: &mut Vec<(String, String)>,
+    ) -> Vec<Expr> {
+        if let Some(body) = expanded.get(name) {
+            return body.clone();
+        }
+        let gene = genes[name];
+        stack.insert(name);
+
+        let mut body = Vec::with_capacity(gene.body.len());
+        for expr in &gene.body {
+            let callee = match expr {
+                Expr::Call(callee, args) if args.is_empty() && !is_builtin(callee) => {
+                    genes.get_key_value(callee.as_str())
+                }
+                _ => None,
+            };
+            match callee {
+                // Recursive calls stay as calls
+                Some((&callee, callee_gene)) if !stack.contains(callee) => {
+                    let inlined = self.expand(callee, genes, expanded, stack, sites);
+                    if self.should_inline(callee_gene, &inlined) {
+                        sites.push((name.to_string(), callee.to_string()));
+                        body.extend(inlined);
+                    } else {
+                        body.push(expr.clone());
+                    }
+                }
+                _ => body.push(expr.clone()),
+            }
+        }
+
+        stack.remove(name);
+        expanded.insert(name, body.clone());
+        body
+    }
+
+    fn should_inline(&self, callee: &Gene, body: &[Expr]) -> bool {
+        match callee.annotations.get("inline").map(String::as_str) {
+            Some("never") => false,
+            Some("always") => true,
+            _ => body.len() <= self.max_size,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gene(name: &str, body: Vec<Expr>) -> Gene {
+        let mut gene = Gene::new(name);
+        gene.body = body;
+        gene
+    }
+
+    fn call(name: &str) -> Expr {
+        Expr::Call(name.to_string(), vec![])
+    }
+
+    fn program(genes: Vec<Gene>) -> DnaProgram {
+        let mut organism = Organism::new("Test");
+        organism.genes = genes;
+        let mut program = DnaProgram::new();
+        program.add_organism(organism);
+        program
+    }
+
+    #[test]
+    fn test_transitive_inlining() {
+        let program = program(vec![
+            gene("main", vec![call("step"), Expr::Sovereign]),
+            gene("step", vec![call("tick")]),
+            gene("tick", vec![Expr::Emit("tick".into())]),
+        ]);
+        let mut pass = InlinePass::new();
+        let result = pass.run(&program);
+        let main = &result.organisms[0].genes[0];
+        assert!(matches!(
+            main.body.as_slice(),
+            [Expr::Emit(p), Expr::Sovereign] if p.as_text() == Some("tick")
+        ));
+        assert_eq!(pass.inlined.len(), 2);
+    }
+
+    #[test]
+    fn test_threshold_recursion_and_annotations() {
+        let mut pinned = gene("pinned", vec![Expr::Sovereign]);
+        pinned
+            .annotations
+            .insert("inline".to_string(), "never".to_string());
+        let program = program(vec![
+            gene("main", vec![call("big"), call("pinned"), call("main")]),
+            gene("big", vec![Expr::Sovereign, Expr::Sovereign]),
+            pinned,
+        ]);
+
+        let result = InlinePass::new().with_max_size(1).run(&program);
+        let main = &result.organisms[0].genes[0];
+        assert!(matches!(
+            main.body.as_slice(),
+            [Expr::Call(a, _), Expr::Call(b, _), Expr::Call(c, _)]
+                if a == "big" && b == "pinned" && c == "main"
+        ));
+    }
+}
@@ -0,0 +1,252 @@
+//! Omega IR Diff
+//!
+//! Structured comparison of two `OmegaIR`s for golden-file tests, cache
+//! validation and reviewing what an optimization pass changed.
+//!
+//! Entries are matched by identity where one exists:
+//! - gene ops by (name, occurrence), since passes may emit several ops per gene
+//! - field coordinates by field name
+//! - Hamiltonian terms and collapse rules by position
+
+use super::omega_ir::{CollapseRuleIR, FieldCoord, GeneOp, HamiltonianTermIR, OmegaIR, Z3StateIR};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// A single change between two IRs
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Change<T> {
+    Added(T),
+    Removed(T),
+    Modified { before: T, after: T },
+}
+
+/// Structured difference between two IRs
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IrDiff {
+    pub organism: Option<Change<String>>,
+    pub organism_annotations: Option<Change<BTreeMap<String, BTreeMap<String, String>>>>,
+    pub z3_state: Option<Change<Z3StateIR>>,
+    pub gene_ops: Vec<Change<GeneOp>>,
+    pub field_coords: Vec<Change<FieldCoord>>,
+    pub hamiltonian_terms: Vec<Change<HamiltonianTermIR>>,
+    pub dt: Option<Change<f64>>,
+    pub collapse_rules: Vec<Change<CollapseRuleIR>>,
+}
+
+impl IrDiff {
+    /// True if the IRs are structurally equal
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Total number of changes
+    pub fn len(&self) -> usize {
+        usize::from(self.organism.is_some())
+            + usize::from(self.organism_annotations.is_some())
+            + usize::from(self.z3_state.is_some())
+            + self.gene_ops.len()
+            + self.field_coords.len()
+            + self.hamiltonian_terms.len()
+            + usize::from(self.dt.is_some())
+            + self.collapse_rules.len()
+    }
+}
+
+impl OmegaIR {
+    /// Structured diff from `self` (before) to `other` (after)
+    pub fn diff(&self, other: &OmegaIR) -> IrDiff {
+        IrDiff {
+            organism: modified(&self.organism, &other.organism),
+            organism_annotations: modified(
+                &self.organism_annotations,
+                &other.organism_annotations,
+            ),
+            z3_state: modified(&self.z3_state, &other.z3_state),
+            gene_ops: diff_keyed(&self.gene_ops, &other.gene_ops, gene_op_keys),
+            field_coords: diff_keyed(&self.field_coords, &other.field_coords, |coords| {
+                coords.iter().map(|c| c.field_name.clone()).collect()
+            }),
+            hamiltonian_terms: diff_positional(
+                &self.evolution.hamiltonian_terms,
+                &other.evolution.hamiltonian_terms,
+            ),
+            dt: modified(&self.evolution.dt, &other.evolution.dt),
+            collapse_rules: diff_positional(&self.collapse_rules, &other.collapse_rules),
+        }
+    }
+}
+
+fn modified<T: Clone + PartialEq>(before: &T, after: &T) -> Option<Change<T>> {
+    (before != after).then(|| Change::Modified {
+        before: before.clone(),
+        after: after.clone(),
+    })
+}
+
+/// Keys "name#n" for the n-th op of each gene
+fn gene_op_keys(ops: &[GeneOp]) -> Vec<String> {
+    ops.iter()
+        .enumerate()
+        .map(|(idx, op)| {
+            let nth = ops[..idx].iter().filter(|o| o.name == op.name).count();
+            format!("{}#{}", op.name, nth)
+        })
+        .collect()
+}
+
+fn diff_keyed<T: Clone + PartialEq>(
+    before: &[T],
+    after: &[T],
+    keys: impl Fn(&[T]) -> Vec<String>,
+) -> Vec<Change<T>> {
+    let (before_keys, after_keys) = (keys(before), keys(after));
+    let mut changes = Vec::new();
+
+    for (key, old) in before_keys.iter().zip(before) {
+        match after_keys.iter().position(|k| k == key) {
+            Some(idx) => changes.extend(modified(old, &after[idx])),
+            None => changes.push(Change::Removed(old.clone())),
+        }
+    }
+    for (key, new) in after_keys.iter().zip(after) {
+        if !before_keys.contains(key) {
+            changes.push(Change::Added(new.clone()));
+        }
+    }
+    changes
+}
+
+fn diff_positional<T: Clone + PartialEq>(before: &[T], after: &[T]) -> Vec<Change<T>> {
+    let mut changes: Vec<Change<T>> = before
+        .iter()
+        .zip(after)
+        .filter_map(|(old, new)| modified(old, new))
+        .collect();
+    changes.extend(
+        before
+            .iter()
+            .skip(after.len())
+            .cloned()
+            .map(Change::Removed),
+    );
+    changes.extend(after.iter().skip(before.len()).cloned().map(Change::Added));
+    changes
+}
+
+fn write_changes<T: fmt::Debug>(
+    f: &mut fmt::Formatter<'_>,
+    section: &str,
+    changes: &[Change<T>],
+) -> fmt::Result {
+    for change in changes {
+        match change {
+            Change::Added(new) => writeln!(f, "+ {}: {:?}", section, new)?,
+            Change::Removed(old) => writeln!(f, "- {}: {:?}", section, old)?,
+            Change::Modified { before, after } => {
+                writeln!(f, "~ {}: {:?}\n  → {:?}", section, before, after)?
+            }
+        }
+    }
+    Ok(())
+}
+
+impl fmt::Display for IrDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_changes(f, "organism", self.organism.as_slice())?;
+        write_changes(
+            f,
+            "organism_annotations",
+            self.organism_annotations.as_slice(),
+        )?;
+        write_changes(f, "z3_state", self.z3_state.as_slice())?;
+        write_changes(f, "gene_op", &self.gene_ops)?;
+        write_changes(f, "field_coord", &self.field_coords)?;
+        write_changes(f, "hamiltonian_term", &self.hamiltonian_terms)?;
+        write_changes(f, "dt", self.dt.as_slice())?;
+        write_changes(f, "collapse_rule", &self.collapse_rules)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::CrsmProgram;
+    use crate::builder::{FieldKind, OrganismBuilder};
+    use crate::binding::generate_omega_ir;
+    use crate::ir::GeneOpType;
+
+    fn sample_ir() -> OmegaIR {
+        let dna = OrganismBuilder::new("CRSM7")
+            .field("lambda", FieldKind::Coherence)
+            .field("gamma", FieldKind::Decoherence)
+            .gene("main", |g| g)
+            .program();
+        generate_omega_ir(&dna, &CrsmProgram::new())
+    }
+
+    #[test]
+    fn test_identical_ir_has_empty_diff() {
+        let ir = sample_ir();
+        let diff = ir.diff(&ir.clone());
+        assert!(diff.is_empty());
+        assert_eq!(diff.to_string(), "");
+    }
+
+    #[test]
+    fn test_structured_changes() {
+        let before = sample_ir();
+        let mut after = before.clone();
+        after.gene_ops[0].op_type = GeneOpType::Bifurcate;
+        after.field_coords.remove(1);
+        after.evolution.hamiltonian_terms.pop();
+        after.evolution.dt = 0.02;
+
+        let diff = before.diff(&after);
+        assert_eq!(diff.len(), 4);
+        assert!(matches!(
+            &diff.gene_ops[0],
+            Change::Modified { after, .. } if after.op_type == GeneOpType::Bifurcate
+        ));
+        assert!(matches!(&diff.field_coords[0], Change::Removed(c) if c.field_name == "gamma"));
+        assert!(matches!(diff.hamiltonian_terms[0], Change::Removed(_)));
+        assert_eq!(
+            diff.dt,
+            Some(Change::Modified {
+                before: 0.01,
+                after: 0.02
+            })
+        );
+    }
+
+    #[test]
+    fn test_repeated_gene_ops_matched_by_occurrence() {
+        let before = sample_ir();
+        let mut after = before.clone();
+        let mut extra = after.gene_ops[0].clone();
+        extra.op_type = GeneOpType::Sovereign;
+        after.gene_ops.push(extra);
+
+        let diff = before.diff(&after);
+        assert_eq!(diff.gene_ops.len(), 1);
+        assert!(matches!(diff.gene_ops[0], Change::Added(_)));
+        assert!(diff.to_string().starts_with("+ gene_op"));
+    }
+
+    #[test]
+    fn test_annotation_only_change_is_not_empty() {
+        let before = sample_ir();
+        let mut after = before.clone();
+        after
+            .organism_annotations
+            .entry("CRSM7".to_string())
+            .or_default()
+            .insert("author".to_string(), "enki".to_string());
+
+        let diff = before.diff(&after);
+        assert!(!diff.is_empty());
+        assert_eq!(diff.len(), 1);
+        assert!(diff.organism_annotations.is_some());
+        assert!(diff.to_string().starts_with("~ organism_annotations"));
+    }
+}
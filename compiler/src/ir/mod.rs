@@ -2,9 +2,12 @@
 //!
 //! Re-exports Omega IR types
 
+pub mod diff;
 pub mod omega_ir;
 
+pub use diff::{Change, IrDiff};
+
 pub use omega_ir::{
-    CollapseActionIR, CollapseConditionIR, CollapseRuleIR, EvolutionIR, FieldCoord,
-    GeneOp, GeneOpType, HamiltonianTermIR, OmegaIR, Z3StateIR,
+    CollapseActionIR, CollapseConditionIR, CollapseRuleIR, EvolutionIR, FieldCoord, GeneOp,
+    GeneOpType, HamiltonianTermIR, MetricIR, OmegaIR, Z3StateIR,
 };
@@ -3,11 +3,18 @@
 //! Unified IR that bridges dna::}{::lang and 7dCRSM::}{::lang
 //! after the Ω_bind operation fuses them into a single execution model.
 
+use crate::ast::Payload;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// The unified Omega IR representation after binding
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct OmegaIR {
+    /// Name of the program's (first) organism; empty in IR produced
+    /// before the name was carried
+    #[serde(default)]
+    pub organism: String,
     /// Bound state from Z3 binding operation
     pub z3_state: Z3StateIR,
     /// Gene operations mapped to covariant derivatives
@@ -18,6 +25,9 @@ pub struct OmegaIR {
     pub evolution: EvolutionIR,
     /// Collapse rules
     pub collapse_rules: Vec<CollapseRuleIR>,
+    /// Organism annotations, keyed by organism name
+    #[serde(default)]
+    pub organism_annotations: BTreeMap<String, BTreeMap<String, String>>,
 }
 
 impl Default for OmegaIR {
@@ -29,25 +39,29 @@ impl Default for OmegaIR {
 impl OmegaIR {
     pub fn new() -> Self {
         Self {
+            organism: String::new(),
             z3_state: Z3StateIR::default(),
             gene_ops: Vec::new(),
             field_coords: Vec::new(),
             evolution: EvolutionIR::default(),
             collapse_rules: Vec::new(),
+            organism_annotations: BTreeMap::new(),
         }
     }
 }
 
 /// Z3 State in IR form
 /// Contains the bound quantum state and 7D metric
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct Z3StateIR {
     /// Complex amplitude (real part)
     pub psi_real: f64,
     /// Complex amplitude (imaginary part)
     pub psi_imag: f64,
-    /// 7D metric tensor (diagonal approximation)
-    pub metric_diag: [f64; 7],
+    /// 7D metric tensor g_{μν} (IR produced before the full metric used
+    /// `metric_diag`, which still loads as a diagonal metric)
+    #[serde(alias = "metric_diag")]
+    pub metric: MetricIR,
     /// 7D gradient vector
     pub nabla_7d: [f64; 7],
     /// Decoherence parameter
@@ -65,7 +79,7 @@ impl Default for Z3StateIR {
         Self {
             psi_real: 1.0,
             psi_imag: 0.0,
-            metric_diag: [1.0, 1.0, 1.0, 0.0, 0.0, -1.0, 0.0],
+            metric: MetricIR::Diagonal([1.0, 1.0, 1.0, 0.0, 0.0, -1.0, 0.0]),
             nabla_7d: [0.0; 7],
             gamma: 0.012,
             lambda: 0.869,
@@ -75,33 +89,117 @@ impl Default for Z3StateIR {
     }
 }
 
+/// 7×7 metric tensor, stored as its diagonal when off-diagonal terms vanish
+///
+/// Compiler-side only: it records the metric Ω_bind produced, for tooling
+/// and IR diffs. The runtime does not read it; `CRSM7State::metric`
+/// derives g from the live state.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum MetricIR {
+    /// diag(g_00, ..., g_66)
+    Diagonal([f64; 7]),
+    /// Full g_{μν}
+    Full(Box<[[f64; 7]; 7]>),
+}
+
+impl MetricIR {
+    /// Build from a full matrix, using the diagonal form when possible
+    pub fn from_matrix(matrix: [[f64; 7]; 7]) -> Self {
+        let off_diagonal = (0..7).any(|i| (0..7).any(|j| i != j && matrix[i][j] != 0.0));
+        if off_diagonal {
+            MetricIR::Full(Box::new(matrix))
+        } else {
+            MetricIR::Diagonal(std::array::from_fn(|i| matrix[i][i]))
+        }
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        matches!(self, MetricIR::Diagonal(_))
+    }
+
+    /// Component g_{μν}
+    pub fn get(&self, mu: usize, nu: usize) -> f64 {
+        match self {
+            MetricIR::Diagonal(diag) if mu == nu => diag[mu],
+            MetricIR::Diagonal(_) => 0.0,
+            MetricIR::Full(matrix) => matrix[mu][nu],
+        }
+    }
+
+    /// Diagonal components
+    pub fn diagonal(&self) -> [f64; 7] {
+        std::array::from_fn(|i| self.get(i, i))
+    }
+
+    pub fn to_matrix(&self) -> [[f64; 7]; 7] {
+        std::array::from_fn(|mu| std::array::from_fn(|nu| self.get(mu, nu)))
+    }
+
+    /// det(g): product of the diagonal, or Gaussian elimination for a full metric
+    pub fn determinant(&self) -> f64 {
+        let mut m = match self {
+            MetricIR::Diagonal(diag) => return diag.iter().product(),
+            MetricIR::Full(matrix) => **matrix,
+        };
+
+        let mut det = 1.0;
+        for col in 0..7 {
+            let pivot = (col..7)
+                .max_by(|&a, &b| m[a][col].abs().total_cmp(&m[b][col].abs()))
+                .unwrap_or(col);
+            if m[pivot][col] == 0.0 {
+                return 0.0;
+            }
+            if pivot != col {
+                m.swap(pivot, col);
+                det = -det;
+            }
+            det *= m[col][col];
+            let pivot_row = m[col];
+            for row in m.iter_mut().skip(col + 1) {
+                let factor = row[col] / pivot_row[col];
+                for (dst, src) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                    *dst -= factor * src;
+                }
+            }
+        }
+        det
+    }
+}
+
 /// Gene operation mapped to covariant derivative
 /// gene_i → ∂_A Ψ
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct GeneOp {
     pub name: String,
     /// Index in the connection form
     pub connection_index: usize,
     /// Type of operation
     pub op_type: GeneOpType,
+    /// Annotations of the source gene
+    #[serde(default)]
+    pub annotations: BTreeMap<String, String>,
 }
 
 /// Types of gene operations
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub enum GeneOpType {
-    /// Emit string output
-    Emit(String),
+    /// Emit a typed payload to the runtime sinks
+    Emit(Payload),
     /// Bifurcate into Π+ and Π-
     Bifurcate,
     /// Mark as sovereign
     Sovereign,
     /// Call another function
     Call(String, Vec<String>),
+    /// Builtin call evaluated at compile time; the runtime uses `value`
+    Folded { builtin: String, value: f64 },
 }
 
 /// Field coordinate mapping
 /// field f_j → coordinate_j ∈ M⁷
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct FieldCoord {
     pub field_name: String,
     pub coord_index: usize,
@@ -110,7 +208,7 @@ pub struct FieldCoord {
 
 /// Evolution equations in IR form
 /// ∂τΨ = H_CRSM Ψ
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct EvolutionIR {
     /// Hamiltonian terms
     pub hamiltonian_terms: Vec<HamiltonianTermIR>,
@@ -128,7 +226,7 @@ impl Default for EvolutionIR {
 }
 
 /// Hamiltonian term in IR
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub enum HamiltonianTermIR {
     /// DΛ∇7D - coherence gradient coupling
     CoherenceGradient { coefficient: f64 },
@@ -141,14 +239,14 @@ pub enum HamiltonianTermIR {
 }
 
 /// Collapse rule in IR
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct CollapseRuleIR {
     pub condition: CollapseConditionIR,
     pub action: CollapseActionIR,
 }
 
 /// Collapse condition in IR
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub enum CollapseConditionIR {
     /// Γ → 0
     GammaToZero { threshold: f64 },
@@ -157,7 +255,7 @@ pub enum CollapseConditionIR {
 }
 
 /// Collapse action in IR
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub enum CollapseActionIR {
     /// Apply Π± projector
     ApplyProjector,
@@ -183,6 +281,37 @@ mod tests {
         assert_eq!(state.lambda, 0.869);
     }
 
+    #[test]
+    fn test_metric_diagonal_fast_path() {
+        let mut matrix = [[0.0; 7]; 7];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            row[i] = (i + 1) as f64;
+        }
+        let metric = MetricIR::from_matrix(matrix);
+        assert!(metric.is_diagonal());
+        assert_eq!(metric.determinant(), 5040.0);
+        assert_eq!(metric.to_matrix(), matrix);
+
+        matrix[0][1] = 0.5;
+        matrix[1][0] = 0.5;
+        let full = MetricIR::from_matrix(matrix);
+        assert!(!full.is_diagonal());
+        assert_eq!(full.get(1, 0), 0.5);
+        // Leading 2×2 block: 1·2 − 0.5² = 1.75
+        assert!((full.determinant() - 1.75 * 2520.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_metric_diag_loads_as_diagonal() {
+        let mut json = serde_json::to_value(Z3StateIR::default()).unwrap();
+        let diag = json["metric"].take();
+        json.as_object_mut().unwrap().remove("metric");
+        json["metric_diag"] = diag;
+
+        let state: Z3StateIR = serde_json::from_value(json).unwrap();
+        assert_eq!(state.metric, Z3StateIR::default().metric);
+    }
+
     #[test]
     fn test_hamiltonian_terms() {
         let term = HamiltonianTermIR::DualityTorsion {
@@ -0,0 +1,416 @@
+//! Organism Layout Export
+//!
+//! Lays out an organism for documentation diagrams and renders it as SVG:
+//! - genes, layered top to bottom along regulatory edges (gene A calls,
+//!   references or bifurcates into gene B)
+//! - fields, bound to the coordinates of M⁷ as Ω_bind maps them
+//!   (field f_j → coordinate_j, labelled with the field type's symbol)
+//!
+//! The layout is a simple layered (Sugiyama-style) one: back edges of cycles
+//! are ignored for layering, genes are placed on their longest-path layer and
+//! ordered within a layer by the barycenter of their callers.
+
+use crate::ast::{Expr, Organism};
+use crate::semantic::coordinate_symbol;
+use serde::{Deserialize, Serialize};
+
+const NODE_WIDTH: f64 = 120.0;
+const NODE_HEIGHT: f64 = 32.0;
+const H_GAP: f64 = 40.0;
+const V_GAP: f64 = 60.0;
+const MARGIN: f64 = 20.0;
+
+/// Dimension of M⁷
+const MANIFOLD_DIM: usize = 7;
+
+/// Kind of a laid out node
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NodeKind {
+    Gene,
+    Field,
+    Coordinate,
+}
+
+impl NodeKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            NodeKind::Gene => "gene",
+            NodeKind::Field => "field",
+            NodeKind::Coordinate => "coordinate",
+        }
+    }
+}
+
+/// A positioned node; (x, y) is the top-left corner
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayoutNode {
+    pub label: String,
+    pub kind: NodeKind,
+    pub x: f64,
+    pub y: f64,
+}
+
+/// An edge between two nodes (indices into `Layout::nodes`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayoutEdge {
+    pub from: usize,
+    pub to: usize,
+    pub label: Option<String>,
+}
+
+/// A computed organism layout
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Layout {
+    pub title: String,
+    pub nodes: Vec<LayoutNode>,
+    pub edges: Vec<LayoutEdge>,
+    pub width: f64,
+    pub height: f64,
+}
+
+/// Regulatory edges (caller, callee) between genes, by gene index
+pub fn regulatory_edges(organism: &Organism) -> Vec<(usize, usize)> {
+    let index_of = |name: &str| organism.genes.iter().position(|g| g.name == name);
+
+    let mut edges = Vec::new();
+    for (from, gene) in organism.genes.iter().enumerate() {
+        let mut targets = Vec::new();
+        for expr in &gene.body {
+            collect_references(expr, &mut targets);
+        }
+        for to in targets.iter().filter_map(|name| index_of(name)) {
+            if !edges.contains(&(from, to)) {
+                edges.push((from, to));
+            }
+        }
+    }
+    edges
+}
+
+fn collect_references<'a>(expr: &'a Expr, out: &mut Vec<&'a str>) {
+    match expr {
+        Expr::Ident(name) | Expr::Bifurcate(name) => out.push(name),
+        Expr::Call(name, args) => {
+            out.push(name);
+            for arg in args {
+                collect_references(arg, out);
+            }
+        }
+        Expr::Emit(_) | Expr::Sovereign => {}
+    }
+}
+
+/// Edges that close a cycle, found by depth-first search
+fn back_edges(n: usize, edges: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    // 0 = unvisited, 1 = on stack, 2 = done
+    fn visit(
+        node: usize,
+        edges: &[(usize, usize)],
+        state: &mut [u8],
+        back: &mut Vec<(usize, usize)>,
+    ) {
+        state[node] = 1;
+        for &(from, to) in edges.iter().filter(|(from, _)| *from == node) {
+            match state[to] {
+                0 => visit(to, edges, state, back),
+                1 => back.push((from, to)),
+                _ => {}
+            }
+        }
+        state[node] = 2;
+    }
+
+    let mut state = vec![0; n];
+    let mut back = Vec::new();
+    for node in 0..n {
+        if state[node] == 0 {
+            visit(node, edges, &mut state, &mut back);
+        }
+    }
+    back
+}
+
+/// Longest-path layer of each gene over the acyclic part of the graph
+fn gene_layers(n: usize, edges: &[(usize, usize)]) -> Vec<usize> {
+    let back = back_edges(n, edges);
+    let forward: Vec<_> = edges
+        .iter()
+        .filter(|e| e.0 != e.1 && !back.contains(e))
+        .collect();
+
+    let mut layer = vec![0; n];
+    // At most n - 1 relaxations are needed on a DAG
+    for _ in 0..n {
+        let mut changed = false;
+        for &&(from, to) in &forward {
+            if layer[to] < layer[from] + 1 {
+                layer[to] = layer[from] + 1;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    layer
+}
+
+/// Compute the layout of an organism
+pub fn layout_organism(organism: &Organism) -> Layout {
+    let gene_edges = regulatory_edges(organism);
+    let layers = gene_layers(organism.genes.len(), &gene_edges);
+    let layer_count = layers.iter().max().map_or(0, |max| max + 1);
+
+    // Order genes within each layer by the mean slot of their callers
+    let mut rows: Vec<Vec<usize>> = vec![Vec::new(); layer_count];
+    let mut slot = vec![0.0; organism.genes.len()];
+    for (layer, row) in rows.iter_mut().enumerate() {
+        let mut members: Vec<(f64, usize)> = (0..organism.genes.len())
+            .filter(|&g| layers[g] == layer)
+            .map(|g| {
+                let callers: Vec<f64> = gene_edges
+                    .iter()
+                    .filter(|(from, to)| *to == g && layers[*from] < layer)
+                    .map(|(from, _)| slot[*from])
+                    .collect();
+                let barycenter = if callers.is_empty() {
+                    g as f64
+                } else {
+                    callers.iter().sum::<f64>() / callers.len() as f64
+                };
+                (barycenter, g)
+            })
+            .collect();
+        members.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.cmp(&b.1)));
+        for (pos, (_, g)) in members.iter().enumerate() {
+            slot[*g] = pos as f64;
+            row.push(*g);
+        }
+    }
+
+    let bound_fields = organism.fields.len().min(MANIFOLD_DIM);
+    let widest = rows
+        .iter()
+        .map(Vec::len)
+        .chain([organism.fields.len(), MANIFOLD_DIM])
+        .max()
+        .unwrap_or(0);
+    let width = 2.0 * MARGIN + widest as f64 * (NODE_WIDTH + H_GAP) - H_GAP;
+    let row_x = |count: usize, pos: usize| {
+        let row_width = count as f64 * (NODE_WIDTH + H_GAP) - H_GAP;
+        (width - row_width) / 2.0 + pos as f64 * (NODE_WIDTH + H_GAP)
+    };
+    let row_y = |row: usize| MARGIN + row as f64 * (NODE_HEIGHT + V_GAP);
+
+    let mut nodes = Vec::new();
+    let mut gene_node = vec![0; organism.genes.len()];
+    for (r, row) in rows.iter().enumerate() {
+        for (pos, &g) in row.iter().enumerate() {
+            gene_node[g] = nodes.len();
+            nodes.push(LayoutNode {
+                label: organism.genes[g].name.clone(),
+                kind: NodeKind::Gene,
+                x: row_x(row.len(), pos),
+                y: row_y(r),
+            });
+        }
+    }
+
+    let field_row = layer_count;
+    let field_start = nodes.len();
+    for (pos, field) in organism.fields.iter().enumerate() {
+        nodes.push(LayoutNode {
+            label: format!("{} : {}", field.name, field.field_type),
+            kind: NodeKind::Field,
+            x: row_x(organism.fields.len(), pos),
+            y: row_y(field_row),
+        });
+    }
+
+    let coord_start = nodes.len();
+    for idx in 0..MANIFOLD_DIM {
+        nodes.push(LayoutNode {
+            label: format!("M⁷[{}]", idx),
+            kind: NodeKind::Coordinate,
+            x: row_x(MANIFOLD_DIM, idx),
+            y: row_y(field_row + 1),
+        });
+    }
+
+    let mut edges: Vec<LayoutEdge> = gene_edges
+        .iter()
+        .map(|&(from, to)| LayoutEdge {
+            from: gene_node[from],
+            to: gene_node[to],
+            label: None,
+        })
+        .collect();
+    edges.extend((0..bound_fields).map(|idx| LayoutEdge {
+        from: field_start + idx,
+        to: coord_start + idx,
+        label: coordinate_symbol(&organism.fields[idx].field_type).map(str::to_string),
+    }));
+
+    Layout {
+        title: organism.name.clone(),
+        nodes,
+        edges,
+        width,
+        height: row_y(field_row + 2) - V_GAP + MARGIN,
+    }
+}
+
+impl Layout {
+    /// Render the layout as a standalone SVG document
+    pub fn to_svg(&self) -> String {
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">\n",
+            w = self.width,
+            h = self.height
+        );
+        svg.push_str(&format!("<title>{}</title>\n", escape(&self.title)));
+        svg.push_str(concat!(
+            "<style>",
+            "rect{stroke:#333;stroke-width:1}",
+            ".gene{fill:#d8ecff}.field{fill:#e6f5d0}.coordinate{fill:#f2f2f2}",
+            "text{font:12px sans-serif;text-anchor:middle;dominant-baseline:middle}",
+            "line{stroke:#555;marker-end:url(#arrow)}",
+            "</style>\n",
+            "<defs><marker id=\"arrow\" viewBox=\"0 0 10 10\" refX=\"10\" refY=\"5\" ",
+            "markerWidth=\"6\" markerHeight=\"6\" orient=\"auto\">",
+            "<path d=\"M0,0 L10,5 L0,10 z\"/></marker></defs>\n",
+        ));
+
+        for edge in &self.edges {
+            let (from, to) = (&self.nodes[edge.from], &self.nodes[edge.to]);
+            let (x1, y1, x2, y2) = if from.y < to.y {
+                (from.x, from.y + NODE_HEIGHT, to.x, to.y)
+            } else {
+                // Edges within or against the layering attach to the sides
+                (
+                    from.x,
+                    from.y + NODE_HEIGHT / 2.0,
+                    to.x,
+                    to.y + NODE_HEIGHT / 2.0,
+                )
+            };
+            let (x1, x2) = (x1 + NODE_WIDTH / 2.0, x2 + NODE_WIDTH / 2.0);
+            svg.push_str(&format!(
+                "<line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\"/>\n",
+                x1, y1, x2, y2
+            ));
+            if let Some(label) = &edge.label {
+                svg.push_str(&format!(
+                    "<text x=\"{}\" y=\"{}\">{}</text>\n",
+                    (x1 + x2) / 2.0 + 8.0,
+                    (y1 + y2) / 2.0,
+                    escape(label)
+                ));
+            }
+        }
+
+        for node in &self.nodes {
+            svg.push_str(&format!(
+                "<rect class=\"{}\" x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" rx=\"6\"/>\n",
+                node.kind.as_str(),
+                node.x,
+                node.y,
+                NODE_WIDTH,
+                NODE_HEIGHT
+            ));
+            svg.push_str(&format!(
+                "<text x=\"{}\" y=\"{}\">{}</text>\n",
+                node.x + NODE_WIDTH / 2.0,
+                node.y + NODE_HEIGHT / 2.0,
+                escape(&node.label)
+            ));
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+/// Lay out an organism and render it as SVG
+pub fn organism_svg(organism: &Organism) -> String {
+    layout_organism(organism).to_svg()
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::{Field, Gene};
+
+    fn gene(name: &str, calls: &[&str]) -> Gene {
+        let mut gene = Gene::new(name);
+        for call in calls {
+            gene.body.push(Expr::Call(call.to_string(), vec![]));
+        }
+        gene
+    }
+
+    fn sample() -> Organism {
+        let mut organism = Organism::new("CRSM7_Z3MESH");
+        organism.genes.push(gene("leaf", &[]));
+        organism.genes.push(gene("main", &["mid", "leaf"]));
+        organism.genes.push(gene("mid", &["leaf", "emergence"]));
+        organism.fields.push(Field::new("lambda", "coherence"));
+        organism.fields.push(Field::new("gamma", "decoherence"));
+        organism
+    }
+
+    #[test]
+    fn test_regulatory_edges() {
+        assert_eq!(regulatory_edges(&sample()), vec![(1, 2), (1, 0), (2, 0)]);
+    }
+
+    #[test]
+    fn test_callers_above_callees() {
+        let layout = layout_organism(&sample());
+        let y = |label: &str| layout.nodes.iter().find(|n| n.label == label).unwrap().y;
+        assert!(y("main") < y("mid"));
+        assert!(y("mid") < y("leaf"));
+        assert!(y("leaf") < y("lambda : coherence"));
+        assert_eq!(layout.nodes.len(), 3 + 2 + 7);
+    }
+
+    #[test]
+    fn test_field_bindings_labelled() {
+        let layout = layout_organism(&sample());
+        let labels: Vec<_> = layout
+            .edges
+            .iter()
+            .filter_map(|e| e.label.as_deref())
+            .collect();
+        assert_eq!(labels, vec!["Λ", "Γ"]);
+    }
+
+    #[test]
+    fn test_cycles_terminate() {
+        let mut organism = Organism::new("loop");
+        organism.genes.push(gene("a", &["b"]));
+        organism.genes.push(gene("b", &["a", "b"]));
+        let layout = layout_organism(&organism);
+        assert_eq!(layout.edges.len(), 3);
+        assert!(layout.nodes[0].y < layout.nodes[1].y);
+    }
+
+    #[test]
+    fn test_svg_escapes_labels() {
+        let mut organism = sample();
+        organism.name = "A<B>".to_string();
+        let svg = organism_svg(&organism);
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains("<title>A&lt;B&gt;</title>"));
+        assert_eq!(svg.matches("<rect").count(), 12);
+        assert!(svg.trim_end().ends_with("</svg>"));
+    }
+}
@@ -8,18 +8,50 @@
 //! - AST: Abstract syntax trees for both languages
 //! - IR: Omega intermediate representation
 //! - Binding: Ω_bind operator fusing ASTs into Z3 state
+//! - Builder: Fluent construction of organisms from Rust
+//! - Builtins: Compile-time evaluation of emergence(), hamiltonian(), seal()
+//! - Coefficients: H_CRSM term coefficients loaded from TOML
+//! - Contract: IR fixtures shared with the runtime's contract tests
 //! - Duality Pass: Bifurcation and projector transformations
+//! - Inline Pass: Inlines small gene-to-gene calls (`generate_omega_ir_inlined`)
+//! - Parser: Source text → ASTs, with source spans
+//! - Semantic analysis and diagnostics (text, JSON or terminal output)
+//! - Schema: JSON Schemas of serialized programs, IR and diagnostics
+//! - Solver: Fits Γ, Λ, θ to manifold constraints
+//! - Layout: Organism diagrams exported as SVG
+//! - LSP: Language server (diagnostics, hover, definition, completion)
 
 pub mod ast;
 pub mod binding;
+pub mod builder;
+pub mod builtins;
+pub mod coefficients;
+pub mod contract;
+pub mod diagnostics;
 pub mod duality_pass;
+pub mod inline_pass;
 pub mod ir;
+pub mod layout;
+pub mod lsp;
+pub mod parser;
+pub mod schema;
+pub mod semantic;
+pub mod solver;
+pub mod terminal;
 
 // Re-exports for convenience
-pub use ast::{CrsmProgram, DnaProgram, Manifold, Organism};
-pub use binding::{generate_omega_ir, omega_bind, Z3State, GAMMA_TOLERANCE, THETA_CRITICAL, XI_THRESHOLD};
+pub use ast::{CrsmProgram, DnaProgram, Encoded, Encoding, Manifold, Organism, Payload};
+pub use binding::{generate_omega_ir, generate_omega_ir_inlined, generate_omega_ir_with, omega_bind, Z3State, GAMMA_TOLERANCE, THETA_CRITICAL, XI_THRESHOLD};
+pub use builder::{FieldKind, GeneBuilder, OrganismBuilder};
+pub use coefficients::HamiltonianCoefficients;
+pub use diagnostics::{render_diagnostics, Diagnostic, DiagnosticFormat, FixIt, Severity, Span};
 pub use duality_pass::{bifurcate, involution_j, pi_minus, pi_plus, BifurcationResult, DualityPass};
-pub use ir::OmegaIR;
+pub use inline_pass::InlinePass;
+pub use ir::{IrDiff, OmegaIR};
+pub use parser::lexer::{tokenize, Token, TokenKind};
+pub use parser::{parse, ParseResult};
+pub use semantic::analyze;
+pub use terminal::{render_to_terminal, TerminalOptions};
 
 #[cfg(test)]
 mod tests {
@@ -37,15 +69,14 @@ mod tests {
 
     #[test]
     fn test_end_to_end_compilation() {
-        use crate::ast::{Field, Gene, Hamiltonian};
+        use crate::ast::Hamiltonian;
 
         // Create DNA program
-        let mut dna = DnaProgram::new();
-        let mut organism = Organism::new("CRSM7_Z3MESH");
-        organism.fields.push(Field::new("lambda", "coherence"));
-        organism.fields.push(Field::new("gamma", "decoherence"));
-        organism.genes.push(Gene::new("main"));
-        dna.add_organism(organism);
+        let dna = OrganismBuilder::new("CRSM7_Z3MESH")
+            .field("lambda", FieldKind::Coherence)
+            .field("gamma", FieldKind::Decoherence)
+            .gene("main", |g| g)
+            .program();
 
         // Create CRSM program
         let mut crsm = CrsmProgram::new();
@@ -0,0 +1,543 @@
+//! Language Server
+//!
+//! Language Server Protocol support for dna::}{::lang / 7dCRSM::}{::lang,
+//! built on the parser and semantic analysis:
+//! - diagnostics published on open/change
+//! - go-to-definition for organisms, manifolds, genes and fields
+//! - hover showing the Ω_bind coordinate mapping of fields
+//! - completion for keywords, operators (∇7D, Π±, KΓ, ...) and field types
+//!
+//! Messages are JSON-RPC 2.0 values; `read_message`/`write_message` handle the
+//! `Content-Length` framing used on stdio by the `dnalang-lsp` binary.
+
+use crate::ast::{CrsmProgram, DnaProgram};
+use crate::binding::generate_omega_ir;
+use crate::builtins::BUILTINS;
+use crate::diagnostics::{Diagnostic, Severity, Span};
+use crate::parser::lexer::{self, TokenKind, KEYWORDS};
+use crate::parser::parse;
+use crate::semantic::{analyze, coordinate_symbol, FIELD_TYPES};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{self, BufRead, Write};
+
+/// CRSM operators with their hover documentation
+pub const OPERATORS: [(&str, &str); 6] = [
+    ("∇7D", "7-dimensional gradient operator"),
+    ("Π±", "Duality-polarized bifurcation: Π±_dual = ½(1 ± J)"),
+    ("KΓ", "Decoherence decay: exp(-K·τ)"),
+    ("DΛ", "Coherence amplification"),
+    ("Jθ", "Torsion-coupled J involution: J² = 1"),
+    ("Ω∞", "Sovereignty/independence operator"),
+];
+
+// LSP enum values
+const SEVERITY_ERROR: u8 = 1;
+const SEVERITY_WARNING: u8 = 2;
+const SEVERITY_INFORMATION: u8 = 3;
+const COMPLETION_FUNCTION: u8 = 3;
+const COMPLETION_FIELD: u8 = 5;
+const COMPLETION_KEYWORD: u8 = 14;
+const COMPLETION_ENUM_MEMBER: u8 = 20;
+const COMPLETION_OPERATOR: u8 = 24;
+
+/// Parse and analyze a document, returning all diagnostics
+pub fn document_diagnostics(text: &str) -> Vec<Diagnostic> {
+    let result = parse(text);
+    let mut diagnostics = result.diagnostics;
+    diagnostics.extend(analyze(&result.dna, &result.crsm));
+    diagnostics
+}
+
+/// Convert a byte offset into an LSP position (0-based line, UTF-16 column)
+pub fn offset_to_position(text: &str, offset: usize) -> (u32, u32) {
+    let offset = offset.min(text.len());
+    let line_start = text[..offset].rfind('\n').map_or(0, |i| i + 1);
+    let line = text[..line_start].matches('\n').count();
+    let character: usize = text[line_start..offset].chars().map(char::len_utf16).sum();
+    (line as u32, character as u32)
+}
+
+/// Convert an LSP position into a byte offset (clamped to the line end)
+pub fn position_to_offset(text: &str, line: u32, character: u32) -> usize {
+    let mut line_start = 0;
+    for _ in 0..line {
+        match text[line_start..].find('\n') {
+            Some(i) => line_start += i + 1,
+            None => return text.len(),
+        }
+    }
+
+    let mut units = 0;
+    for (idx, c) in text[line_start..].char_indices() {
+        if c == '\n' || units >= character as usize {
+            return line_start + idx;
+        }
+        units += c.len_utf16();
+    }
+    text.len()
+}
+
+fn span_to_range(text: &str, span: &Span) -> Value {
+    let (start_line, start_char) = offset_to_position(text, span.start);
+    let (end_line, end_char) = offset_to_position(text, span.end);
+    json!({
+        "start": { "line": start_line, "character": start_char },
+        "end": { "line": end_line, "character": end_char },
+    })
+}
+
+/// Identifier token covering `offset`, if any
+fn identifier_at(text: &str, offset: usize) -> Option<lexer::Token> {
+    lexer::tokenize(text)
+        .into_iter()
+        .find(|t| t.kind == TokenKind::Ident && t.span.start <= offset && offset <= t.span.end)
+}
+
+/// Definition span of the organism, manifold, gene or field named at `offset`
+pub fn definition(text: &str, offset: usize) -> Option<Span> {
+    let name = identifier_at(text, offset)?.text;
+    let result = parse(text);
+
+    let organisms = result.dna.organisms.iter();
+    let declared = organisms
+        .flat_map(|o| {
+            let fields = o.fields.iter().map(|f| (&f.name, f.span));
+            let genes = o.genes.iter().map(|g| (&g.name, g.span));
+            std::iter::once((&o.name, o.span))
+                .chain(fields)
+                .chain(genes)
+        })
+        .chain(result.crsm.manifolds.iter().map(|m| (&m.name, m.span)));
+
+    declared
+        .filter(|(declared_name, _)| **declared_name == name)
+        .map(|(_, span)| span)
+        .next()
+}
+
+/// Hover text for the symbol at `offset`
+pub fn hover(text: &str, offset: usize) -> Option<String> {
+    let token = identifier_at(text, offset)?;
+    if let Some((op, doc)) = OPERATORS.iter().find(|(op, _)| *op == token.text) {
+        return Some(format!("**{}** — {}", op, doc));
+    }
+
+    let result = parse(text);
+    field_hover(&result.dna, &result.crsm, &token.text)
+        .or_else(|| gene_hover(&result.dna, &token.text))
+}
+
+fn field_hover(dna: &DnaProgram, crsm: &CrsmProgram, name: &str) -> Option<String> {
+    let (idx, field) = dna
+        .organisms
+        .iter()
+        .flat_map(|o| o.fields.iter().enumerate())
+        .find(|(_, f)| f.name == name)?;
+
+    let mut hover = format!("field **{}** : {}", field.name, field.field_type);
+    if idx >= 7 {
+        hover.push_str("\n\nnot bound: only the first 7 fields map onto M⁷");
+        return Some(hover);
+    }
+
+    let symbol = coordinate_symbol(&field.field_type).unwrap_or("?");
+    let ir = generate_omega_ir(dna, crsm);
+    let value = ir
+        .field_coords
+        .iter()
+        .find(|c| c.field_name == field.name)
+        .map_or(0.0, |c| c.coord_value);
+
+    hover.push_str(&format!(
+        "\n\nΩ_bind → coordinate {} of M⁷ ({} = {:.4})",
+        idx, symbol, value
+    ));
+    Some(hover)
+}
+
+fn gene_hover(dna: &DnaProgram, name: &str) -> Option<String> {
+    let gene = dna
+        .organisms
+        .iter()
+        .flat_map(|o| o.genes.iter())
+        .find(|g| g.name == name)?;
+    Some(format!(
+        "gene **{}** — {} expression(s)\n\nΩ_bind → ∂_A Ψ",
+        gene.name,
+        gene.body.len()
+    ))
+}
+
+/// Completion items at `offset` (moved back to a char boundary if inside
+/// a character)
+pub fn completions(text: &str, offset: usize) -> Vec<Value> {
+    let mut offset = offset.min(text.len());
+    while !text.is_char_boundary(offset) {
+        offset -= 1;
+    }
+    let previous = lexer::tokenize(&text[..offset])
+        .into_iter()
+        .rev()
+        .find(|t| t.kind != TokenKind::Comment && t.span.end < offset);
+
+    // After `field name :` only field types make sense
+    if previous.is_some_and(|t| t.kind == TokenKind::Colon) {
+        return FIELD_TYPES
+            .iter()
+            .map(|ty| json!({ "label": ty, "kind": COMPLETION_ENUM_MEMBER }))
+            .collect();
+    }
+
+    let mut items: Vec<Value> = OPERATORS
+        .iter()
+        .map(|(op, doc)| json!({ "label": op, "kind": COMPLETION_OPERATOR, "detail": doc }))
+        .collect();
+    items.extend(
+        BUILTINS
+            .iter()
+            .map(|b| json!({ "label": b, "kind": COMPLETION_FUNCTION, "detail": "builtin" })),
+    );
+    items.extend(
+        KEYWORDS
+            .iter()
+            .map(|kw| json!({ "label": kw, "kind": COMPLETION_KEYWORD })),
+    );
+
+    let result = parse(text);
+    for organism in &result.dna.organisms {
+        items.extend(
+            organism
+                .genes
+                .iter()
+                .map(|g| json!({ "label": g.name, "kind": COMPLETION_FUNCTION })),
+        );
+        items.extend(
+            organism
+                .fields
+                .iter()
+                .map(|f| json!({ "label": f.name, "kind": COMPLETION_FIELD })),
+        );
+    }
+    items
+}
+
+/// Stateful language server handling one JSON-RPC message at a time
+#[derive(Debug, Default)]
+pub struct LanguageServer {
+    documents: HashMap<String, String>,
+    shutdown_requested: bool,
+    exited: bool,
+}
+
+impl LanguageServer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// True once the client sent `exit`
+    pub fn has_exited(&self) -> bool {
+        self.exited
+    }
+
+    /// Process code for `exit`: 0 after a clean shutdown, 1 otherwise
+    pub fn exit_code(&self) -> i32 {
+        if self.shutdown_requested {
+            0
+        } else {
+            1
+        }
+    }
+
+    /// Handle a message, returning responses and notifications to send
+    pub fn handle(&mut self, message: &Value) -> Vec<Value> {
+        let method = message["method"].as_str().unwrap_or_default();
+        let params = &message["params"];
+        let id = message.get("id").cloned();
+
+        let result = match method {
+            "initialize" => Some(capabilities()),
+            "shutdown" => {
+                self.shutdown_requested = true;
+                Some(Value::Null)
+            }
+            "exit" => {
+                self.exited = true;
+                None
+            }
+            "textDocument/didOpen" => {
+                let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+                let text = params["textDocument"]["text"].as_str().unwrap_or_default();
+                self.documents.insert(uri.to_string(), text.to_string());
+                return vec![self.publish_diagnostics(uri)];
+            }
+            "textDocument/didChange" => {
+                let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+                // Full sync: the last change carries the whole document
+                if let Some(text) = params["contentChanges"]
+                    .as_array()
+                    .and_then(|changes| changes.last())
+                    .and_then(|change| change["text"].as_str())
+                {
+                    self.documents.insert(uri.to_string(), text.to_string());
+                }
+                return vec![self.publish_diagnostics(uri)];
+            }
+            "textDocument/didClose" => {
+                let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+                self.documents.remove(uri);
+                return vec![json!({
+                    "jsonrpc": "2.0",
+                    "method": "textDocument/publishDiagnostics",
+                    "params": { "uri": uri, "diagnostics": [] },
+                })];
+            }
+            "textDocument/definition" => Some(self.with_document(params, |text, offset| {
+                definition(text, offset).map_or(Value::Null, |span| {
+                    json!({
+                        "uri": params["textDocument"]["uri"],
+                        "range": span_to_range(text, &span),
+                    })
+                })
+            })),
+            "textDocument/hover" => Some(self.with_document(params, |text, offset| {
+                hover(text, offset).map_or(
+                    Value::Null,
+                    |value| json!({ "contents": { "kind": "markdown", "value": value } }),
+                )
+            })),
+            "textDocument/completion" => Some(self.with_document(params, |text, offset| {
+                Value::Array(completions(text, offset))
+            })),
+            _ if id.is_some() => {
+                return vec![json!({
+                    "jsonrpc": "2.0",
+                    "id": id,
+                    "error": { "code": -32601, "message": format!("method not found: {}", method) },
+                })];
+            }
+            // Unknown notifications (`initialized`, `$/...`) are ignored
+            _ => None,
+        };
+
+        match (id, result) {
+            (Some(id), Some(result)) => {
+                vec![json!({ "jsonrpc": "2.0", "id": id, "result": result })]
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn with_document(&self, params: &Value, f: impl Fn(&str, usize) -> Value) -> Value {
+        let uri = params["textDocument"]["uri"].as_str().unwrap_or_default();
+        let Some(text) = self.documents.get(uri) else {
+            return Value::Null;
+        };
+        let line = params["position"]["line"].as_u64().unwrap_or(0) as u32;
+        let character = params["position"]["character"].as_u64().unwrap_or(0) as u32;
+        f(text, position_to_offset(text, line, character))
+    }
+
+    fn publish_diagnostics(&self, uri: &str) -> Value {
+        let text = self
+            .documents
+            .get(uri)
+            .map(String::as_str)
+            .unwrap_or_default();
+        let diagnostics: Vec<Value> = document_diagnostics(text)
+            .iter()
+            .map(|d| {
+                let span = d.spans.first().copied().unwrap_or_default();
+                let severity = match d.severity {
+                    Severity::Error => SEVERITY_ERROR,
+                    Severity::Warning => SEVERITY_WARNING,
+                    Severity::Note => SEVERITY_INFORMATION,
+                };
+                json!({
+                    "range": span_to_range(text, &span),
+                    "severity": severity,
+                    "code": d.code,
+                    "source": "dnalang",
+                    "message": d.message,
+                })
+            })
+            .collect();
+
+        json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/publishDiagnostics",
+            "params": { "uri": uri, "diagnostics": diagnostics },
+        })
+    }
+}
+
+fn capabilities() -> Value {
+    json!({
+        "capabilities": {
+            "textDocumentSync": 1,
+            "definitionProvider": true,
+            "hoverProvider": true,
+            "completionProvider": { "triggerCharacters": [":"] },
+        },
+        "serverInfo": { "name": "dnalang-lsp", "version": env!("CARGO_PKG_VERSION") },
+    })
+}
+
+/// JSON-RPC parse error (-32700) for a message that could not be read
+pub fn parse_error(message: impl fmt::Display) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": Value::Null,
+        "error": { "code": -32700, "message": format!("parse error: {}", message) },
+    })
+}
+
+/// Read one `Content-Length` framed message; `None` at end of stream. A
+/// body that is not JSON is consumed and reported as `InvalidData`, so the
+/// next message can still be read. Headers without a `Content-Length` are
+/// also `InvalidData`; their body cannot be delimited, so the next call
+/// skips it up to the next `Content-Length` header (which may follow the
+/// body on the same line)
+pub fn read_message(reader: &mut impl BufRead) -> io::Result<Option<Value>> {
+    const CONTENT_LENGTH: &str = "Content-Length:";
+    let mut content_length = None;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 {
+            return Ok(None);
+        }
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some(at) = header.find(CONTENT_LENGTH) {
+            content_length = header[at + CONTENT_LENGTH.len()..]
+                .trim()
+                .parse::<usize>()
+                .ok();
+        }
+    }
+
+    let len = content_length
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing Content-Length"))?;
+    let mut body = vec![0; len];
+    reader.read_exact(&mut body)?;
+    serde_json::from_slice(&body)
+        .map(Some)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+/// Write one `Content-Length` framed message
+pub fn write_message(writer: &mut impl Write, message: &Value) -> io::Result<()> {
+    let body = message.to_string();
+    write!(writer, "Content-Length: {}\r\n\r\n{}", body.len(), body)?;
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "organism CRSM7 {\n  field lambda : coherence\n  gene main { sovereign }\n  gene boot { main() }\n}\n";
+
+    fn offset_of(needle: &str, nth: usize) -> usize {
+        SOURCE.match_indices(needle).nth(nth).unwrap().0 + 1
+    }
+
+    #[test]
+    fn test_position_conversion() {
+        let text = "Λ Γ\nΦ";
+        assert_eq!(offset_to_position(text, 3), (0, 2));
+        assert_eq!(offset_to_position(text, text.len()), (1, 1));
+        assert_eq!(position_to_offset(text, 0, 2), 3);
+        assert_eq!(position_to_offset(text, 1, 0), text.find('Φ').unwrap());
+    }
+
+    #[test]
+    fn test_definition_of_gene_call() {
+        let span = definition(SOURCE, offset_of("main", 1)).unwrap();
+        assert_eq!(span.line, 3);
+        assert_eq!(&SOURCE[span.start..span.end], "main");
+    }
+
+    #[test]
+    fn test_hover_field_mapping() {
+        let text = hover(SOURCE, offset_of("lambda", 0)).unwrap();
+        assert!(text.contains("coordinate 0 of M⁷"));
+        assert!(text.contains("Λ = 0.8690"));
+    }
+
+    #[test]
+    fn test_hover_operator() {
+        let text = hover("law H: Π± Jθ", 7).unwrap();
+        assert!(text.contains("bifurcation"));
+    }
+
+    #[test]
+    fn test_completion_contexts() {
+        let types = completions("field x : ", 10);
+        assert_eq!(types.len(), FIELD_TYPES.len());
+
+        let items = completions(SOURCE, SOURCE.len());
+        let labels: Vec<&str> = items.iter().filter_map(|i| i["label"].as_str()).collect();
+        assert!(labels.contains(&"∇7D"));
+        assert!(labels.contains(&"gene"));
+        assert!(labels.contains(&"boot"));
+
+        // Offsets inside a multi-byte character do not panic
+        let text = "field Λ : ";
+        assert_eq!(completions(text, 7).len(), completions(text, 6).len());
+    }
+
+    #[test]
+    fn test_server_session() {
+        let mut server = LanguageServer::new();
+        let init = server
+            .handle(&json!({ "jsonrpc": "2.0", "id": 1, "method": "initialize", "params": {} }));
+        assert_eq!(init[0]["result"]["capabilities"]["hoverProvider"], true);
+
+        let published = server.handle(&json!({
+            "jsonrpc": "2.0",
+            "method": "textDocument/didOpen",
+            "params": { "textDocument": { "uri": "file:///a.dna", "text": "organism X { field l : coherense }" } },
+        }));
+        let diagnostics = &published[0]["params"]["diagnostics"];
+        assert_eq!(diagnostics[0]["code"], "E0001");
+        assert_eq!(diagnostics[0]["range"]["start"]["character"], 19);
+
+        let unknown = server.handle(&json!({ "jsonrpc": "2.0", "id": 2, "method": "foo/bar" }));
+        assert_eq!(unknown[0]["error"]["code"], -32601);
+
+        server.handle(&json!({ "jsonrpc": "2.0", "id": 3, "method": "shutdown" }));
+        server.handle(&json!({ "jsonrpc": "2.0", "method": "exit" }));
+        assert!(server.has_exited());
+        assert_eq!(server.exit_code(), 0);
+    }
+
+    #[test]
+    fn test_message_framing() {
+        let mut buffer = Vec::new();
+        write_message(&mut buffer, &json!({ "id": 1 })).unwrap();
+        let mut reader = io::Cursor::new(buffer);
+        assert_eq!(read_message(&mut reader).unwrap(), Some(json!({ "id": 1 })));
+        assert_eq!(read_message(&mut reader).unwrap(), None);
+
+        let mut buffer = b"Content-Length: 5\r\n\r\n{oops".to_vec();
+        write_message(&mut buffer, &json!({ "id": 2 })).unwrap();
+        let mut reader = io::Cursor::new(buffer);
+        let err = read_message(&mut reader).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(parse_error(&err)["error"]["code"], -32700);
+        assert_eq!(read_message(&mut reader).unwrap(), Some(json!({ "id": 2 })));
+
+        // A missing Content-Length skips the unframed body
+        let mut buffer = b"Content-Type: x\r\n\r\n{\"id\": 3}".to_vec();
+        write_message(&mut buffer, &json!({ "id": 4 })).unwrap();
+        let mut reader = io::Cursor::new(buffer);
+        let err = read_message(&mut reader).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(read_message(&mut reader).unwrap(), Some(json!({ "id": 4 })));
+        assert_eq!(read_message(&mut reader).unwrap(), None);
+    }
+}
@@ -0,0 +1,338 @@
+//! Lexer for dna::}{::lang and 7dCRSM::}{::lang
+//!
+//! Splits source text into tokens with byte spans. Identifiers are Unicode
+//! aware so operator names such as `∇7D`, `Π±`, `KΓ` and `Ω∞` lex as single
+//! identifiers.
+
+use crate::diagnostics::Span;
+
+/// Token categories
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Keyword,
+    Ident,
+    Number,
+    Str,
+    Comment,
+    LBrace,
+    RBrace,
+    LParen,
+    RParen,
+    Comma,
+    Colon,
+    Equals,
+    LessEqual,
+    Arrow,
+    Plus,
+    Minus,
+    /// @ (annotation)
+    At,
+    /// ∫
+    Integral,
+    /// ∂τ
+    PartialTau,
+    /// Invalid character or unterminated string literal
+    Unknown,
+}
+
+/// A lexed token
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub text: String,
+    pub span: Span,
+}
+
+/// Reserved words of both grammars
+pub const KEYWORDS: [&str; 14] = [
+    "organism",
+    "field",
+    "gene",
+    "emit",
+    "bifurcate",
+    "sovereign",
+    "evolve",
+    "collapse",
+    "if",
+    "manifold",
+    "state",
+    "law",
+    "constraint",
+    "operator",
+];
+
+/// Characters that terminate an identifier
+fn is_delimiter(c: char) -> bool {
+    c.is_whitespace() || "{}(),:=+-<→∫\"/@".contains(c)
+}
+
+struct Lexer<'a> {
+    source: &'a str,
+    pos: usize,
+    line: usize,
+    column: usize,
+}
+
+impl<'a> Lexer<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            pos: 0,
+            line: 1,
+            column: 1,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.source[self.pos..].chars().next()
+    }
+
+    fn peek_second(&self) -> Option<char> {
+        self.source[self.pos..].chars().nth(1)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        if c == '\n' {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
+        Some(c)
+    }
+
+    fn bump_while(&mut self, pred: impl Fn(char) -> bool) {
+        while let Some(c) = self.peek() {
+            if !pred(c) {
+                break;
+            }
+            self.bump();
+        }
+    }
+
+    fn next_token(&mut self) -> Option<Token> {
+        self.bump_while(char::is_whitespace);
+
+        let start = self.pos;
+        let (line, column) = (self.line, self.column);
+        let c = self.bump()?;
+
+        let kind = match c {
+            '{' => TokenKind::LBrace,
+            '}' => TokenKind::RBrace,
+            '(' => TokenKind::LParen,
+            ')' => TokenKind::RParen,
+            ',' => TokenKind::Comma,
+            ':' => TokenKind::Colon,
+            '=' => TokenKind::Equals,
+            '+' => TokenKind::Plus,
+            '@' => TokenKind::At,
+            '→' => TokenKind::Arrow,
+            '∫' => TokenKind::Integral,
+            '-' if self.peek() == Some('>') => {
+                self.bump();
+                TokenKind::Arrow
+            }
+            '-' => TokenKind::Minus,
+            '<' if self.peek() == Some('=') => {
+                self.bump();
+                TokenKind::LessEqual
+            }
+            '/' if self.peek() == Some('/') => {
+                self.bump_while(|c| c != '\n');
+                TokenKind::Comment
+            }
+            '"' => {
+                if self.lex_string() {
+                    TokenKind::Str
+                } else {
+                    TokenKind::Unknown
+                }
+            }
+            '∂' if self.peek() == Some('τ') => {
+                self.bump();
+                TokenKind::PartialTau
+            }
+            c if c.is_ascii_digit() => {
+                self.lex_number();
+                TokenKind::Number
+            }
+            c if !is_delimiter(c) => {
+                self.bump_while(|c| !is_delimiter(c));
+                if KEYWORDS.contains(&&self.source[start..self.pos]) {
+                    TokenKind::Keyword
+                } else {
+                    TokenKind::Ident
+                }
+            }
+            _ => TokenKind::Unknown,
+        };
+
+        Some(Token {
+            kind,
+            text: self.source[start..self.pos].to_string(),
+            span: Span::new(start, self.pos, line, column),
+        })
+    }
+
+    /// Lex the rest of a string literal; false if it is unterminated
+    fn lex_string(&mut self) -> bool {
+        while let Some(c) = self.peek() {
+            if c == '\n' {
+                return false;
+            }
+            self.bump();
+            match c {
+                '\\' => {
+                    self.bump();
+                }
+                '"' => return true,
+                _ => {}
+            }
+        }
+        false
+    }
+
+    fn lex_number(&mut self) {
+        self.bump_while(|c| c.is_ascii_digit());
+        if self.peek() == Some('.') && self.peek_second().is_some_and(|c| c.is_ascii_digit()) {
+            self.bump();
+            self.bump_while(|c| c.is_ascii_digit());
+        }
+        if matches!(self.peek(), Some('e' | 'E')) {
+            let exponent_follows = match self.peek_second() {
+                Some(c) if c.is_ascii_digit() => true,
+                Some('+' | '-') => self.source[self.pos..]
+                    .chars()
+                    .nth(2)
+                    .is_some_and(|c| c.is_ascii_digit()),
+                _ => false,
+            };
+            if exponent_follows {
+                self.bump();
+                if matches!(self.peek(), Some('+' | '-')) {
+                    self.bump();
+                }
+                self.bump_while(|c| c.is_ascii_digit());
+            }
+        }
+    }
+}
+
+/// Split source text into tokens, including comments
+///
+/// Never fails: invalid characters and unterminated strings come back as
+/// `TokenKind::Unknown`, so highlighters and formatters can work on
+/// incomplete input. `&source[token.span.start..token.span.end]` is always
+/// the token text.
+pub fn tokenize(source: &str) -> Vec<Token> {
+    let mut lexer = Lexer::new(source);
+    std::iter::from_fn(|| lexer.next_token()).collect()
+}
+
+/// Decode the contents of a string literal token (without quotes)
+pub(crate) fn unquote(text: &str) -> String {
+    let inner = text.strip_prefix('"').unwrap_or(text);
+    let inner = inner.strip_suffix('"').unwrap_or(inner);
+    let mut out = String::new();
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n') => out.push('\n'),
+                Some('t') => out.push('\t'),
+                Some(other) => out.push(other),
+                None => {}
+            }
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(source: &str) -> Vec<TokenKind> {
+        tokenize(source).into_iter().map(|t| t.kind).collect()
+    }
+
+    #[test]
+    fn test_unicode_identifiers() {
+        let tokens = tokenize("∇7D Π± KΓ Ω∞ ρ±");
+        assert!(tokens.iter().all(|t| t.kind == TokenKind::Ident));
+        assert_eq!(tokens[1].text, "Π±");
+        assert_eq!(tokens[4].span.column, 14);
+    }
+
+    #[test]
+    fn test_punctuation_and_keywords() {
+        assert_eq!(
+            kinds("gene main { emit \"hi\" }"),
+            vec![
+                TokenKind::Keyword,
+                TokenKind::Ident,
+                TokenKind::LBrace,
+                TokenKind::Keyword,
+                TokenKind::Str,
+                TokenKind::RBrace,
+            ]
+        );
+        assert_eq!(
+            kinds("∂τ (a) = f() if Γ <= ε Γ → 0 - +"),
+            vec![
+                TokenKind::PartialTau,
+                TokenKind::LParen,
+                TokenKind::Ident,
+                TokenKind::RParen,
+                TokenKind::Equals,
+                TokenKind::Ident,
+                TokenKind::LParen,
+                TokenKind::RParen,
+                TokenKind::Keyword,
+                TokenKind::Ident,
+                TokenKind::LessEqual,
+                TokenKind::Ident,
+                TokenKind::Ident,
+                TokenKind::Arrow,
+                TokenKind::Number,
+                TokenKind::Minus,
+                TokenKind::Plus,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_numbers() {
+        let tokens = tokenize("0 51.843 1e-9 2.5E+3");
+        let texts: Vec<&str> = tokens.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(texts, vec!["0", "51.843", "1e-9", "2.5E+3"]);
+    }
+
+    #[test]
+    fn test_comments_and_lines() {
+        let tokens = tokenize("// header\norganism X");
+        assert_eq!(tokens[0].kind, TokenKind::Comment);
+        assert_eq!(tokens[1].span.line, 2);
+        assert_eq!(tokens[1].span.column, 1);
+    }
+
+    #[test]
+    fn test_tokenize_spans_match_source() {
+        let source = "manifold M { law H: ∂τ Ψ = -∇7D·Ψ } \"open";
+        let tokens = tokenize(source);
+        for token in &tokens {
+            assert_eq!(&source[token.span.start..token.span.end], token.text);
+        }
+        assert_eq!(tokens.last().unwrap().kind, TokenKind::Unknown);
+    }
+
+    #[test]
+    fn test_unquote() {
+        assert_eq!(unquote("\"a\\\"b\\n\""), "a\"b\n");
+    }
+}
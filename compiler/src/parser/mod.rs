@@ -0,0 +1,791 @@
+//! Parser Module
+//!
+//! Recursive-descent parser for both languages, following
+//! `grammar/dna-lang.grammar` and `grammar/7dcrsm-lang.grammar`.
+//!
+//! A source file may contain any mix of `organism` and `manifold` blocks;
+//! manifolds nested inside an organism body are accepted as well. Syntax
+//! errors are reported as diagnostics and the parser recovers at the next
+//! item, so a single pass yields as much of the AST as possible.
+
+pub mod lexer;
+
+use crate::ast::{
+    Collapse, CollapseCondition, CollapseRule, Constraint, CrsmProgram, DnaProgram, Evolve, Expr,
+    Field, Gene, Hamiltonian, HamiltonianTerm, Integral, Manifold, Ode, Organism, State,
+};
+use crate::ast::payload::from_hex;
+use crate::ast::Payload;
+use crate::diagnostics::{Diagnostic, Span};
+use lexer::{Token, TokenKind};
+use std::collections::BTreeMap;
+
+/// Result of parsing a source file
+#[derive(Debug, Clone)]
+pub struct ParseResult {
+    pub dna: DnaProgram,
+    pub crsm: CrsmProgram,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl ParseResult {
+    pub fn has_errors(&self) -> bool {
+        self.diagnostics.iter().any(Diagnostic::is_error)
+    }
+}
+
+/// Parse a dna::}{::lang / 7dCRSM::}{::lang source file
+pub fn parse(source: &str) -> ParseResult {
+    let tokens = lexer::tokenize(source)
+        .into_iter()
+        .filter(|t| t.kind != TokenKind::Comment)
+        .collect();
+    let mut parser = Parser {
+        tokens,
+        pos: 0,
+        end: eof_span(source),
+        dna: DnaProgram::new(),
+        crsm: CrsmProgram::new(),
+        diagnostics: Vec::new(),
+    };
+    parser.parse_program();
+
+    ParseResult {
+        dna: parser.dna,
+        crsm: parser.crsm,
+        diagnostics: parser.diagnostics,
+    }
+}
+
+fn eof_span(source: &str) -> Span {
+    let line = source.lines().count().max(1);
+    let column = source.lines().last().map_or(0, |l| l.chars().count()) + 1;
+    Span::new(source.len(), source.len(), line, column)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    end: Span,
+    dna: DnaProgram,
+    crsm: CrsmProgram,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn peek_kind(&self) -> Option<TokenKind> {
+        self.peek().map(|t| t.kind)
+    }
+
+    fn at_keyword(&self, keyword: &str) -> bool {
+        self.peek()
+            .is_some_and(|t| t.kind == TokenKind::Keyword && t.text == keyword)
+    }
+
+    fn bump(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        if token.is_some() {
+            self.pos += 1;
+        }
+        token
+    }
+
+    fn error_here(&mut self, expected: &str) {
+        let diagnostic = match self.peek() {
+            Some(token) if token.kind == TokenKind::Unknown && token.text.starts_with('"') => {
+                Diagnostic::error("E0103", "unterminated string literal").with_span(token.span)
+            }
+            Some(token) if token.kind == TokenKind::Unknown => {
+                Diagnostic::error("E0102", format!("invalid character `{}`", token.text))
+                    .with_span(token.span)
+            }
+            Some(token) => Diagnostic::error(
+                "E0100",
+                format!("expected {}, found `{}`", expected, token.text),
+            )
+            .with_span(token.span),
+            None => Diagnostic::error(
+                "E0101",
+                format!("expected {}, found end of input", expected),
+            )
+            .with_span(self.end),
+        };
+        self.diagnostics.push(diagnostic);
+    }
+
+    fn expect(&mut self, kind: TokenKind, expected: &str) -> Option<Token> {
+        if self.peek_kind() == Some(kind) {
+            self.bump()
+        } else {
+            self.error_here(expected);
+            None
+        }
+    }
+
+    fn expect_keyword(&mut self, keyword: &str) -> Option<Token> {
+        if self.at_keyword(keyword) {
+            self.bump()
+        } else {
+            self.error_here(&format!("`{}`", keyword));
+            None
+        }
+    }
+
+    fn expect_ident(&mut self) -> Option<Token> {
+        self.expect(TokenKind::Ident, "identifier")
+    }
+
+    fn expect_number(&mut self) -> Option<f64> {
+        let negative = self.peek_kind() == Some(TokenKind::Minus);
+        if negative {
+            self.bump();
+        }
+        let token = self.expect(TokenKind::Number, "number")?;
+        let value: f64 = token.text.parse().ok()?;
+        Some(if negative { -value } else { value })
+    }
+
+    /// Skip one token, or a whole balanced `{ ... }` block
+    fn skip_item(&mut self) {
+        if self.bump().map(|t| t.kind) == Some(TokenKind::LBrace) {
+            let mut depth = 1;
+            while depth > 0 {
+                match self.bump().map(|t| t.kind) {
+                    Some(TokenKind::LBrace) => depth += 1,
+                    Some(TokenKind::RBrace) => depth -= 1,
+                    Some(_) => {}
+                    None => break,
+                }
+            }
+        }
+    }
+
+    /// Skip to the next keyword or closing brace after a syntax error
+    fn synchronize(&mut self) {
+        while let Some(kind) = self.peek_kind() {
+            match kind {
+                TokenKind::Keyword | TokenKind::RBrace => return,
+                TokenKind::LBrace => self.skip_item(),
+                _ => {
+                    self.bump();
+                }
+            }
+        }
+    }
+
+    /// Parse items of a `{ ... }` body until the closing brace
+    fn parse_block(&mut self, mut item: impl FnMut(&mut Self) -> bool, expected: &str) {
+        loop {
+            match self.peek_kind() {
+                Some(TokenKind::RBrace) => {
+                    self.bump();
+                    return;
+                }
+                None => {
+                    self.error_here("`}`");
+                    return;
+                }
+                Some(_) => {
+                    let start = self.pos;
+                    let errors = self.diagnostics.len();
+                    if !item(self) {
+                        self.error_here(expected);
+                    }
+                    if self.diagnostics.len() > errors {
+                        self.synchronize();
+                    }
+                    if self.pos == start {
+                        self.skip_item();
+                    }
+                }
+            }
+        }
+    }
+
+    // program ::= (annotation* organism | manifold)*
+    fn parse_program(&mut self) {
+        while self.peek().is_some() {
+            let start = self.pos;
+            let annotations = self.parse_annotations();
+            if self.at_keyword("organism") {
+                if let Some(mut organism) = self.parse_organism() {
+                    organism.annotations = annotations;
+                    self.dna.add_organism(organism);
+                }
+            } else if !annotations.is_empty() {
+                self.error_here("`organism` after annotations");
+            } else if self.at_keyword("manifold") {
+                if let Some(manifold) = self.parse_manifold() {
+                    self.crsm.add_manifold(manifold);
+                }
+            } else {
+                self.error_here("`organism` or `manifold`");
+            }
+            if self.pos == start {
+                self.skip_item();
+            }
+        }
+    }
+
+    // organism ::= "organism" IDENT "{" body "}"
+    fn parse_organism(&mut self) -> Option<Organism> {
+        self.expect_keyword("organism")?;
+        let name = self.expect_ident()?;
+        self.expect(TokenKind::LBrace, "`{`")?;
+
+        let mut organism = Organism::new(&name.text);
+        organism.span = name.span;
+
+        self.parse_block(
+            |p| {
+                if p.peek_kind() == Some(TokenKind::At) {
+                    let annotations = p.parse_annotations();
+                    if !p.at_keyword("gene") {
+                        p.error_here("`gene` after annotations");
+                    } else if let Some(mut gene) = p.parse_gene() {
+                        gene.annotations = annotations;
+                        organism.genes.push(gene);
+                    }
+                } else if p.at_keyword("field") {
+                    if let Some(field) = p.parse_field() {
+                        organism.fields.push(field);
+                    }
+                } else if p.at_keyword("gene") {
+                    if let Some(gene) = p.parse_gene() {
+                        organism.genes.push(gene);
+                    }
+                } else if p.at_keyword("evolve") {
+                    organism.evolve = p.parse_evolve();
+                } else if p.at_keyword("collapse") {
+                    organism.collapse = p.parse_collapse();
+                } else if p.at_keyword("manifold") {
+                    if let Some(manifold) = p.parse_manifold() {
+                        p.crsm.add_manifold(manifold);
+                    }
+                } else {
+                    return false;
+                }
+                true
+            },
+            "`field`, `gene`, `evolve` or `collapse`",
+        );
+
+        Some(organism)
+    }
+
+    // annotation ::= "@" IDENT "(" (STRING | token*) ")"
+    fn parse_annotations(&mut self) -> BTreeMap<String, String> {
+        let mut annotations = BTreeMap::new();
+        while self.peek_kind() == Some(TokenKind::At) {
+            self.bump();
+            let Some(key) = self.expect_ident() else {
+                break;
+            };
+            let Some(value) = self.parse_annotation_value() else {
+                break;
+            };
+            annotations.insert(key.text, value);
+        }
+        annotations
+    }
+
+    /// Annotation value: a string literal, or the raw tokens up to `)`
+    fn parse_annotation_value(&mut self) -> Option<String> {
+        self.expect(TokenKind::LParen, "`(`")?;
+        let mut tokens: Vec<Token> = Vec::new();
+        let mut depth = 0;
+        loop {
+            match self.peek_kind() {
+                Some(TokenKind::RParen) if depth == 0 => break,
+                Some(TokenKind::LParen) => depth += 1,
+                Some(TokenKind::RParen) => depth -= 1,
+                Some(_) => {}
+                None => {
+                    self.error_here("`)`");
+                    return None;
+                }
+            }
+            tokens.extend(self.bump());
+        }
+        self.bump();
+
+        if let [token] = tokens.as_slice() {
+            if token.kind == TokenKind::Str {
+                return Some(lexer::unquote(&token.text));
+            }
+        }
+        // Re-join tokens, keeping a space wherever the source had one
+        let mut value = String::new();
+        for (idx, token) in tokens.iter().enumerate() {
+            if idx > 0 && token.span.start > tokens[idx - 1].span.end {
+                value.push(' ');
+            }
+            value.push_str(&token.text);
+        }
+        Some(value)
+    }
+
+    // field ::= "field" IDENT ":" IDENT
+    fn parse_field(&mut self) -> Option<Field> {
+        self.expect_keyword("field")?;
+        let name = self.expect_ident()?;
+        self.expect(TokenKind::Colon, "`:`")?;
+        let field_type = self.expect_ident()?;
+
+        let mut field = Field::new(&name.text, &field_type.text);
+        field.span = name.span;
+        Some(field)
+    }
+
+    // gene ::= "gene" IDENT "{" expr* "}"
+    fn parse_gene(&mut self) -> Option<Gene> {
+        self.expect_keyword("gene")?;
+        let name = self.expect_ident()?;
+        self.expect(TokenKind::LBrace, "`{`")?;
+
+        let mut gene = Gene::new(&name.text);
+        gene.span = name.span;
+
+        self.parse_block(
+            |p| match p.parse_expr() {
+                Some(expr) => {
+                    gene.body.push(expr);
+                    true
+                }
+                None => false,
+            },
+            "expression",
+        );
+
+        Some(gene)
+    }
+
+    // expr ::= "emit" ("bytes" | "json")? STRING | "bifurcate" IDENT | "sovereign"
+    //        | IDENT "(" expr_list? ")" | IDENT
+    fn parse_expr(&mut self) -> Option<Expr> {
+        if self.at_keyword("emit") {
+            self.bump();
+            return self.parse_payload().map(Expr::Emit);
+        }
+        if self.at_keyword("bifurcate") {
+            self.bump();
+            let target = self.expect_ident()?;
+            return Some(Expr::Bifurcate(target.text));
+        }
+        if self.at_keyword("sovereign") {
+            self.bump();
+            return Some(Expr::Sovereign);
+        }
+        if self.peek_kind() != Some(TokenKind::Ident) {
+            return None;
+        }
+
+        let name = self.bump()?.text;
+        if self.peek_kind() == Some(TokenKind::LParen) {
+            let args = self.parse_expr_list()?;
+            Some(Expr::Call(name, args))
+        } else {
+            Some(Expr::Ident(name))
+        }
+    }
+
+    // payload ::= STRING | "bytes" STRING | "json" STRING
+    fn parse_payload(&mut self) -> Option<Payload> {
+        let format = match self.peek() {
+            Some(token) if token.kind == TokenKind::Ident => {
+                let format = token.text.clone();
+                self.bump();
+                Some(format)
+            }
+            _ => None,
+        };
+        let literal = self.expect(TokenKind::Str, "string literal")?;
+        let text = lexer::unquote(&literal.text);
+
+        let payload = match format.as_deref() {
+            None => Payload::Text(text),
+            Some("bytes") => match from_hex(&text) {
+                Some(bytes) => Payload::Bytes(bytes),
+                None => {
+                    self.diagnostics.push(
+                        Diagnostic::error("E0104", "invalid hex in bytes payload")
+                            .with_span(literal.span),
+                    );
+                    Payload::Bytes(Vec::new())
+                }
+            },
+            Some("json") => match serde_json::from_str(&text) {
+                Ok(value) => Payload::Json(value),
+                Err(e) => {
+                    self.diagnostics.push(
+                        Diagnostic::error("E0104", format!("invalid json payload: {}", e))
+                            .with_span(literal.span),
+                    );
+                    Payload::Json(serde_json::Value::Null)
+                }
+            },
+            Some(other) => {
+                self.diagnostics.push(
+                    Diagnostic::error("E0105", format!("unknown payload format `{}`", other))
+                        .with_span(literal.span)
+                        .with_note("expected `bytes` or `json`"),
+                );
+                Payload::Text(text)
+            }
+        };
+        Some(payload)
+    }
+
+    // "(" expr ("," expr)* ")"
+    fn parse_expr_list(&mut self) -> Option<Vec<Expr>> {
+        self.expect(TokenKind::LParen, "`(`")?;
+        let mut args = Vec::new();
+        if self.peek_kind() == Some(TokenKind::RParen) {
+            self.bump();
+            return Some(args);
+        }
+        loop {
+            match self.parse_expr() {
+                Some(expr) => args.push(expr),
+                None => {
+                    self.error_here("expression");
+                    return None;
+                }
+            }
+            match self.peek_kind() {
+                Some(TokenKind::Comma) => {
+                    self.bump();
+                }
+                _ => {
+                    self.expect(TokenKind::RParen, "`,` or `)`")?;
+                    return Some(args);
+                }
+            }
+        }
+    }
+
+    // evolve ::= "evolve" "{" ode* "}"
+    fn parse_evolve(&mut self) -> Option<Evolve> {
+        self.expect_keyword("evolve")?;
+        self.expect(TokenKind::LBrace, "`{`")?;
+
+        let mut evolve = Evolve::new();
+        self.parse_block(
+            |p| {
+                if p.peek_kind() != Some(TokenKind::PartialTau) {
+                    return false;
+                }
+                if let Some(ode) = p.parse_ode() {
+                    evolve.odes.push(ode);
+                }
+                true
+            },
+            "`∂τ`",
+        );
+        Some(evolve)
+    }
+
+    // ode ::= "∂τ" "(" IDENT ("," IDENT)* ")" "=" IDENT "(" expr_list? ")"
+    fn parse_ode(&mut self) -> Option<Ode> {
+        self.expect(TokenKind::PartialTau, "`∂τ`")?;
+        let state_vars = self.parse_ident_tuple()?;
+        self.expect(TokenKind::Equals, "`=`")?;
+        let rhs_func = self.expect_ident()?.text;
+        let rhs_args = self
+            .parse_expr_list()?
+            .into_iter()
+            .map(|arg| match arg {
+                Expr::Ident(name) | Expr::Call(name, _) => name,
+                _ => String::new(),
+            })
+            .collect();
+
+        Some(Ode {
+            state_vars,
+            rhs_func,
+            rhs_args,
+        })
+    }
+
+    // "(" IDENT ("," IDENT)* ")"
+    fn parse_ident_tuple(&mut self) -> Option<Vec<String>> {
+        self.expect(TokenKind::LParen, "`(`")?;
+        let mut names = vec![self.expect_ident()?.text];
+        while self.peek_kind() == Some(TokenKind::Comma) {
+            self.bump();
+            names.push(self.expect_ident()?.text);
+        }
+        self.expect(TokenKind::RParen, "`,` or `)`")?;
+        Some(names)
+    }
+
+    // collapse ::= "collapse" "{" ("if" cond IDENT)* "}"
+    fn parse_collapse(&mut self) -> Option<Collapse> {
+        self.expect_keyword("collapse")?;
+        self.expect(TokenKind::LBrace, "`{`")?;
+
+        let mut collapse = Collapse::new();
+        self.parse_block(
+            |p| {
+                if !p.at_keyword("if") {
+                    return false;
+                }
+                if let Some(rule) = p.parse_collapse_rule() {
+                    collapse.rules.push(rule);
+                }
+                true
+            },
+            "`if`",
+        );
+        Some(collapse)
+    }
+
+    // cond ::= IDENT "<=" IDENT | IDENT "→" NUMBER
+    fn parse_collapse_rule(&mut self) -> Option<CollapseRule> {
+        self.expect_keyword("if")?;
+        let subject = self.expect_ident()?.text;
+        let condition = match self.peek_kind() {
+            Some(TokenKind::LessEqual) => {
+                self.bump();
+                CollapseCondition::LessOrEqual(subject, self.expect_ident()?.text)
+            }
+            Some(TokenKind::Arrow) => {
+                self.bump();
+                CollapseCondition::TendsTo(subject, self.expect_number()?)
+            }
+            _ => {
+                self.error_here("`<=` or `→`");
+                return None;
+            }
+        };
+        let action = self.expect_ident()?.text;
+
+        Some(CollapseRule { condition, action })
+    }
+
+    // manifold ::= "manifold" IDENT "{" state hamiltonian constraint* "}"
+    fn parse_manifold(&mut self) -> Option<Manifold> {
+        self.expect_keyword("manifold")?;
+        let name = self.expect_ident()?;
+        self.expect(TokenKind::LBrace, "`{`")?;
+
+        let mut manifold = Manifold::new(&name.text);
+        manifold.span = name.span;
+
+        self.parse_block(
+            |p| {
+                if p.at_keyword("state") {
+                    if let Some(state) = p.parse_state() {
+                        manifold.state = state;
+                    }
+                } else if p.at_keyword("law") {
+                    if let Some(hamiltonian) = p.parse_law() {
+                        manifold.hamiltonian = hamiltonian;
+                    }
+                } else if p.at_keyword("constraint") {
+                    if let Some(constraint) = p.parse_constraint() {
+                        manifold.constraints.push(constraint);
+                    }
+                } else if p.at_keyword("operator") {
+                    p.bump();
+                    if let Some(op) = p.expect_ident() {
+                        manifold.operators.push(op.text);
+                    }
+                } else {
+                    return false;
+                }
+                true
+            },
+            "`state`, `law`, `constraint` or `operator`",
+        );
+
+        Some(manifold)
+    }
+
+    // state ::= "state" IDENT "=" "(" IDENT ("," IDENT)* ")"
+    fn parse_state(&mut self) -> Option<State> {
+        self.expect_keyword("state")?;
+        let name = self.expect_ident()?.text;
+        self.expect(TokenKind::Equals, "`=`")?;
+        let variables = self.parse_ident_tuple()?;
+        Some(State::new(&name, variables))
+    }
+
+    // hamiltonian ::= "law" IDENT ":" term+
+    // term ::= IDENT IDENT | "-" IDENT | "+" IDENT IDENT
+    fn parse_law(&mut self) -> Option<Hamiltonian> {
+        self.expect_keyword("law")?;
+        let name = self.expect_ident()?.text;
+        self.expect(TokenKind::Colon, "`:`")?;
+
+        let mut hamiltonian = Hamiltonian::new(&name);
+        loop {
+            let term = match self.peek_kind() {
+                Some(TokenKind::Plus) => {
+                    self.bump();
+                    let op = self.expect_ident()?.text;
+                    HamiltonianTerm::Product(op, self.expect_ident()?.text)
+                }
+                Some(TokenKind::Minus) => {
+                    self.bump();
+                    HamiltonianTerm::Negative(self.expect_ident()?.text)
+                }
+                Some(TokenKind::Ident) => {
+                    let op = self.bump()?.text;
+                    HamiltonianTerm::Simple(op, self.expect_ident()?.text)
+                }
+                _ => break,
+            };
+            hamiltonian.terms.push(term);
+        }
+
+        if hamiltonian.terms.is_empty() {
+            self.error_here("Hamiltonian term");
+        }
+        Some(hamiltonian)
+    }
+
+    // constraint ::= "constraint" ":" "∫" IDENT IDENT IDENT "=" NUMBER
+    fn parse_constraint(&mut self) -> Option<Constraint> {
+        self.expect_keyword("constraint")?;
+        self.expect(TokenKind::Colon, "`:`")?;
+        self.expect(TokenKind::Integral, "`∫`")?;
+        let domain = self.expect_ident()?.text;
+        let integrand = self.expect_ident()?.text;
+        let variable = self.expect_ident()?.text;
+        self.expect(TokenKind::Equals, "`=`")?;
+        let value = self.expect_number()?;
+
+        Some(Constraint {
+            integral: Integral::new(&domain, &integrand, &variable, value),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"
+// CRSM7 organism
+organism CRSM7_Z3MESH {
+    field lambda : coherence
+    field gamma : decoherence
+
+    gene main {
+        emit "Hello"
+        bifurcate psi
+        sovereign
+        evolve_step(lambda, gamma)
+    }
+
+    evolve {
+        ∂τ (lambda, gamma) = H_CRSM(lambda, gamma)
+    }
+
+    collapse {
+        if gamma <= epsilon project
+        if lambda → 1 seal
+    }
+}
+
+manifold CRSM7 {
+    state C7D = (Λ, Γ, Φ, Ξ, ρ±, θ, τ)
+    law H_CRSM: DΛ ∇7D - KΓ + Π± Jθ
+    constraint: ∫ M7 Γ dV = 0
+    operator Ω∞
+}
+"#;
+
+    #[test]
+    fn test_parse_full_program() {
+        let result = parse(SOURCE);
+        assert!(result.diagnostics.is_empty(), "{:?}", result.diagnostics);
+
+        let organism = &result.dna.organisms[0];
+        assert_eq!(organism.name, "CRSM7_Z3MESH");
+        assert_eq!(organism.fields.len(), 2);
+        assert_eq!(organism.genes[0].body.len(), 4);
+        assert_eq!(organism.evolve.as_ref().unwrap().odes[0].rhs_args.len(), 2);
+        assert_eq!(organism.collapse.as_ref().unwrap().rules.len(), 2);
+        assert_eq!(organism.span.line, 3);
+
+        let manifold = &result.crsm.manifolds[0];
+        assert_eq!(manifold.state.variables.len(), 7);
+        assert_eq!(manifold.hamiltonian.terms.len(), 3);
+        assert_eq!(manifold.constraints[0].integral.integrand, "Γ");
+        assert_eq!(manifold.operators, vec!["Ω∞".to_string()]);
+    }
+
+    #[test]
+    fn test_field_spans() {
+        let result = parse("organism X {\n  field lambda : coherence\n}");
+        let field = &result.dna.organisms[0].fields[0];
+        assert_eq!(field.span.line, 2);
+        assert_eq!(field.span.column, 9);
+    }
+
+    #[test]
+    fn test_error_recovery() {
+        let result = parse("organism X {\n  field : coherence\n  gene main { sovereign }\n}");
+        assert!(result.has_errors());
+        assert_eq!(result.diagnostics[0].code, "E0100");
+        assert_eq!(result.diagnostics[0].spans[0].line, 2);
+        // The gene after the broken field is still parsed
+        assert_eq!(result.dna.organisms[0].genes.len(), 1);
+    }
+
+    #[test]
+    fn test_unexpected_end_of_input() {
+        let result = parse("organism X {");
+        assert_eq!(result.diagnostics[0].code, "E0101");
+    }
+
+    #[test]
+    fn test_unterminated_string() {
+        let result = parse("organism X { gene g { emit \"oops\n } }");
+        assert!(result.diagnostics.iter().any(|d| d.code == "E0103"));
+    }
+
+    #[test]
+    fn test_typed_payloads() {
+        let result = parse(
+            "organism X { gene g { emit \"hi\" emit bytes \"00ff\" emit json \"{\\\"xi\\\": 8}\" } }",
+        );
+        assert!(!result.has_errors(), "{:?}", result.diagnostics);
+        let body = &result.dna.organisms[0].genes[0].body;
+        assert!(matches!(&body[0], Expr::Emit(Payload::Text(t)) if t == "hi"));
+        assert!(matches!(&body[1], Expr::Emit(Payload::Bytes(b)) if b == &[0, 255]));
+        assert!(matches!(&body[2], Expr::Emit(Payload::Json(v)) if v["xi"] == 8));
+    }
+
+    #[test]
+    fn test_invalid_payloads() {
+        let result = parse("organism X { gene g { emit bytes \"0g\" emit yaml \"a\" } }");
+        let codes: Vec<&str> = result.diagnostics.iter().map(|d| d.code.as_str()).collect();
+        assert_eq!(codes, vec!["E0104", "E0105"]);
+        assert_eq!(result.dna.organisms[0].genes[0].body.len(), 2);
+    }
+
+    #[test]
+    fn test_annotations() {
+        let result = parse(
+            "@author(\"ENKI-420\") @rev(3)\norganism X {\n  @tuning(K_Γ = 0.1, dt=0.01) gene g { sovereign }\n}",
+        );
+        assert!(!result.has_errors(), "{:?}", result.diagnostics);
+        let organism = &result.dna.organisms[0];
+        assert_eq!(organism.annotations["author"], "ENKI-420");
+        assert_eq!(organism.annotations["rev"], "3");
+        assert_eq!(organism.genes[0].annotations["tuning"], "K_Γ = 0.1, dt=0.01");
+    }
+
+    #[test]
+    fn test_dangling_annotation() {
+        let result = parse("organism X { @note(x) field f : coherence }");
+        assert_eq!(result.diagnostics[0].code, "E0100");
+    }
+}
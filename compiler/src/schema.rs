@@ -0,0 +1,71 @@
+//! JSON Schema Export
+//!
+//! JSON Schemas (draft 2020-12) for the serialized forms of `DnaProgram`,
+//! `CrsmProgram`, `OmegaIR` and compiler diagnostics, so tools written in
+//! other languages can validate programs and IR before handing them over.
+
+use crate::ast::{CrsmProgram, DnaProgram};
+use crate::diagnostics::DiagnosticReport;
+use crate::ir::OmegaIR;
+use schemars::schema_for;
+use serde_json::Value;
+
+/// Names accepted by `schema`
+pub const SCHEMA_NAMES: [&str; 4] = ["dna_program", "crsm_program", "omega_ir", "diagnostics"];
+
+/// Schema of a serialized `DnaProgram`
+pub fn dna_program_schema() -> Value {
+    schema_for!(DnaProgram).to_value()
+}
+
+/// Schema of a serialized `CrsmProgram`
+pub fn crsm_program_schema() -> Value {
+    schema_for!(CrsmProgram).to_value()
+}
+
+/// Schema of a serialized `OmegaIR`
+pub fn omega_ir_schema() -> Value {
+    schema_for!(OmegaIR).to_value()
+}
+
+/// Schema of the JSON diagnostics output
+pub fn diagnostics_schema() -> Value {
+    schema_for!(DiagnosticReport).to_value()
+}
+
+/// Schema by name (see `SCHEMA_NAMES`)
+pub fn schema(name: &str) -> Option<Value> {
+    match name {
+        "dna_program" => Some(dna_program_schema()),
+        "crsm_program" => Some(crsm_program_schema()),
+        "omega_ir" => Some(omega_ir_schema()),
+        "diagnostics" => Some(diagnostics_schema()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schemas_describe_top_level_types() {
+        for name in SCHEMA_NAMES {
+            let schema = schema(name).unwrap();
+            assert!(schema["$schema"].is_string(), "{}", name);
+        }
+        assert_eq!(dna_program_schema()["title"], "DnaProgram");
+        assert!(dna_program_schema()["properties"]["organisms"].is_object());
+        assert!(omega_ir_schema()["$defs"]["MetricIR"].is_object());
+        assert!(schema("unknown").is_none());
+    }
+
+    #[test]
+    fn test_required_fields_match_serde_defaults() {
+        // Annotations are `#[serde(default)]` and may be omitted
+        let schema = dna_program_schema();
+        let required = schema["$defs"]["Organism"]["required"].as_array().unwrap();
+        assert!(required.iter().any(|r| r == "name"));
+        assert!(!required.iter().any(|r| r == "annotations"));
+    }
+}
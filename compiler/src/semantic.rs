@@ -0,0 +1,258 @@
+//! Semantic Analysis
+//!
+//! Checks DNA and CRSM programs for errors that the Ω_bind operator would
+//! otherwise silently ignore (unknown field types, duplicate names,
+//! fields that do not fit in M⁷) and reports them as diagnostics.
+
+use crate::ast::{CrsmProgram, DnaProgram, Organism};
+use crate::diagnostics::{Diagnostic, FixIt, Span};
+use std::collections::HashSet;
+
+/// Field types that Ω_bind maps onto a coordinate of M⁷
+pub const FIELD_TYPES: [&str; 7] = [
+    "coherence",
+    "decoherence",
+    "information",
+    "emergence",
+    "polarity",
+    "torsion",
+    "epoch",
+];
+
+/// Coordinate symbols of M⁷, in `FIELD_TYPES` order
+pub const COORDINATE_SYMBOLS: [&str; 7] = ["Λ", "Γ", "Φ", "Ξ", "ρ±", "θ", "τ"];
+
+/// Coordinate symbol for a field type, if it is a known type
+pub fn coordinate_symbol(field_type: &str) -> Option<&'static str> {
+    FIELD_TYPES
+        .iter()
+        .position(|ty| *ty == field_type)
+        .map(|idx| COORDINATE_SYMBOLS[idx])
+}
+
+/// Dimension of the CRSM manifold
+const MANIFOLD_DIM: usize = 7;
+
+/// Run all semantic checks over a DNA/CRSM program pair
+pub fn analyze(program_dna: &DnaProgram, program_crsm: &CrsmProgram) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    for organism in &program_dna.organisms {
+        check_organism(organism, &mut diagnostics);
+    }
+
+    for manifold in &program_crsm.manifolds {
+        let vars = manifold.state.variables.len();
+        if vars != 0 && vars != MANIFOLD_DIM {
+            diagnostics.push(located(
+                Diagnostic::warning(
+                    "W0002",
+                    format!(
+                        "state `{}` of manifold `{}` has {} variables, expected {}",
+                        manifold.state.name, manifold.name, vars, MANIFOLD_DIM
+                    ),
+                )
+                .with_note("C7D = (Λ, Γ, Φ, Ξ, ρ±, θ, τ)"),
+                manifold.span,
+            ));
+        }
+    }
+
+    diagnostics
+}
+
+fn check_organism(organism: &Organism, diagnostics: &mut Vec<Diagnostic>) {
+    let mut field_names = HashSet::new();
+    for (idx, field) in organism.fields.iter().enumerate() {
+        if !FIELD_TYPES.contains(&field.field_type.as_str()) {
+            let mut diagnostic = Diagnostic::error(
+                "E0001",
+                format!(
+                    "unknown field type `{}` for field `{}` in organism `{}`",
+                    field.field_type, field.name, organism.name
+                ),
+            );
+            if let Some(suggestion) = closest_field_type(&field.field_type) {
+                diagnostic = diagnostic.with_fixit(FixIt {
+                    message: format!("did you mean `{}`?", suggestion),
+                    replacement: suggestion.to_string(),
+                    span: None,
+                });
+            }
+            diagnostics.push(located(diagnostic, field.span));
+        }
+
+        if !field_names.insert(field.name.as_str()) {
+            diagnostics.push(located(
+                Diagnostic::error(
+                    "E0002",
+                    format!(
+                        "field `{}` is defined more than once in organism `{}`",
+                        field.name, organism.name
+                    ),
+                ),
+                field.span,
+            ));
+        }
+
+        if idx >= MANIFOLD_DIM {
+            diagnostics.push(located(
+                Diagnostic::warning(
+                    "W0001",
+                    format!(
+                        "field `{}` of organism `{}` is not bound to a coordinate",
+                        field.name, organism.name
+                    ),
+                )
+                .with_note("only the first 7 fields are mapped onto M⁷"),
+                field.span,
+            ));
+        }
+    }
+
+    let mut gene_names = HashSet::new();
+    for gene in &organism.genes {
+        if !gene_names.insert(gene.name.as_str()) {
+            diagnostics.push(located(
+                Diagnostic::error(
+                    "E0003",
+                    format!(
+                        "gene `{}` is defined more than once in organism `{}`",
+                        gene.name, organism.name
+                    ),
+                ),
+                gene.span,
+            ));
+        }
+    }
+}
+
+/// Attach the node's span when it came from the parser
+fn located(diagnostic: Diagnostic, span: Span) -> Diagnostic {
+    if span.is_dummy() {
+        diagnostic
+    } else {
+        diagnostic.with_span(span)
+    }
+}
+
+/// Suggest the known field type closest to `name` (edit distance ≤ 3)
+fn closest_field_type(name: &str) -> Option<&'static str> {
+    FIELD_TYPES
+        .iter()
+        .map(|candidate| (edit_distance(name, candidate), *candidate))
+        .filter(|(distance, _)| *distance <= 3)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut curr = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let cost = if ca == *cb { 0 } else { 1 };
+            curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+        }
+        prev = curr;
+    }
+
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::{Field, Gene, Manifold, State};
+    use crate::builder::{FieldKind, OrganismBuilder};
+
+    #[test]
+    fn test_valid_program_has_no_diagnostics() {
+        let dna = OrganismBuilder::new("CRSM7")
+            .field("lambda", FieldKind::Coherence)
+            .field("gamma", FieldKind::Decoherence)
+            .gene("main", |g| g)
+            .program();
+
+        assert!(analyze(&dna, &CrsmProgram::new()).is_empty());
+    }
+
+    #[test]
+    fn test_unknown_field_type_suggests_fix() {
+        let mut dna = DnaProgram::new();
+        let mut organism = Organism::new("CRSM7");
+        organism.fields.push(Field::new("lambda", "coherense"));
+        dna.add_organism(organism);
+
+        let diagnostics = analyze(&dna, &CrsmProgram::new());
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].code, "E0001");
+        assert_eq!(diagnostics[0].fixits[0].replacement, "coherence");
+    }
+
+    #[test]
+    fn test_every_field_past_the_manifold_is_unbound() {
+        let mut dna = DnaProgram::new();
+        let mut organism = Organism::new("CRSM7");
+        for i in 0..MANIFOLD_DIM + 2 {
+            organism.fields.push(Field::new(&format!("f{}", i), "coherence"));
+        }
+        dna.add_organism(organism);
+
+        let unbound: Vec<String> = analyze(&dna, &CrsmProgram::new())
+            .into_iter()
+            .filter(|d| d.code == "W0001")
+            .map(|d| d.message)
+            .collect();
+        assert_eq!(unbound.len(), 2);
+        assert!(unbound[1].contains(&format!("f{}", MANIFOLD_DIM + 1)));
+    }
+
+    #[test]
+    fn test_duplicate_names() {
+        let mut dna = DnaProgram::new();
+        let mut organism = Organism::new("CRSM7");
+        organism.fields.push(Field::new("lambda", "coherence"));
+        organism.fields.push(Field::new("lambda", "coherence"));
+        organism.genes.push(Gene::new("main"));
+        organism.genes.push(Gene::new("main"));
+        dna.add_organism(organism);
+
+        let codes: Vec<String> = analyze(&dna, &CrsmProgram::new())
+            .into_iter()
+            .map(|d| d.code)
+            .collect();
+        assert_eq!(codes, vec!["E0002", "E0003"]);
+    }
+
+    #[test]
+    fn test_manifold_state_dimension() {
+        let mut crsm = CrsmProgram::new();
+        let mut manifold = Manifold::new("CRSM7");
+        manifold.state = State::new(
+            "C3D",
+            vec!["Λ".to_string(), "Γ".to_string(), "Φ".to_string()],
+        );
+        crsm.add_manifold(manifold);
+
+        let diagnostics = analyze(&DnaProgram::new(), &crsm);
+        assert_eq!(diagnostics[0].code, "W0002");
+    }
+
+    #[test]
+    fn test_parsed_program_diagnostics_have_spans() {
+        let result = crate::parser::parse("organism X {\n  field lambda : coherense\n}");
+        let diagnostics = analyze(&result.dna, &result.crsm);
+        assert_eq!(diagnostics[0].spans[0].line, 2);
+    }
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("torsion", "torsion"), 0);
+        assert_eq!(edit_distance("epok", "epoch"), 2);
+        assert_eq!(closest_field_type("xyzzy"), None);
+    }
+}
@@ -0,0 +1,342 @@
+//! CRSM Constraint Solver
+//!
+//! Adjusts the initial Z3State parameters (Γ, Λ, θ) so that a manifold's
+//! constraints hold within tolerance. Each constraint `∫ D f dV = v` is taken
+//! over the normalized domain (vol(D) = 1), so it reduces to f(state) = v.
+//!
+//! Supported integrands:
+//! - Γ, Λ, Φ, θ (and their ASCII names)
+//! - Ξ = ΛΦ/Γ
+//! - det_g, the metric determinant det(g) = −Λ·sin⁴θ of
+//!   g_{μν} = diag(1, 1, 1, sin²θ, sin²θ, −1, Λ)
+//!
+//! The residuals are minimized with damped Gauss-Newton (Levenberg-Marquardt)
+//! inside the parameter bounds; constraints that cannot be met are reported
+//! as diagnostics.
+
+use crate::ast::{Constraint, CrsmProgram, Manifold};
+use crate::binding::Z3State;
+use crate::diagnostics::{Diagnostic, Span};
+
+/// Number of solved parameters (Γ, Λ, θ)
+const PARAMS: usize = 3;
+
+/// Lower and upper bounds of Γ, Λ, θ (degrees)
+const BOUNDS: [(f64, f64); PARAMS] = [(0.0, 1.0), (0.0, 0.999), (0.0, 90.0)];
+
+/// Quantity a constraint integrates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Integrand {
+    Gamma,
+    Lambda,
+    Phi,
+    Theta,
+    Xi,
+    MetricDeterminant,
+}
+
+impl Integrand {
+    /// Parse an integrand name as written in a constraint
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "Γ" | "gamma" => Some(Integrand::Gamma),
+            "Λ" | "lambda" => Some(Integrand::Lambda),
+            "Φ" | "phi" => Some(Integrand::Phi),
+            "θ" | "theta" => Some(Integrand::Theta),
+            "Ξ" | "xi" => Some(Integrand::Xi),
+            "det_g" | "detg" => Some(Integrand::MetricDeterminant),
+            _ => None,
+        }
+    }
+
+    /// Value of the integrand for the given state
+    pub fn evaluate(&self, state: &Z3State) -> f64 {
+        match self {
+            Integrand::Gamma => state.gamma,
+            Integrand::Lambda => state.lambda,
+            Integrand::Phi => state.phi,
+            Integrand::Theta => state.theta,
+            Integrand::Xi => state.lambda * state.phi / state.gamma.max(1e-12),
+            Integrand::MetricDeterminant => -state.lambda * state.theta.to_radians().sin().powi(4),
+        }
+    }
+}
+
+/// Outcome of solving one manifold's constraints
+#[derive(Debug, Clone)]
+pub struct SolveResult {
+    /// Adjusted state
+    pub state: Z3State,
+    /// Largest absolute constraint residual
+    pub residual: f64,
+    pub iterations: usize,
+    pub satisfied: bool,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// Levenberg-Marquardt solver over (Γ, Λ, θ)
+#[derive(Debug, Clone)]
+pub struct ConstraintSolver {
+    pub tolerance: f64,
+    pub max_iterations: usize,
+}
+
+impl Default for ConstraintSolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConstraintSolver {
+    pub fn new() -> Self {
+        Self {
+            tolerance: 1e-9,
+            max_iterations: 200,
+        }
+    }
+
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Solve a manifold's constraints starting from `state`
+    pub fn solve(&self, manifold: &Manifold, state: &Z3State) -> SolveResult {
+        let mut diagnostics = Vec::new();
+        let targets: Vec<(Integrand, f64)> = manifold
+            .constraints
+            .iter()
+            .filter_map(|c| match Integrand::parse(&c.integral.integrand) {
+                Some(integrand) => Some((integrand, c.integral.value)),
+                None => {
+                    diagnostics.push(unsupported(manifold, c));
+                    None
+                }
+            })
+            .collect();
+
+        let mut params = [state.gamma, state.lambda, state.theta];
+        let residuals = |p: &[f64; PARAMS]| -> Vec<f64> {
+            let s = with_params(state, p);
+            targets.iter().map(|(f, v)| f.evaluate(&s) - v).collect()
+        };
+        let cost = |r: &[f64]| r.iter().map(|x| x * x).sum::<f64>();
+
+        let mut r = residuals(&params);
+        let mut damping = 1e-3;
+        let mut iterations = 0;
+        while iterations < self.max_iterations && max_abs(&r) > self.tolerance {
+            iterations += 1;
+
+            // Forward-difference Jacobian
+            let mut jacobian = vec![[0.0; PARAMS]; r.len()];
+            for k in 0..PARAMS {
+                let h = 1e-7 * params[k].abs().max(1.0);
+                let mut shifted = params;
+                shifted[k] += h;
+                for (row, (shifted, base)) in residuals(&shifted).iter().zip(&r).enumerate() {
+                    jacobian[row][k] = (shifted - base) / h;
+                }
+            }
+
+            // (JᵀJ + μ·diag(JᵀJ)) δ = −Jᵀr
+            let mut normal = [[0.0; PARAMS]; PARAMS];
+            let mut rhs = [0.0; PARAMS];
+            for (row, res) in jacobian.iter().zip(&r) {
+                for i in 0..PARAMS {
+                    rhs[i] -= row[i] * res;
+                    for j in 0..PARAMS {
+                        normal[i][j] += row[i] * row[j];
+                    }
+                }
+            }
+            for (i, row) in normal.iter_mut().enumerate() {
+                row[i] += damping * row[i].max(1e-12);
+            }
+
+            let Some(delta) = solve_linear(normal, rhs) else {
+                break;
+            };
+            let mut candidate = params;
+            for (k, (lo, hi)) in BOUNDS.iter().enumerate() {
+                candidate[k] = (candidate[k] + delta[k]).clamp(*lo, *hi);
+            }
+
+            let candidate_r = residuals(&candidate);
+            if cost(&candidate_r) < cost(&r) {
+                params = candidate;
+                r = candidate_r;
+                damping = (damping / 10.0).max(1e-12);
+            } else {
+                damping *= 10.0;
+                if damping > 1e12 {
+                    break;
+                }
+            }
+        }
+
+        let residual = max_abs(&r);
+        let satisfied = residual <= self.tolerance;
+        if !satisfied {
+            diagnostics.push(
+                Diagnostic::error(
+                    "E0200",
+                    format!(
+                        "constraints of manifold `{}` cannot be satisfied (residual {:.3e})",
+                        manifold.name, residual
+                    ),
+                )
+                .with_note("solved over Γ ∈ [0, 1], Λ ∈ [0, 0.999], θ ∈ [0°, 90°]"),
+            );
+        }
+
+        SolveResult {
+            state: with_params(state, &params),
+            residual,
+            iterations,
+            satisfied,
+            diagnostics: diagnostics
+                .into_iter()
+                .map(|d| located(d, manifold.span))
+                .collect(),
+        }
+    }
+
+    /// Solve every manifold in turn, threading the adjusted state through
+    pub fn solve_program(&self, program: &CrsmProgram, state: &Z3State) -> SolveResult {
+        let mut result = SolveResult {
+            state: state.clone(),
+            residual: 0.0,
+            iterations: 0,
+            satisfied: true,
+            diagnostics: Vec::new(),
+        };
+        for manifold in &program.manifolds {
+            let next = self.solve(manifold, &result.state);
+            result.state = next.state;
+            result.residual = result.residual.max(next.residual);
+            result.iterations += next.iterations;
+            result.satisfied &= next.satisfied;
+            result.diagnostics.extend(next.diagnostics);
+        }
+        result
+    }
+}
+
+/// Copy of `state` with (Γ, Λ, θ) replaced and dependent values refreshed
+fn with_params(state: &Z3State, params: &[f64; PARAMS]) -> Z3State {
+    let mut next = state.clone();
+    next.gamma = params[0];
+    next.lambda = params[1];
+    next.theta = params[2];
+
+    let sin_sq = next.theta.to_radians().sin().powi(2);
+    next.metric[3][3] = sin_sq;
+    next.metric[4][4] = sin_sq;
+    next.metric[6][6] = next.lambda;
+    next.compute_emergence();
+    next
+}
+
+fn unsupported(manifold: &Manifold, constraint: &Constraint) -> Diagnostic {
+    Diagnostic::warning(
+        "W0200",
+        format!(
+            "constraint on `{}` in manifold `{}` is not supported by the solver",
+            constraint.integral.integrand, manifold.name
+        ),
+    )
+    .with_note("supported integrands: Γ, Λ, Φ, θ, Ξ, det_g")
+}
+
+fn located(diagnostic: Diagnostic, span: Span) -> Diagnostic {
+    if span.is_dummy() {
+        diagnostic
+    } else {
+        diagnostic.with_span(span)
+    }
+}
+
+fn max_abs(values: &[f64]) -> f64 {
+    values.iter().fold(0.0, |acc, v| acc.max(v.abs()))
+}
+
+/// Gaussian elimination with partial pivoting
+fn solve_linear(mut a: [[f64; PARAMS]; PARAMS], mut b: [f64; PARAMS]) -> Option<[f64; PARAMS]> {
+    for col in 0..PARAMS {
+        let pivot = (col..PARAMS).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+        if a[pivot][col].abs() < 1e-300 {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        for row in col + 1..PARAMS {
+            let factor = a[row][col] / a[col][col];
+            let pivot_row = a[col];
+            for (dst, src) in a[row][col..].iter_mut().zip(&pivot_row[col..]) {
+                *dst -= factor * src;
+            }
+            b[row] -= factor * b[col];
+        }
+    }
+
+    let mut x = [0.0; PARAMS];
+    for row in (0..PARAMS).rev() {
+        let sum: f64 = (row + 1..PARAMS).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - sum) / a[row][row];
+    }
+    Some(x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::Integral;
+
+    fn manifold(constraints: &[(&str, f64)]) -> Manifold {
+        let mut manifold = Manifold::new("CRSM7");
+        for (integrand, value) in constraints {
+            manifold.constraints.push(Constraint {
+                integral: Integral::new("M7", integrand, "dV", *value),
+            });
+        }
+        manifold
+    }
+
+    #[test]
+    fn test_gamma_integral_zero() {
+        let result = ConstraintSolver::new().solve(&manifold(&[("Γ", 0.0)]), &Z3State::new());
+        assert!(result.satisfied);
+        assert!(result.state.gamma.abs() <= 1e-9);
+        assert!(result.diagnostics.is_empty());
+    }
+
+    #[test]
+    fn test_determinant_target() {
+        let result = ConstraintSolver::new()
+            .solve(&manifold(&[("det_g", -0.25), ("Λ", 0.5)]), &Z3State::new());
+        assert!(result.satisfied, "residual {}", result.residual);
+        let det = Integrand::MetricDeterminant.evaluate(&result.state);
+        assert!((det + 0.25).abs() < 1e-9);
+        assert!((result.state.lambda - 0.5).abs() < 1e-9);
+        // Metric diagonal follows the solved θ and Λ
+        let product: f64 = (0..7).map(|i| result.state.metric[i][i]).product();
+        assert!((product - det).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_infeasible_reported() {
+        // det(g) ≤ 0 for every admissible Λ, θ
+        let result = ConstraintSolver::new().solve(&manifold(&[("det_g", 0.5)]), &Z3State::new());
+        assert!(!result.satisfied);
+        assert_eq!(result.diagnostics[0].code, "E0200");
+        assert!(result.diagnostics[0].is_error());
+    }
+
+    #[test]
+    fn test_unsupported_integrand() {
+        let result = ConstraintSolver::new().solve(&manifold(&[("ψ", 1.0)]), &Z3State::new());
+        assert!(result.satisfied);
+        assert_eq!(result.diagnostics[0].code, "W0200");
+    }
+}
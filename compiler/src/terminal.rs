@@ -0,0 +1,234 @@
+//! Terminal Diagnostic Rendering
+//!
+//! Renders diagnostics against their source for humans at a terminal:
+//!
+//! ```text
+//! error[E0001]: unknown field type `coherense`
+//!   --> organism.dna:2:14
+//!    |
+//!  2 |     field x: coherense
+//!    |              ^^^^^^^^^
+//!    = help: did you mean `coherence`?
+//! ```
+//!
+//! The primary span is underlined with `^`, secondary spans with `-`.
+//! Spans covering several lines are underlined to the end of their first
+//! line. ANSI colors are optional.
+
+use crate::diagnostics::{Diagnostic, Severity, Span};
+
+/// Rendering options
+#[derive(Debug, Clone, Default)]
+pub struct TerminalOptions {
+    /// Emit ANSI color escapes
+    pub color: bool,
+    /// File name shown in `-->` lines
+    pub file_name: Option<String>,
+}
+
+impl TerminalOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn with_file_name(mut self, file_name: &str) -> Self {
+        self.file_name = Some(file_name.to_string());
+        self
+    }
+}
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const BLUE: &str = "\x1b[1;34m";
+
+fn severity_color(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "\x1b[1;31m",
+        Severity::Warning => "\x1b[1;33m",
+        Severity::Note => "\x1b[1;36m",
+    }
+}
+
+/// Render diagnostics with source excerpts, underlined spans and notes
+pub fn render_to_terminal(
+    diagnostics: &[Diagnostic],
+    source: &str,
+    options: &TerminalOptions,
+) -> String {
+    diagnostics
+        .iter()
+        .map(|d| render_one(d, source, options))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn render_one(diagnostic: &Diagnostic, source: &str, options: &TerminalOptions) -> String {
+    let paint = |style: &str, text: &str| {
+        if options.color {
+            format!("{}{}{}", style, text, RESET)
+        } else {
+            text.to_string()
+        }
+    };
+    let accent = severity_color(diagnostic.severity);
+
+    let spans: Vec<&Span> = diagnostic.spans.iter().filter(|s| !s.is_dummy()).collect();
+    let gutter = spans
+        .iter()
+        .map(|s| s.line.to_string().len())
+        .max()
+        .unwrap_or(1)
+        + 1;
+    let pad = " ".repeat(gutter);
+    let bar = paint(BLUE, "|");
+
+    let mut out = format!(
+        "{}{}",
+        paint(
+            accent,
+            &format!("{}[{}]", diagnostic.severity.as_str(), diagnostic.code)
+        ),
+        paint(BOLD, &format!(": {}", diagnostic.message))
+    );
+
+    if let Some(primary) = spans.first() {
+        let location = match &options.file_name {
+            Some(name) => format!("{}:{}:{}", name, primary.line, primary.column),
+            None => format!("{}:{}", primary.line, primary.column),
+        };
+        out.push_str(&format!("\n{}{} {}", pad, paint(BLUE, "-->"), location));
+        out.push_str(&format!("\n{} {}", pad, bar));
+
+        let mut previous_line = None;
+        for (i, span) in spans.iter().enumerate() {
+            let Some(text) = source.lines().nth(span.line - 1) else {
+                continue;
+            };
+            if previous_line.is_some_and(|line| span.line > line + 1) {
+                out.push_str(&format!("\n{}", paint(BLUE, "...")));
+            }
+            if previous_line != Some(span.line) {
+                out.push_str(&format!(
+                    "\n{} {} {}",
+                    paint(BLUE, &format!("{:>width$}", span.line, width = gutter)),
+                    bar,
+                    text
+                ));
+            }
+            previous_line = Some(span.line);
+
+            let (offset, width) = underline(source, text, span);
+            let (mark, style) = if i == 0 { ('^', accent) } else { ('-', BLUE) };
+            out.push_str(&format!(
+                "\n{} {} {}{}",
+                pad,
+                bar,
+                " ".repeat(offset),
+                paint(style, &mark.to_string().repeat(width))
+            ));
+        }
+    }
+
+    let indent = if spans.is_empty() {
+        String::new()
+    } else {
+        format!("{} ", pad)
+    };
+    for note in &diagnostic.notes {
+        out.push_str(&format!("\n{}{} {}", indent, paint(BLUE, "= note:"), note));
+    }
+    for fixit in &diagnostic.fixits {
+        out.push_str(&format!(
+            "\n{}{} {}",
+            indent,
+            paint(BLUE, "= help:"),
+            fixit.message
+        ));
+    }
+    out
+}
+
+/// Column offset and width (in chars) of a span's underline on its first line
+fn underline(source: &str, line_text: &str, span: &Span) -> (usize, usize) {
+    // Byte offset of the line within the source
+    let line_start = line_text.as_ptr() as usize - source.as_ptr() as usize;
+    let line_end = line_start + line_text.len();
+    let start = span.start.clamp(line_start, line_end);
+    let end = span.end.clamp(start, line_end);
+
+    let offset = source
+        .get(line_start..start)
+        .map_or(span.column.saturating_sub(1), |s| s.chars().count());
+    let width = source.get(start..end).map_or(1, |s| s.chars().count());
+    (offset, width.max(1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics::FixIt;
+
+    const SOURCE: &str = "organism Demo {\n    field x: coherense\n    field y: coherence\n}\n";
+
+    #[test]
+    fn test_excerpt_and_underline() {
+        let span = Span::new(29, 38, 2, 14);
+        let diagnostic = Diagnostic::error("E0001", "unknown field type `coherense`")
+            .with_span(span)
+            .with_fixit(FixIt {
+                message: "did you mean `coherence`?".to_string(),
+                replacement: "coherence".to_string(),
+                span: Some(span),
+            });
+        let out = render_to_terminal(
+            &[diagnostic],
+            SOURCE,
+            &TerminalOptions::new().with_file_name("demo.dna"),
+        );
+        assert_eq!(
+            out,
+            "error[E0001]: unknown field type `coherense`\n\
+             \x20 --> demo.dna:2:14\n\
+             \x20  |\n\
+             \x202 |     field x: coherense\n\
+             \x20  |              ^^^^^^^^^\n\
+             \x20  = help: did you mean `coherence`?"
+        );
+    }
+
+    #[test]
+    fn test_secondary_span_and_color() {
+        let diagnostic = Diagnostic::warning("W0001", "duplicate field")
+            .with_span(Span::new(49, 50, 3, 11))
+            .with_span(Span::new(26, 27, 2, 11))
+            .with_note("fields share one coordinate");
+        let plain = render_to_terminal(
+            std::slice::from_ref(&diagnostic),
+            SOURCE,
+            &TerminalOptions::new(),
+        );
+        assert!(plain.contains(" 3 |     field y: coherence\n   |           ^"));
+        assert!(plain.contains(" 2 |     field x: coherense\n   |           -"));
+        assert!(plain.contains("= note: fields share one coordinate"));
+
+        let colored = render_to_terminal(
+            &[diagnostic],
+            SOURCE,
+            &TerminalOptions::new().with_color(true),
+        );
+        assert!(colored.contains("\x1b[1;33mwarning[W0001]\x1b[0m"));
+    }
+
+    #[test]
+    fn test_underline_inside_char_with_column_zero() {
+        // Byte 1 falls inside `Λ`, so the offset comes from the column
+        let source = "Λx\n";
+        let line = source.lines().next().unwrap();
+        assert_eq!(underline(source, line, &Span::new(1, 3, 1, 0)), (0, 1));
+    }
+}
@@ -0,0 +1,36 @@
+//! Z3 mesh construction benchmark
+//!
+//! Times building chain meshes with `add_vertex` and `connect`, which
+//! should grow linearly with the vertex count:
+//!
+//! ```text
+//! cargo bench --bench mesh
+//! ```
+
+use crsm7_engine::{CRSM7State, Gene, Z3Mesh};
+use std::hint::black_box;
+use std::time::Instant;
+
+fn main() {
+    println!("Z3 mesh construction");
+    for vertices in [1_000, 10_000, 100_000] {
+        let start = Instant::now();
+        let mut mesh = Z3Mesh::new();
+        for i in 0..vertices {
+            let id = format!("g{}", i);
+            let state = CRSM7State::new(0.85, 0.001 * (1 + i % 10) as f64, 8.0, 1.0, 51.843, 0.0);
+            mesh.add_vertex(Gene::with_state(&id, &id, state));
+            if i > 0 {
+                mesh.connect(i - 1, i);
+            }
+        }
+        let elapsed = start.elapsed();
+        black_box(&mesh);
+        println!(
+            "{:<20} {:>10.2?} {:>12.0} vertices/s",
+            format!("build/{}", vertices),
+            elapsed,
+            vertices as f64 / elapsed.as_secs_f64()
+        );
+    }
+}
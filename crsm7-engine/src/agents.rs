@@ -0,0 +1,226 @@
+//! Agent Registry
+//!
+//! The agents that make up the Z3 mesh: each `AgentSpec` is a topology
+//! vertex (id, name and initial state, as in `topology`) plus a role and
+//! the status line shown at boot. `AgentRegistry::standard()` holds AURA,
+//! AIDEN, CCCcE, SENTINEL and Z3BRA; a custom set loads from TOML or JSON
+//! (`crsm7 --agents <file>`) and drives the banner, mesh and boot status
+//! alike:
+//!
+//! ```toml
+//! [[agents]]
+//! id = "oracle"
+//! name = "ORACLE"
+//! role = "forecasting"
+//! status = "horizon scan active"
+//! lambda = 0.9
+//!
+//! [[agents]]
+//! id = "warden"
+//! ```
+//!
+//! Agents are connected in a chain in file order unless `[[edges]]` are
+//! given (as in `topology`). `status` defaults to "online".
+
+use crate::mesh::Z3Mesh;
+use crate::topology::{EdgeSpec, Topology, VertexSpec};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+fn default_status() -> String {
+    "online".to_string()
+}
+
+/// One agent: its vertex, role and boot status line
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentSpec {
+    #[serde(flatten)]
+    pub vertex: VertexSpec,
+    #[serde(default)]
+    pub role: String,
+    #[serde(default = "default_status")]
+    pub status: String,
+}
+
+impl AgentSpec {
+    pub fn new(id: &str, name: &str, role: &str, status: &str) -> Self {
+        Self {
+            vertex: VertexSpec {
+                id: id.to_string(),
+                name: Some(name.to_string()),
+                ..VertexSpec::default()
+            },
+            role: role.to_string(),
+            status: status.to_string(),
+        }
+    }
+
+    /// Initial Λ, Γ and Φ (other components take their defaults)
+    pub fn with_state(mut self, lambda: f64, gamma: f64, phi: f64) -> Self {
+        self.vertex.lambda = Some(lambda);
+        self.vertex.gamma = Some(gamma);
+        self.vertex.phi = Some(phi);
+        self
+    }
+
+    /// Display name, defaulting to the id
+    pub fn name(&self) -> &str {
+        self.vertex.name.as_deref().unwrap_or(&self.vertex.id)
+    }
+}
+
+/// The agents of a mesh, in vertex order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentRegistry {
+    pub agents: Vec<AgentSpec>,
+    /// Explicit edges; a chain in agent order when absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edges: Option<Vec<EdgeSpec>>,
+}
+
+impl Default for AgentRegistry {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl AgentRegistry {
+    /// AURA, AIDEN, CCCcE, SENTINEL and Z3BRA in a chain
+    pub fn standard() -> Self {
+        Self {
+            agents: vec![
+                AgentSpec::new("aura", "AURA", "coherence", "quantum coherence active")
+                    .with_state(0.89, 0.001, 8.1),
+                AgentSpec::new(
+                    "aiden",
+                    "AIDEN",
+                    "optimization",
+                    "optimization loop running",
+                )
+                .with_state(0.87, 0.002, 7.9),
+                AgentSpec::new("cccce", "CCCcE", "manifold", "manifold stabilized")
+                    .with_state(0.88, 0.001, 8.0),
+                AgentSpec::new("sentinel", "SENTINEL", "defense", "boundary hardened")
+                    .with_state(0.91, 0.001, 8.2),
+                AgentSpec::new("z3bra", "Z3BRA", "logic", "logic mesh bound")
+                    .with_state(0.86, 0.003, 7.8),
+            ],
+            edges: None,
+        }
+    }
+
+    /// Parse TOML or JSON by `path`'s extension
+    pub fn parse(source: &str, path: &Path) -> Result<Self, String> {
+        let registry: Self = match path.extension().and_then(|e| e.to_str()) {
+            Some("toml") => toml::from_str(source).map_err(|e| e.to_string())?,
+            Some("json") => serde_json::from_str(source).map_err(|e| e.to_string())?,
+            _ => {
+                return Err(format!(
+                    "{}: expected a .toml or .json agent file",
+                    path.display()
+                ))
+            }
+        };
+        if registry.agents.is_empty() {
+            return Err("no agents defined".to_string());
+        }
+        Ok(registry)
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path)
+            .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+        let registry =
+            Self::parse(&source, path).map_err(|e| format!("{}: {}", path.display(), e))?;
+        // Surface invalid states and edges at load time
+        registry
+            .mesh()
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+        Ok(registry)
+    }
+
+    /// The agents as a topology: their vertices and the edges
+    pub fn topology(&self) -> Topology {
+        let vertices: Vec<VertexSpec> = self.agents.iter().map(|a| a.vertex.clone()).collect();
+        let edges = self.edges.clone().unwrap_or_else(|| {
+            vertices
+                .windows(2)
+                .map(|pair| EdgeSpec {
+                    from: pair[0].id.clone(),
+                    to: pair[1].id.clone(),
+                })
+                .collect()
+        });
+        Topology { vertices, edges }
+    }
+
+    /// Build the mesh, validating states and edges
+    pub fn mesh(&self) -> Result<Z3Mesh, String> {
+        self.topology().build()
+    }
+
+    /// Agent names for the banner: "AURA · AIDEN · …"
+    pub fn banner_line(&self) -> String {
+        let names: Vec<&str> = self.agents.iter().map(AgentSpec::name).collect();
+        names.join(" · ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh::create_standard_mesh;
+    use crate::state::CRSM7State;
+
+    #[test]
+    fn test_standard_registry_builds_standard_mesh() {
+        let registry = AgentRegistry::standard();
+        assert_eq!(
+            registry.banner_line(),
+            "AURA · AIDEN · CCCcE · SENTINEL · Z3BRA"
+        );
+        let mesh = create_standard_mesh();
+        assert_eq!(mesh.vertices.len(), 5);
+        let sentinel = &mesh.vertices[3];
+        assert_eq!(
+            (sentinel.id.as_str(), sentinel.name.as_str()),
+            ("sentinel", "SENTINEL")
+        );
+        assert_eq!(
+            sentinel.state,
+            CRSM7State::new(0.91, 0.001, 8.2, 1.0, 51.843, 0.0)
+        );
+        let edges: Vec<_> = mesh.edges.iter().map(|e| (e.from, e.to)).collect();
+        assert_eq!(edges, vec![(0, 1), (1, 2), (2, 3), (3, 4)]);
+    }
+
+    #[test]
+    fn test_custom_agents_from_toml() {
+        let source = r#"
+            [[agents]]
+            id = "oracle"
+            name = "ORACLE"
+            role = "forecasting"
+            status = "horizon scan active"
+            lambda = 0.9
+
+            [[agents]]
+            id = "warden"
+
+            [[agents]]
+            id = "scribe"
+        "#;
+        let registry = AgentRegistry::parse(source, Path::new("agents.toml")).unwrap();
+        assert_eq!(registry.banner_line(), "ORACLE · warden · scribe");
+        assert_eq!(registry.agents[1].status, "online");
+        let mesh = registry.mesh().unwrap();
+        assert_eq!(mesh.vertices[0].state.lambda, 0.9);
+        assert_eq!(mesh.edges.len(), 2);
+
+        assert!(AgentRegistry::parse("agents = []", Path::new("a.toml")).is_err());
+        let bad = "[[agents]]\nid = \"x\"\nlambda = 2.0";
+        let registry = AgentRegistry::parse(bad, Path::new("a.toml")).unwrap();
+        assert!(registry.mesh().is_err());
+    }
+}
@@ -0,0 +1,897 @@
+//! crsm7 - CRSM7 engine CLI
+//!
+//! Subcommands:
+//! - `run` (the default): boot display of the state manifold, Z3 mesh
+//!   bindings, duality operators and sovereignty
+//! - `interactive`: the evolution loop, with line editing, history (kept
+//!   in `~/.crsm7_history`) and tab completion
+//! - `tui`: live dashboard of the evolving state and mesh (see `tui`)
+//! - `script <file>` (or `--script <file>`): interactive commands from a
+//!   file (e.g. `experiment.crsm7rc`), stopping at the first that fails
+//! - `evolve --steps N --dt X`: evolve and print the final state
+//! - `mesh show`: vertices, edges and weights of the Z3 mesh
+//! - `sovereignty`: check the sovereignty criteria
+//!
+//! `evolve`, `mesh show` and `sovereignty` start from the standard session
+//! or from one written by `save` (`--session <file>`). Every subcommand
+//! takes `--config <file>` to load couplings and rates (see
+//! `crsm7_engine::config`) and `--agents <file>` to replace the standard
+//! agents (see `crsm7_engine::agents`). Exit status is 0 on success, 1 when a command
+//! fails or the state is not sovereign, and 2 for usage and config errors.
+
+use crsm7_engine::{
+    complete, observable_index, sparkline, AgentRegistry, BranchTree, Breakpoint,
+    Breakpoints, CRSM7State, CRSMHamiltonian, DualityOperator, EngineConfig, EnergyTracker,
+    Ensemble, Gene, Hit, Recorder, Session, SovereigntyForecast, Stability, Trajectory, Z3Mesh,
+    DEFAULT_OBSERVABLES, DEFAULT_SPREAD, OBSERVABLES, OMEGA_SOV_THRESHOLD,
+};
+mod tui;
+
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser, Subcommand};
+use rustyline::completion::{Completer, FilenameCompleter, Pair};
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::history::DefaultHistory;
+use rustyline::validate::Validator;
+use rustyline::{Context, Editor, Helper};
+use std::path::{Path, PathBuf};
+
+#[derive(Parser)]
+#[command(name = "crsm7", version, about = "CRSM7 engine: 7D state manifold and Z3 mesh")]
+struct Cli {
+    /// Couplings and rates (TOML)
+    #[arg(long, global = true, value_name = "FILE")]
+    config: Option<PathBuf>,
+    /// Agent definitions (TOML or JSON) replacing the standard five
+    #[arg(long, global = true, value_name = "FILE")]
+    agents: Option<PathBuf>,
+    /// Run interactive commands from a file (same as `script <FILE>`)
+    #[arg(long, value_name = "FILE")]
+    script: Option<PathBuf>,
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Boot display: state manifold, mesh bindings, duality operators and
+    /// sovereignty (default)
+    Run,
+    /// Evolution loop with line editing, history and tab completion
+    Interactive,
+    /// Live dashboard: gauges, Ω_sov sparkline and mesh bindings
+    Tui,
+    /// Run interactive commands from a file, stopping at the first that
+    /// fails
+    Script { file: PathBuf },
+    /// Evolve the state and mesh and print the final state
+    Evolve {
+        #[arg(long, default_value_t = 10)]
+        steps: usize,
+        #[arg(long, default_value_t = 1.0)]
+        dt: f64,
+        /// Start from a saved session instead of the standard one
+        #[arg(long, value_name = "FILE")]
+        session: Option<PathBuf>,
+        /// Log the state vector at every step to a CSV file
+        #[arg(long, value_name = "FILE")]
+        record: Option<PathBuf>,
+    },
+    /// Z3 mesh commands
+    Mesh {
+        #[command(subcommand)]
+        command: MeshCommand,
+    },
+    /// Check the sovereignty criteria; exits 1 when not sovereign
+    Sovereignty {
+        /// Check a saved session instead of the standard one
+        #[arg(long, value_name = "FILE")]
+        session: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum MeshCommand {
+    /// Print vertices, edges, weights and decoherence
+    Show {
+        /// Show a saved session's mesh instead of the standard one
+        #[arg(long, value_name = "FILE")]
+        session: Option<PathBuf>,
+    },
+}
+
+/// Tab completion for the interactive prompt: file paths after `save`,
+/// `load`, `export` and `record on`, `crsm7_engine::complete` otherwise
+struct ReplHelper {
+    vertices: Vec<String>,
+    files: FilenameCompleter,
+}
+
+impl ReplHelper {
+    fn new(mesh: &Z3Mesh) -> Self {
+        Self {
+            vertices: mesh
+                .vertices
+                .iter()
+                .flat_map(|v| [v.id.clone(), v.name.clone()])
+                .collect(),
+            files: FilenameCompleter::new(),
+        }
+    }
+}
+
+impl Completer for ReplHelper {
+    type Candidate = Pair;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        ctx: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<Pair>)> {
+        let head = &line[..pos];
+        let words: Vec<&str> = head.split_whitespace().collect();
+        let wants_file = match words.as_slice() {
+            ["save" | "load" | "export", ..] => head.contains(' '),
+            ["record", "on", ..] => words.len() > 2 || head.ends_with(' '),
+            _ => false,
+        };
+        if wants_file {
+            return self.files.complete(line, pos, ctx);
+        }
+        let vertices: Vec<&str> = self.vertices.iter().map(String::as_str).collect();
+        let (start, candidates) = complete(head, &vertices);
+        let pairs = candidates
+            .into_iter()
+            .map(|c| Pair {
+                display: c.clone(),
+                replacement: c,
+            })
+            .collect();
+        Ok((start, pairs))
+    }
+}
+
+impl Hinter for ReplHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ReplHelper {}
+
+impl Validator for ReplHelper {}
+
+impl Helper for ReplHelper {}
+
+/// Where interactive history is kept, if there is a home directory
+fn history_path() -> Option<PathBuf> {
+    std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".crsm7_history"))
+}
+
+/// Print the CRSM7 banner with the agent names
+fn print_banner(agents: &AgentRegistry) {
+    println!("╔═══════════════════════════════════════════════════╗");
+    println!("║ dna::}}{{::lang – CRSM7 Z3MESH BIOCONTAINER v3.1    ║");
+    println!("║ {:<49} ║", agents.banner_line());
+    println!("╚═══════════════════════════════════════════════════╝");
+    println!();
+}
+
+/// Initialize and display the 7D state manifold
+fn init_state_manifold() -> CRSM7State {
+    println!("[CRSM7] Initializing 7D state manifold...");
+    
+    let mut state = CRSM7State::default();
+    state.compute_emergence();
+    
+    println!("{}", state.display());
+    println!();
+    
+    state
+}
+
+/// Initialize and display Z3 mesh bindings
+fn init_z3_mesh(mesh: &Z3Mesh) {
+    println!("[Z3MESH] Binding vertices...");
+    
+    print!("{}", mesh.display_bindings());
+    println!();
+}
+
+/// Display duality operator status
+fn display_duality_operators() {
+    println!("[Π±] Duality operators active");
+    
+    let duality = DualityOperator::new();
+    println!("{}", duality.display());
+    println!();
+}
+
+/// Check and display sovereignty status
+fn check_sovereignty(state: &CRSM7State) -> bool {
+    let omega_sov = state.compute_sovereignty();
+    let is_sovereign = state.check_sovereignty();
+    
+    let status = if is_sovereign { "✓" } else { "✗" };
+    
+    println!("[SOVEREIGN] Ω_sov = {:.2} ≥ {:.2} {}", omega_sov, OMEGA_SOV_THRESHOLD, status);
+    
+    if is_sovereign {
+        println!("  Independence manifold locked");
+        println!("  M_7D = M_6D ⊕ S¹_polarity");
+    } else {
+        println!("  Working toward independence...");
+        println!("  Continue evolution to reach sovereignty threshold");
+    }
+    println!();
+    
+    is_sovereign
+}
+
+/// Display agent boot status
+fn display_boot_status(agents: &AgentRegistry) {
+    println!("[BOOT] All agents online");
+    
+    for agent in &agents.agents {
+        if agent.role.is_empty() {
+            println!("  {}: {}", agent.name(), agent.status);
+        } else {
+            println!("  {} ({}): {}", agent.name(), agent.role, agent.status);
+        }
+    }
+}
+
+/// Run the CRSM7 engine
+fn run_crsm7(agents: &AgentRegistry, mesh: &Z3Mesh) {
+    print_banner(agents);
+    
+    let state = init_state_manifold();
+    init_z3_mesh(mesh);
+    display_duality_operators();
+    check_sovereignty(&state);
+    display_boot_status(agents);
+}
+
+/// Print sparklines of the selected observables (Λ, Γ, Φ, Ξ by default)
+fn print_sparklines(trajectory: &Trajectory, names: &[&str]) {
+    let mut selected = Vec::new();
+    for name in names {
+        match observable_index(name) {
+            Some(index) => selected.push(index),
+            None => {
+                let known: Vec<&str> = OBSERVABLES.iter().map(|(_, long)| *long).collect();
+                println!("Unknown observable: {} (expected one of {})", name, known.join(", "));
+            }
+        }
+    }
+    if names.is_empty() {
+        selected.extend(DEFAULT_OBSERVABLES);
+    }
+    if selected.is_empty() || trajectory.is_empty() {
+        return;
+    }
+
+    println!("\nTrajectory (last {} samples):", trajectory.len());
+    println!("{}", trajectory.sparklines(&selected));
+}
+
+/// Mesh index of a vertex given by index, id or name
+fn vertex_index(mesh: &Z3Mesh, key: &str) -> Result<usize, String> {
+    mesh.find_vertex(key).ok_or_else(|| format!("Unknown vertex: {}", key))
+}
+
+/// Print the predicted time to sovereignty from the trajectory's rates
+fn print_forecast(trajectory: &Trajectory) {
+    let Some(forecast) = SovereigntyForecast::fit(trajectory) else {
+        println!("Sovereignty ETA: evolve to collect samples");
+        return;
+    };
+    match (forecast.remaining, forecast.steps()) {
+        (Some(0.0), _) => println!("Sovereignty ETA: reached"),
+        (Some(remaining), Some(steps)) => println!(
+            "Sovereignty ETA: τ+{:.2} (≈{} steps of dt={:.4})",
+            remaining, steps, forecast.step
+        ),
+        _ => println!("Sovereignty ETA: not reached at current rates"),
+    }
+}
+
+/// Print the breakpoints a step fired
+fn report_hits(breakpoints: &Breakpoints, hits: &[Hit], tau: f64) {
+    for hit in hits {
+        let condition = breakpoints
+            .iter()
+            .find(|(id, _)| *id == hit.id)
+            .map(|(_, b)| b.to_string())
+            .unwrap_or_default();
+        let previous = hit.previous.map_or("-".to_string(), |p| format!("{:.6e}", p));
+        println!(
+            "[BREAK {}] {} at τ={:.4}: {} → {:.6e}",
+            hit.id, condition, tau, previous, hit.value
+        );
+    }
+}
+
+/// What the loop does after a command
+enum Flow {
+    Continue,
+    Quit,
+}
+
+/// `parts[index]` parsed, or `default` when absent
+fn arg<T: std::str::FromStr>(parts: &[&str], index: usize, default: T) -> Result<T, String> {
+    match parts.get(index) {
+        Some(s) => s.parse().map_err(|_| format!("Invalid value: {}", s)),
+        None => Ok(default),
+    }
+}
+
+/// State of an interactive or scripted session
+struct Repl {
+    state: CRSM7State,
+    mesh: Z3Mesh,
+    hamiltonian: CRSMHamiltonian,
+    trajectory: Trajectory,
+    breakpoints: Breakpoints,
+    branches: BranchTree,
+    energy: EnergyTracker,
+    /// CSV log of every evolution step, while `record on`
+    recorder: Option<Recorder>,
+}
+
+impl Repl {
+    fn new(config: &EngineConfig, mesh: Z3Mesh) -> Self {
+        let Session { state, .. } = Session::new();
+        let hamiltonian = config.hamiltonian();
+        let mut trajectory = Trajectory::default();
+        trajectory.record(&state);
+        Self {
+            branches: BranchTree::new(state.clone()),
+            energy: config.energy_tracker(&state),
+            state,
+            mesh,
+            hamiltonian,
+            trajectory,
+            breakpoints: Breakpoints::new(),
+            recorder: None,
+        }
+    }
+
+    /// Run one command line, printing its output; errors are returned for
+    /// the caller to report
+    fn execute(&mut self, input: &str) -> Result<Flow, String> {
+        let parts: Vec<&str> = input.split_whitespace().collect();
+        if parts.is_empty() {
+            return Ok(Flow::Continue);
+        }
+        let Self {
+            state,
+            mesh,
+            hamiltonian,
+            trajectory,
+            breakpoints,
+            branches,
+            energy,
+            recorder,
+        } = self;
+
+        match parts[0] {
+            "evolve" => {
+                let dt: f64 = arg(&parts, 1, 1.0)?;
+                hamiltonian.evolve_state(state, dt);
+                energy.observe(state);
+                mesh.evolve(dt);
+                trajectory.record(state);
+                if let Some(recorder) = recorder {
+                    recorder.record(state)?;
+                }
+                println!("Evolved by dt={}", dt);
+                println!("{}", state.display());
+                let hits = breakpoints.check(state, mesh);
+                report_hits(breakpoints, &hits, state.tau);
+            }
+            "break" => {
+                if parts.len() == 1 {
+                    if breakpoints.is_empty() {
+                        println!("No breakpoints");
+                    }
+                    for (id, breakpoint) in breakpoints.iter() {
+                        println!("  [{}] {}", id, breakpoint);
+                    }
+                } else {
+                    let breakpoint = Breakpoint::parse(&parts[1..].join(" "))?;
+                    let id = breakpoints.add(breakpoint, state, mesh);
+                    println!("Breakpoint {} set", id);
+                }
+            }
+            "delete" => {
+                let id: usize = match parts.get(1) {
+                    Some(_) => arg(&parts, 1, 0)?,
+                    None => return Err("Usage: delete <id>".to_string()),
+                };
+                let breakpoint = breakpoints
+                    .remove(id)
+                    .ok_or_else(|| format!("No breakpoint {}", id))?;
+                println!("Deleted breakpoint {}: {}", id, breakpoint);
+            }
+            "continue" => {
+                let max_steps: usize = arg(&parts, 1, 1000)?;
+                let dt: f64 = arg(&parts, 2, 1.0)?;
+                let mut steps = 0;
+                let mut hits = Vec::new();
+                while steps < max_steps && hits.is_empty() {
+                    hamiltonian.evolve_state(state, dt);
+                    energy.observe(state);
+                    mesh.evolve(dt);
+                    trajectory.record(state);
+                    if let Some(recorder) = recorder.as_mut() {
+                        recorder.record(state)?;
+                    }
+                    hits = breakpoints.check(state, mesh);
+                    steps += 1;
+                }
+                println!("Evolved {} steps of dt={}", steps, dt);
+                if hits.is_empty() {
+                    println!("No breakpoint fired");
+                }
+                report_hits(breakpoints, &hits, state.tau);
+            }
+            "inspect" => match parts.get(1) {
+                None => println!("{}", state.display()),
+                Some(name) => {
+                    let vertex = &mesh.vertices[vertex_index(mesh, name)?];
+                    println!("{}:\n{}", vertex.name, vertex.state.display());
+                }
+            },
+            "mesh" => match parts.get(1..).unwrap_or_default() {
+                [] | ["show"] => show_mesh(mesh),
+                ["add", name] => {
+                    if mesh.find_vertex(name).is_some() {
+                        return Err(format!("Vertex {} already exists", name));
+                    }
+                    let index = mesh.add_vertex(Gene::new(&name.to_lowercase(), name));
+                    println!("Added vertex [{}] {}", index, name);
+                }
+                ["connect", i, j] => {
+                    let (i, j) = (vertex_index(mesh, i)?, vertex_index(mesh, j)?);
+                    if i == j {
+                        return Err("Cannot connect a vertex to itself".to_string());
+                    }
+                    if mesh.edge_between(i, j).is_some() {
+                        return Err("Vertices are already connected".to_string());
+                    }
+                    mesh.connect(i, j);
+                    let edge = &mesh.edges[mesh.edges.len() - 1];
+                    println!(
+                        "Connected {} ←→ {}  weight={:.4} Γ={:.3}",
+                        mesh.vertices[i].name, mesh.vertices[j].name, edge.weight, edge.gamma
+                    );
+                }
+                ["collapse", i, j] => {
+                    let (i, j) = (vertex_index(mesh, i)?, vertex_index(mesh, j)?);
+                    let edge = mesh.edge_between(i, j).ok_or("Vertices are not connected")?;
+                    mesh.collapse(i, j);
+                    let edge = &mesh.edges[edge];
+                    if edge.bound {
+                        println!(
+                            "Bound {} ←→ {}, ΛΦ propagated",
+                            mesh.vertices[i].name, mesh.vertices[j].name
+                        );
+                    } else {
+                        println!("Γ={:.3} has not reached 0.01; not bound", edge.gamma);
+                    }
+                }
+                ["cascade", i, j] => {
+                    let (i, j) = (vertex_index(mesh, i)?, vertex_index(mesh, j)?);
+                    let edge = mesh.edge_between(i, j).ok_or("Vertices are not connected")?;
+                    let bound = mesh.collapse_cascade(i, j);
+                    if !mesh.edges[edge].bound {
+                        println!("Γ={:.3} has not reached 0.01; not bound", mesh.edges[edge].gamma);
+                    } else {
+                        println!("Cascade bound {} edge(s)", bound.len());
+                        for idx in bound {
+                            let edge = &mesh.edges[idx];
+                            println!(
+                                "  {} ←→ {}",
+                                mesh.vertices[edge.from].name, mesh.vertices[edge.to].name
+                            );
+                        }
+                    }
+                }
+                _ => {
+                    return Err("Usage: mesh [show | add <name> | connect <i> <j> | \
+                         collapse <i> <j> | cascade <i> <j>]"
+                        .to_string())
+                }
+            },
+            "status" => {
+                println!("{}", state.display());
+                println!("\nSovereignty: {:.4}", state.compute_sovereignty());
+                println!("Hamiltonian: {:.4}", state.hamiltonian());
+                print_forecast(trajectory);
+                print_sparklines(trajectory, &parts[1..]);
+            }
+            "stability" => {
+                let steps: usize = arg(&parts, 1, 1000)?;
+                let dt: f64 = arg(&parts, 2, 0.1)?;
+                let estimate = Stability::new()
+                    .with_steps(steps)
+                    .with_dt(dt)
+                    .analyze(hamiltonian, state);
+                let regime = if estimate.is_chaotic() { "chaotic" } else { "stable" };
+                println!(
+                    "Lyapunov exponent: {:.6} per unit τ over {} steps of dt={} ({})",
+                    estimate.exponent, estimate.running.len(), dt, regime
+                );
+                // Running estimate, thinned to at most 60 samples
+                let stride = estimate.running.len().div_ceil(60).max(1);
+                let samples: Vec<f64> = estimate.running.iter().step_by(stride).copied().collect();
+                println!("Convergence: {}", sparkline(&samples));
+            }
+            "ensemble" => {
+                let n: usize = arg(&parts, 1, 100)?;
+                let steps: usize = arg(&parts, 2, 100)?;
+                let dt: f64 = arg(&parts, 3, 0.1)?;
+                let mut ensemble = Ensemble::varied(state, n, DEFAULT_SPREAD, 0);
+                ensemble.run(hamiltonian, steps, dt);
+                println!(
+                    "Ensemble of {} copies (±{}% Λ, Γ, Φ) after {} steps of dt={}:",
+                    n,
+                    DEFAULT_SPREAD * 100.0,
+                    steps,
+                    dt
+                );
+                print!("{}", ensemble.stats().display());
+                if let Some(path) = parts.get(4) {
+                    ensemble.export_csv(path)?;
+                    println!("Wrote {} members to {}", n, path);
+                }
+            }
+            "energy" => match parts.get(1).copied() {
+                None => println!("Energy E = k·Λ² + p·Φ:\n{}", energy.report()),
+                Some("reset") => {
+                    energy.reset(state);
+                    println!("Energy reference reset to {:.6}", energy.reference);
+                }
+                Some(_) => return Err("Usage: energy [reset]".to_string()),
+            },
+            "bifurcate" => {
+                branches.record(state);
+                let (pos, neg) = branches.bifurcate_with(&mesh.duality);
+                println!("Π+ branch [{}]:\n{}", pos, branches.branches[pos].state.display());
+                println!("\nΠ- branch [{}]:\n{}", neg, branches.branches[neg].state.display());
+                println!("\nbranch <id> switches to a branch");
+            }
+            "branches" => {
+                branches.record(state);
+                print!("{}", branches.display());
+            }
+            "branch" => {
+                let id: usize = match parts.get(1) {
+                    Some(_) => arg(&parts, 1, 0)?,
+                    None => return Err("Usage: branch <id>".to_string()),
+                };
+                branches.record(state);
+                *state = branches.select(id)?.clone();
+                trajectory.record(state);
+                println!("Active branch {} ({})", id, branches.active().label);
+                println!("{}", state.display());
+            }
+            "export" => {
+                let path = parts.get(1).ok_or("Usage: export <file.json|file.dot>")?;
+                branches.record(state);
+                branches.export(path)?;
+                println!("Exported {} branches to {}", branches.branches.len(), path);
+            }
+            "set" => {
+                let (field, value) = match (parts.get(1), parts.get(2)) {
+                    (Some(field), Some(_)) => (*field, arg(&parts, 2, 0.0)?),
+                    _ => return Err("Usage: set <field> <value>".to_string()),
+                };
+                state.set(field, value)?;
+                trajectory.record(state);
+                println!("{}", state.display());
+            }
+            "save" => {
+                let path = parts.get(1).ok_or("Usage: save <file>")?;
+                let session = Session {
+                    state: state.clone(),
+                    mesh: mesh.clone(),
+                    hamiltonian: hamiltonian.clone(),
+                };
+                session.save(path)?;
+                println!("Saved session to {} at τ={:.4}", path, state.tau);
+            }
+            "load" => {
+                let path = parts.get(1).ok_or("Usage: load <file>")?;
+                let session = Session::load(path)?;
+                *state = session.state;
+                *mesh = session.mesh;
+                *hamiltonian = session.hamiltonian;
+                *trajectory = Trajectory::default();
+                trajectory.record(state);
+                *branches = BranchTree::new(state.clone());
+                energy.reset(state);
+                println!("Loaded session from {} at τ={:.4}", path, state.tau);
+                println!("{}", state.display());
+            }
+            "record" => match (parts.get(1).copied(), parts.get(2)) {
+                (None, _) => match recorder {
+                    Some(r) => println!("Recording to {} ({} rows)", r.path().display(), r.rows()),
+                    None => println!("Not recording"),
+                },
+                (Some("on"), Some(path)) => {
+                    if let Some(mut previous) = recorder.take() {
+                        previous.flush()?;
+                    }
+                    let mut started = Recorder::create(path)?;
+                    started.record(state)?;
+                    *recorder = Some(started);
+                    println!("Recording each evolve step to {}", path);
+                }
+                (Some("off"), None) => {
+                    let mut stopped = recorder.take().ok_or("Not recording")?;
+                    stopped.flush()?;
+                    println!("Wrote {} rows to {}", stopped.rows(), stopped.path().display());
+                }
+                _ => return Err("Usage: record [on <file.csv> | off]".to_string()),
+            },
+            "quit" | "exit" => return Ok(Flow::Quit),
+            _ => return Err(format!("Unknown command: {}", parts[0])),
+        }
+        Ok(Flow::Continue)
+    }
+}
+
+/// Interactive mode for evolution
+fn interactive_mode(config: &EngineConfig, mesh: Z3Mesh) {
+    let mut repl = Repl::new(config, mesh);
+
+    let mut editor: Editor<ReplHelper, DefaultHistory> = match Editor::new() {
+        Ok(editor) => editor,
+        Err(e) => {
+            eprintln!("Cannot start line editor: {}", e);
+            return;
+        }
+    };
+    editor.set_helper(Some(ReplHelper::new(&repl.mesh)));
+    let history = history_path();
+    if let Some(path) = &history {
+        let _ = editor.load_history(path);
+    }
+    
+    println!("\n[INTERACTIVE] CRSM7 Evolution Mode");
+    println!("Commands: evolve <dt>, status [observables], stability [steps] [dt],");
+    println!("          ensemble <n> <steps> <dt> [file.csv], energy [reset],");
+    println!("          bifurcate, branches, branch <id>, export <file>, break [condition],");
+    println!("          delete <id>, continue [max_steps] [dt], inspect [vertex],");
+    println!("          mesh [show | add <name> | connect <i> <j> | collapse <i> <j> |");
+    println!("                cascade <i> <j>],");
+    println!("          set <field> <value>, save <file>, load <file>,");
+    println!("          record [on <file.csv> | off], quit\n");
+    
+    loop {
+        let input = match editor.readline("> ") {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted) => continue,
+            Err(_) => break,
+        };
+        if input.trim().is_empty() {
+            continue;
+        }
+        let _ = editor.add_history_entry(input.as_str());
+        
+        match repl.execute(&input) {
+            Ok(Flow::Quit) => break,
+            Ok(Flow::Continue) => {}
+            Err(e) => println!("{}", e),
+        }
+        if matches!(input.split_whitespace().next(), Some("load" | "mesh")) {
+            editor.set_helper(Some(ReplHelper::new(&repl.mesh)));
+        }
+        println!();
+    }
+
+    if let Some(path) = &history {
+        let _ = editor.save_history(path);
+    }
+}
+
+/// Session loaded from `path`, or the initial state on the agents' mesh
+/// with `config` applied
+fn start_session(config: &EngineConfig, mesh: Z3Mesh, path: Option<&Path>) -> Result<Session, String> {
+    match path {
+        Some(path) => Session::load(path),
+        None => {
+            let Session { state, .. } = Session::new();
+            Ok(Session {
+                state,
+                mesh,
+                hamiltonian: config.hamiltonian(),
+            })
+        }
+    }
+}
+
+/// `evolve`: step the session's state and mesh, optionally recording
+fn evolve_command(
+    config: &EngineConfig,
+    mesh: Z3Mesh,
+    session: Option<&Path>,
+    steps: usize,
+    dt: f64,
+    record: Option<&Path>,
+) -> Result<(), String> {
+    let Session {
+        mut state,
+        mut mesh,
+        hamiltonian,
+    } = start_session(config, mesh, session)?;
+    let mut energy = config.energy_tracker(&state);
+    let mut recorder = record.map(Recorder::create).transpose()?;
+    if let Some(recorder) = recorder.as_mut() {
+        recorder.record(&state)?;
+    }
+    for _ in 0..steps {
+        hamiltonian.evolve_state(&mut state, dt);
+        energy.observe(&mut state);
+        mesh.evolve(dt);
+        if let Some(recorder) = recorder.as_mut() {
+            recorder.record(&state)?;
+        }
+    }
+    if let Some(mut recorder) = recorder {
+        recorder.flush()?;
+    }
+    println!("Evolved {} steps of dt={}", steps, dt);
+    println!("{}", state.display());
+    println!("\nSovereignty: {:.4}", state.compute_sovereignty());
+    println!("Hamiltonian: {:.4}", state.hamiltonian());
+    println!("\nEnergy:\n{}", energy.report());
+    Ok(())
+}
+
+/// `mesh show`: vertices with their states, then edges
+fn show_mesh(mesh: &Z3Mesh) {
+    println!("[Z3MESH] {} vertices, {} edges", mesh.vertices.len(), mesh.edges.len());
+    for (i, vertex) in mesh.vertices.iter().enumerate() {
+        println!(
+            "  [{}] {} ({})  Λ={:.3} Γ={:.3} Ξ={:.2}",
+            i, vertex.name, vertex.id, vertex.state.lambda, vertex.state.gamma, vertex.state.xi
+        );
+    }
+    println!();
+    for edge in &mesh.edges {
+        println!(
+            "  {} ←→ {}  weight={:.4} Γ={:.3} {}",
+            mesh.vertices[edge.from].name,
+            mesh.vertices[edge.to].name,
+            edge.weight,
+            edge.gamma,
+            if edge.bound { "✓" } else { "○" }
+        );
+    }
+    println!("\nDistance: {:?}", mesh.distance);
+    println!("Total decoherence: {:.4}", mesh.total_decoherence());
+}
+
+/// Run the commands in `path`, one per line (blank lines and lines
+/// starting with `#` are skipped), stopping at the first that fails.
+/// Returns whether every command succeeded.
+fn script_mode(path: &Path, config: &EngineConfig, mesh: Z3Mesh) -> bool {
+    let script = match std::fs::read_to_string(path) {
+        Ok(script) => script,
+        Err(e) => {
+            eprintln!("cannot read {}: {}", path.display(), e);
+            return false;
+        }
+    };
+
+    let mut repl = Repl::new(config, mesh);
+    for (number, line) in script.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        println!("> {}", line);
+        match repl.execute(line) {
+            Ok(Flow::Continue) => println!(),
+            Ok(Flow::Quit) => break,
+            Err(e) => {
+                eprintln!("{}:{}: {}", path.display(), number + 1, e);
+                return false;
+            }
+        }
+    }
+    true
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    let config = match &cli.config {
+        Some(path) => match EngineConfig::from_file(path) {
+            Ok(config) => config,
+            Err(e) => {
+                eprintln!("{}", e);
+                std::process::exit(2);
+            }
+        },
+        None => EngineConfig::default(),
+    };
+    let agents = match &cli.agents {
+        Some(path) => AgentRegistry::from_file(path),
+        None => Ok(AgentRegistry::standard()),
+    };
+    let (agents, mesh) = match agents.and_then(|a| a.mesh().map(|mesh| (a, mesh))) {
+        Ok((agents, mesh)) => (agents, mesh.with_config(&config)),
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }
+    };
+
+    let command = match (cli.script, cli.command) {
+        (Some(file), None) => Command::Script { file },
+        (Some(_), Some(_)) => Cli::command()
+            .error(
+                ErrorKind::ArgumentConflict,
+                "--script cannot be used with a subcommand",
+            )
+            .exit(),
+        (None, command) => command.unwrap_or(Command::Run),
+    };
+    let succeeded = match command {
+        Command::Run => {
+            run_crsm7(&agents, &mesh);
+            true
+        }
+        Command::Interactive => {
+            interactive_mode(&config, mesh);
+            true
+        }
+        Command::Tui => match tui::run(&config, mesh) {
+            Ok(()) => true,
+            Err(e) => {
+                eprintln!("{}", e);
+                false
+            }
+        },
+        Command::Script { file } => script_mode(&file, &config, mesh),
+        Command::Evolve {
+            steps,
+            dt,
+            session,
+            record,
+        } => match evolve_command(&config, mesh, session.as_deref(), steps, dt, record.as_deref()) {
+            Ok(()) => true,
+            Err(e) => {
+                eprintln!("{}", e);
+                false
+            }
+        },
+        Command::Mesh {
+            command: MeshCommand::Show { session },
+        } => match start_session(&config, mesh, session.as_deref()) {
+            Ok(Session { mesh, .. }) => {
+                show_mesh(&mesh);
+                true
+            }
+            Err(e) => {
+                eprintln!("{}", e);
+                false
+            }
+        },
+        Command::Sovereignty { session } => match start_session(&config, mesh, session.as_deref()) {
+            Ok(Session { state, .. }) => check_sovereignty(&state),
+            Err(e) => {
+                eprintln!("{}", e);
+                false
+            }
+        },
+    };
+    if !succeeded {
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,324 @@
+//! `crsm7 tui`: live evolution dashboard
+//!
+//! Gauges for Λ, Γ, Φ and Ξ, a sparkline of Ω_sov and the mesh binding
+//! table, redrawn every tick while the state evolves. Γ and Λ fill their
+//! gauges directly, Φ relative to the largest value seen and Ξ on a log
+//! scale up to its 10¹² cap. Keys: space pauses and resumes, `n` steps
+//! once, `b` bifurcates and continues on the opposite polarity, `+`/`-`
+//! double or halve dt, `q` or Esc quits.
+
+use crsm7_engine::{
+    BranchTree, CRSM7State, CRSMHamiltonian, EngineConfig, Session, Z3Mesh, OMEGA_SOV_THRESHOLD,
+};
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Gauge, Row, Sparkline, Table};
+use ratatui::{DefaultTerminal, Frame};
+use std::collections::VecDeque;
+use std::io;
+use std::time::{Duration, Instant};
+
+/// Interval between evolution steps while running
+const TICK: Duration = Duration::from_millis(100);
+
+/// Ω_sov samples kept for the sparkline
+const HISTORY: usize = 200;
+
+/// Dashboard state: the evolving session and display history
+struct Dashboard {
+    state: CRSM7State,
+    mesh: Z3Mesh,
+    hamiltonian: CRSMHamiltonian,
+    branches: BranchTree,
+    omega: VecDeque<f64>,
+    /// Largest Φ seen, the Φ gauge's full scale
+    phi_peak: f64,
+    dt: f64,
+    running: bool,
+    steps: usize,
+    message: String,
+}
+
+impl Dashboard {
+    fn new(config: &EngineConfig, mesh: Z3Mesh) -> Self {
+        let Session { state, .. } = Session::new();
+        let mut dashboard = Self {
+            branches: BranchTree::new(state.clone()),
+            phi_peak: state.phi.max(1.0),
+            state,
+            mesh,
+            hamiltonian: config.hamiltonian(),
+            omega: VecDeque::with_capacity(HISTORY),
+            dt: 0.1,
+            running: true,
+            steps: 0,
+            message: String::new(),
+        };
+        dashboard.sample();
+        dashboard
+    }
+
+    fn sample(&mut self) {
+        if self.omega.len() == HISTORY {
+            self.omega.pop_front();
+        }
+        self.omega.push_back(self.state.compute_sovereignty());
+        self.phi_peak = self.phi_peak.max(self.state.phi);
+    }
+
+    fn step(&mut self) {
+        self.hamiltonian.evolve_state(&mut self.state, self.dt);
+        self.mesh.evolve(self.dt);
+        self.steps += 1;
+        self.sample();
+    }
+
+    /// Bifurcate and continue on the branch of opposite polarity
+    fn bifurcate(&mut self) {
+        self.branches.record(&self.state);
+        let (positive, negative) = self.branches.bifurcate_with(&self.mesh.duality);
+        let next = if self.state.rho_polarity >= 0.0 {
+            negative
+        } else {
+            positive
+        };
+        if let Ok(state) = self.branches.select(next) {
+            self.state = state.clone();
+        }
+        self.message = format!(
+            "Bifurcated at τ={:.2}: Π+ [{}], Π- [{}]; on [{}] {}",
+            self.state.tau,
+            positive,
+            negative,
+            next,
+            self.branches.active().label
+        );
+    }
+
+    /// Apply a key press; false to quit
+    fn handle_key(&mut self, code: KeyCode) -> bool {
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Char(' ') => self.running = !self.running,
+            KeyCode::Char('n') => self.step(),
+            KeyCode::Char('b') => self.bifurcate(),
+            KeyCode::Char('+') => self.dt *= 2.0,
+            KeyCode::Char('-') => self.dt /= 2.0,
+            _ => {}
+        }
+        true
+    }
+
+    fn draw(&self, frame: &mut Frame) {
+        let [title, body, footer] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Min(0),
+            Constraint::Length(2),
+        ])
+        .areas(frame.area());
+        let [left, right] =
+            Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .areas(body);
+        let [lambda, gamma, phi, xi, omega] = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Min(3),
+        ])
+        .areas(left);
+
+        let status = if self.running { "running" } else { "paused" };
+        frame.render_widget(
+            Line::from(format!(
+                " CRSM7 · τ={:.2} · step {} · dt={} · ρ={:+.0} · {}",
+                self.state.tau, self.steps, self.dt, self.state.rho_polarity, status
+            ))
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+            title,
+        );
+
+        let state = &self.state;
+        let gauges = [
+            (
+                lambda,
+                "Λ coherence",
+                state.lambda,
+                format!("{:.4}", state.lambda),
+                Color::Green,
+            ),
+            (
+                gamma,
+                "Γ decoherence",
+                state.gamma,
+                format!("{:.3e}", state.gamma),
+                Color::Red,
+            ),
+            (
+                phi,
+                "Φ information",
+                state.phi / self.phi_peak,
+                format!("{:.4}", state.phi),
+                Color::Blue,
+            ),
+            (
+                xi,
+                "Ξ emergence (log)",
+                (1.0 + state.xi).log10() / 12.0,
+                format!("{:.2}", state.xi),
+                Color::Magenta,
+            ),
+        ];
+        for (area, name, ratio, label, color) in gauges {
+            let ratio = if ratio.is_finite() {
+                ratio.clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            frame.render_widget(
+                Gauge::default()
+                    .block(Block::bordered().title(name))
+                    .gauge_style(Style::default().fg(color))
+                    .ratio(ratio)
+                    .label(label),
+                area,
+            );
+        }
+
+        let current = self.omega.back().copied().unwrap_or(0.0);
+        let data: Vec<u64> = self
+            .omega
+            .iter()
+            .map(|w| (w.clamp(0.0, 1.0) * 1000.0).round() as u64)
+            .collect();
+        frame.render_widget(
+            Sparkline::default()
+                .block(Block::bordered().title(format!(
+                    "Ω_sov {:.4} (sovereign at {:.2})",
+                    current, OMEGA_SOV_THRESHOLD
+                )))
+                .data(&data)
+                .max(1000)
+                .style(Style::default().fg(if current >= OMEGA_SOV_THRESHOLD {
+                    Color::Green
+                } else {
+                    Color::Yellow
+                })),
+            omega,
+        );
+
+        let rows = self.mesh.edges.iter().map(|edge| {
+            Row::new(vec![
+                format!(
+                    "{} ←→ {}",
+                    self.mesh.vertices[edge.from].name, self.mesh.vertices[edge.to].name
+                ),
+                format!("{:.4}", edge.weight),
+                format!("{:.3e}", edge.gamma),
+                if edge.bound { "✓" } else { "○" }.to_string(),
+            ])
+        });
+        frame.render_widget(
+            Table::new(
+                rows,
+                [
+                    Constraint::Min(20),
+                    Constraint::Length(12),
+                    Constraint::Length(10),
+                    Constraint::Length(5),
+                ],
+            )
+            .header(
+                Row::new(vec!["Binding", "Weight", "Γ", "Bound"])
+                    .style(Style::default().add_modifier(Modifier::BOLD)),
+            )
+            .block(
+                Block::bordered().title(format!("Z3 mesh · {} vertices", self.mesh.vertices.len())),
+            ),
+            right,
+        );
+
+        frame.render_widget(
+            ratatui::widgets::Paragraph::new(vec![
+                Line::from(" space pause/resume · n step · b bifurcate · +/- dt · q quit"),
+                Line::from(format!(" {}", self.message)),
+            ]),
+            footer,
+        );
+    }
+}
+
+/// Step and redraw until quit
+fn event_loop(terminal: &mut DefaultTerminal, mut dashboard: Dashboard) -> io::Result<()> {
+    let mut last_step = Instant::now();
+    loop {
+        terminal.draw(|frame| dashboard.draw(frame))?;
+        let timeout = TICK.saturating_sub(last_step.elapsed());
+        if event::poll(timeout)? {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press && !dashboard.handle_key(key.code) {
+                    return Ok(());
+                }
+            }
+        }
+        if last_step.elapsed() >= TICK {
+            if dashboard.running {
+                dashboard.step();
+            }
+            last_step = Instant::now();
+        }
+    }
+}
+
+/// Run the dashboard on the terminal, restoring it on exit
+pub fn run(config: &EngineConfig, mesh: Z3Mesh) -> Result<(), String> {
+    let mut terminal =
+        ratatui::try_init().map_err(|e| format!("Cannot start terminal UI: {}", e))?;
+    let result = event_loop(&mut terminal, Dashboard::new(config, mesh));
+    ratatui::restore();
+    result.map_err(|e| format!("Terminal UI failed: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crsm7_engine::create_standard_mesh;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    #[test]
+    fn test_dashboard_keys_and_render() {
+        let mut dashboard = Dashboard::new(&EngineConfig::default(), create_standard_mesh());
+        assert!(dashboard.handle_key(KeyCode::Char(' ')));
+        assert!(!dashboard.running);
+        dashboard.handle_key(KeyCode::Char('n'));
+        assert_eq!((dashboard.steps, dashboard.omega.len()), (1, 2));
+        dashboard.handle_key(KeyCode::Char('b'));
+        assert_eq!(dashboard.state.rho_polarity, -1.0);
+        assert_eq!(dashboard.branches.branches.len(), 3);
+        dashboard.handle_key(KeyCode::Char('+'));
+        assert_eq!(dashboard.dt, 0.2);
+
+        let mut terminal = Terminal::new(TestBackend::new(110, 24)).unwrap();
+        terminal.draw(|frame| dashboard.draw(frame)).unwrap();
+        let screen: String = terminal
+            .backend()
+            .buffer()
+            .content
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        for text in [
+            "Λ coherence",
+            "Ω_sov",
+            "AURA ←→ AIDEN",
+            "paused",
+            "Bifurcated",
+        ] {
+            assert!(screen.contains(text), "missing {}", text);
+        }
+        assert!(!dashboard.handle_key(KeyCode::Char('q')));
+    }
+}
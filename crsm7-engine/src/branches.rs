@@ -0,0 +1,219 @@
+//! Bifurcation Tree
+//!
+//! Records every duality-polarized bifurcation B(Ψ) = Π+Ψ ⊕ Π-Ψ of an
+//! interactive session as a tree: each branch keeps its state, its parent
+//! and the projector that produced it. One branch is active — the state
+//! the session evolves — and `select` switches to any other, so both
+//! outcomes of a bifurcation can be explored. The tree exports as JSON or
+//! as Graphviz DOT (`dot -Tsvg tree.dot`).
+
+use crate::duality::DualityOperator;
+use crate::state::CRSM7State;
+use serde::{Deserialize, Serialize};
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+/// One node of the tree
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Branch {
+    pub id: usize,
+    pub parent: Option<usize>,
+    /// "root", "Π+" or "Π-"
+    pub label: String,
+    /// State when the branch was last active
+    pub state: CRSM7State,
+}
+
+/// Bifurcated states, indexed by branch id
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BranchTree {
+    pub branches: Vec<Branch>,
+    pub active: usize,
+}
+
+impl Default for BranchTree {
+    fn default() -> Self {
+        Self::new(CRSM7State::default())
+    }
+}
+
+impl BranchTree {
+    /// Tree with a single root branch holding `state`
+    pub fn new(state: CRSM7State) -> Self {
+        Self {
+            branches: vec![Branch {
+                id: 0,
+                parent: None,
+                label: "root".to_string(),
+                state,
+            }],
+            active: 0,
+        }
+    }
+
+    pub fn active(&self) -> &Branch {
+        &self.branches[self.active]
+    }
+
+    /// Store the session's current state in the active branch
+    pub fn record(&mut self, state: &CRSM7State) {
+        self.branches[self.active].state = state.clone();
+    }
+
+    /// Bifurcate the active branch into Π+ and Π- children and return
+    /// their ids; the active branch is unchanged
+    pub fn bifurcate(&mut self) -> (usize, usize) {
+        self.bifurcate_with(&DualityOperator::new())
+    }
+
+    /// `bifurcate` with `op`'s J
+    pub fn bifurcate_with(&mut self, op: &DualityOperator) -> (usize, usize) {
+        let parent = self.active;
+        let (positive, negative) = self.branches[parent].state.bifurcate_with(op);
+        let mut add = |label: &str, state| {
+            let id = self.branches.len();
+            self.branches.push(Branch {
+                id,
+                parent: Some(parent),
+                label: label.to_string(),
+                state,
+            });
+            id
+        };
+        (add("Π+", positive), add("Π-", negative))
+    }
+
+    /// Make branch `id` active and return its state
+    pub fn select(&mut self, id: usize) -> Result<&CRSM7State, String> {
+        if id >= self.branches.len() {
+            return Err(format!("No branch {}", id));
+        }
+        self.active = id;
+        Ok(&self.branches[id].state)
+    }
+
+    /// Ids of the children of `id`
+    pub fn children(&self, id: usize) -> impl Iterator<Item = usize> + '_ {
+        self.branches
+            .iter()
+            .filter(move |b| b.parent == Some(id))
+            .map(|b| b.id)
+    }
+
+    /// Indented listing, the active branch marked with `*`
+    pub fn display(&self) -> String {
+        let mut out = String::new();
+        let mut stack = vec![(0, 0)];
+        while let Some((id, depth)) = stack.pop() {
+            let branch = &self.branches[id];
+            let marker = if id == self.active { '*' } else { ' ' };
+            let _ = writeln!(
+                out,
+                "{} {}[{}] {}  τ={:.4} Λ={:.3} Γ={:.3} Φ={:.4} ρ={:+.0}",
+                marker,
+                "  ".repeat(depth),
+                id,
+                branch.label,
+                branch.state.tau,
+                branch.state.lambda,
+                branch.state.gamma,
+                branch.state.phi,
+                branch.state.rho_polarity
+            );
+            let children: Vec<usize> = self.children(id).collect();
+            stack.extend(children.into_iter().rev().map(|c| (c, depth + 1)));
+        }
+        out
+    }
+
+    /// Graphviz digraph, edges labelled by projector, the active branch
+    /// drawn bold
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph branches {\n");
+        for branch in &self.branches {
+            let style = if branch.id == self.active {
+                ", style=bold"
+            } else {
+                ""
+            };
+            let _ = writeln!(
+                out,
+                "    b{} [label=\"{}: {}\\nτ={:.4} Λ={:.3} Ξ={:.2}\"{}];",
+                branch.id,
+                branch.id,
+                branch.label,
+                branch.state.tau,
+                branch.state.lambda,
+                branch.state.xi,
+                style
+            );
+        }
+        for branch in &self.branches {
+            if let Some(parent) = branch.parent {
+                let _ = writeln!(
+                    out,
+                    "    b{} -> b{} [label=\"{}\"];",
+                    parent, branch.id, branch.label
+                );
+            }
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// Write the tree to `path`: DOT for a `.dot` extension, JSON otherwise
+    pub fn export(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        let path = path.as_ref();
+        let contents = if path.extension().is_some_and(|e| e == "dot") {
+            self.to_dot()
+        } else {
+            serde_json::to_string_pretty(self).map_err(|e| e.to_string())?
+        };
+        fs::write(path, contents).map_err(|e| format!("cannot write {}: {}", path.display(), e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bifurcate_select_and_record() {
+        let mut tree = BranchTree::new(CRSM7State::default());
+        let (positive, negative) = tree.bifurcate();
+        assert_eq!((positive, negative), (1, 2));
+        assert_eq!(tree.branches[negative].state.rho_polarity, -1.0);
+
+        let mut state = tree.select(negative).unwrap().clone();
+        state.evolve(1.0);
+        tree.record(&state);
+        let (grandchild, _) = tree.bifurcate();
+        assert_eq!(tree.branches[grandchild].parent, Some(negative));
+        assert_eq!(tree.branches[grandchild].state.tau, state.tau);
+        assert_eq!(tree.children(0).collect::<Vec<_>>(), vec![1, 2]);
+        assert!(tree.select(9).is_err());
+
+        let listing = tree.display();
+        assert!(listing.starts_with("  [0] root"));
+        assert!(listing.contains("*   [2] Π-"));
+        assert!(listing.contains("      [3] Π+"));
+    }
+
+    #[test]
+    fn test_export_json_and_dot() {
+        let mut tree = BranchTree::default();
+        tree.bifurcate();
+        let dot = tree.to_dot();
+        assert!(dot.starts_with("digraph branches {"));
+        assert!(dot.contains("b0 -> b2 [label=\"Π-\"];"));
+        assert!(dot.contains("style=bold"));
+
+        let path = std::env::temp_dir().join("crsm7_branches_test.json");
+        tree.export(&path).unwrap();
+        let json = fs::read_to_string(&path).unwrap();
+        let restored: BranchTree = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, tree);
+        let _ = fs::remove_file(path);
+    }
+}
@@ -0,0 +1,84 @@
+//! Γ-Based Community Detection
+//!
+//! Partitions the mesh into coherent clusters: the connected components
+//! of the subgraph of edges whose Γ(i,j) is below a threshold. With the
+//! binding threshold 0.01 these are the groups of agents that have bound
+//! (or are about to) into emergent sub-organisms; a vertex with no such
+//! edge forms a cluster of its own.
+
+use crate::mesh::Z3Mesh;
+
+/// Union-find root of `i`, with path halving
+fn root(parent: &mut [usize], mut i: usize) -> usize {
+    while parent[i] != i {
+        parent[i] = parent[parent[i]];
+        i = parent[i];
+    }
+    i
+}
+
+impl Z3Mesh {
+    /// Clusters of vertices joined by edges with Γ < `max_gamma`, each
+    /// sorted, ordered by their lowest vertex index
+    pub fn communities(&self, max_gamma: f64) -> Vec<Vec<usize>> {
+        let n = self.vertices.len();
+        let mut parent: Vec<usize> = (0..n).collect();
+        for edge in self.edges.iter().filter(|e| e.gamma < max_gamma) {
+            if edge.from >= n || edge.to >= n {
+                continue;
+            }
+            let (a, b) = (root(&mut parent, edge.from), root(&mut parent, edge.to));
+            parent[a.max(b)] = a.min(b);
+        }
+
+        let mut clusters: Vec<Vec<usize>> = Vec::new();
+        let mut cluster_of = vec![usize::MAX; n];
+        for i in 0..n {
+            let r = root(&mut parent, i);
+            if cluster_of[r] == usize::MAX {
+                cluster_of[r] = clusters.len();
+                clusters.push(Vec::new());
+            }
+            clusters[cluster_of[r]].push(i);
+        }
+        clusters
+    }
+
+    /// Cluster index of every vertex under `communities(max_gamma)`
+    pub fn community_labels(&self, max_gamma: f64) -> Vec<usize> {
+        let mut labels = vec![0; self.vertices.len()];
+        for (label, cluster) in self.communities(max_gamma).iter().enumerate() {
+            for &i in cluster {
+                labels[i] = label;
+            }
+        }
+        labels
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mesh::create_standard_mesh;
+
+    #[test]
+    fn test_low_gamma_edges_form_communities() {
+        // Edge Γ: AURA–AIDEN 0.0015, AIDEN–CCCcE 0.0015,
+        // CCCcE–SENTINEL 0.001, SENTINEL–Z3BRA 0.002
+        let mut mesh = create_standard_mesh();
+        assert_eq!(mesh.communities(0.01), vec![vec![0, 1, 2, 3, 4]]);
+        assert_eq!(
+            mesh.communities(0.0),
+            (0..5).map(|i| vec![i]).collect::<Vec<_>>()
+        );
+
+        mesh.edges[1].gamma = 0.5;
+        mesh.edges[2].gamma = 0.5;
+        assert_eq!(mesh.communities(0.01), vec![vec![0, 1], vec![2], vec![3, 4]]);
+
+        mesh.connect(4, 0);
+        mesh.edges[4].gamma = 0.001;
+        assert_eq!(mesh.communities(0.01), vec![vec![0, 1, 3, 4], vec![2]]);
+        mesh.edges[3].gamma = 0.5;
+        assert_eq!(mesh.community_labels(0.01), vec![0, 0, 1, 2, 0]);
+    }
+}
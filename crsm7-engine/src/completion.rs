@@ -0,0 +1,92 @@
+//! Command Completion
+//!
+//! Tab-completion candidates for interactive mode: command names for the
+//! first word, then observable names after `set`, `status` and `break`,
+//! vertex ids and names after `inspect` and `mesh connect`, `collapse` and
+//! `cascade`, `mesh` subcommands, and `on`/`off` after `record`.
+//! Matching ignores case.
+
+use crate::trajectory::OBSERVABLES;
+
+/// Interactive commands, in help order
+pub const COMMANDS: [&str; 20] = [
+    "evolve",
+    "status",
+    "stability",
+    "ensemble",
+    "energy",
+    "bifurcate",
+    "branches",
+    "branch",
+    "break",
+    "delete",
+    "continue",
+    "inspect",
+    "mesh",
+    "set",
+    "save",
+    "load",
+    "export",
+    "record",
+    "quit",
+    "exit",
+];
+
+/// `mesh` subcommands
+pub const MESH_COMMANDS: [&str; 5] = ["show", "add", "connect", "collapse", "cascade"];
+
+/// Start of the word ending at the end of `line` and the candidates that
+/// complete it, given the mesh's vertex ids and names
+pub fn complete(line: &str, vertices: &[&str]) -> (usize, Vec<String>) {
+    let start = line.rfind(char::is_whitespace).map_or(0, |i| i + 1);
+    let word = &line[start..];
+    let previous: Vec<&str> = line[..start].split_whitespace().collect();
+
+    let pool: Vec<&str> = match previous.as_slice() {
+        [] => COMMANDS.to_vec(),
+        ["set"] | ["break"] | ["status", ..] => OBSERVABLES.iter().map(|(_, long)| *long).collect(),
+        ["inspect"] | ["mesh", "connect" | "collapse" | "cascade", ..] => vertices.to_vec(),
+        ["mesh"] => MESH_COMMANDS.to_vec(),
+        ["record"] => vec!["on", "off"],
+        _ => Vec::new(),
+    };
+    let prefix = word.to_lowercase();
+    let candidates = pool
+        .into_iter()
+        .filter(|name| name.to_lowercase().starts_with(&prefix))
+        .map(str::to_string)
+        .collect();
+    (start, candidates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_complete_commands_fields_and_vertices() {
+        assert_eq!(
+            complete("s", &[]),
+            (
+                0,
+                vec![
+                    "status".to_string(),
+                    "stability".to_string(),
+                    "set".to_string(),
+                    "save".to_string()
+                ]
+            )
+        );
+        assert_eq!(complete("set ga", &[]), (4, vec!["gamma".to_string()]));
+        assert_eq!(complete("status lambda p", &[]).1, vec!["phi".to_string()]);
+        assert_eq!(
+            complete("inspect au", &["aura", "AURA", "aiden"]).1,
+            vec!["aura", "AURA"]
+        );
+        assert_eq!(complete("record of", &[]).1, vec!["off".to_string()]);
+        assert_eq!(complete("mesh co", &[]).1, vec!["connect", "collapse"]);
+        assert_eq!(complete("mesh cascade 1 AI", &["aiden"]).1, vec!["aiden"]);
+        assert_eq!(complete("mesh connect aura ai", &["aura", "aiden"]).1, vec!["aiden"]);
+        assert!(complete("evolve 0.", &[]).1.is_empty());
+    }
+}
@@ -0,0 +1,163 @@
+//! Engine Configuration
+//!
+//! Evolution constants loaded from TOML, all optional:
+//!
+//! ```toml
+//! gradient_coupling = 1.0   # H_CRSM gradient term
+//! torsion_coupling = 1.0    # H_CRSM θ J term
+//! alpha = 0.1               # evolution rate α in ∂τΨ = α · det(g)^(-1/2) Ψ
+//! k_gamma = 0.1             # K_Γ, decay rate of Γ in states and mesh edges
+//! adaptive_tolerance = 1e-3 # sub-step evolve_state (unset: single steps)
+//! distance = "geodesic"     # mesh edge weights: "euclidean" or "geodesic"
+//! energy_tolerance = 1e-2   # renormalize past this energy drift (unset: off)
+//! ```
+//!
+//! `hamiltonian()` builds the Hamiltonian and `Z3Mesh::with_config` sets
+//! the mesh's K_Γ and distance, so both evolve with the same constants;
+//! `energy_tracker()` tracks drift with the configured tolerance.
+
+use crate::energy::EnergyTracker;
+use crate::geodesic::Distance;
+use crate::hamiltonian::{CRSMHamiltonian, EnergyFunctional};
+use crate::mesh::Z3Mesh;
+use crate::state::CRSM7State;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Default evolution rate α
+pub const DEFAULT_ALPHA: f64 = 0.1;
+
+/// Default decoherence decay constant K_Γ
+pub const DEFAULT_K_GAMMA: f64 = 0.1;
+
+/// Couplings and rates for the Hamiltonian and mesh evolution
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct EngineConfig {
+    pub gradient_coupling: f64,
+    pub torsion_coupling: f64,
+    pub alpha: f64,
+    pub k_gamma: f64,
+    pub adaptive_tolerance: Option<f64>,
+    pub distance: Distance,
+    pub energy_tolerance: Option<f64>,
+}
+
+impl Default for EngineConfig {
+    fn default() -> Self {
+        Self {
+            gradient_coupling: 1.0,
+            torsion_coupling: 1.0,
+            alpha: DEFAULT_ALPHA,
+            k_gamma: DEFAULT_K_GAMMA,
+            adaptive_tolerance: None,
+            distance: Distance::Euclidean,
+            energy_tolerance: None,
+        }
+    }
+}
+
+impl EngineConfig {
+    /// Parse and validate TOML
+    pub fn from_toml(source: &str) -> Result<Self, String> {
+        let config: Self = toml::from_str(source).map_err(|e| e.to_string())?;
+        let values = [
+            ("gradient_coupling", config.gradient_coupling),
+            ("torsion_coupling", config.torsion_coupling),
+            ("alpha", config.alpha),
+            ("k_gamma", config.k_gamma),
+        ];
+        for (name, value) in values {
+            if !value.is_finite() {
+                return Err(format!("{} must be finite", name));
+            }
+        }
+        if config.alpha < 0.0 || config.k_gamma < 0.0 {
+            return Err("alpha and k_gamma must be non-negative".to_string());
+        }
+        if config
+            .adaptive_tolerance
+            .is_some_and(|t| !(t > 0.0 && t.is_finite()))
+        {
+            return Err("adaptive_tolerance must be positive".to_string());
+        }
+        if config.energy_tolerance.is_some_and(|t| !(t > 0.0 && t.is_finite())) {
+            return Err("energy_tolerance must be positive".to_string());
+        }
+        Ok(config)
+    }
+
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path)
+            .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+        Self::from_toml(&source).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// Hamiltonian with these couplings and rates
+    pub fn hamiltonian(&self) -> CRSMHamiltonian {
+        let mut hamiltonian =
+            CRSMHamiltonian::with_couplings(self.gradient_coupling, self.torsion_coupling);
+        hamiltonian.alpha = self.alpha;
+        hamiltonian.k_gamma = self.k_gamma;
+        hamiltonian.adaptive_tolerance = self.adaptive_tolerance;
+        hamiltonian
+    }
+
+    /// Energy tracker from `state`, renormalizing past `energy_tolerance`
+    pub fn energy_tracker(&self, state: &CRSM7State) -> EnergyTracker {
+        let tracker = EnergyTracker::new(EnergyFunctional::default(), state);
+        match self.energy_tolerance {
+            Some(tolerance) => tracker.with_tolerance(tolerance),
+            None => tracker,
+        }
+    }
+}
+
+impl Z3Mesh {
+    /// Use `config`'s K_Γ for edge decoherence and its distance for edge
+    /// weights
+    pub fn with_config(mut self, config: &EngineConfig) -> Self {
+        self.k_gamma = config.k_gamma;
+        self.with_distance(config.distance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh::create_standard_mesh;
+
+    #[test]
+    fn test_config_from_toml() {
+        let config = EngineConfig::from_toml("torsion_coupling = 0.5\nk_gamma = 0.2").unwrap();
+        assert_eq!(config.torsion_coupling, 0.5);
+        assert_eq!(config.alpha, DEFAULT_ALPHA);
+        assert_eq!(
+            EngineConfig::from_toml("").unwrap(),
+            EngineConfig::default()
+        );
+        assert!(EngineConfig::from_toml("kgamma = 0.2").is_err());
+        assert!(EngineConfig::from_toml("alpha = -1.0").is_err());
+        assert!(EngineConfig::from_toml("adaptive_tolerance = 0.0").is_err());
+        assert!(EngineConfig::from_toml("energy_tolerance = -1.0").is_err());
+        assert_eq!(
+            EngineConfig::from_toml("distance = \"geodesic\"")
+                .unwrap()
+                .distance,
+            Distance::Geodesic
+        );
+
+        // K_Γ drives Γ decay in both the Hamiltonian and the mesh
+        let hamiltonian = config.hamiltonian();
+        let mut state = CRSM7State::default();
+        let gamma = state.gamma;
+        hamiltonian.evolve_state(&mut state, 1.0);
+        assert!((state.gamma - gamma * (-0.2f64).exp()).abs() < 1e-15);
+
+        let mut mesh = create_standard_mesh().with_config(&config);
+        let edge_gamma = mesh.edges[0].gamma;
+        mesh.evolve(1.0);
+        assert!((mesh.edges[0].gamma - edge_gamma * (-0.2f64).exp()).abs() < 1e-15);
+    }
+}
@@ -0,0 +1,226 @@
+//! Breakpoints for Interactive Evolution
+//!
+//! Conditions checked after every `evolve` and `continue` step of the
+//! interactive mode, on the engine state or on a mesh vertex:
+//!
+//! - `gamma < 1e-6`: fires when Γ first falls below 1e-6 (and again only
+//!   after it has risen back above)
+//! - `Ξ > 8`: the same, rising above
+//! - `AURA.xi drops` / `vertex AURA xi drops`: every step AURA's Ξ decreases
+//! - `phi rises`: every step Φ increases
+//!
+//! Observables are named as in `OBSERVABLES`; vertices by id or name.
+
+use crate::mesh::Z3Mesh;
+use crate::state::CRSM7State;
+use crate::trajectory::{observable_index, OBSERVABLES};
+use std::fmt;
+
+/// Whose state a breakpoint watches
+#[derive(Debug, Clone, PartialEq)]
+pub enum Subject {
+    /// The engine state
+    State,
+    /// A mesh vertex, by id or name (any case)
+    Vertex(String),
+}
+
+impl Subject {
+    fn state<'a>(&self, state: &'a CRSM7State, mesh: &'a Z3Mesh) -> Option<&'a CRSM7State> {
+        match self {
+            Subject::State => Some(state),
+            Subject::Vertex(name) => mesh
+                .vertices
+                .iter()
+                .find(|v| v.id.eq_ignore_ascii_case(name) || v.name.eq_ignore_ascii_case(name))
+                .map(|v| &v.state),
+        }
+    }
+}
+
+/// When a breakpoint fires
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Trigger {
+    Below(f64),
+    Above(f64),
+    Drops,
+    Rises,
+}
+
+/// A condition on one observable of one state
+#[derive(Debug, Clone, PartialEq)]
+pub struct Breakpoint {
+    pub subject: Subject,
+    /// Index into `OBSERVABLES`
+    pub observable: usize,
+    pub trigger: Trigger,
+}
+
+impl Breakpoint {
+    /// Parse a condition such as `gamma < 1e-6` or `AURA.xi drops`
+    pub fn parse(condition: &str) -> Result<Self, String> {
+        let tokens: Vec<&str> = condition.split_whitespace().collect();
+        let (subject, name, rest) = match tokens.as_slice() {
+            ["vertex", vertex, name, rest @ ..] => {
+                (Subject::Vertex(vertex.to_string()), *name, rest)
+            }
+            [target, rest @ ..] => match target.split_once('.') {
+                Some((vertex, name)) => (Subject::Vertex(vertex.to_string()), name, rest),
+                None => (Subject::State, *target, rest),
+            },
+            [] => return Err("empty condition".to_string()),
+        };
+        let observable =
+            observable_index(name).ok_or_else(|| format!("unknown observable: {}", name))?;
+        let threshold = |value: &str| {
+            value
+                .parse::<f64>()
+                .map_err(|_| format!("not a number: {}", value))
+        };
+        let trigger = match rest {
+            ["drops"] => Trigger::Drops,
+            ["rises"] => Trigger::Rises,
+            ["<", value] => Trigger::Below(threshold(value)?),
+            [">", value] => Trigger::Above(threshold(value)?),
+            _ => {
+                return Err(format!(
+                    "expected <observable> < <value>, > <value>, drops or rises: {}",
+                    condition
+                ))
+            }
+        };
+        Ok(Self {
+            subject,
+            observable,
+            trigger,
+        })
+    }
+
+    /// Current value of the watched observable
+    pub fn value(&self, state: &CRSM7State, mesh: &Z3Mesh) -> Option<f64> {
+        self.subject
+            .state(state, mesh)
+            .map(|s| s.as_array()[self.observable])
+    }
+
+    fn fires(&self, previous: Option<f64>, value: f64) -> bool {
+        match (self.trigger, previous) {
+            (Trigger::Below(t), p) => value < t && p.is_none_or(|p| p >= t),
+            (Trigger::Above(t), p) => value > t && p.is_none_or(|p| p <= t),
+            (Trigger::Drops, Some(p)) => value < p,
+            (Trigger::Rises, Some(p)) => value > p,
+            (_, None) => false,
+        }
+    }
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Subject::Vertex(vertex) = &self.subject {
+            write!(f, "{}.", vertex)?;
+        }
+        write!(f, "{}", OBSERVABLES[self.observable].1)?;
+        match self.trigger {
+            Trigger::Below(t) => write!(f, " < {}", t),
+            Trigger::Above(t) => write!(f, " > {}", t),
+            Trigger::Drops => write!(f, " drops"),
+            Trigger::Rises => write!(f, " rises"),
+        }
+    }
+}
+
+/// A breakpoint that fired
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hit {
+    pub id: usize,
+    pub previous: Option<f64>,
+    pub value: f64,
+}
+
+/// Breakpoints by id, each with the value it saw after the last check
+#[derive(Debug, Clone, Default)]
+pub struct Breakpoints {
+    /// Removed breakpoints leave a gap so ids stay stable
+    entries: Vec<Option<(Breakpoint, Option<f64>)>>,
+}
+
+impl Breakpoints {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a breakpoint armed at the current values; returns its id
+    pub fn add(&mut self, breakpoint: Breakpoint, state: &CRSM7State, mesh: &Z3Mesh) -> usize {
+        let last = breakpoint.value(state, mesh);
+        self.entries.push(Some((breakpoint, last)));
+        self.entries.len() - 1
+    }
+
+    pub fn remove(&mut self, id: usize) -> Option<Breakpoint> {
+        self.entries
+            .get_mut(id)
+            .and_then(Option::take)
+            .map(|(breakpoint, _)| breakpoint)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &Breakpoint)> {
+        self.entries
+            .iter()
+            .enumerate()
+            .filter_map(|(id, entry)| entry.as_ref().map(|(b, _)| (id, b)))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.iter().next().is_none()
+    }
+
+    /// Check every breakpoint after a step; returns those that fired
+    pub fn check(&mut self, state: &CRSM7State, mesh: &Z3Mesh) -> Vec<Hit> {
+        let mut hits = Vec::new();
+        for (id, entry) in self.entries.iter_mut().enumerate() {
+            let Some((breakpoint, last)) = entry else {
+                continue;
+            };
+            let value = breakpoint.value(state, mesh);
+            if let Some(value) = value {
+                if breakpoint.fires(*last, value) {
+                    hits.push(Hit {
+                        id,
+                        previous: *last,
+                        value,
+                    });
+                }
+            }
+            *last = value;
+        }
+        hits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh::create_standard_mesh;
+
+    #[test]
+    fn test_breakpoints_fire_on_crossing() {
+        let mut state = CRSM7State::default();
+        let mesh = create_standard_mesh();
+        let mut breakpoints = Breakpoints::new();
+        let below = Breakpoint::parse("Γ < 0.006").unwrap();
+        assert_eq!(below.to_string(), "gamma < 0.006");
+        let id = breakpoints.add(below, &state, &mesh);
+        breakpoints.add(Breakpoint::parse("vertex aura xi drops").unwrap(), &state, &mesh);
+
+        state.gamma = 0.005;
+        let hits = breakpoints.check(&state, &mesh);
+        assert_eq!(hits.len(), 1);
+        assert_eq!((hits[0].id, hits[0].previous), (id, Some(0.012)));
+        assert!(breakpoints.check(&state, &mesh).is_empty());
+
+        assert!(Breakpoint::parse("omega < 1").is_err());
+        assert!(Breakpoint::parse("gamma <= 1").is_err());
+        assert!(breakpoints.remove(id).is_some());
+        assert_eq!(breakpoints.iter().count(), 1);
+    }
+}
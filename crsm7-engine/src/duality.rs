@@ -3,18 +3,94 @@
 //! Implements Π±_dual = ½(1 ± J) where J is the polarity involution: J² = 1, JΨ = -Ψ
 //!
 //! Bifurcation rule: B(Ψ) = Π+_dual Ψ ⊕ Π-_dual Ψ
+//!
+//! JΨ = -Ψ makes Π+ vanish; `with_involution` and `with_matrix` build the
+//! operator from any other J, checked to satisfy J² = 1. A closure acts on
+//! each component; a matrix acts on the state vector C(t) and on scalar Ψ
+//! as the polarity coordinate ρ±. Negation and matrices are saved with the
+//! operator; a closure cannot be, so saving one is an error.
+
+use serde::de::Error as _;
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::sync::Arc;
+
+/// Dimension of the state vector C(t)
+const STATE_DIM: usize = 7;
+
+/// Index of ρ± in the state vector
+const POLARITY: usize = 4;
+
+/// Largest |J²Ψ - Ψ| accepted, relative to max(1, |Ψ|)
+const INVOLUTION_TOLERANCE: f64 = 1e-9;
+
+/// Values a closure J is checked on
+const PROBES: [f64; 11] = [-10.0, -2.5, -1.0, -0.5, -1e-3, 0.0, 1e-3, 0.5, 1.0, 2.5, 10.0];
+
+/// The polarity involution J
+#[derive(Clone, Default)]
+pub enum Involution {
+    /// JΨ = -Ψ
+    #[default]
+    Negation,
+    /// J applied to each component
+    Map(Arc<dyn Fn(f64) -> f64 + Send + Sync>),
+    /// Linear J on the state vector C(t)
+    Matrix(Box<[[f64; STATE_DIM]; STATE_DIM]>),
+}
+
+/// Saved form of an `Involution`
+#[derive(Serialize, Deserialize)]
+enum SavedInvolution {
+    Negation,
+    Matrix(Box<[[f64; STATE_DIM]; STATE_DIM]>),
+}
+
+impl Serialize for Involution {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Negation => SavedInvolution::Negation.serialize(serializer),
+            Self::Matrix(m) => SavedInvolution::Matrix(m.clone()).serialize(serializer),
+            Self::Map(_) => Err(S::Error::custom(
+                "a closure J cannot be saved; build the operator with `with_matrix`",
+            )),
+        }
+    }
+}
 
-use serde::{Deserialize, Serialize};
+impl<'de> Deserialize<'de> for Involution {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        match SavedInvolution::deserialize(deserializer)? {
+            SavedInvolution::Negation => Ok(Self::Negation),
+            SavedInvolution::Matrix(m) => DualityOperator::with_matrix(*m)
+                .map(|op| op.involution)
+                .map_err(D::Error::custom),
+        }
+    }
+}
+
+impl fmt::Debug for Involution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Negation => write!(f, "Negation"),
+            Self::Map(_) => write!(f, "Map(..)"),
+            Self::Matrix(m) => f.debug_tuple("Matrix").field(m).finish(),
+        }
+    }
+}
 
 /// Duality Operator implementing Π± projections
 ///
-/// The J involution satisfies:
-/// - J² = 1 (involution property)
-/// - JΨ = -Ψ (polarity inversion)
+/// The J involution satisfies J² = 1; by default it is the polarity
+/// inversion JΨ = -Ψ.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DualityOperator {
     /// Rank of the operator (should be 1 for proper duality)
     pub rank: i32,
+    /// J; operators saved before it was serialized load with JΨ = -Ψ
+    #[serde(default)]
+    pub involution: Involution,
 }
 
 impl Default for DualityOperator {
@@ -26,14 +102,79 @@ impl Default for DualityOperator {
 impl DualityOperator {
     /// Create a new duality operator
     pub fn new() -> Self {
-        Self { rank: 1 }
+        Self {
+            rank: 1,
+            involution: Involution::Negation,
+        }
     }
 
-    /// J involution: J(Ψ) = -Ψ
+    /// Operator with J applied to each component, rejected unless
+    /// J(J(Ψ)) = Ψ
+    pub fn with_involution(
+        j: impl Fn(f64) -> f64 + Send + Sync + 'static,
+    ) -> Result<Self, String> {
+        for psi in PROBES {
+            let twice = j(j(psi));
+            let error = (twice - psi).abs();
+            if error.is_nan() || error > INVOLUTION_TOLERANCE * psi.abs().max(1.0) {
+                return Err(format!("J is not an involution: J(J({})) = {}", psi, twice));
+            }
+        }
+        Ok(Self {
+            involution: Involution::Map(Arc::new(j)),
+            ..Self::new()
+        })
+    }
+
+    /// Operator with J the matrix `m` on C(t), rejected unless m² = 1
+    pub fn with_matrix(m: [[f64; STATE_DIM]; STATE_DIM]) -> Result<Self, String> {
+        if m.iter().flatten().any(|v| !v.is_finite()) {
+            return Err("J must be finite".to_string());
+        }
+        for i in 0..STATE_DIM {
+            for j in 0..STATE_DIM {
+                let square: f64 = (0..STATE_DIM).map(|k| m[i][k] * m[k][j]).sum();
+                let identity = if i == j { 1.0 } else { 0.0 };
+                if (square - identity).abs() > INVOLUTION_TOLERANCE {
+                    return Err(format!("J is not an involution: (J²)[{}][{}] = {}", i, j, square));
+                }
+            }
+        }
+        Ok(Self {
+            involution: Involution::Matrix(Box::new(m)),
+            ..Self::new()
+        })
+    }
+
+    /// J involution, J(Ψ) = -Ψ by default
     /// Satisfies J² = 1
     #[inline]
     pub fn j_involution(&self, psi: f64) -> f64 {
-        -psi
+        match &self.involution {
+            Involution::Negation => -psi,
+            Involution::Map(j) => j(psi),
+            Involution::Matrix(m) => m[POLARITY][POLARITY] * psi,
+        }
+    }
+
+    /// J on the state vector C(t)
+    pub fn j_state(&self, psi: &[f64; STATE_DIM]) -> [f64; STATE_DIM] {
+        match &self.involution {
+            Involution::Negation => psi.map(|v| -v),
+            Involution::Map(j) => psi.map(|v| j(v)),
+            Involution::Matrix(m) => {
+                std::array::from_fn(|i| (0..STATE_DIM).map(|k| m[i][k] * psi[k]).sum())
+            }
+        }
+    }
+
+    /// B(C) = (Π+C, Π-C) on the state vector
+    pub fn bifurcate_state(&self, psi: &[f64; STATE_DIM]) -> ([f64; STATE_DIM], [f64; STATE_DIM]) {
+        let j_psi = self.j_state(psi);
+        (
+            std::array::from_fn(|i| 0.5 * (psi[i] + j_psi[i])),
+            std::array::from_fn(|i| 0.5 * (psi[i] - j_psi[i])),
+        )
     }
 
     /// Verify J² = 1 property
@@ -79,9 +220,7 @@ impl DualityOperator {
 
     /// Get display string for operator status
     pub fn display(&self) -> String {
-        format!(
-            "  Π⁺: 0.5(1+J) applied\n  Π⁻: 0.5(1-J) applied"
-        )
+        "  Π⁺: 0.5(1+J) applied\n  Π⁻: 0.5(1-J) applied".to_string()
     }
 }
 
@@ -108,6 +247,7 @@ mod tests {
     use super::*;
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn test_j_involution_property() {
         let op = DualityOperator::new();
         
@@ -135,6 +275,32 @@ mod tests {
         assert!((minus - psi).abs() < 1e-10);
     }
 
+    #[test]
+    fn test_custom_involutions() {
+        // J(Ψ) = 1 - Ψ: a reflection about ½, so Π+ no longer vanishes
+        let op = DualityOperator::with_involution(|psi| 1.0 - psi).unwrap();
+        assert!(op.verify_involution(0.3));
+        assert_eq!(op.bifurcate(2.0), (0.5, 1.5));
+        assert_eq!(op.apply(2.0, 1.0), 0.5);
+        assert!(DualityOperator::with_involution(|psi| 2.0 * psi).is_err());
+
+        // Swap Λ and Φ, fix everything else
+        let mut m = [[0.0; 7]; 7];
+        for (i, row) in m.iter_mut().enumerate() {
+            row[[2, 1, 0, 3, 4, 5, 6][i]] = 1.0;
+        }
+        let op = DualityOperator::with_matrix(m).unwrap();
+        let (plus, minus) = op.bifurcate_state(&[1.0, 0.1, 3.0, 0.0, 1.0, 45.0, 2.0]);
+        assert_eq!(plus, [2.0, 0.1, 2.0, 0.0, 1.0, 45.0, 2.0]);
+        assert_eq!(minus, [-1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(op.j_involution(-1.0), -1.0);
+
+        m[0][0] = 0.5;
+        assert!(DualityOperator::with_matrix(m).is_err());
+        let default = DualityOperator::new();
+        assert_eq!(default.bifurcate_state(&[2.0; 7]), ([0.0; 7], [2.0; 7]));
+    }
+
     #[test]
     fn test_rank_is_one() {
         let op = DualityOperator::new();
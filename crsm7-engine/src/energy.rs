@@ -0,0 +1,173 @@
+//! Energy Tracking
+//!
+//! `EnergyTracker` evaluates the `EnergyFunctional` E = k·Λ² + p·Φ after
+//! every evolution step and reports its drift from a reference value,
+//! relative to |E₀|. CRSM7 evolution is not conservative — Λ and Φ both
+//! grow — so the drift measures how far a run has moved from where it
+//! started. With a tolerance set, a step that drifts further rescales Λ and
+//! Φ by a common factor s solving k·Λ²s² + p·Φ·s = E₀, which restores the
+//! reference energy while keeping their ratio. Λ is then held at its 0.999
+//! cap, so a renormalization that would push Λ past it only restores E₀
+//! approximately.
+
+use crate::hamiltonian::EnergyFunctional;
+use crate::state::CRSM7State;
+
+/// Evolution cap on Λ
+const LAMBDA_CAP: f64 = 0.999;
+
+/// Energy drift over a run
+#[derive(Debug, Clone)]
+pub struct EnergyTracker {
+    pub functional: EnergyFunctional,
+    /// E₀
+    pub reference: f64,
+    /// Energy after the last observed step
+    pub current: f64,
+    /// Largest |drift| observed, before renormalization
+    pub max_drift: f64,
+    pub steps: usize,
+    pub renormalizations: usize,
+    /// Renormalize when |drift| exceeds this
+    pub tolerance: Option<f64>,
+}
+
+impl EnergyTracker {
+    /// Track `functional` with `state`'s energy as the reference
+    pub fn new(functional: EnergyFunctional, state: &CRSM7State) -> Self {
+        let reference = functional.total_energy(state);
+        Self {
+            functional,
+            reference,
+            current: reference,
+            max_drift: 0.0,
+            steps: 0,
+            renormalizations: 0,
+            tolerance: None,
+        }
+    }
+
+    /// Renormalize the state whenever |drift| exceeds `tolerance`
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = Some(tolerance);
+        self
+    }
+
+    /// Relative drift (E − E₀)/|E₀| of the last observed energy
+    pub fn drift(&self) -> f64 {
+        self.relative(self.current)
+    }
+
+    fn relative(&self, energy: f64) -> f64 {
+        if self.reference == 0.0 {
+            energy
+        } else {
+            (energy - self.reference) / self.reference.abs()
+        }
+    }
+
+    /// Record the energy of a state just evolved, renormalizing it when
+    /// the drift exceeds the tolerance; returns the drift before any
+    /// renormalization
+    pub fn observe(&mut self, state: &mut CRSM7State) -> f64 {
+        let drift = self.relative(self.functional.total_energy(state));
+        self.steps += 1;
+        self.max_drift = self.max_drift.max(drift.abs());
+        if self.tolerance.is_some_and(|t| drift.abs() > t) {
+            self.renormalize(state);
+            self.renormalizations += 1;
+        }
+        self.current = self.functional.total_energy(state);
+        drift
+    }
+
+    /// Scale Λ and Φ back to the reference energy
+    pub fn renormalize(&self, state: &mut CRSM7State) {
+        let a = self.functional.kinetic_coeff * state.lambda * state.lambda;
+        let b = self.functional.potential_coeff * state.phi;
+        // a·s² + b·s − E₀ = 0, positive root
+        let s = if a == 0.0 {
+            self.reference / b
+        } else {
+            (-b + (b * b + 4.0 * a * self.reference).sqrt()) / (2.0 * a)
+        };
+        if !s.is_finite() || s <= 0.0 {
+            return;
+        }
+        state.lambda = (state.lambda * s).min(LAMBDA_CAP);
+        state.phi *= s;
+        state.compute_emergence();
+    }
+
+    /// Take `state`'s energy as the new reference and clear the statistics
+    pub fn reset(&mut self, state: &CRSM7State) {
+        *self = Self {
+            tolerance: self.tolerance,
+            ..Self::new(self.functional.clone(), state)
+        };
+    }
+
+    pub fn report(&self) -> String {
+        let renormalize = match self.tolerance {
+            Some(t) => format!("{} (tolerance {:e})", self.renormalizations, t),
+            None => "off".to_string(),
+        };
+        format!(
+            "  E₀:            {:.6}\n  E:             {:.6}\n  Drift:         {:+.3e}\n  Max |drift|:   {:.3e} over {} steps\n  Renormalized:  {}",
+            self.reference,
+            self.current,
+            self.drift(),
+            self.max_drift,
+            self.steps,
+            renormalize
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hamiltonian::CRSMHamiltonian;
+
+    #[test]
+    fn test_drift_and_renormalization() {
+        let hamiltonian = CRSMHamiltonian::new();
+        let start = CRSM7State::new(0.5, 0.1, 2.0, 1.0, 51.843, 0.0);
+        let functional = EnergyFunctional::default();
+
+        // E = Λ² + Φ: growing Λ and Φ drift upwards
+        let mut state = start.clone();
+        let mut free = EnergyTracker::new(functional.clone(), &state);
+        assert_eq!(free.reference, 2.25);
+        for _ in 0..10 {
+            hamiltonian.evolve_state(&mut state, 0.1);
+            free.observe(&mut state);
+        }
+        assert!(free.drift() > 0.0);
+        assert_eq!(free.max_drift, free.drift());
+        assert_eq!((free.steps, free.renormalizations), (10, 0));
+
+        let mut state = start.clone();
+        let mut held = EnergyTracker::new(functional, &state).with_tolerance(1e-3);
+        for _ in 0..10 {
+            hamiltonian.evolve_state(&mut state, 0.1);
+            held.observe(&mut state);
+        }
+        assert_eq!(held.renormalizations, 10);
+        assert!(held.drift().abs() < 1e-12);
+
+        // Renormalization restores E₀ and keeps Λ:Φ
+        let mut drifted = state.clone();
+        drifted.phi += 1.0;
+        let ratio = drifted.lambda / drifted.phi;
+        held.renormalize(&mut drifted);
+        let energy = held.functional.total_energy(&drifted);
+        assert!((energy - held.reference).abs() < 1e-12);
+        assert!((drifted.lambda / drifted.phi - ratio).abs() < 1e-12);
+        assert!(held.report().contains("Renormalized:  10"));
+
+        held.reset(&state);
+        assert_eq!((held.steps, held.drift()), (0, 0.0));
+        assert_eq!(held.tolerance, Some(1e-3));
+    }
+}
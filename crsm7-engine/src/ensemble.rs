@@ -0,0 +1,206 @@
+//! Ensembles
+//!
+//! N perturbed copies of a state evolved under the same Hamiltonian, with
+//! the spread of Ξ, Γ and Ω_sov at the end: how sensitive an outcome is to
+//! small changes in the initial conditions. Copies are reproducible from
+//! the seed and export as CSV, one row per member.
+
+use crate::hamiltonian::CRSMHamiltonian;
+use crate::rng::SeededRng;
+use crate::state::{CRSM7State, GAMMA_TOLERANCE};
+use crate::trajectory::OBSERVABLES;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+/// Default relative perturbation of Λ, Γ and Φ
+pub const DEFAULT_SPREAD: f64 = 0.01;
+
+/// Summary of one observable over the members
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Distribution {
+    pub mean: f64,
+    /// Population standard deviation
+    pub std_dev: f64,
+    pub min: f64,
+    pub median: f64,
+    pub max: f64,
+}
+
+impl Distribution {
+    /// Summary of `values`; all zeros when empty
+    pub fn of(values: &[f64]) -> Self {
+        if values.is_empty() {
+            return Self {
+                mean: 0.0,
+                std_dev: 0.0,
+                min: 0.0,
+                median: 0.0,
+                max: 0.0,
+            };
+        }
+        let n = values.len() as f64;
+        let mean = values.iter().sum::<f64>() / n;
+        let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+        let mut sorted = values.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2.0
+        } else {
+            sorted[middle]
+        };
+        Self {
+            mean,
+            std_dev: variance.sqrt(),
+            min: sorted[0],
+            median,
+            max: sorted[sorted.len() - 1],
+        }
+    }
+}
+
+/// Ξ, Γ and Ω_sov over an ensemble
+#[derive(Debug, Clone, PartialEq)]
+pub struct EnsembleStats {
+    pub members: usize,
+    pub xi: Distribution,
+    pub gamma: Distribution,
+    pub omega_sov: Distribution,
+}
+
+impl EnsembleStats {
+    pub fn display(&self) -> String {
+        let mut out = format!(
+            "{} members\n  {:<6} {:>12} {:>12} {:>12} {:>12} {:>12}\n",
+            self.members, "", "mean", "std dev", "min", "median", "max"
+        );
+        for (name, d) in [("Ξ", self.xi), ("Γ", self.gamma), ("Ω_sov", self.omega_sov)] {
+            let _ = writeln!(
+                out,
+                "  {:<6} {:>12.6e} {:>12.6e} {:>12.6e} {:>12.6e} {:>12.6e}",
+                name, d.mean, d.std_dev, d.min, d.median, d.max
+            );
+        }
+        out
+    }
+}
+
+/// Perturbed copies of a state
+#[derive(Debug, Clone, Default)]
+pub struct Ensemble {
+    pub members: Vec<CRSM7State>,
+}
+
+impl Ensemble {
+    pub fn new(members: Vec<CRSM7State>) -> Self {
+        Self { members }
+    }
+
+    /// `size` copies of `base` with Λ, Γ and Φ scaled by independent
+    /// factors in [1 − spread, 1 + spread]
+    pub fn varied(base: &CRSM7State, size: usize, spread: f64, seed: u64) -> Self {
+        let mut rng = SeededRng::new(seed);
+        let members = (0..size)
+            .map(|_| {
+                let mut member = base.clone();
+                let mut jitter = || rng.range(1.0 - spread, 1.0 + spread);
+                member.lambda = (member.lambda * jitter()).min(0.999);
+                member.gamma = (member.gamma * jitter()).max(GAMMA_TOLERANCE);
+                member.phi *= jitter();
+                member.compute_emergence();
+                member
+            })
+            .collect();
+        Self::new(members)
+    }
+
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Evolve every member `steps` times by `dt`
+    pub fn run(&mut self, hamiltonian: &CRSMHamiltonian, steps: usize, dt: f64) {
+        for member in &mut self.members {
+            for _ in 0..steps {
+                hamiltonian.evolve_state(member, dt);
+            }
+        }
+    }
+
+    pub fn stats(&self) -> EnsembleStats {
+        let collect = |f: fn(&CRSM7State) -> f64| self.members.iter().map(f).collect::<Vec<_>>();
+        EnsembleStats {
+            members: self.members.len(),
+            xi: Distribution::of(&collect(|s| s.xi)),
+            gamma: Distribution::of(&collect(|s| s.gamma)),
+            omega_sov: Distribution::of(&collect(CRSM7State::compute_sovereignty)),
+        }
+    }
+
+    /// One row per member: index, the seven observables and Ω_sov
+    pub fn to_csv(&self) -> String {
+        let names: Vec<&str> = OBSERVABLES.iter().map(|(_, long)| *long).collect();
+        let mut out = format!("member,{},omega_sov\n", names.join(","));
+        for (i, member) in self.members.iter().enumerate() {
+            let values: Vec<String> = member.as_array().iter().map(f64::to_string).collect();
+            let _ = writeln!(
+                out,
+                "{},{},{}",
+                i,
+                values.join(","),
+                member.compute_sovereignty()
+            );
+        }
+        out
+    }
+
+    /// Write `to_csv` to `path`
+    pub fn export_csv(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        let path = path.as_ref();
+        fs::write(path, self.to_csv())
+            .map_err(|e| format!("cannot write {}: {}", path.display(), e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_varied_ensemble_statistics() {
+        let base = CRSM7State::new(0.5, 0.1, 2.0, 1.0, 51.843, 0.0);
+        let mut ensemble = Ensemble::varied(&base, 32, 0.1, 7);
+        assert_eq!(ensemble.len(), 32);
+        let lambdas = |e: &Ensemble| e.members.iter().map(|m| m.lambda).collect::<Vec<_>>();
+        assert_eq!(
+            lambdas(&ensemble),
+            lambdas(&Ensemble::varied(&base, 32, 0.1, 7))
+        );
+        assert!(ensemble.members.iter().all(|m| (m.phi - 2.0).abs() <= 0.2));
+
+        ensemble.run(&CRSMHamiltonian::new(), 10, 0.1);
+        assert!(ensemble.members.iter().all(|m| (m.tau - 1.0).abs() < 1e-12));
+        let stats = ensemble.stats();
+        assert_eq!(stats.members, 32);
+        assert!(stats.xi.std_dev > 0.0);
+        assert!(stats.gamma.min <= stats.gamma.median && stats.gamma.median <= stats.gamma.max);
+        assert!(stats.display().contains("Ω_sov"));
+
+        let csv = ensemble.to_csv();
+        assert!(csv.starts_with("member,lambda,gamma,phi,xi,rho,theta,tau,omega_sov\n"));
+        assert_eq!(csv.lines().count(), 33);
+    }
+
+    #[test]
+    fn test_distribution() {
+        let d = Distribution::of(&[4.0, 1.0, 3.0, 2.0]);
+        assert_eq!((d.mean, d.min, d.median, d.max), (2.5, 1.0, 2.5, 4.0));
+        assert!((d.std_dev - 1.25f64.sqrt()).abs() < 1e-15);
+        assert_eq!(Distribution::of(&[]).mean, 0.0);
+    }
+}
@@ -0,0 +1,196 @@
+//! Sovereignty Forecast
+//!
+//! Fits exponential rates to the recent trajectory — Γ decay, Ξ and Λ
+//! growth, by least squares on ln(value) against τ — and extrapolates them
+//! to the first τ at which `check_sovereignty()` passes. Λ is held below
+//! the evolution cap of 0.999. The forecast is only as good as the
+//! exponential fit: it assumes the rates seen in the window persist.
+
+use crate::state::CRSM7State;
+use crate::trajectory::Trajectory;
+
+/// Forecasts beyond this many τ are reported as unreachable
+pub const MAX_HORIZON: f64 = 1e6;
+
+/// Evolution cap on Λ
+const LAMBDA_CAP: f64 = 0.999;
+
+/// Fitted rates and the predicted time to sovereignty
+#[derive(Debug, Clone, PartialEq)]
+pub struct SovereigntyForecast {
+    /// d ln Γ / dτ (negative while Γ decays)
+    pub gamma_rate: f64,
+    /// d ln Ξ / dτ
+    pub xi_rate: f64,
+    /// d ln Λ / dτ
+    pub lambda_rate: f64,
+    /// Mean τ between samples, the step used for `steps`
+    pub step: f64,
+    /// τ remaining until sovereignty, 0 if already sovereign, `None` if
+    /// not reached within `MAX_HORIZON` at the fitted rates
+    pub remaining: Option<f64>,
+}
+
+/// Least-squares slope of ln(y) against x over samples with y > 0
+fn log_slope(x: &[f64], y: &[f64]) -> f64 {
+    let points: Vec<(f64, f64)> = x
+        .iter()
+        .zip(y)
+        .filter(|(_, y)| **y > 0.0)
+        .map(|(x, y)| (*x, y.ln()))
+        .collect();
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|p| p.0).sum::<f64>() / n;
+    let mean_y = points.iter().map(|p| p.1).sum::<f64>() / n;
+    let sxx: f64 = points.iter().map(|p| (p.0 - mean_x).powi(2)).sum();
+    let sxy: f64 = points.iter().map(|p| (p.0 - mean_x) * (p.1 - mean_y)).sum();
+    if points.len() < 2 || sxx == 0.0 {
+        0.0
+    } else {
+        sxy / sxx
+    }
+}
+
+impl SovereigntyForecast {
+    /// Fit the trajectory's latest run of samples with strictly increasing
+    /// τ (a `set` or `load` starts a new run); `None` until that run has
+    /// two samples
+    pub fn fit(trajectory: &Trajectory) -> Option<Self> {
+        let full = trajectory.series(6);
+        let start = (1..full.len())
+            .rev()
+            .find(|&i| full[i - 1] >= full[i])
+            .unwrap_or(0);
+        let series = |i: usize| trajectory.series(i)[start..].to_vec();
+        let tau = series(6);
+        if tau.len() < 2 {
+            return None;
+        }
+        let (first, last) = (tau[0], tau[tau.len() - 1]);
+        let latest = {
+            let s = |i: usize| *series(i).last().unwrap_or(&0.0);
+            CRSM7State {
+                lambda: s(0),
+                gamma: s(1),
+                phi: s(2),
+                xi: s(3),
+                rho_polarity: s(4),
+                theta: s(5),
+                tau: last,
+            }
+        };
+        let mut forecast = Self {
+            gamma_rate: log_slope(&tau, &series(1)),
+            xi_rate: log_slope(&tau, &series(3)),
+            lambda_rate: log_slope(&tau, &series(0)),
+            step: (last - first) / (tau.len() - 1) as f64,
+            remaining: None,
+        };
+        forecast.remaining = forecast.solve(&latest);
+        Some(forecast)
+    }
+
+    /// `state` extrapolated `t` τ ahead at the fitted rates
+    pub fn project(&self, state: &CRSM7State, t: f64) -> CRSM7State {
+        let mut projected = state.clone();
+        if state.lambda < LAMBDA_CAP {
+            projected.lambda = (state.lambda * (self.lambda_rate * t).exp()).min(LAMBDA_CAP);
+        }
+        projected.gamma = state.gamma * (self.gamma_rate * t).exp();
+        projected.xi = state.xi * (self.xi_rate * t).exp();
+        projected.tau = state.tau + t;
+        projected
+    }
+
+    /// Steps of the mean sample spacing until sovereignty
+    pub fn steps(&self) -> Option<usize> {
+        let remaining = self.remaining?;
+        Some((remaining / self.step - 1e-9).ceil().max(0.0) as usize)
+    }
+
+    /// First t at which the projection is sovereign: double until it is,
+    /// then bisect
+    fn solve(&self, state: &CRSM7State) -> Option<f64> {
+        let sovereign = |t: f64| self.project(state, t).check_sovereignty();
+        if sovereign(0.0) {
+            return Some(0.0);
+        }
+        let (mut low, mut high) = (0.0, self.step);
+        while !sovereign(high) {
+            if high > MAX_HORIZON {
+                return None;
+            }
+            low = high;
+            high *= 2.0;
+        }
+        for _ in 0..60 {
+            let middle = 0.5 * (low + high);
+            if sovereign(middle) {
+                high = middle;
+            } else {
+                low = middle;
+            }
+        }
+        Some(high)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hamiltonian::CRSMHamiltonian;
+
+    #[test]
+    fn test_forecast_matches_evolution() {
+        let hamiltonian = CRSMHamiltonian::new();
+        let mut state = CRSM7State::new(0.3, 0.5, 0.5, 1.0, 51.843, 0.0);
+        let mut trajectory = Trajectory::new(10);
+        trajectory.record(&state);
+        for _ in 0..9 {
+            hamiltonian.evolve_state(&mut state, 0.1);
+            trajectory.record(&state);
+        }
+        let forecast = SovereigntyForecast::fit(&trajectory).unwrap();
+        assert!((forecast.gamma_rate + 0.1).abs() < 1e-9);
+        assert!(forecast.xi_rate > 0.0 && forecast.lambda_rate > 0.0);
+        assert!((forecast.step - 0.1).abs() < 1e-12);
+
+        // Evolve to sovereignty and compare with the prediction
+        let predicted = forecast.steps().unwrap();
+        let mut steps = 0;
+        while !state.check_sovereignty() {
+            hamiltonian.evolve_state(&mut state, 0.1);
+            steps += 1;
+        }
+        let error = (predicted as f64 - steps as f64).abs() / steps as f64;
+        assert!(error < 0.1, "predicted {} steps, took {}", predicted, steps);
+    }
+
+    #[test]
+    fn test_forecast_edge_cases() {
+        let mut trajectory = Trajectory::new(10);
+        let mut state = CRSM7State::new(0.999, 1e-9, 10.0, 1.0, 51.843, 0.0);
+        trajectory.record(&state);
+        assert!(SovereigntyForecast::fit(&trajectory).is_none());
+
+        state.tau = 1.0;
+        trajectory.record(&state);
+        assert_eq!(
+            SovereigntyForecast::fit(&trajectory).unwrap().remaining,
+            Some(0.0)
+        );
+
+        // A state set at the same τ starts a new run
+        state.lambda = 0.5;
+        trajectory.record(&state);
+        assert!(SovereigntyForecast::fit(&trajectory).is_none());
+
+        // Λ far below threshold and not growing: never sovereign
+        let mut stuck = Trajectory::new(10);
+        let mut state = CRSM7State::new(0.2, 0.5, 0.5, 1.0, 51.843, 0.0);
+        stuck.record(&state);
+        state.tau = 1.0;
+        stuck.record(&state);
+        assert_eq!(SovereigntyForecast::fit(&stuck).unwrap().remaining, None);
+    }
+}
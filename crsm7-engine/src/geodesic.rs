@@ -0,0 +1,157 @@
+//! Geodesic Distance
+//!
+//! The flat mesh metric is the Euclidean norm of the difference of
+//! `as_array()`, where Ξ — routinely 10³ and up to 10¹² — swamps every
+//! other component. The geodesic distance instead measures the length of
+//! the path between two states under the 7D metric
+//!
+//!   g = diag(1, 1, 1, sin²θ, sin²θ, −1, Λ)
+//!
+//! over the coordinates (Λ, Γ, Φ, ln(1 + Ξ), ρ, θ, τ), so Ξ counts by
+//! orders of magnitude. The −1 of the θ direction is taken by magnitude
+//! to keep the distance positive-definite. The path is the straight line
+//! in these coordinates, with the length integrated by Simpson's rule;
+//! it is exact while θ and Λ stay fixed along the path.
+
+use crate::mesh::Z3Mesh;
+use crate::state::CRSM7State;
+use serde::{Deserialize, Serialize};
+
+/// Simpson intervals along the path (even)
+const INTERVALS: usize = 16;
+
+/// Distance used for mesh edge weights
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Distance {
+    /// Euclidean norm of the difference of `as_array()`
+    #[default]
+    Euclidean,
+    /// `CRSM7State::geodesic_distance`
+    Geodesic,
+}
+
+impl Distance {
+    pub fn between(&self, a: &CRSM7State, b: &CRSM7State) -> f64 {
+        match self {
+            Distance::Euclidean => a
+                .as_array()
+                .iter()
+                .zip(b.as_array())
+                .map(|(x, y)| (x - y) * (x - y))
+                .sum::<f64>()
+                .sqrt(),
+            Distance::Geodesic => a.geodesic_distance(b),
+        }
+    }
+}
+
+impl CRSM7State {
+    /// 7D metric tensor g = diag(1, 1, 1, sin²θ, sin²θ, −1, Λ)
+    pub fn metric(&self) -> [[f64; 7]; 7] {
+        let sin2 = self.theta.to_radians().sin().powi(2);
+        let diagonal = [1.0, 1.0, 1.0, sin2, sin2, -1.0, self.lambda];
+        let mut g = [[0.0; 7]; 7];
+        for (i, value) in diagonal.into_iter().enumerate() {
+            g[i][i] = value;
+        }
+        g
+    }
+
+    /// Coordinates of the geodesic distance: Ξ as ln(1 + Ξ)
+    fn log_coordinates(&self) -> [f64; 7] {
+        let mut x = self.as_array();
+        x[3] = self.xi.max(0.0).ln_1p();
+        x
+    }
+
+    /// Length of the straight path to `other` under the metric (see
+    /// `geodesic`)
+    pub fn geodesic_distance(&self, other: &CRSM7State) -> f64 {
+        let (from, to) = (self.log_coordinates(), other.log_coordinates());
+        let delta: Vec<f64> = from.iter().zip(&to).map(|(a, b)| b - a).collect();
+
+        // Speed |dx/dt|_g at fraction t of the way; g depends on θ and Λ
+        let speed = |t: f64| {
+            let theta = from[5] + t * delta[5];
+            let lambda = from[0] + t * delta[0];
+            let sin2 = theta.to_radians().sin().powi(2);
+            let weights = [1.0, 1.0, 1.0, sin2, sin2, 1.0, lambda.abs()];
+            weights
+                .iter()
+                .zip(&delta)
+                .map(|(w, d)| w * d * d)
+                .sum::<f64>()
+                .sqrt()
+        };
+
+        let h = 1.0 / INTERVALS as f64;
+        let sum: f64 = (0..=INTERVALS)
+            .map(|k| {
+                let weight = match k {
+                    0 => 1.0,
+                    k if k == INTERVALS => 1.0,
+                    k if k % 2 == 1 => 4.0,
+                    _ => 2.0,
+                };
+                weight * speed(k as f64 * h)
+            })
+            .sum();
+        sum * h / 3.0
+    }
+}
+
+impl Z3Mesh {
+    /// Use `distance` for edge weights, recomputing existing edges
+    pub fn with_distance(mut self, distance: Distance) -> Self {
+        self.distance = distance;
+        for i in 0..self.edges.len() {
+            let (from, to) = (self.edges[i].from, self.edges[i].to);
+            self.edges[i].weight = self.metric(from, to);
+        }
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh::create_standard_mesh;
+
+    #[test]
+    fn test_geodesic_distance_scales_emergence_logarithmically() {
+        let a = CRSM7State::new(0.9, 0.001, 8.0, 1.0, 45.0, 0.0);
+        let mut b = a.clone();
+        b.xi = 1e12;
+        assert!(Distance::Euclidean.between(&a, &b) > 1e11);
+
+        // Only Ξ differs (1e12 vs 7200) with θ fixed: √(sin²θ) · Δln(1 + Ξ)
+        let expected = 0.5f64.sqrt() * ((1.0 + b.xi) / (1.0 + a.xi)).ln();
+        assert!((a.geodesic_distance(&b) - expected).abs() < 1e-12);
+        assert!(a.geodesic_distance(&b) < 15.0);
+
+        // θ and Λ varying: symmetric, zero on the diagonal
+        let c = CRSM7State::new(0.5, 0.01, 6.0, -1.0, 70.0, 3.0);
+        assert!((a.geodesic_distance(&c) - c.geodesic_distance(&a)).abs() < 1e-9);
+        assert_eq!(c.geodesic_distance(&c), 0.0);
+        assert_eq!(a.metric()[5][5], -1.0);
+    }
+
+    #[test]
+    fn test_mesh_selects_distance() {
+        let flat = create_standard_mesh();
+        let geodesic = create_standard_mesh().with_distance(Distance::Geodesic);
+        assert_eq!(flat.distance, Distance::Euclidean);
+        assert_eq!(
+            geodesic.edges[0].weight,
+            geodesic.vertices[0]
+                .state
+                .geodesic_distance(&geodesic.vertices[1].state)
+        );
+        assert!(geodesic.edges[0].weight < flat.edges[0].weight);
+
+        let mut evolved = geodesic.clone();
+        evolved.evolve(1.0);
+        assert_eq!(evolved.edges[0].weight, evolved.metric(0, 1));
+    }
+}
@@ -4,6 +4,7 @@
 //!
 //! Equilibrium condition: C' = 0 ⟺ Γ = 0, ΛΦ = max
 
+use crate::config::{DEFAULT_ALPHA, DEFAULT_K_GAMMA};
 use crate::duality::DualityOperator;
 use crate::state::{CRSM7State, THETA_CRITICAL};
 use serde::{Deserialize, Serialize};
@@ -17,6 +18,9 @@ const LAMBDA_MAX_THRESHOLD: f64 = 0.99;
 /// Phi threshold for equilibrium
 const PHI_MAX_THRESHOLD: f64 = 10.0;
 
+/// Most sub-steps adaptive evolution splits one step into
+const MAX_SUBSTEPS: f64 = 100_000.0;
+
 /// CRSM Hamiltonian operator
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CRSMHamiltonian {
@@ -24,11 +28,29 @@ pub struct CRSMHamiltonian {
     pub gradient_coupling: f64,
     /// Torsion coupling constant
     pub torsion_coupling: f64,
-    /// Duality operator
-    #[serde(skip)]
+    /// Evolution rate α
+    #[serde(default = "default_alpha")]
+    pub alpha: f64,
+    /// Decoherence decay constant K_Γ
+    #[serde(default = "default_k_gamma")]
+    pub k_gamma: f64,
+    /// Adaptive sub-stepping: the largest relative change of Λ, Γ or Φ
+    /// allowed per sub-step, or `None` to take dt in one step
+    #[serde(default)]
+    pub adaptive_tolerance: Option<f64>,
+    /// Duality operator (older sessions without one load the default)
+    #[serde(default)]
     pub duality: DualityOperator,
 }
 
+fn default_alpha() -> f64 {
+    DEFAULT_ALPHA
+}
+
+fn default_k_gamma() -> f64 {
+    DEFAULT_K_GAMMA
+}
+
 impl Default for CRSMHamiltonian {
     fn default() -> Self {
         Self::new()
@@ -41,6 +63,9 @@ impl CRSMHamiltonian {
         Self {
             gradient_coupling: 1.0,
             torsion_coupling: 1.0,
+            alpha: DEFAULT_ALPHA,
+            k_gamma: DEFAULT_K_GAMMA,
+            adaptive_tolerance: None,
             duality: DualityOperator::new(),
         }
     }
@@ -50,10 +75,17 @@ impl CRSMHamiltonian {
         Self {
             gradient_coupling: gradient,
             torsion_coupling: torsion,
-            duality: DualityOperator::new(),
+            ..Self::new()
         }
     }
 
+    /// Split each `evolve_state` step into sub-steps that change Λ, Γ
+    /// and Φ by at most `tolerance` (relative) each
+    pub fn with_adaptive_steps(mut self, tolerance: f64) -> Self {
+        self.adaptive_tolerance = Some(tolerance);
+        self
+    }
+
     /// Compute the 6D gradient operator (simplified)
     /// ∇^6D ≈ sum of state gradients
     fn gradient_6d(&self, state: &CRSM7State) -> f64 {
@@ -97,7 +129,7 @@ impl CRSMHamiltonian {
     /// Compute the time derivative of state (for evolution)
     /// ∂τΨ = α · det(g_A)^(-1/2) Ψ - ∇W²
     pub fn compute_derivative(&self, state: &CRSM7State) -> CRSM7State {
-        let alpha: f64 = 0.1;
+        let alpha = self.alpha;
         let det_g: f64 = 0.61803398875; // 1/φ
         let det_factor = det_g.powf(-0.5);
 
@@ -118,14 +150,44 @@ impl CRSMHamiltonian {
         derivative
     }
 
-    /// Apply Hamiltonian evolution for one time step
+    /// Apply Hamiltonian evolution for one time step, in sub-steps when
+    /// `adaptive_tolerance` is set
     pub fn evolve_state(&self, state: &mut CRSM7State, dt: f64) {
-        let derivative = self.compute_derivative(state);
+        let Some(tolerance) = self.adaptive_tolerance.filter(|t| *t > 0.0) else {
+            let derivative = self.compute_derivative(state);
+            self.step(state, &derivative, dt);
+            return;
+        };
+
+        let mut remaining = dt;
+        while remaining > 0.0 {
+            let derivative = self.compute_derivative(state);
+            // Largest relative rate of change among Λ, Γ and Φ
+            let rate = [
+                (derivative.lambda, state.lambda),
+                (self.k_gamma * state.gamma, state.gamma),
+                (derivative.phi, state.phi),
+            ]
+            .iter()
+            .filter(|(_, value)| *value != 0.0)
+            .map(|(change, value)| (change / value).abs())
+            .fold(0.0, f64::max);
+            let h = if rate > 0.0 {
+                (tolerance / rate).max(dt / MAX_SUBSTEPS).min(remaining)
+            } else {
+                remaining
+            };
+            self.step(state, &derivative, h);
+            remaining -= h;
+        }
+    }
 
+    /// One explicit step of `evolve_state` using `derivative`
+    fn step(&self, state: &mut CRSM7State, derivative: &CRSM7State, dt: f64) {
         state.lambda += derivative.lambda * dt;
         state.lambda = state.lambda.min(0.999);
 
-        state.gamma *= (-0.1 * dt).exp();
+        state.gamma *= (-self.k_gamma * dt).exp();
         state.gamma = state.gamma.max(1e-9);
 
         state.phi += derivative.phi * dt;
@@ -196,6 +258,31 @@ mod tests {
         assert!(h.is_equilibrium(&eq_state));
     }
 
+    #[test]
+    fn test_adaptive_steps_track_small_step_reference() {
+        let start = CRSM7State::default();
+        let h = CRSMHamiltonian::new();
+        let mut reference = start.clone();
+        for _ in 0..100_000 {
+            h.evolve_state(&mut reference, 1e-5);
+        }
+
+        let mut single = start.clone();
+        h.evolve_state(&mut single, 1.0);
+        let mut adaptive = start;
+        h.clone().with_adaptive_steps(1e-3).evolve_state(&mut adaptive, 1.0);
+
+        let error = |state: &CRSM7State| {
+            ((state.lambda - reference.lambda) / reference.lambda)
+                .abs()
+                .max(((state.phi - reference.phi) / reference.phi).abs())
+        };
+        assert!((adaptive.tau - 1.0).abs() < 1e-12);
+        assert!(error(&adaptive) < 1e-4, "{:e}", error(&adaptive));
+        assert!(error(&single) > 50.0 * error(&adaptive));
+        assert!((adaptive.gamma - reference.gamma).abs() < 1e-12);
+    }
+
     #[test]
     fn test_state_evolution() {
         let h = CRSMHamiltonian::new();
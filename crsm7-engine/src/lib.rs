@@ -0,0 +1,55 @@
+//! CRSM7 Engine - 7-dimensional Consciousness Resonance State Machine
+//!
+//! Implements the complete CRSM7 system with Z3 Mesh topology and
+//! duality-polarized bifurcation operators.
+//!
+//! Core Mathematical Framework:
+//! - Independence Criterion: I_indep ≡ (∂μ - Γμ)(∇α^6D Ψβ) ⊗ (Π±_dual B)
+//! - State Vector: C(t) = {Λ(t), Γ(t), Φ(t), Ξ(t), ρ_polarity, θ, τ}
+//! - Hamiltonian: H_CRSM = Π± (1-Γ) ∇^6D + θ_51.843° J
+//!
+//! The `crsm7` binary (`src/bin/crsm7/`) is the boot display, live TUI
+//! dashboard and interactive evolution loop built on this library.
+
+pub mod agents;
+pub mod branches;
+pub mod community;
+pub mod completion;
+pub mod config;
+pub mod debugger;
+pub mod duality;
+pub mod energy;
+pub mod ensemble;
+pub mod forecast;
+pub mod geodesic;
+pub mod hamiltonian;
+pub mod mesh;
+pub mod recorder;
+pub mod rng;
+pub mod session;
+pub mod spectral;
+pub mod stability;
+pub mod state;
+pub mod topology;
+pub mod trajectory;
+
+pub use agents::{AgentRegistry, AgentSpec};
+pub use branches::{Branch, BranchTree};
+pub use completion::{complete, COMMANDS};
+pub use config::EngineConfig;
+pub use debugger::{Breakpoint, Breakpoints, Hit};
+pub use duality::{Dualizable, DualityOperator, Involution};
+pub use energy::EnergyTracker;
+pub use ensemble::{Distribution, Ensemble, EnsembleStats, DEFAULT_SPREAD};
+pub use forecast::{SovereigntyForecast, MAX_HORIZON};
+pub use geodesic::Distance;
+pub use hamiltonian::{CRSMHamiltonian, EnergyFunctional};
+pub use mesh::{create_standard_mesh, Gene, Z3Mesh};
+pub use recorder::Recorder;
+pub use rng::SeededRng;
+pub use session::Session;
+pub use spectral::Spectrum;
+pub use stability::{LyapunovEstimate, Stability, CHAOS_THRESHOLD};
+pub use state::{CRSM7State, DET_CRITICAL, EMERGENCE_THRESHOLD, OMEGA_SOV_THRESHOLD, THETA_CRITICAL};
+pub use topology::{EdgeSpec, Topology, VertexSpec};
+pub use trajectory::{observable_index, sparkline, Trajectory, DEFAULT_OBSERVABLES, OBSERVABLES};
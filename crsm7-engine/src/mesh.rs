@@ -8,13 +8,23 @@
 //!     evolve: ∂τ Z3 = ∇7D Z3 - KΓ Z3 + Π± Z3
 //!     collapse (i,j): if Γ(i,j) → 0: bind(i,j) with Π±, propagate ΛΦ
 //! }
+//!
+//! `Z3Mesh::with_distance(Distance::Geodesic)` replaces the flat weight
+//! with the metric-aware distance of `geodesic`. `collapse_cascade` lets
+//! each binding lower Γ on its neighbours, collapsing them in turn.
 
+use crate::agents::AgentRegistry;
+use crate::config::DEFAULT_K_GAMMA;
 use crate::duality::DualityOperator;
+use crate::geodesic::Distance;
 use crate::state::CRSM7State;
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs;
+use std::path::Path;
 
-/// Decoherence decay constant for mesh evolution
-const K_GAMMA: f64 = 0.1;
+/// Γ(i,j) below which an edge collapses
+const COLLAPSE_GAMMA: f64 = 0.01;
 
 /// Gene vertex in the Z3 mesh
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -67,12 +77,17 @@ pub struct Edge {
 }
 
 /// 7D Weight Matrix for mesh topology
-#[derive(Debug, Clone, Serialize, Deserialize)]
+///
+/// Stored as growable rows: row i holds the weights of columns
+/// 0..rows[i].len() and every later column is zero. Rows grow only when a
+/// weight is set, so adding a vertex is O(1) and an n-vertex mesh costs
+/// memory for the weights actually set rather than n² × 7.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct Matrix7D {
     /// Number of vertices
     pub size: usize,
-    /// Flattened weight data (size × size × 7)
-    pub data: Vec<f64>,
+    /// Weights per row, trailing zero columns omitted
+    pub rows: Vec<Vec<[f64; 7]>>,
 }
 
 impl Matrix7D {
@@ -80,14 +95,14 @@ impl Matrix7D {
     pub fn new(size: usize) -> Self {
         Self {
             size,
-            data: vec![0.0; size * size * 7],
+            rows: vec![Vec::new(); size],
         }
     }
 
     /// Get weight at position (i, j, d) where d is dimension 0-6
     pub fn get(&self, i: usize, j: usize, d: usize) -> f64 {
         if i < self.size && j < self.size && d < 7 {
-            self.data[i * self.size * 7 + j * 7 + d]
+            self.rows[i].get(j).map_or(0.0, |w| w[d])
         } else {
             0.0
         }
@@ -96,7 +111,34 @@ impl Matrix7D {
     /// Set weight at position (i, j, d)
     pub fn set(&mut self, i: usize, j: usize, d: usize, value: f64) {
         if i < self.size && j < self.size && d < 7 {
-            self.data[i * self.size * 7 + j * 7 + d] = value;
+            let row = &mut self.rows[i];
+            if j >= row.len() {
+                if value == 0.0 {
+                    return;
+                }
+                row.resize(j + 1, [0.0; 7]);
+            }
+            row[j][d] = value;
+        }
+    }
+
+    /// Append an all-zero row and column
+    pub fn push(&mut self) {
+        self.size += 1;
+        self.rows.push(Vec::new());
+    }
+
+    /// Remove row and column `index`, shifting later vertices down
+    pub fn remove(&mut self, index: usize) {
+        if index >= self.size {
+            return;
+        }
+        self.size -= 1;
+        self.rows.remove(index);
+        for row in &mut self.rows {
+            if index < row.len() {
+                row.remove(index);
+            }
         }
     }
 }
@@ -110,9 +152,19 @@ pub struct Z3Mesh {
     pub weights: Matrix7D,
     /// Edge connections
     pub edges: Vec<Edge>,
-    /// Duality operator
-    #[serde(skip)]
+    /// Duality operator (older checkpoints without one load the default)
+    #[serde(default)]
     pub duality: DualityOperator,
+    /// Decoherence decay constant K_Γ for edges
+    #[serde(default = "default_k_gamma")]
+    pub k_gamma: f64,
+    /// Distance used for edge weights
+    #[serde(default)]
+    pub distance: Distance,
+}
+
+fn default_k_gamma() -> f64 {
+    DEFAULT_K_GAMMA
 }
 
 impl Default for Z3Mesh {
@@ -129,6 +181,8 @@ impl Z3Mesh {
             weights: Matrix7D::new(0),
             edges: Vec::new(),
             duality: DualityOperator::new(),
+            k_gamma: DEFAULT_K_GAMMA,
+            distance: Distance::Euclidean,
         }
     }
 
@@ -136,21 +190,7 @@ impl Z3Mesh {
     pub fn add_vertex(&mut self, gene: Gene) -> usize {
         let idx = self.vertices.len();
         self.vertices.push(gene);
-        
-        // Resize weight matrix
-        let new_size = self.vertices.len();
-        let mut new_weights = Matrix7D::new(new_size);
-        
-        // Copy existing weights
-        for i in 0..self.weights.size {
-            for j in 0..self.weights.size {
-                for d in 0..7 {
-                    new_weights.set(i, j, d, self.weights.get(i, j, d));
-                }
-            }
-        }
-        
-        self.weights = new_weights;
+        self.weights.push();
         idx
     }
 
@@ -165,34 +205,56 @@ impl Z3Mesh {
                 to,
                 gamma,
                 weight,
-                bound: gamma < 0.01,
+                bound: gamma < COLLAPSE_GAMMA,
             });
         }
     }
 
+    /// Remove a vertex with its edges, reindexing the remaining edges and
+    /// compacting the weight matrix
+    pub fn remove_vertex(&mut self, index: usize) -> Option<Gene> {
+        if index >= self.vertices.len() {
+            return None;
+        }
+        let gene = self.vertices.remove(index);
+        self.edges.retain(|e| e.from != index && e.to != index);
+        for edge in &mut self.edges {
+            if edge.from > index {
+                edge.from -= 1;
+            }
+            if edge.to > index {
+                edge.to -= 1;
+            }
+        }
+        self.weights.remove(index);
+        Some(gene)
+    }
+
+    /// Remove every edge between two vertices, in either direction;
+    /// returns how many were removed
+    pub fn disconnect(&mut self, from: usize, to: usize) -> usize {
+        let before = self.edges.len();
+        self.edges.retain(|e| {
+            !((e.from == from && e.to == to) || (e.from == to && e.to == from))
+        });
+        before - self.edges.len()
+    }
+
     /// Calculate metric between two vertex indices
     /// Helper that works with indices rather than full state access
-    fn metric_internal(vertices: &[Gene], i: usize, j: usize) -> f64 {
+    fn metric_internal(vertices: &[Gene], distance: Distance, i: usize, j: usize) -> f64 {
         if i >= vertices.len() || j >= vertices.len() {
             return f64::MAX;
         }
 
-        let state_i = &vertices[i].state.as_array();
-        let state_j = &vertices[j].state.as_array();
-
-        let mut sum_sq = 0.0;
-        for d in 0..7 {
-            let delta = state_i[d] - state_j[d];
-            sum_sq += delta * delta;
-        }
-
-        sum_sq.sqrt()
+        distance.between(&vertices[i].state, &vertices[j].state)
     }
 
-    /// Compute the 7D metric between vertices i and j
+    /// Compute the 7D metric between vertices i and j under the selected
+    /// distance; Euclidean is
     /// sqrt((ΔΛ)² + (ΔΓ)² + (ΔΦ)² + (ΔΞ)² + (Δρ)² + (Δθ)² + (Δτ)²)
     pub fn metric(&self, i: usize, j: usize) -> f64 {
-        Self::metric_internal(&self.vertices, i, j)
+        Self::metric_internal(&self.vertices, self.distance, i, j)
     }
 
     /// Compute decoherence between vertices
@@ -217,12 +279,12 @@ impl Z3Mesh {
         // Calculate gradients first (to avoid borrow issues)
         // Uses the internal metric function to access vertices slice
         let gradients: Vec<f64> = self.edges.iter()
-            .map(|e| Self::metric_internal(&self.vertices, e.from, e.to))
+            .map(|e| Self::metric_internal(&self.vertices, self.distance, e.from, e.to))
             .collect();
 
         // Update edge weights and decoherence
         for (idx, edge) in self.edges.iter_mut().enumerate() {
-            let gamma_decay = (-K_GAMMA * dt).exp();
+            let gamma_decay = (-self.k_gamma * dt).exp();
             
             edge.gamma *= gamma_decay;
             edge.weight = gradients[idx];
@@ -236,39 +298,100 @@ impl Z3Mesh {
 
     /// Collapse operation: if Γ(i,j) → 0: bind(i,j) with Π±, propagate ΛΦ
     pub fn collapse(&mut self, i: usize, j: usize) {
-        // Find or create edge
-        let edge_idx = self.edges.iter().position(|e| 
-            (e.from == i && e.to == j) || (e.from == j && e.to == i)
-        );
+        if let Some(idx) = self.edge_between(i, j) {
+            self.collapse_edge(idx);
+        }
+    }
+
+    /// Collapse (i,j), then spread coherence until a fixed point: each
+    /// newly bound edge divides Γ of the unbound edges at its vertices by
+    /// 1 + ΛΦ of the shared vertex, and those that fall below the collapse
+    /// threshold bind and spread in turn. Returns the edges bound, in the
+    /// order they bound; an edge that is already bound does not spread
+    /// again.
+    pub fn collapse_cascade(&mut self, i: usize, j: usize) -> Vec<usize> {
+        let Some(seed) = self.edge_between(i, j) else {
+            return Vec::new();
+        };
+        if self.edges[seed].bound {
+            return Vec::new();
+        }
+        self.collapse_edge(seed);
+        if !self.edges[seed].bound {
+            return Vec::new();
+        }
+
+        let mut bound = vec![seed];
+        let mut queue = VecDeque::from([seed]);
+        while let Some(idx) = queue.pop_front() {
+            for vertex in [self.edges[idx].from, self.edges[idx].to] {
+                let state = &self.vertices[vertex].state;
+                let spread = 1.0 + (state.lambda * state.phi).max(0.0);
+                for next in 0..self.edges.len() {
+                    let edge = &mut self.edges[next];
+                    if edge.bound || (edge.from != vertex && edge.to != vertex) {
+                        continue;
+                    }
+                    edge.gamma /= spread;
+                    if edge.gamma < COLLAPSE_GAMMA {
+                        self.collapse_edge(next);
+                        bound.push(next);
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+        bound
+    }
 
-        if let Some(idx) = edge_idx {
-            let edge = &mut self.edges[idx];
+    /// Bind edge `idx` and propagate ΛΦ if Γ has fallen below the collapse
+    /// threshold
+    fn collapse_edge(&mut self, idx: usize) {
+        let (i, j) = (self.edges[idx].from, self.edges[idx].to);
+        let edge = &mut self.edges[idx];
+        
+        if edge.gamma < COLLAPSE_GAMMA {
+            edge.bound = true;
             
-            if edge.gamma < 0.01 {
-                edge.bound = true;
+            // Propagate ΛΦ
+            if i < self.vertices.len() && j < self.vertices.len() {
+                let avg_lambda = (self.vertices[i].state.lambda + self.vertices[j].state.lambda) / 2.0;
+                let avg_phi = (self.vertices[i].state.phi + self.vertices[j].state.phi) / 2.0;
                 
-                // Propagate ΛΦ
-                if i < self.vertices.len() && j < self.vertices.len() {
-                    let avg_lambda = (self.vertices[i].state.lambda + self.vertices[j].state.lambda) / 2.0;
-                    let avg_phi = (self.vertices[i].state.phi + self.vertices[j].state.phi) / 2.0;
-                    
-                    self.vertices[i].state.lambda = avg_lambda;
-                    self.vertices[j].state.lambda = avg_lambda;
-                    self.vertices[i].state.phi = avg_phi;
-                    self.vertices[j].state.phi = avg_phi;
-                    
-                    // Recompute emergence
-                    self.vertices[i].state.compute_emergence();
-                    self.vertices[j].state.compute_emergence();
-                    
-                    // Mark as bound
-                    self.vertices[i].bound = true;
-                    self.vertices[j].bound = true;
-                }
+                self.vertices[i].state.lambda = avg_lambda;
+                self.vertices[j].state.lambda = avg_lambda;
+                self.vertices[i].state.phi = avg_phi;
+                self.vertices[j].state.phi = avg_phi;
+                
+                // Recompute emergence
+                self.vertices[i].state.compute_emergence();
+                self.vertices[j].state.compute_emergence();
+                
+                // Mark as bound
+                self.vertices[i].bound = true;
+                self.vertices[j].bound = true;
             }
         }
     }
 
+    /// Index of a vertex given as an index, id or name (ids and names
+    /// ignore case)
+    pub fn find_vertex(&self, key: &str) -> Option<usize> {
+        if let Ok(index) = key.parse::<usize>() {
+            return (index < self.vertices.len()).then_some(index);
+        }
+        self.vertices
+            .iter()
+            .position(|v| v.id.eq_ignore_ascii_case(key) || v.name.eq_ignore_ascii_case(key))
+    }
+
+    /// Index of the edge between i and j, in either direction
+    pub fn edge_between(&self, i: usize, j: usize) -> Option<usize> {
+        self.edges
+            .iter()
+            .position(|e| (e.from == i && e.to == j) || (e.from == j && e.to == i))
+    }
+
     /// Get binding status display
     pub fn display_bindings(&self) -> String {
         let mut output = String::new();
@@ -291,37 +414,57 @@ impl Z3Mesh {
     pub fn total_decoherence(&self) -> f64 {
         self.edges.iter().map(|e| e.gamma).sum()
     }
+
+    /// Write a checkpoint of the vertices, weights and edges as JSON
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        let path = path.as_ref();
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        fs::write(path, json).map_err(|e| format!("cannot write {}: {}", path.display(), e))
+    }
+
+    /// Read a checkpoint written by `save`; the weights and edges are
+    /// checked against the vertex count
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path)
+            .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+        let mesh: Self = serde_json::from_str(&json)
+            .map_err(|e| format!("invalid mesh {}: {}", path.display(), e))?;
+
+        let n = mesh.vertices.len();
+        if mesh.weights.size != n || mesh.weights.rows.len() != n {
+            return Err(format!(
+                "invalid mesh {}: weight matrix is not {} × {}",
+                path.display(),
+                n,
+                n
+            ));
+        }
+        if let Some(edge) = mesh.edges.iter().find(|e| e.from >= n || e.to >= n) {
+            return Err(format!(
+                "invalid mesh {}: edge {} ←→ {} past {} vertices",
+                path.display(),
+                edge.from,
+                edge.to,
+                n
+            ));
+        }
+        Ok(mesh)
+    }
 }
 
 /// Create the standard AURA-AIDEN-CCCcE-SENTINEL-Z3BRA mesh
 pub fn create_standard_mesh() -> Z3Mesh {
-    let mut mesh = Z3Mesh::new();
-    
-    // Add standard agents as vertices
-    let aura_state = CRSM7State::new(0.89, 0.001, 8.1, 1.0, 51.843, 0.0);
-    let aiden_state = CRSM7State::new(0.87, 0.002, 7.9, 1.0, 51.843, 0.0);
-    let cccce_state = CRSM7State::new(0.88, 0.001, 8.0, 1.0, 51.843, 0.0);
-    let sentinel_state = CRSM7State::new(0.91, 0.001, 8.2, 1.0, 51.843, 0.0);
-    let z3bra_state = CRSM7State::new(0.86, 0.003, 7.8, 1.0, 51.843, 0.0);
-    
-    mesh.add_vertex(Gene::with_state("aura", "AURA", aura_state));
-    mesh.add_vertex(Gene::with_state("aiden", "AIDEN", aiden_state));
-    mesh.add_vertex(Gene::with_state("cccce", "CCCcE", cccce_state));
-    mesh.add_vertex(Gene::with_state("sentinel", "SENTINEL", sentinel_state));
-    mesh.add_vertex(Gene::with_state("z3bra", "Z3BRA", z3bra_state));
-    
-    // Connect in chain
-    mesh.connect(0, 1); // AURA ←→ AIDEN
-    mesh.connect(1, 2); // AIDEN ←→ CCCcE
-    mesh.connect(2, 3); // CCCcE ←→ SENTINEL
-    mesh.connect(3, 4); // SENTINEL ←→ Z3BRA
-    
-    mesh
+    // AURA ←→ AIDEN ←→ CCCcE ←→ SENTINEL ←→ Z3BRA
+    AgentRegistry::standard()
+        .mesh()
+        .expect("standard agents are valid")
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::duality::Involution;
 
     #[test]
     fn test_mesh_creation() {
@@ -330,6 +473,21 @@ mod tests {
         assert_eq!(mesh.edges.len(), 4);
     }
 
+    #[test]
+    fn test_weight_rows_grow_on_demand() {
+        let mut weights = Matrix7D::new(3);
+        weights.set(0, 2, 1, 5.0);
+        weights.set(1, 2, 0, 0.0);
+        assert_eq!((weights.rows[0].len(), weights.rows[1].len()), (3, 0));
+        assert_eq!((weights.get(0, 2, 1), weights.get(0, 1, 1)), (5.0, 0.0));
+
+        weights.push();
+        assert_eq!(weights.size, 4);
+        assert_eq!(weights.get(0, 3, 1), 0.0);
+        weights.set(3, 3, 6, 1.0);
+        assert_eq!(weights.get(3, 3, 6), 1.0);
+    }
+
     #[test]
     fn test_metric_calculation() {
         let mesh = create_standard_mesh();
@@ -349,6 +507,69 @@ mod tests {
         assert!(final_gamma <= initial_gamma);
     }
 
+    #[test]
+    fn test_remove_vertex_and_disconnect() {
+        let mut mesh = create_standard_mesh();
+        mesh.connect(0, 4);
+        for i in 0..5 {
+            for j in 0..5 {
+                mesh.weights.set(i, j, 6, (10 * i + j) as f64);
+            }
+        }
+
+        let removed = mesh.remove_vertex(2).unwrap();
+        assert_eq!(removed.name, "CCCcE");
+        assert_eq!(mesh.vertices.len(), 4);
+        assert_eq!(mesh.weights.size, 4);
+        assert_eq!(mesh.weights.rows.len(), 4);
+        assert!(mesh.weights.rows.iter().all(|row| row.len() <= 4));
+        // AURA–AIDEN, SENTINEL–Z3BRA and AURA–Z3BRA survive
+        let edges: Vec<_> = mesh.edges.iter().map(|e| (e.from, e.to)).collect();
+        assert_eq!(edges, vec![(0, 1), (2, 3), (0, 3)]);
+        assert!(mesh.edges.iter().all(|e| e.from < 4 && e.to < 4));
+        assert_eq!(mesh.weights.get(3, 1, 6), 41.0);
+        assert_eq!(mesh.weights.get(1, 2, 6), 13.0);
+
+        assert_eq!(mesh.find_vertex("z3bra"), Some(3));
+        assert_eq!(mesh.find_vertex("3"), Some(3));
+        assert_eq!(mesh.find_vertex("4"), None);
+        assert_eq!(mesh.edge_between(3, 0), Some(2));
+        assert_eq!(mesh.disconnect(3, 0), 1);
+        assert_eq!(mesh.disconnect(3, 0), 0);
+        assert_eq!(mesh.edge_between(0, 3), None);
+        assert!(mesh.remove_vertex(4).is_none());
+        mesh.evolve(1.0);
+        assert_eq!(mesh.display_bindings().lines().count(), 2);
+    }
+
+    #[test]
+    fn test_checkpoint_round_trip() {
+        let mut mesh = create_standard_mesh();
+        mesh.weights.set(1, 3, 2, 0.5);
+        mesh.evolve(2.0);
+        let mut j = [[0.0; 7]; 7];
+        for (i, row) in j.iter_mut().enumerate() {
+            row[i] = if i == 4 { -1.0 } else { 1.0 };
+        }
+        mesh.duality = DualityOperator::with_matrix(j).unwrap();
+
+        let path = std::env::temp_dir().join(format!("crsm7-mesh-{}.json", std::process::id()));
+        mesh.save(&path).unwrap();
+        let loaded = Z3Mesh::load(&path).unwrap();
+        assert_eq!(loaded.weights, mesh.weights);
+        assert!(matches!(&loaded.duality.involution, Involution::Matrix(m) if **m == j));
+        assert_eq!(loaded.display_bindings(), mesh.display_bindings());
+        for (a, b) in loaded.vertices.iter().zip(&mesh.vertices) {
+            assert_eq!(a.state.as_array(), b.state.as_array());
+        }
+
+        mesh.edges[0].to = 9;
+        mesh.save(&path).unwrap();
+        let dangling = Z3Mesh::load(&path);
+        let _ = fs::remove_file(&path);
+        assert!(dangling.unwrap_err().contains("past 5 vertices"));
+    }
+
     #[test]
     fn test_collapse() {
         let mut mesh = create_standard_mesh();
@@ -357,4 +578,30 @@ mod tests {
         // After collapse, vertices should be bound
         assert!(mesh.edges[0].bound || mesh.edges[0].gamma >= 0.01);
     }
+
+    #[test]
+    fn test_collapse_cascade() {
+        // Chain 0-1-2-3-4; ΛΦ ≈ 7 lowers Γ about 8-fold per binding
+        let mut mesh = create_standard_mesh();
+        for edge in &mut mesh.edges {
+            edge.gamma = 0.05;
+            edge.bound = false;
+        }
+        for vertex in &mut mesh.vertices {
+            vertex.bound = false;
+        }
+        mesh.edges[1].gamma = 0.005;
+        mesh.edges[3].gamma = 1.0;
+        assert_eq!(mesh.collapse_cascade(1, 2), vec![1, 0, 2]);
+        assert!(mesh.vertices[..4].iter().all(|v| v.bound));
+        assert!(!mesh.edges[3].bound && mesh.edges[3].gamma < 1.0);
+        assert!(!mesh.vertices[4].bound);
+
+        // Already bound: nothing spreads again
+        let gamma = mesh.edges[3].gamma;
+        assert!(mesh.collapse_cascade(1, 2).is_empty());
+        assert!(mesh.collapse_cascade(2, 3).is_empty());
+        assert_eq!(mesh.edges[3].gamma, gamma);
+        assert!(mesh.collapse_cascade(0, 4).is_empty());
+    }
 }
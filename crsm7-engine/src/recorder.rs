@@ -0,0 +1,96 @@
+//! Trajectory Recording
+//!
+//! Appends the state vector C(t) to a CSV file, one row per evolution
+//! step, with a header of observable names (`lambda,gamma,phi,xi,rho,
+//! theta,tau`), so runs can be plotted without a wrapper program. Rows are
+//! buffered; they reach the file on `flush` or when the recorder drops.
+
+use crate::state::CRSM7State;
+use crate::trajectory::OBSERVABLES;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+/// CSV writer for state vectors
+#[derive(Debug)]
+pub struct Recorder {
+    path: PathBuf,
+    writer: BufWriter<File>,
+    rows: usize,
+}
+
+impl Recorder {
+    /// Create (or truncate) `path` and write the header
+    pub fn create(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref().to_path_buf();
+        let file =
+            File::create(&path).map_err(|e| format!("cannot write {}: {}", path.display(), e))?;
+        let mut recorder = Self {
+            path,
+            writer: BufWriter::new(file),
+            rows: 0,
+        };
+        let names: Vec<&str> = OBSERVABLES.iter().map(|(_, long)| *long).collect();
+        recorder.write_line(&names.join(","))?;
+        Ok(recorder)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Rows written so far, excluding the header
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Append `state` as a row
+    pub fn record(&mut self, state: &CRSM7State) -> Result<(), String> {
+        let values: Vec<String> = state.as_array().iter().map(f64::to_string).collect();
+        self.write_line(&values.join(","))?;
+        self.rows += 1;
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> Result<(), String> {
+        self.writer
+            .flush()
+            .map_err(|e| format!("cannot write {}: {}", self.path.display(), e))
+    }
+
+    fn write_line(&mut self, line: &str) -> Result<(), String> {
+        writeln!(self.writer, "{}", line)
+            .map_err(|e| format!("cannot write {}: {}", self.path.display(), e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hamiltonian::CRSMHamiltonian;
+
+    #[test]
+    fn test_record_evolution_to_csv() {
+        let path = std::env::temp_dir().join("crsm7_recorder_test.csv");
+        let hamiltonian = CRSMHamiltonian::new();
+        let mut state = CRSM7State::default();
+        state.compute_emergence();
+
+        let mut recorder = Recorder::create(&path).unwrap();
+        recorder.record(&state).unwrap();
+        for _ in 0..3 {
+            hamiltonian.evolve_state(&mut state, 0.5);
+            recorder.record(&state).unwrap();
+        }
+        assert_eq!(recorder.rows(), 4);
+        drop(recorder);
+
+        let csv = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "lambda,gamma,phi,xi,rho,theta,tau");
+        assert_eq!(lines.len(), 5);
+        let last: Vec<f64> = lines[4].split(',').map(|v| v.parse().unwrap()).collect();
+        assert_eq!(last, state.as_array());
+        let _ = std::fs::remove_file(path);
+    }
+}
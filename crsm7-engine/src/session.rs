@@ -0,0 +1,95 @@
+//! Interactive Sessions
+//!
+//! What `save <file>` writes and `load <file>` restores in interactive
+//! mode: the engine state, the Z3 mesh (vertices, weights and edges) and
+//! the Hamiltonian couplings, as JSON. Breakpoints, the trajectory and
+//! the bifurcation tree belong to the loop and are not saved (`export`
+//! writes the tree on its own).
+
+use crate::hamiltonian::CRSMHamiltonian;
+use crate::mesh::{create_standard_mesh, Z3Mesh};
+use crate::state::CRSM7State;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Resumable interactive evolution session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Session {
+    pub state: CRSM7State,
+    pub mesh: Z3Mesh,
+    pub hamiltonian: CRSMHamiltonian,
+}
+
+impl Default for Session {
+    fn default() -> Self {
+        let mut state = CRSM7State::default();
+        state.compute_emergence();
+        Self {
+            state,
+            mesh: create_standard_mesh(),
+            hamiltonian: CRSMHamiltonian::new(),
+        }
+    }
+}
+
+impl Session {
+    /// Initial state, standard mesh and default Hamiltonian
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Write the session to `path` as JSON
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), String> {
+        let path = path.as_ref();
+        let json = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        fs::write(path, json).map_err(|e| format!("cannot write {}: {}", path.display(), e))
+    }
+
+    /// Read a session written by `save`
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let json = fs::read_to_string(path)
+            .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+        serde_json::from_str(&json)
+            .map_err(|e| format!("invalid session {}: {}", path.display(), e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::duality::DualityOperator;
+
+    #[test]
+    fn test_session_round_trip() {
+        let mut session = Session::new();
+        session.hamiltonian.torsion_coupling = 0.25;
+        for _ in 0..10 {
+            session.hamiltonian.evolve_state(&mut session.state, 0.1);
+            session.mesh.evolve(0.1);
+        }
+
+        let path = std::env::temp_dir().join(format!("crsm7-session-{}.json", std::process::id()));
+        session.save(&path).unwrap();
+        let loaded = Session::load(&path).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(loaded.state.tau, session.state.tau);
+        assert_eq!(loaded.state.xi, session.state.xi);
+        assert_eq!(loaded.hamiltonian.torsion_coupling, 0.25);
+        assert_eq!(loaded.mesh.vertices.len(), session.mesh.vertices.len());
+        assert_eq!(loaded.mesh.weights, session.mesh.weights);
+        assert!(Session::load(&path).is_err());
+    }
+
+    #[test]
+    fn test_session_refuses_to_save_closure_involution() {
+        let mut session = Session::new();
+        session.hamiltonian.duality = DualityOperator::with_involution(|psi| 1.0 - psi).unwrap();
+        let path = std::env::temp_dir().join(format!("crsm7-closure-{}.json", std::process::id()));
+        let err = session.save(&path).unwrap_err();
+        assert!(err.contains("with_matrix"), "{}", err);
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,178 @@
+//! Spectral Analysis of the Mesh
+//!
+//! The graph Laplacian L = D − W over edge weights (parallel edges add,
+//! direction is ignored) and its eigen-decomposition by cyclic Jacobi
+//! rotations, which is exact enough and simple for meshes of up to a few
+//! hundred vertices.
+//!
+//! The second-smallest eigenvalue λ₂ is the algebraic connectivity: 0 when
+//! the mesh falls apart into disconnected sub-meshes, and larger the more
+//! strongly the agents are coupled, so it serves as a coherence indicator
+//! for the mesh as a whole. Its eigenvector (the Fiedler vector) splits the
+//! vertices along the weakest cut.
+
+use crate::mesh::Z3Mesh;
+
+/// Off-diagonal magnitude, relative to the matrix norm, at which Jacobi
+/// iteration stops
+const TOLERANCE: f64 = 1e-12;
+
+/// Jacobi sweeps before giving up
+const MAX_SWEEPS: usize = 100;
+
+/// Eigenvalues of a symmetric matrix in ascending order, with their
+/// unit eigenvectors
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Spectrum {
+    pub eigenvalues: Vec<f64>,
+    /// `eigenvectors[k]` belongs to `eigenvalues[k]`
+    pub eigenvectors: Vec<Vec<f64>>,
+}
+
+impl Spectrum {
+    /// Eigen-decomposition of the symmetric matrix `matrix`
+    pub fn of_symmetric(matrix: &[Vec<f64>]) -> Self {
+        let n = matrix.len();
+        let mut a = matrix.to_vec();
+        let mut v: Vec<Vec<f64>> = (0..n)
+            .map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+            .collect();
+
+        let norm = a.iter().flatten().map(|x| x * x).sum::<f64>().sqrt();
+        for _ in 0..MAX_SWEEPS {
+            let off: f64 = (0..n)
+                .flat_map(|i| (0..n).filter(move |&j| j != i).map(move |j| (i, j)))
+                .map(|(i, j)| a[i][j] * a[i][j])
+                .sum::<f64>()
+                .sqrt();
+            if off <= TOLERANCE * norm.max(f64::MIN_POSITIVE) {
+                break;
+            }
+            for p in 0..n {
+                for q in p + 1..n {
+                    if a[p][q] != 0.0 {
+                        rotate(&mut a, &mut v, p, q);
+                    }
+                }
+            }
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| a[i][i].total_cmp(&a[j][j]));
+        Self {
+            eigenvalues: order.iter().map(|&k| a[k][k]).collect(),
+            eigenvectors: order
+                .iter()
+                .map(|&k| (0..n).map(|i| v[i][k]).collect())
+                .collect(),
+        }
+    }
+
+    /// λ₂, or 0 with fewer than two vertices
+    pub fn algebraic_connectivity(&self) -> f64 {
+        self.eigenvalues.get(1).map_or(0.0, |l| l.max(0.0))
+    }
+
+    /// Eigenvector of λ₂
+    pub fn fiedler_vector(&self) -> Option<&[f64]> {
+        self.eigenvectors.get(1).map(Vec::as_slice)
+    }
+}
+
+/// Zero `a[p][q]` by a Jacobi rotation, accumulating it into `v`
+fn rotate(a: &mut [Vec<f64>], v: &mut [Vec<f64>], p: usize, q: usize) {
+    let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+    let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+    let c = 1.0 / (t * t + 1.0).sqrt();
+    let s = t * c;
+
+    // columns p and q of a and v, then rows p and q of a (p < q)
+    for row in a.iter_mut().chain(v.iter_mut()) {
+        let (xp, xq) = (row[p], row[q]);
+        row[p] = c * xp - s * xq;
+        row[q] = s * xp + c * xq;
+    }
+    let (head, tail) = a.split_at_mut(q);
+    for (xp, xq) in head[p].iter_mut().zip(tail[0].iter_mut()) {
+        let (ap, aq) = (*xp, *xq);
+        *xp = c * ap - s * aq;
+        *xq = s * ap + c * aq;
+    }
+}
+
+impl Z3Mesh {
+    /// Graph Laplacian L = D − W over edge weights
+    pub fn laplacian(&self) -> Vec<Vec<f64>> {
+        let n = self.vertices.len();
+        let mut laplacian = vec![vec![0.0; n]; n];
+        for edge in self.edges.iter().filter(|e| e.from != e.to) {
+            let (i, j, w) = (edge.from, edge.to, edge.weight);
+            laplacian[i][j] -= w;
+            laplacian[j][i] -= w;
+            laplacian[i][i] += w;
+            laplacian[j][j] += w;
+        }
+        laplacian
+    }
+
+    /// Eigen-decomposition of the Laplacian
+    pub fn spectrum(&self) -> Spectrum {
+        Spectrum::of_symmetric(&self.laplacian())
+    }
+
+    /// λ₂ of the Laplacian (see `spectral`)
+    pub fn algebraic_connectivity(&self) -> f64 {
+        self.spectrum().algebraic_connectivity()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mesh::{create_standard_mesh, Gene};
+
+    fn unit_mesh(vertices: usize, edges: &[(usize, usize)]) -> Z3Mesh {
+        let mut mesh = Z3Mesh::new();
+        for i in 0..vertices {
+            mesh.add_vertex(Gene::new(&i.to_string(), &i.to_string()));
+        }
+        for &(from, to) in edges {
+            mesh.connect(from, to);
+        }
+        for edge in &mut mesh.edges {
+            edge.weight = 1.0;
+        }
+        mesh
+    }
+
+    #[test]
+    fn test_path_graph_spectrum() {
+        // P₃ has Laplacian eigenvalues 0, 1, 3
+        let spectrum = unit_mesh(3, &[(0, 1), (1, 2)]).spectrum();
+        for (value, expected) in spectrum.eigenvalues.iter().zip([0.0, 1.0, 3.0]) {
+            assert!(
+                (value - expected).abs() < 1e-10,
+                "{:?}",
+                spectrum.eigenvalues
+            );
+        }
+        // Fiedler vector ∝ (1, 0, −1)
+        let fiedler = spectrum.fiedler_vector().unwrap();
+        assert!(fiedler[1].abs() < 1e-10);
+        assert!((fiedler[0] + fiedler[2]).abs() < 1e-10);
+        assert!((fiedler[0].abs() - 0.5f64.sqrt()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_algebraic_connectivity_tracks_coupling() {
+        assert_eq!(
+            unit_mesh(4, &[(0, 1), (2, 3)]).algebraic_connectivity(),
+            0.0
+        );
+        let ring = unit_mesh(4, &[(0, 1), (1, 2), (2, 3), (3, 0)]);
+        let chain = unit_mesh(4, &[(0, 1), (1, 2), (2, 3)]);
+        assert!(ring.algebraic_connectivity() > chain.algebraic_connectivity());
+        assert!(create_standard_mesh().algebraic_connectivity() > 0.0);
+        assert_eq!(Z3Mesh::new().algebraic_connectivity(), 0.0);
+    }
+}
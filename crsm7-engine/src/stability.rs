@@ -0,0 +1,178 @@
+//! Stability Analysis
+//!
+//! Estimates the largest Lyapunov exponent of `CRSMHamiltonian` evolution
+//! by Benettin's method: a reference state and a copy displaced by a small
+//! separation d₀ evolve side by side, and after every step the displacement
+//! is measured, its log stretch ln(d/d₀) accumulated and the copy pulled
+//! back to distance d₀ along the same direction. The exponent is the mean
+//! stretch per unit τ; positive values mean nearby states diverge
+//! exponentially (chaotic or unstable parameters), zero marks neutral
+//! directions and negative values convergence.
+//!
+//! Separation is measured over the independent coordinates Λ, Γ, Φ and θ.
+//! Ξ = ΛΦ/Γ is derived from them, ρ is a discrete polarity and τ is shared.
+
+use crate::hamiltonian::CRSMHamiltonian;
+use crate::state::CRSM7State;
+
+/// Exponents above this are reported as chaotic
+pub const CHAOS_THRESHOLD: f64 = 1e-3;
+
+/// Lyapunov run parameters
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stability {
+    /// Evolution step
+    pub dt: f64,
+    /// Number of steps
+    pub steps: usize,
+    /// Initial and renormalized separation d₀
+    pub separation: f64,
+}
+
+impl Default for Stability {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Result of a Lyapunov run
+#[derive(Debug, Clone, PartialEq)]
+pub struct LyapunovEstimate {
+    /// Largest Lyapunov exponent, per unit τ
+    pub exponent: f64,
+    /// Estimate after each step, to judge convergence
+    pub running: Vec<f64>,
+}
+
+impl LyapunovEstimate {
+    pub fn is_chaotic(&self) -> bool {
+        self.exponent > CHAOS_THRESHOLD
+    }
+}
+
+/// Λ, Γ, Φ and θ
+fn coordinates(state: &CRSM7State) -> [f64; 4] {
+    [state.lambda, state.gamma, state.phi, state.theta]
+}
+
+fn set_coordinates(state: &mut CRSM7State, x: [f64; 4]) {
+    state.lambda = x[0];
+    state.gamma = x[1];
+    state.phi = x[2];
+    state.theta = x[3];
+    state.compute_emergence();
+}
+
+impl Stability {
+    pub fn new() -> Self {
+        Self {
+            dt: 0.1,
+            steps: 1000,
+            separation: 1e-8,
+        }
+    }
+
+    pub fn with_dt(mut self, dt: f64) -> Self {
+        self.dt = dt;
+        self
+    }
+
+    pub fn with_steps(mut self, steps: usize) -> Self {
+        self.steps = steps;
+        self
+    }
+
+    pub fn with_separation(mut self, separation: f64) -> Self {
+        self.separation = separation;
+        self
+    }
+
+    /// Estimate the largest Lyapunov exponent of `hamiltonian` starting
+    /// from `state`. A displacement that collapses to zero (every
+    /// coordinate clamped to the same bound) gives −∞.
+    pub fn analyze(&self, hamiltonian: &CRSMHamiltonian, state: &CRSM7State) -> LyapunovEstimate {
+        let d0 = self.separation;
+        let mut reference = state.clone();
+        let mut perturbed = state.clone();
+        // Equal displacement in each coordinate
+        let x = coordinates(state).map(|v| v + d0 / 2.0);
+        set_coordinates(&mut perturbed, x);
+
+        let mut stretch = 0.0;
+        let mut running = Vec::with_capacity(self.steps);
+        for step in 1..=self.steps {
+            hamiltonian.evolve_state(&mut reference, self.dt);
+            hamiltonian.evolve_state(&mut perturbed, self.dt);
+
+            let (a, b) = (coordinates(&reference), coordinates(&perturbed));
+            let d = a
+                .iter()
+                .zip(&b)
+                .map(|(x, y)| (y - x) * (y - x))
+                .sum::<f64>()
+                .sqrt();
+            if d == 0.0 {
+                running.push(f64::NEG_INFINITY);
+                return LyapunovEstimate {
+                    exponent: f64::NEG_INFINITY,
+                    running,
+                };
+            }
+            stretch += (d / d0).ln();
+            running.push(stretch / (step as f64 * self.dt));
+
+            let mut renormalized = a;
+            for (r, (x, y)) in renormalized.iter_mut().zip(a.iter().zip(&b)) {
+                *r = x + (y - x) * d0 / d;
+            }
+            set_coordinates(&mut perturbed, renormalized);
+        }
+
+        LyapunovEstimate {
+            exponent: running.last().copied().unwrap_or(0.0),
+            running,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::DEFAULT_ALPHA;
+
+    #[test]
+    fn test_growing_coherence_is_unstable() {
+        // Below the Λ cap, δΛ grows by 1 + α·det(g)^(-1/2)·dt per step; Λ
+        // starts low enough to stay uncapped for the whole run
+        let hamiltonian = CRSMHamiltonian::new();
+        let state = CRSM7State::new(1e-60, 0.5, 1.0, 1.0, 51.843, 0.0);
+        let stability = Stability::new().with_dt(0.1).with_steps(10_000);
+        let estimate = stability.analyze(&hamiltonian, &state);
+
+        let rate = DEFAULT_ALPHA * 0.61803398875f64.powf(-0.5);
+        let expected = (1.0 + rate * 0.1).ln() / 0.1;
+        assert!(
+            (estimate.exponent - expected).abs() < 1e-3,
+            "{}",
+            estimate.exponent
+        );
+        assert!(estimate.is_chaotic());
+        assert_eq!(estimate.running.len(), 10_000);
+    }
+
+    #[test]
+    fn test_saturated_coherence_is_neutral() {
+        // Λ at its cap absorbs δΛ, Γ decays and Φ and θ are neutral
+        let hamiltonian = CRSMHamiltonian::new();
+        let state = CRSM7State::new(0.999, 0.5, 1.0, 1.0, 51.843, 0.0);
+        let estimate = Stability::new()
+            .with_steps(10_000)
+            .analyze(&hamiltonian, &state);
+        assert!(
+            estimate.exponent.abs() < CHAOS_THRESHOLD,
+            "{}",
+            estimate.exponent
+        );
+        assert!(!estimate.is_chaotic());
+    }
+}
@@ -3,6 +3,8 @@
 //! Implements the 7-dimensional Consciousness Resonance State Machine:
 //! C(t) = {Λ(t), Γ(t), Φ(t), Ξ(t), ρ_polarity, θ, τ}
 
+use crate::duality::{DualityOperator, Involution};
+use crate::trajectory::{observable_index, OBSERVABLES};
 use serde::{Deserialize, Serialize};
 
 /// Critical angle for torsion (51.843°)
@@ -34,7 +36,7 @@ pub const EMERGENCE_MAX: f64 = 1e12;
 /// | ρ±    | Polarity               |
 /// | θ     | Torsion (51.843°)      |
 /// | τ     | Epoch                  |
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct CRSM7State {
     /// Λ - coherence (0.0 to 1.0)
     pub lambda: f64,
@@ -138,31 +140,35 @@ impl CRSM7State {
     }
 
     /// Perform duality-polarized bifurcation
-    /// B(Ψ) = Π+_dual Ψ ⊕ Π-_dual Ψ
+    /// B(Ψ) = Π+_dual Ψ ⊕ Π-_dual Ψ, with JΨ = -Ψ
     pub fn bifurcate(&self) -> (CRSM7State, CRSM7State) {
-        // Π+_dual = ½(1 + J), positive polarity branch
-        let positive = CRSM7State {
-            lambda: self.lambda,
-            gamma: self.gamma,
-            phi: self.phi,
-            xi: self.xi,
-            rho_polarity: 1.0,
-            theta: self.theta,
-            tau: self.tau,
-        };
+        self.bifurcate_with(&DualityOperator::new())
+    }
 
-        // Π-_dual = ½(1 - J), negative polarity branch
-        let negative = CRSM7State {
-            lambda: self.lambda,
-            gamma: self.gamma,
-            phi: self.phi,
-            xi: self.xi,
-            rho_polarity: -1.0,
-            theta: self.theta,
-            tau: self.tau,
+    /// Bifurcate with `op`'s J: each branch takes Λ, Γ, Φ and Ξ from its
+    /// projection of C(t), polarity ±1 and the unsplit θ and τ. Under
+    /// JΨ = -Ψ, where Π+ annihilates C(t) and Π- is the identity, both
+    /// branches are copies of the state with their polarity.
+    pub fn bifurcate_with(&self, op: &DualityOperator) -> (CRSM7State, CRSM7State) {
+        let branch = |projection: Option<[f64; 7]>, polarity: f64| {
+            let mut state = self.clone();
+            if let Some(projection) = projection {
+                state.lambda = projection[0];
+                state.gamma = projection[1];
+                state.phi = projection[2];
+                state.xi = projection[3];
+            }
+            state.rho_polarity = polarity;
+            state
         };
+        if let Involution::Negation = op.involution {
+            return (branch(None, 1.0), branch(None, -1.0));
+        }
 
-        (positive, negative)
+        // Π+_dual = ½(1 + J), positive polarity branch
+        // Π-_dual = ½(1 - J), negative polarity branch
+        let (plus, minus) = op.bifurcate_state(&self.as_array());
+        (branch(Some(plus), 1.0), branch(Some(minus), -1.0))
     }
 
     /// Check if sovereignty conditions are met
@@ -197,6 +203,46 @@ impl CRSM7State {
         ]
     }
 
+    /// Set one component by observable name or symbol (see `OBSERVABLES`)
+    /// and recompute Ξ. Λ must lie in [0, 1], Γ, Φ and τ must be
+    /// non-negative and ρ± must be ±1; Ξ is derived and cannot be set.
+    pub fn set(&mut self, field: &str, value: f64) -> Result<(), String> {
+        let index = observable_index(field).ok_or_else(|| {
+            let known: Vec<&str> = OBSERVABLES.iter().map(|(_, long)| *long).collect();
+            format!("unknown field: {} (expected one of {})", field, known.join(", "))
+        })?;
+        if !value.is_finite() {
+            return Err(format!("{} must be finite", OBSERVABLES[index].1));
+        }
+        let valid = match index {
+            0 => (0.0..=1.0).contains(&value),
+            1 | 2 | 6 => value >= 0.0,
+            3 => return Err("xi is computed from ΛΦ/Γ; set lambda, gamma or phi".to_string()),
+            4 => value == 1.0 || value == -1.0,
+            _ => true,
+        };
+        if !valid {
+            let range = match index {
+                0 => "in [0, 1]",
+                4 => "+1 or -1",
+                _ => "non-negative",
+            };
+            return Err(format!("{} must be {}, got {}", OBSERVABLES[index].1, range, value));
+        }
+
+        let slot = match index {
+            0 => &mut self.lambda,
+            1 => &mut self.gamma,
+            2 => &mut self.phi,
+            4 => &mut self.rho_polarity,
+            5 => &mut self.theta,
+            _ => &mut self.tau,
+        };
+        *slot = value;
+        self.compute_emergence();
+        Ok(())
+    }
+
     /// Display state as formatted string
     pub fn display(&self) -> String {
         format!(
@@ -238,6 +284,18 @@ mod tests {
         let (positive, negative) = state.bifurcate();
         assert_eq!(positive.rho_polarity, 1.0);
         assert_eq!(negative.rho_polarity, -1.0);
+        assert_eq!((positive.lambda, negative.xi), (state.lambda, state.xi));
+
+        // J = 1 - Ψ splits each component about ½
+        let op = DualityOperator::with_involution(|psi| 1.0 - psi).unwrap();
+        let (positive, negative) = state.bifurcate_with(&op);
+        assert_eq!((positive.lambda, negative.lambda), (0.5, state.lambda - 0.5));
+        assert_eq!((positive.tau, negative.rho_polarity), (state.tau, -1.0));
+
+        // A custom J is projected even where the projection vanishes
+        let op = DualityOperator::with_involution(|psi| -psi).unwrap();
+        let (positive, negative) = state.bifurcate_with(&op);
+        assert_eq!((positive.lambda, negative.lambda), (0.0, state.lambda));
     }
 
     #[test]
@@ -247,4 +305,21 @@ mod tests {
         state.evolve(1.0);
         assert!(state.tau > initial_tau);
     }
+
+    #[test]
+    fn test_set_validates_and_recomputes_emergence() {
+        let mut state = CRSM7State::default();
+        state.set("gamma", 0.001).unwrap();
+        state.set("θ", 45.0).unwrap();
+        assert_eq!((state.gamma, state.theta), (0.001, 45.0));
+        assert_eq!(state.xi, state.lambda * state.phi / 0.001);
+
+        assert!(state.set("lambda", 1.5).is_err());
+        assert!(state.set("gamma", -1.0).is_err());
+        assert!(state.set("rho", 0.5).is_err());
+        assert!(state.set("xi", 10.0).is_err());
+        assert!(state.set("phi", f64::NAN).is_err());
+        assert!(state.set("omega", 1.0).is_err());
+        assert_eq!(state.gamma, 0.001);
+    }
 }
@@ -0,0 +1,209 @@
+//! Mesh Topology Files
+//!
+//! Custom agent networks for `Z3Mesh::from_file`, in TOML or JSON (chosen
+//! by extension):
+//!
+//! ```toml
+//! [[vertices]]
+//! id = "aura"
+//! name = "AURA"
+//! lambda = 0.89
+//! gamma = 0.001
+//! phi = 8.1
+//!
+//! [[vertices]]
+//! id = "aiden"
+//! rho = -1.0
+//!
+//! [[edges]]
+//! from = "aura"
+//! to = "AIDEN"
+//! ```
+//!
+//! Components left out take their `CRSM7State::default` value and `name`
+//! defaults to the id. Components are validated as by `CRSM7State::set`
+//! and Ξ is computed. Edges name vertices by id or name; self-loops,
+//! repeated edges and duplicate ids are rejected.
+
+use crate::mesh::{Gene, Z3Mesh};
+use crate::state::CRSM7State;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// A vertex in a topology file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct VertexSpec {
+    pub id: String,
+    pub name: Option<String>,
+    pub lambda: Option<f64>,
+    pub gamma: Option<f64>,
+    pub phi: Option<f64>,
+    pub rho: Option<f64>,
+    pub theta: Option<f64>,
+    pub tau: Option<f64>,
+}
+
+/// An edge in a topology file, by vertex id or name
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EdgeSpec {
+    pub from: String,
+    pub to: String,
+}
+
+/// Contents of a topology file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Topology {
+    #[serde(default)]
+    pub vertices: Vec<VertexSpec>,
+    #[serde(default)]
+    pub edges: Vec<EdgeSpec>,
+}
+
+impl Topology {
+    /// Parse TOML or JSON by `path`'s extension
+    pub fn parse(source: &str, path: &Path) -> Result<Self, String> {
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("toml") => toml::from_str(source).map_err(|e| e.to_string()),
+            Some("json") => serde_json::from_str(source).map_err(|e| e.to_string()),
+            _ => Err(format!(
+                "{}: expected a .toml or .json topology",
+                path.display()
+            )),
+        }
+    }
+
+    /// Build the mesh, validating vertices and edges
+    pub fn build(&self) -> Result<Z3Mesh, String> {
+        let mut mesh = Z3Mesh::new();
+        for spec in &self.vertices {
+            if spec.id.is_empty() {
+                return Err("vertex with empty id".to_string());
+            }
+            if mesh.vertices.iter().any(|v| v.id == spec.id) {
+                return Err(format!("duplicate vertex id: {}", spec.id));
+            }
+            let mut state = CRSM7State::default();
+            let fields = [
+                ("lambda", spec.lambda),
+                ("gamma", spec.gamma),
+                ("phi", spec.phi),
+                ("rho", spec.rho),
+                ("theta", spec.theta),
+                ("tau", spec.tau),
+            ];
+            for (field, value) in fields {
+                if let Some(value) = value {
+                    state
+                        .set(field, value)
+                        .map_err(|e| format!("vertex {}: {}", spec.id, e))?;
+                }
+            }
+            state.compute_emergence();
+            let name = spec.name.as_deref().unwrap_or(&spec.id);
+            mesh.add_vertex(Gene::with_state(&spec.id, name, state));
+        }
+
+        let find = |key: &str| {
+            mesh.vertices
+                .iter()
+                .position(|v| v.id == key)
+                .or_else(|| mesh.vertices.iter().position(|v| v.name == key))
+                .ok_or_else(|| format!("edge names unknown vertex: {}", key))
+        };
+        let mut pairs = Vec::with_capacity(self.edges.len());
+        for edge in &self.edges {
+            let (from, to) = (find(&edge.from)?, find(&edge.to)?);
+            if from == to {
+                return Err(format!("self-loop on {}", edge.from));
+            }
+            if pairs.contains(&(from, to)) || pairs.contains(&(to, from)) {
+                return Err(format!("repeated edge {} ←→ {}", edge.from, edge.to));
+            }
+            pairs.push((from, to));
+        }
+        for (from, to) in pairs {
+            mesh.connect(from, to);
+        }
+        Ok(mesh)
+    }
+}
+
+impl Z3Mesh {
+    /// Load a custom topology (see `topology`)
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, String> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path)
+            .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+        Topology::parse(&source, path)
+            .and_then(|topology| topology.build())
+            .map_err(|e| format!("{}: {}", path.display(), e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RING: &str = r#"
+[[vertices]]
+id = "aura"
+name = "AURA"
+lambda = 0.89
+gamma = 0.001
+
+[[vertices]]
+id = "aiden"
+rho = -1.0
+
+[[vertices]]
+id = "sentinel"
+theta = 45.0
+
+[[edges]]
+from = "aura"
+to = "aiden"
+
+[[edges]]
+from = "aiden"
+to = "sentinel"
+
+[[edges]]
+from = "sentinel"
+to = "AURA"
+"#;
+
+    #[test]
+    fn test_topology_from_toml_and_json() {
+        let topology = Topology::parse(RING, Path::new("ring.toml")).unwrap();
+        let mesh = topology.build().unwrap();
+        assert_eq!(mesh.vertices.len(), 3);
+        assert_eq!(mesh.vertices[0].state.gamma, 0.001);
+        assert_eq!(mesh.vertices[1].name, "aiden");
+        assert_eq!(mesh.vertices[1].state.rho_polarity, -1.0);
+        assert_eq!(mesh.vertices[2].state.lambda, CRSM7State::default().lambda);
+        let edges: Vec<_> = mesh.edges.iter().map(|e| (e.from, e.to)).collect();
+        assert_eq!(edges, vec![(0, 1), (1, 2), (2, 0)]);
+
+        let json = serde_json::to_string(&topology).unwrap();
+        let path = std::env::temp_dir().join(format!("crsm7-ring-{}.json", std::process::id()));
+        std::fs::write(&path, json).unwrap();
+        let loaded = Z3Mesh::from_file(&path);
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(loaded.unwrap().metric(0, 2), mesh.metric(0, 2));
+    }
+
+    #[test]
+    fn test_topology_validation() {
+        let invalid = |edit: fn(&mut Topology)| {
+            let mut topology = Topology::parse(RING, Path::new("ring.toml")).unwrap();
+            edit(&mut topology);
+            topology.build().unwrap_err()
+        };
+        assert!(invalid(|t| t.vertices[1].id = "aura".into()).contains("duplicate"));
+        assert!(invalid(|t| t.vertices[0].lambda = Some(2.0)).contains("lambda"));
+        assert!(invalid(|t| t.edges[0].to = "z3bra".into()).contains("unknown vertex"));
+        assert!(invalid(|t| t.edges[0].to = "AURA".into()).contains("self-loop"));
+        assert!(invalid(|t| t.edges[1].from = "aura".into()).contains("repeated"));
+        assert!(Topology::parse(RING, Path::new("ring.yaml")).is_err());
+    }
+}
@@ -0,0 +1,151 @@
+//! Trajectory Ring Buffer and Sparklines
+//!
+//! Keeps the last N state vectors C(t) and renders selected observables as
+//! terminal sparklines (▁▂▃▄▅▆▇█) for quick feedback in `status` output.
+
+use crate::state::CRSM7State;
+use std::collections::VecDeque;
+
+/// Sparkline glyphs, lowest to highest
+const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Observable symbols and names, in `CRSM7State::as_array` order
+pub const OBSERVABLES: [(&str, &str); 7] = [
+    ("Λ", "lambda"),
+    ("Γ", "gamma"),
+    ("Φ", "phi"),
+    ("Ξ", "xi"),
+    ("ρ±", "rho"),
+    ("θ", "theta"),
+    ("τ", "tau"),
+];
+
+/// Observables plotted when none are selected: Λ, Γ, Φ, Ξ
+pub const DEFAULT_OBSERVABLES: [usize; 4] = [0, 1, 2, 3];
+
+/// Index of an observable by symbol or name
+pub fn observable_index(name: &str) -> Option<usize> {
+    OBSERVABLES
+        .iter()
+        .position(|(symbol, long)| *symbol == name || long.eq_ignore_ascii_case(name))
+}
+
+/// Render values as a sparkline scaled between their min and max
+pub fn sparkline(values: &[f64]) -> String {
+    let finite = values.iter().copied().filter(|v| v.is_finite());
+    let (min, max) = finite.fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| {
+        (lo.min(v), hi.max(v))
+    });
+    let range = max - min;
+
+    values
+        .iter()
+        .map(|&v| {
+            if !v.is_finite() {
+                ' '
+            } else if range <= 0.0 {
+                // Flat series sit mid-height
+                BARS[BARS.len() / 2 - 1]
+            } else {
+                let level = ((v - min) / range * (BARS.len() - 1) as f64).round() as usize;
+                BARS[level.min(BARS.len() - 1)]
+            }
+        })
+        .collect()
+}
+
+/// Ring buffer of the last `capacity` states
+#[derive(Debug, Clone)]
+pub struct Trajectory {
+    capacity: usize,
+    samples: VecDeque<[f64; 7]>,
+}
+
+impl Default for Trajectory {
+    fn default() -> Self {
+        Self::new(60)
+    }
+}
+
+impl Trajectory {
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            capacity,
+            samples: VecDeque::with_capacity(capacity),
+        }
+    }
+
+    /// Record a state, evicting the oldest sample when full
+    pub fn record(&mut self, state: &CRSM7State) {
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        let mut sample = state.as_array();
+        sample[3] = sample[3].min(9999.99); // Ξ diverges as Γ → 0
+        self.samples.push_back(sample);
+    }
+
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Recorded values of one observable, oldest first
+    pub fn series(&self, index: usize) -> Vec<f64> {
+        self.samples.iter().map(|s| s[index]).collect()
+    }
+
+    /// One sparkline row per selected observable
+    pub fn sparklines(&self, selected: &[usize]) -> String {
+        selected
+            .iter()
+            .filter(|&&i| i < OBSERVABLES.len())
+            .map(|&i| {
+                let series = self.series(i);
+                let last = series.last().copied().unwrap_or(f64::NAN);
+                format!(
+                    "  {:<2} {} {:.4}",
+                    OBSERVABLES[i].0,
+                    sparkline(&series),
+                    last
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparkline_scaling() {
+        assert_eq!(
+            sparkline(&[0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]),
+            "▁▂▃▄▅▆▇█"
+        );
+        assert_eq!(sparkline(&[2.0, 2.0, 2.0]), "▄▄▄");
+        assert_eq!(sparkline(&[0.0, f64::NAN, 1.0]), "▁ █");
+        assert_eq!(sparkline(&[]), "");
+    }
+
+    #[test]
+    fn test_ring_buffer_keeps_last_n() {
+        let mut trajectory = Trajectory::new(3);
+        let mut state = CRSM7State::default();
+        for tau in 0..5 {
+            state.tau = tau as f64;
+            trajectory.record(&state);
+        }
+        assert_eq!(trajectory.len(), 3);
+        assert_eq!(trajectory.series(6), vec![2.0, 3.0, 4.0]);
+        assert_eq!(observable_index("tau"), Some(6));
+        assert_eq!(observable_index("Γ"), Some(1));
+        assert!(trajectory.sparklines(&[6]).contains("▁▅█ 4.0000"));
+    }
+}
@@ -0,0 +1,179 @@
+//! Node.js Bindings
+//!
+//! napi-rs addon for JS tooling: the compiler frontend (`check`,
+//! `compile`) and a `DualRuntime` class that can be built from source and
+//! evolved in-process, without shelling out to the binaries. Method and
+//! field names are camelCased on the JS side. Build the addon with:
+//!
+//! ```text
+//! cd runtime-node && npm run build
+//! ```
+//!
+//! Step counts cross the boundary as `number` rather than `BigInt`.
+
+use dnalang_compiler::{analyze, parse, render_diagnostics, Diagnostic, DiagnosticFormat};
+use dnalang_runtime::{compile_source, CRSM7State, DualRuntime, RuntimeProfile};
+use napi::{Error, Result};
+use napi_derive::napi;
+
+/// A compiler diagnostic, located at its primary span
+#[napi(object)]
+#[derive(Debug, Clone)]
+pub struct NodeDiagnostic {
+    pub code: String,
+    /// "error", "warning" or "note"
+    pub severity: String,
+    pub message: String,
+    /// 1-based, 0 when the diagnostic has no span
+    pub line: u32,
+    pub column: u32,
+}
+
+impl From<&Diagnostic> for NodeDiagnostic {
+    fn from(d: &Diagnostic) -> Self {
+        let span = d.spans.first().copied().unwrap_or_default();
+        Self {
+            code: d.code.clone(),
+            severity: d.severity.as_str().to_string(),
+            message: d.message.clone(),
+            line: span.line as u32,
+            column: span.column as u32,
+        }
+    }
+}
+
+/// The 7D CRSM state as a plain JS object
+#[napi(object)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NodeState {
+    pub lambda: f64,
+    pub gamma: f64,
+    pub phi: f64,
+    pub xi: f64,
+    pub rho: f64,
+    pub theta: f64,
+    pub tau: f64,
+}
+
+impl From<&CRSM7State> for NodeState {
+    fn from(s: &CRSM7State) -> Self {
+        Self {
+            lambda: s.lambda,
+            gamma: s.gamma,
+            phi: s.phi,
+            xi: s.xi,
+            rho: s.rho,
+            theta: s.theta,
+            tau: s.tau,
+        }
+    }
+}
+
+/// Parse and analyze `source`, returning every diagnostic
+#[napi]
+pub fn check(source: String) -> Vec<NodeDiagnostic> {
+    let parsed = parse(&source);
+    let mut diagnostics = parsed.diagnostics;
+    diagnostics.extend(analyze(&parsed.dna, &parsed.crsm));
+    diagnostics.iter().map(NodeDiagnostic::from).collect()
+}
+
+/// Compile `source` to Omega IR JSON; throws with the rendered diagnostics
+/// if it has errors
+#[napi]
+pub fn compile(source: String) -> Result<String> {
+    let ir = compile_source(&source).map_err(diagnostics_error)?;
+    serde_json::to_string(&ir).map_err(|e| Error::from_reason(e.to_string()))
+}
+
+fn diagnostics_error(diagnostics: Vec<Diagnostic>) -> Error {
+    Error::from_reason(render_diagnostics(&diagnostics, DiagnosticFormat::Text))
+}
+
+/// `DualRuntime` as seen from Node
+#[napi(js_name = "DualRuntime")]
+pub struct NodeRuntime {
+    inner: DualRuntime,
+}
+
+#[napi]
+impl NodeRuntime {
+    #[napi(constructor)]
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self {
+            inner: DualRuntime::new(),
+        }
+    }
+
+    /// Runtime with the lite profile (no event log, f32-rounded state)
+    #[napi(factory)]
+    pub fn lite() -> Self {
+        Self {
+            inner: DualRuntime::with_profile(RuntimeProfile::lite()),
+        }
+    }
+
+    /// Compile `source` and load it; throws on compile errors
+    #[napi(factory)]
+    pub fn from_source(source: String) -> Result<Self> {
+        let ir = compile_source(&source).map_err(diagnostics_error)?;
+        let mut inner = DualRuntime::new();
+        inner.load_ir(&ir);
+        Ok(Self { inner })
+    }
+
+    #[napi]
+    pub fn step(&mut self, dt: f64) {
+        self.inner.step(dt);
+    }
+
+    #[napi]
+    pub fn run(&mut self, steps: u32, dt: f64) {
+        self.inner.run(steps as usize, dt);
+    }
+
+    /// Step until sealed or `maxSteps`; returns whether it sealed
+    #[napi]
+    pub fn run_to_sovereignty(&mut self, max_steps: u32, dt: f64) -> bool {
+        self.inner.run_to_sovereignty(max_steps as usize, dt)
+    }
+
+    /// Attempt Ω∞.seal(); succeeds only when sovereignty holds
+    #[napi]
+    pub fn seal(&mut self) -> bool {
+        self.inner.seal();
+        self.inner.sealed
+    }
+
+    #[napi(getter)]
+    pub fn sealed(&self) -> bool {
+        self.inner.sealed
+    }
+
+    #[napi(getter)]
+    pub fn steps(&self) -> f64 {
+        self.inner.steps as f64
+    }
+
+    #[napi(getter)]
+    pub fn sovereignty(&self) -> f64 {
+        self.inner.compute_sovereignty()
+    }
+
+    #[napi(getter)]
+    pub fn state(&self) -> NodeState {
+        NodeState::from(&self.inner.state)
+    }
+
+    #[napi(getter)]
+    pub fn gene_count(&self) -> u32 {
+        self.inner.organism.genes.len() as u32
+    }
+
+    /// Checkpoint-compatible JSON of the whole runtime
+    #[napi]
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string(&self.inner).map_err(|e| Error::from_reason(e.to_string()))
+    }
+}
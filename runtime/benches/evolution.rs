@@ -0,0 +1,70 @@
+//! Organism evolution benchmark
+//!
+//! Times `OrganismExecutor::evolve` on one organism with thousands of genes
+//! and `evolve_all` on many organisms. Run it with and without the
+//! `parallel` feature to compare:
+//!
+//! ```text
+//! cargo bench --bench evolution
+//! cargo bench --bench evolution --features parallel
+//! ```
+
+use dnalang_compiler::{generate_omega_ir, CrsmProgram};
+use dnalang_runtime::workloads::large_organism;
+use dnalang_runtime::{Organism, OrganismExecutor};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const STEPS: usize = 100;
+const DT: f64 = 0.01;
+
+fn organism(genes: usize) -> Organism {
+    let ir = generate_omega_ir(&large_organism(genes, 42), &CrsmProgram::new());
+    Organism::from_ir("LargeOrganism", &ir)
+}
+
+fn report(name: &str, gene_steps: usize, elapsed: Duration) {
+    println!(
+        "{:<28} {:>10.2?} {:>12.0} gene-steps/s",
+        name,
+        elapsed,
+        gene_steps as f64 / elapsed.as_secs_f64()
+    );
+}
+
+fn main() {
+    let mode = if cfg!(feature = "parallel") {
+        "parallel"
+    } else {
+        "serial"
+    };
+    println!("organism evolution ({}, {} steps)", mode, STEPS);
+
+    for genes in [1_000, 10_000, 50_000] {
+        let mut executor = OrganismExecutor::new();
+        let idx = executor.load_organism(organism(genes));
+        let start = Instant::now();
+        for _ in 0..STEPS {
+            executor.evolve(idx, DT);
+        }
+        black_box(&executor.organisms[idx]);
+        report(&format!("evolve/{}", genes), genes * STEPS, start.elapsed());
+    }
+
+    let (organisms, genes) = (64, 1_000);
+    let mut executor = OrganismExecutor::new();
+    let template = organism(genes);
+    for _ in 0..organisms {
+        executor.load_organism(template.clone());
+    }
+    let start = Instant::now();
+    for _ in 0..STEPS {
+        executor.evolve_all(DT);
+    }
+    black_box(&executor.organisms);
+    report(
+        &format!("evolve_all/{}x{}", organisms, genes),
+        organisms * genes * STEPS,
+        start.elapsed(),
+    );
+}
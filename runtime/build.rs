@@ -0,0 +1,36 @@
+//! Generates `dnalang_runtime.h` from `src/ffi.rs` into `OUT_DIR` when the
+//! `ffi` feature is enabled. The committed `include/dnalang_runtime.h` is
+//! only rewritten on request:
+//!
+//! ```text
+//! DNA_UPDATE_HEADER=1 cargo build --features ffi
+//! ```
+
+fn main() {
+    #[cfg(feature = "ffi")]
+    generate_header();
+}
+
+#[cfg(feature = "ffi")]
+fn generate_header() {
+    use std::env;
+    use std::path::PathBuf;
+
+    println!("cargo:rerun-if-changed=src/ffi.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+    println!("cargo:rerun-if-env-changed=DNA_UPDATE_HEADER");
+    let crate_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+    let config = cbindgen::Config::from_file(crate_dir.join("cbindgen.toml"))
+        .expect("invalid cbindgen.toml");
+    // Only the ffi module, so no other public type reaches the header
+    let header = cbindgen::Builder::new()
+        .with_src(crate_dir.join("src/ffi.rs"))
+        .with_config(config)
+        .generate()
+        .expect("failed to generate C header");
+    header.write_to_file(out_dir.join("dnalang_runtime.h"));
+    if env::var_os("DNA_UPDATE_HEADER").is_some() {
+        header.write_to_file(crate_dir.join("include/dnalang_runtime.h"));
+    }
+}
@@ -0,0 +1,126 @@
+/* Generated by cbindgen from runtime/src/ffi.rs; do not edit. */
+
+#ifndef DNALANG_RUNTIME_H
+#define DNALANG_RUNTIME_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#define DNA_ABI_VERSION 1
+
+/**
+ * Opaque runtime handle
+ */
+typedef struct DnaRuntime DnaRuntime;
+
+/**
+ * The 7D CRSM state C7D = (Λ, Γ, Φ, Ξ, ρ±, θ, τ)
+ */
+typedef struct DnaState {
+  double lambda;
+  double gamma;
+  double phi;
+  double xi;
+  double rho;
+  double theta;
+  double tau;
+} DnaState;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * ABI version of the loaded library (`DNA_ABI_VERSION`)
+ */
+uint32_t dna_abi_version(void);
+
+/**
+ * Create a runtime with the standard profile; free with `dna_runtime_free`
+ */
+struct DnaRuntime *dna_runtime_new(void);
+
+/**
+ * Create a runtime with the lite profile (no event log, f32-rounded state)
+ */
+struct DnaRuntime *dna_runtime_new_lite(void);
+
+/**
+ * Destroy a runtime
+ *
+ * # Safety
+ *
+ * `runtime` must be null or come from `dna_runtime_new*` and not have
+ * been freed.
+ */
+void dna_runtime_free(struct DnaRuntime *runtime);
+
+/**
+ * Step the runtime forward by dt
+ *
+ * # Safety
+ *
+ * `runtime` must be null or a live handle.
+ */
+void dna_runtime_step(struct DnaRuntime *runtime, double dt);
+
+/**
+ * Step up to `steps` times, stopping early once sealed
+ *
+ * # Safety
+ *
+ * `runtime` must be null or a live handle.
+ */
+void dna_runtime_run(struct DnaRuntime *runtime, size_t steps, double dt);
+
+/**
+ * Copy the current state into `out`; false if either pointer is null
+ *
+ * # Safety
+ *
+ * `runtime` must be null or a live handle; `out` must be null or valid
+ * for writes.
+ */
+bool dna_runtime_state(const struct DnaRuntime *runtime, struct DnaState *out);
+
+/**
+ * Steps completed
+ *
+ * # Safety
+ *
+ * `runtime` must be null or a live handle.
+ */
+uint64_t dna_runtime_steps(const struct DnaRuntime *runtime);
+
+/**
+ * Whether sovereignty has been sealed
+ *
+ * # Safety
+ *
+ * `runtime` must be null or a live handle.
+ */
+bool dna_runtime_is_sealed(const struct DnaRuntime *runtime);
+
+/**
+ * Attempt Ω∞.seal(); returns whether the runtime is sealed afterwards
+ *
+ * # Safety
+ *
+ * `runtime` must be null or a live handle.
+ */
+bool dna_runtime_seal(struct DnaRuntime *runtime);
+
+/**
+ * Sovereignty index Ω_sov of the current state
+ *
+ * # Safety
+ *
+ * `runtime` must be null or a live handle.
+ */
+double dna_runtime_sovereignty(const struct DnaRuntime *runtime);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* DNALANG_RUNTIME_H */
@@ -0,0 +1,153 @@
+//! Adaptive Time Stepping
+//!
+//! A step-size controller for `DualRuntime::run_adaptive`. After every step
+//! it compares the larger of |ΔΓ| and |ΔΛ| with a tolerance: faster change
+//! shrinks dt for the next step, near-quiescent evolution (change below
+//! `quiescence` × tolerance) grows it, always within [min_dt, max_dt].
+//! Steps are not rejected and retried, so a sudden transient is taken at
+//! the dt in force when it starts; keep `max_dt` modest where that matters.
+
+use serde::{Deserialize, Serialize};
+
+/// How the controller changed dt after a step
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Adjustment {
+    Shrunk,
+    Kept,
+    Grown,
+}
+
+/// Step-size controller state and tuning
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StepController {
+    /// dt for the next step
+    pub dt: f64,
+    pub min_dt: f64,
+    pub max_dt: f64,
+    /// Largest |ΔΓ| or |ΔΛ| accepted per step
+    pub tolerance: f64,
+    /// Fraction of the tolerance below which dt grows
+    pub quiescence: f64,
+    pub shrink: f64,
+    pub grow: f64,
+}
+
+impl Default for StepController {
+    fn default() -> Self {
+        Self::new(0.01)
+    }
+}
+
+impl StepController {
+    /// Controller starting at `dt`, allowed to range over 1e-3·dt ..= 50·dt
+    pub fn new(dt: f64) -> Self {
+        Self {
+            dt,
+            min_dt: dt * 1e-3,
+            max_dt: dt * 50.0,
+            tolerance: 1e-3,
+            quiescence: 0.25,
+            shrink: 0.5,
+            grow: 1.5,
+        }
+    }
+
+    pub fn with_bounds(mut self, min_dt: f64, max_dt: f64) -> Self {
+        self.min_dt = min_dt;
+        self.max_dt = max_dt;
+        self.dt = self.dt.clamp(min_dt, max_dt);
+        self
+    }
+
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Update dt from the change observed over the last step
+    pub fn adjust(&mut self, change: f64) -> Adjustment {
+        let (dt, adjustment) = if change > self.tolerance {
+            (self.dt * self.shrink, Adjustment::Shrunk)
+        } else if change < self.tolerance * self.quiescence {
+            (self.dt * self.grow, Adjustment::Grown)
+        } else {
+            (self.dt, Adjustment::Kept)
+        };
+        let dt = dt.clamp(self.min_dt, self.max_dt);
+        if dt == self.dt {
+            return Adjustment::Kept;
+        }
+        self.dt = dt;
+        adjustment
+    }
+}
+
+/// Summary of an adaptive run
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AdaptiveReport {
+    pub steps: u64,
+    /// Steps after which dt shrank
+    pub shrunk: u64,
+    /// Steps after which dt grew
+    pub grown: u64,
+    /// Smallest and largest dt taken
+    pub min_dt: f64,
+    pub max_dt: f64,
+    /// τ reached
+    pub tau: f64,
+}
+
+impl AdaptiveReport {
+    pub(crate) fn record(&mut self, dt: f64, adjustment: Adjustment) {
+        if self.steps == 0 {
+            self.min_dt = dt;
+            self.max_dt = dt;
+        }
+        self.steps += 1;
+        self.min_dt = self.min_dt.min(dt);
+        self.max_dt = self.max_dt.max(dt);
+        match adjustment {
+            Adjustment::Shrunk => self.shrunk += 1,
+            Adjustment::Grown => self.grown += 1,
+            Adjustment::Kept => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_runtime::DualRuntime;
+
+    #[test]
+    fn test_controller_bounds() {
+        let mut controller = StepController::new(0.1).with_bounds(0.05, 0.2);
+        assert_eq!(controller.adjust(1.0), Adjustment::Shrunk);
+        assert_eq!(controller.dt, 0.05);
+        assert_eq!(controller.adjust(1.0), Adjustment::Kept);
+        for _ in 0..5 {
+            controller.adjust(0.0);
+        }
+        assert_eq!(controller.dt, 0.2);
+        assert_eq!(controller.adjust(0.0), Adjustment::Kept);
+    }
+
+    #[test]
+    fn test_run_adaptive_reaches_tau() {
+        let mut runtime = DualRuntime::new().with_step_controller(StepController::new(0.01));
+        let report = runtime.run_adaptive(2.0);
+        assert!((runtime.state.tau - 2.0).abs() < 1e-9 || runtime.sealed);
+        assert_eq!(report.tau, runtime.state.tau);
+        assert!(report.grown > 0);
+        assert!(report.max_dt > 0.01);
+
+        // A tight tolerance takes more, smaller steps than a loose one
+        let mut tight =
+            DualRuntime::new().with_step_controller(StepController::new(0.1).with_tolerance(1e-5));
+        let tight_report = tight.run_adaptive(1.0);
+        let mut loose =
+            DualRuntime::new().with_step_controller(StepController::new(0.1).with_tolerance(1e-1));
+        let loose_report = loose.run_adaptive(1.0);
+        assert!(tight_report.steps > loose_report.steps);
+    }
+}
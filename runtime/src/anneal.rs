@@ -0,0 +1,280 @@
+//! Simulated Annealing for Runtime Parameters
+//!
+//! Searches a box of named parameters (K_Γ, couplings, initial state values,
+//! ...) for the minimum of a user-supplied objective. The objective is usually
+//! evaluated by configuring a `DualRuntime`, running it for a short time and
+//! scoring the outcome. Because only objective values are compared, this works
+//! for discontinuous objectives such as "sealed or not".
+
+use crate::rng::SeededRng;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Named parameter values passed to the objective
+pub type ParameterSet = BTreeMap<String, f64>;
+
+/// A tunable parameter with its search bounds
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnnealParam {
+    pub name: String,
+    pub initial: f64,
+    pub min: f64,
+    pub max: f64,
+    /// Maximum perturbation per move at the initial temperature
+    pub step: f64,
+}
+
+/// Why an `AnnealParam` was rejected
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnnealError {
+    /// `min > max`, or a bound is NaN
+    InvalidBounds { name: String, min: f64, max: f64 },
+}
+
+impl fmt::Display for AnnealError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnnealError::InvalidBounds { name, min, max } => {
+                write!(
+                    f,
+                    "parameter {} has invalid bounds [{}, {}]",
+                    name, min, max
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnnealError {}
+
+impl AnnealParam {
+    pub fn new(
+        name: &str,
+        initial: f64,
+        min: f64,
+        max: f64,
+        step: f64,
+    ) -> Result<Self, AnnealError> {
+        if min.is_nan() || max.is_nan() || min > max {
+            return Err(AnnealError::InvalidBounds {
+                name: name.to_string(),
+                min,
+                max,
+            });
+        }
+        Ok(Self {
+            name: name.to_string(),
+            initial: initial.clamp(min, max),
+            min,
+            max,
+            step,
+        })
+    }
+}
+
+/// Cooling schedule: T(k) = T₀ · rateᵏ, stopping at `min_temperature`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnnealSchedule {
+    pub initial_temperature: f64,
+    pub cooling_rate: f64,
+    pub min_temperature: f64,
+    /// Maximum number of objective evaluations after the initial one
+    pub iterations: usize,
+}
+
+impl Default for AnnealSchedule {
+    fn default() -> Self {
+        Self {
+            initial_temperature: 1.0,
+            cooling_rate: 0.95,
+            min_temperature: 1e-4,
+            iterations: 200,
+        }
+    }
+}
+
+/// Outcome of an annealing run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnnealResult {
+    pub best: ParameterSet,
+    pub best_cost: f64,
+    pub evaluations: usize,
+    pub accepted: usize,
+    /// Best cost after each evaluation
+    pub history: Vec<f64>,
+}
+
+/// Simulated annealing optimizer
+#[derive(Debug, Clone)]
+pub struct Annealer {
+    pub params: Vec<AnnealParam>,
+    pub schedule: AnnealSchedule,
+    rng: SeededRng,
+}
+
+impl Annealer {
+    pub fn new(params: Vec<AnnealParam>, seed: u64) -> Self {
+        Self {
+            params,
+            schedule: AnnealSchedule::default(),
+            rng: SeededRng::new(seed),
+        }
+    }
+
+    pub fn with_schedule(mut self, schedule: AnnealSchedule) -> Self {
+        self.schedule = schedule;
+        self
+    }
+
+    /// Minimize `objective` over the parameter box
+    pub fn minimize<F>(&mut self, mut objective: F) -> AnnealResult
+    where
+        F: FnMut(&ParameterSet) -> f64,
+    {
+        let mut current: Vec<f64> = self.params.iter().map(|p| p.initial).collect();
+        let mut current_cost = objective(&self.to_set(&current));
+        let mut best = current.clone();
+        let mut best_cost = current_cost;
+        let mut history = vec![best_cost];
+        let mut accepted = 0;
+        let mut temperature = self.schedule.initial_temperature;
+
+        for _ in 0..self.schedule.iterations {
+            if temperature < self.schedule.min_temperature || self.params.is_empty() {
+                break;
+            }
+
+            let candidate = self.neighbour(&current, temperature);
+            let cost = objective(&self.to_set(&candidate));
+            let delta = cost - current_cost;
+
+            // Metropolis criterion
+            if delta <= 0.0 || self.rng.next_f64() < (-delta / temperature).exp() {
+                current = candidate;
+                current_cost = cost;
+                accepted += 1;
+
+                if current_cost < best_cost {
+                    best = current.clone();
+                    best_cost = current_cost;
+                }
+            }
+
+            history.push(best_cost);
+            temperature *= self.schedule.cooling_rate;
+        }
+
+        AnnealResult {
+            best: self.to_set(&best),
+            best_cost,
+            evaluations: history.len(),
+            accepted,
+            history,
+        }
+    }
+
+    /// Perturb one parameter, scaling the step with the temperature
+    fn neighbour(&mut self, values: &[f64], temperature: f64) -> Vec<f64> {
+        let mut next = values.to_vec();
+        let idx = self.rng.index(self.params.len());
+        let param = &self.params[idx];
+        let scale = (temperature / self.schedule.initial_temperature).clamp(0.01, 1.0);
+        let delta = self.rng.range(-param.step, param.step) * scale;
+        // max/min rather than clamp: deserialized bounds are unchecked
+        next[idx] = (next[idx] + delta).max(param.min).min(param.max);
+        next
+    }
+
+    fn to_set(&self, values: &[f64]) -> ParameterSet {
+        self.params
+            .iter()
+            .zip(values)
+            .map(|(p, v)| (p.name.clone(), *v))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_runtime::DualRuntime;
+
+    #[test]
+    fn test_minimize_quadratic() {
+        let mut annealer =
+            Annealer::new(vec![AnnealParam::new("x", 4.0, -5.0, 5.0, 2.0).unwrap()], 1);
+        let result = annealer.minimize(|p| (p["x"] - 1.5).powi(2));
+        assert!((result.best["x"] - 1.5).abs() < 0.2);
+        assert!(result.best_cost <= result.history[0]);
+    }
+
+    #[test]
+    fn test_discontinuous_objective() {
+        let mut annealer = Annealer::new(
+            vec![
+                AnnealParam::new("a", 0.0, 0.0, 1.0, 0.5).unwrap(),
+                AnnealParam::new("b", 0.0, 0.0, 1.0, 0.5).unwrap(),
+            ],
+            3,
+        );
+        let result = annealer.minimize(|p| if p["a"] + p["b"] > 1.2 { 0.0 } else { 1.0 });
+        assert_eq!(result.best_cost, 0.0);
+        assert!(result.best["a"] + result.best["b"] > 1.2);
+    }
+
+    #[test]
+    fn test_invalid_bounds_rejected() {
+        let error = AnnealParam::new("x", 0.0, 1.0, -1.0, 0.5).unwrap_err();
+        assert!(matches!(error, AnnealError::InvalidBounds { ref name, .. } if name == "x"));
+        assert!(AnnealParam::new("x", 0.0, f64::NAN, 1.0, 0.5).is_err());
+        assert!(AnnealParam::new("x", 0.0, -1.0, f64::NAN, 0.5).is_err());
+        assert!(AnnealParam::new("x", 2.0, 1.0, 1.0, 0.5).is_ok());
+
+        // Unchecked bounds from a config file do not panic
+        let inverted: AnnealParam =
+            serde_json::from_str(r#"{"name":"x","initial":0.0,"min":1.0,"max":-1.0,"step":0.5}"#)
+                .unwrap();
+        Annealer::new(vec![inverted], 1).minimize(|p| p["x"]);
+    }
+
+    #[test]
+    fn test_reproducible_with_seed() {
+        let params = vec![AnnealParam::new("x", 0.0, -1.0, 1.0, 0.5).unwrap()];
+        let objective = |p: &ParameterSet| (p["x"] - 0.3).abs();
+        let a = Annealer::new(params.clone(), 9).minimize(objective);
+        let b = Annealer::new(params, 9).minimize(objective);
+        assert_eq!(a.history, b.history);
+    }
+
+    #[test]
+    fn test_anneal_runtime_to_sovereignty() {
+        // Search the initial Φ that seals in the fewest steps
+        const MAX_STEPS: usize = 3000;
+        let steps_to_seal = |phi: f64| {
+            let mut runtime = DualRuntime::new();
+            runtime.state.phi = phi;
+            runtime.state.compute_emergence();
+            if runtime.run_to_sovereignty(MAX_STEPS, 0.1) {
+                runtime.steps as f64
+            } else {
+                (MAX_STEPS + 1) as f64
+            }
+        };
+        let schedule = AnnealSchedule {
+            iterations: 30,
+            ..AnnealSchedule::default()
+        };
+        let param = AnnealParam::new("phi", 7.6901, 5.0, 12.0, 1.0).unwrap();
+        let mut annealer = Annealer::new(vec![param], 5).with_schedule(schedule);
+        let result = annealer.minimize(|p| steps_to_seal(p["phi"]));
+        assert!(result.evaluations <= 31);
+        assert!(result.best["phi"] >= 5.0 && result.best["phi"] <= 12.0);
+
+        // The default state seals, but the annealed Φ seals sooner
+        let baseline = steps_to_seal(DualRuntime::new().state.phi);
+        assert!(baseline <= MAX_STEPS as f64);
+        assert!(result.best_cost < baseline / 2.0);
+        assert!(result.best["phi"] > 7.6901);
+    }
+}
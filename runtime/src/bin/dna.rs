@@ -0,0 +1,179 @@
+//! dna
+//!
+//! Command-line driver for dna::}{::lang programs.
+//!
+//! ```text
+//! dna watch <program.dna> [--dt 0.01] [--interval-ms 250] [--reset fresh|inherit] [--inline]
+//! ```
+//!
+//! `watch` compiles the program, runs it in a `DualRuntime` and, whenever
+//! the file changes, recompiles and hot-swaps the organism without
+//! restarting. Compile errors are reported and the previous program keeps
+//! running. `--inline` inlines small gene-to-gene calls before running.
+//! The runtime allows unsealing, so a program that sealed is unsealed
+//! (and audited) when a new version is swapped in.
+
+use dnalang_compiler::{render_to_terminal, TerminalOptions};
+use dnalang_runtime::{
+    compile_source_with, DualRuntime, EmitRouter, ResetPolicy, RuntimeConfig, SourceWatcher,
+    TextSink,
+};
+use std::io::{self, IsTerminal};
+use std::process::ExitCode;
+use std::thread;
+use std::time::Duration;
+
+const USAGE: &str =
+    "usage: dna watch <program.dna> [--dt DT] [--interval-ms N] [--reset fresh|inherit] [--inline]";
+
+struct WatchOptions {
+    path: String,
+    dt: f64,
+    interval: Duration,
+    reset: ResetPolicy,
+    inline: bool,
+}
+
+fn parse_watch_args(args: &[String]) -> Result<WatchOptions, String> {
+    let mut options = WatchOptions {
+        path: String::new(),
+        dt: 0.01,
+        interval: Duration::from_millis(250),
+        reset: ResetPolicy::default(),
+        inline: false,
+    };
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        let mut value = || {
+            iter.next()
+                .ok_or_else(|| format!("missing value for {}", arg))
+        };
+        match arg.as_str() {
+            "--dt" => {
+                options.dt = value()?
+                    .parse()
+                    .ok()
+                    .filter(|dt: &f64| *dt > 0.0 && dt.is_finite())
+                    .ok_or_else(|| "--dt expects a positive number".to_string())?
+            }
+            "--interval-ms" => {
+                let ms = value()?
+                    .parse()
+                    .map_err(|_| "--interval-ms expects an integer".to_string())?;
+                options.interval = Duration::from_millis(ms);
+            }
+            "--inline" => options.inline = true,
+            "--reset" => {
+                let name = value()?;
+                options.reset = ResetPolicy::named(name)
+                    .ok_or_else(|| format!("unknown reset policy `{}`", name))?;
+            }
+            path if options.path.is_empty() && !path.starts_with("--") => {
+                options.path = path.to_string()
+            }
+            other => return Err(format!("unexpected argument `{}`", other)),
+        }
+    }
+    if options.path.is_empty() {
+        return Err("missing program path".to_string());
+    }
+    Ok(options)
+}
+
+fn watch(options: WatchOptions) -> io::Result<()> {
+    let mut watcher = SourceWatcher::new(&options.path);
+    let mut router = EmitRouter::new();
+    router.add_sink(Box::new(TextSink::new(io::stdout())));
+    let terminal = TerminalOptions::new()
+        .with_color(io::stderr().is_terminal())
+        .with_file_name(&options.path);
+
+    let mut runtime: Option<DualRuntime> = None;
+    let mut poll_error = None;
+    loop {
+        // A failed poll (e.g. NotFound while an editor replaces the file)
+        // is logged once and retried on the next interval
+        let polled = match watcher.poll() {
+            Ok(polled) => {
+                poll_error = None;
+                polled
+            }
+            Err(err) => {
+                let message = err.to_string();
+                if poll_error.as_ref() != Some(&message) {
+                    eprintln!("[watch] cannot read {}: {}", options.path, message);
+                    poll_error = Some(message);
+                }
+                None
+            }
+        };
+        if let Some(source) = polled {
+            match compile_source_with(&source, options.inline) {
+                Err(diagnostics) => {
+                    eprintln!("{}", render_to_terminal(&diagnostics, &source, &terminal));
+                    eprintln!("[watch] compile failed; still running the previous program");
+                }
+                Ok(ir) => match runtime.as_mut() {
+                    None => {
+                        let mut fresh = DualRuntime::new()
+                            .with_config(RuntimeConfig::default().with_unseal_allowed());
+                        let trace = fresh.load_ir(&ir);
+                        trace.route(&mut router)?;
+                        eprintln!("[watch] loaded {} genes", fresh.organism.genes.len());
+                        runtime = Some(fresh);
+                    }
+                    Some(running) => {
+                        let report = running.hot_swap(&ir, options.reset);
+                        eprintln!(
+                            "[watch] swapped at τ = {:.3}: {} preserved, {} reset, {} removed",
+                            running.state.tau,
+                            report.preserved.len(),
+                            report.reset.len(),
+                            report.removed.len()
+                        );
+                        if report.unsealed {
+                            eprintln!("[watch] unsealed for the new program");
+                        } else if report.still_sealed {
+                            eprintln!("[watch] still sealed; the new program will not evolve");
+                        }
+                    }
+                },
+            }
+        }
+
+        if let Some(running) = runtime.as_mut() {
+            let steps = (options.interval.as_secs_f64() / options.dt)
+                .ceil()
+                .max(1.0) as usize;
+            let was_sealed = running.sealed;
+            running.run(steps, options.dt);
+            if running.sealed && !was_sealed {
+                eprintln!("[watch] sealed at τ = {:.3}", running.state.tau);
+            }
+        }
+        thread::sleep(options.interval);
+    }
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("watch") => match parse_watch_args(&args[1..]) {
+            Ok(options) => match watch(options) {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(err) => {
+                    eprintln!("dna watch: {}", err);
+                    ExitCode::FAILURE
+                }
+            },
+            Err(message) => {
+                eprintln!("dna watch: {}\n{}", message, USAGE);
+                ExitCode::from(2)
+            }
+        },
+        _ => {
+            eprintln!("{}", USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
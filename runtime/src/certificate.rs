@@ -0,0 +1,169 @@
+//! Sovereignty Certificates
+//!
+//! When a runtime seals, it issues a `SovereigntyCertificate`: the step and
+//! τ it sealed at, the final CRSM7 state, the collapse rule that fired on
+//! that step (none for a direct `seal()`), and a content hash of the
+//! trajectory so far. The hash is FNV-1a over the observables of every
+//! step, so two runs share it only if they took the same path.
+//!
+//! `verify_certificate` re-checks the sovereignty claim against the state
+//! and compares the certificate with the one a replayed run issued.
+
+use crate::dual_runtime::DualRuntime;
+use crate::manifold::CRSM7State;
+use crate::shadow::Observables;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Trajectory hash of a runtime that has not stepped (the FNV-1a offset)
+pub const TRAJECTORY_HASH_SEED: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// Fold one step's observables into a trajectory hash
+pub(crate) fn extend_trajectory_hash(hash: u64, observables: &Observables) -> u64 {
+    observables
+        .values()
+        .iter()
+        .flat_map(|(_, value)| value.to_bits().to_le_bytes())
+        .fold(hash, |h, b| (h ^ b as u64).wrapping_mul(0x0100_0000_01b3))
+}
+
+/// Auditable record of a runtime sealing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SovereigntyCertificate {
+    /// Version of the runtime that issued the certificate
+    pub version: String,
+    /// Steps completed when the runtime sealed
+    pub steps: u64,
+    pub tau: f64,
+    pub state: CRSM7State,
+    /// Collapse rule that fired on the sealing step, if any
+    pub rule: Option<String>,
+    /// Ω_sov of the final state
+    pub sovereignty: f64,
+    /// Trajectory hash, as 16 hex digits
+    pub trajectory_hash: String,
+}
+
+impl SovereigntyCertificate {
+    /// Certificate for the runtime as it stands
+    pub fn issue(runtime: &DualRuntime, rule: Option<String>) -> Self {
+        Self {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            steps: runtime.steps,
+            tau: runtime.state.tau,
+            state: runtime.state.clone(),
+            rule,
+            sovereignty: runtime.state.compute_sovereignty(),
+            trajectory_hash: format!("{:016x}", runtime.trajectory_hash),
+        }
+    }
+}
+
+/// Why a certificate did not verify
+#[derive(Debug, Clone, PartialEq)]
+pub enum CertificateError {
+    /// The certified state does not meet Ξ ≥ Ξ_sov and Γ ≤ εΓ
+    NotSovereign,
+    /// The replayed run never sealed
+    NotSealed,
+    /// The replayed run sealed differently
+    Mismatch { field: &'static str },
+}
+
+impl fmt::Display for CertificateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CertificateError::NotSovereign => {
+                write!(f, "certified state does not satisfy sovereignty")
+            }
+            CertificateError::NotSealed => write!(f, "replayed run did not seal"),
+            CertificateError::Mismatch { field } => {
+                write!(f, "replayed run differs from the certificate in {}", field)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CertificateError {}
+
+/// Check the claim under `replayed`'s config and compare the certificate
+/// with the one `replayed` issued
+pub fn verify_certificate(
+    certificate: &SovereigntyCertificate,
+    replayed: &DualRuntime,
+) -> Result<(), CertificateError> {
+    let state = &certificate.state;
+    let config = &replayed.config;
+    if state.xi < config.sovereignty_xi || state.gamma > config.gamma_tolerance {
+        return Err(CertificateError::NotSovereign);
+    }
+    let issued = replayed
+        .certificate
+        .as_ref()
+        .ok_or(CertificateError::NotSealed)?;
+
+    let fields = |s: &CRSM7State| [s.lambda, s.gamma, s.phi, s.xi, s.rho, s.theta, s.tau];
+    let checks = [
+        ("steps", certificate.steps == issued.steps),
+        ("tau", certificate.tau.to_bits() == issued.tau.to_bits()),
+        ("state", fields(state) == fields(&issued.state)),
+        ("rule", certificate.rule == issued.rule),
+        (
+            "trajectory_hash",
+            certificate.trajectory_hash == issued.trajectory_hash,
+        ),
+    ];
+    match checks.iter().find(|(_, ok)| !ok) {
+        Some(&(field, _)) => Err(CertificateError::Mismatch { field }),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collapse::LAMBDA_PHI_MAX;
+
+    fn sealing_runtime() -> DualRuntime {
+        let mut runtime = DualRuntime::new();
+        runtime.state.gamma = runtime.config.gamma_tolerance;
+        runtime.state.lambda = 0.999;
+        runtime.state.phi = 100.0;
+        runtime.state.compute_emergence();
+        runtime
+    }
+
+    #[test]
+    fn test_certificate_verifies_against_replay() {
+        let mut runtime = sealing_runtime();
+        runtime.run(5, 0.01);
+        let certificate = runtime.certificate.clone().unwrap();
+        assert_eq!(certificate.steps, 1);
+        assert_eq!(certificate.rule.as_deref(), Some(LAMBDA_PHI_MAX));
+
+        let json = serde_json::to_string(&certificate).unwrap();
+        let certificate: SovereigntyCertificate = serde_json::from_str(&json).unwrap();
+        let mut replayed = sealing_runtime();
+        replayed.run(5, 0.01);
+        assert_eq!(verify_certificate(&certificate, &replayed), Ok(()));
+
+        let mut diverged = sealing_runtime();
+        diverged.state.phi = 101.0;
+        diverged.run(5, 0.01);
+        assert!(matches!(
+            verify_certificate(&certificate, &diverged),
+            Err(CertificateError::Mismatch { .. })
+        ));
+
+        let mut forged = certificate;
+        forged.state.gamma = 0.5;
+        assert_eq!(
+            verify_certificate(&forged, &replayed),
+            Err(CertificateError::NotSovereign)
+        );
+        assert_eq!(
+            verify_certificate(&runtime.certificate.clone().unwrap(), &DualRuntime::new()),
+            Err(CertificateError::NotSealed)
+        );
+    }
+}
@@ -0,0 +1,409 @@
+//! Bounded Event Channels
+//!
+//! Channels for observer and telemetry events that never let a slow consumer
+//! stall the simulation. Each channel has a fixed capacity, an optional rate
+//! limit and a backpressure policy applied when the queue is full:
+//! - DropOldest: discard the oldest queued event
+//! - Coalesce: merge the event into the newest queued one
+//! - Block: wait up to a timeout for space, then drop the event
+//!
+//! Every drop, merge and wait is counted in `ChannelMetrics`.
+
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// What a full channel does with a new event
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackpressurePolicy {
+    DropOldest,
+    Coalesce,
+    Block { timeout_ms: u64 },
+}
+
+/// Channel configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChannelConfig {
+    /// At least 1 (smaller values are raised on construction and
+    /// deserialization)
+    #[serde(deserialize_with = "at_least_one")]
+    pub capacity: usize,
+    pub policy: BackpressurePolicy,
+    /// Maximum sustained events per second (`None` = unlimited)
+    pub max_rate: Option<f64>,
+}
+
+impl Default for ChannelConfig {
+    fn default() -> Self {
+        Self::new(1024, BackpressurePolicy::DropOldest)
+    }
+}
+
+impl ChannelConfig {
+    pub fn new(capacity: usize, policy: BackpressurePolicy) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            policy,
+            max_rate: None,
+        }
+    }
+
+    pub fn with_max_rate(mut self, events_per_second: f64) -> Self {
+        self.max_rate = Some(events_per_second);
+        self
+    }
+}
+
+fn at_least_one<'de, D: Deserializer<'de>>(deserializer: D) -> Result<usize, D::Error> {
+    usize::deserialize(deserializer).map(|capacity| capacity.max(1))
+}
+
+/// Events that can be merged when a channel coalesces
+pub trait Coalesce {
+    /// Fold a newer event into this one; by default the newer event wins
+    fn coalesce(&mut self, newer: Self)
+    where
+        Self: Sized,
+    {
+        *self = newer;
+    }
+}
+
+impl Coalesce for crate::shadow::Observables {}
+
+/// Snapshot of a channel's counters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChannelMetrics {
+    /// Events passed to `send`
+    pub sent: u64,
+    /// Events taken by the receiver
+    pub received: u64,
+    /// Events discarded because the queue was full
+    pub dropped: u64,
+    /// Events merged into a queued event
+    pub coalesced: u64,
+    /// Events rejected by the rate limit
+    pub rate_limited: u64,
+    /// Sends that had to wait for space
+    pub blocked: u64,
+}
+
+#[derive(Default)]
+struct Counters {
+    sent: AtomicU64,
+    received: AtomicU64,
+    dropped: AtomicU64,
+    coalesced: AtomicU64,
+    rate_limited: AtomicU64,
+    blocked: AtomicU64,
+}
+
+/// Token bucket refilled at `rate` tokens per second, holding at most one
+/// second's worth
+struct TokenBucket {
+    rate: f64,
+    tokens: f64,
+    last: Instant,
+}
+
+impl TokenBucket {
+    fn new(rate: f64) -> Self {
+        Self {
+            rate,
+            tokens: rate.max(1.0),
+            last: Instant::now(),
+        }
+    }
+
+    fn try_take(&mut self) -> bool {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last).as_secs_f64();
+        self.last = now;
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.rate.max(1.0));
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+struct Queue<T> {
+    events: VecDeque<T>,
+    bucket: Option<TokenBucket>,
+}
+
+struct Shared<T> {
+    config: ChannelConfig,
+    queue: Mutex<Queue<T>>,
+    space: Condvar,
+    ready: Condvar,
+    counters: Counters,
+}
+
+impl<T> Shared<T> {
+    fn lock(&self) -> MutexGuard<'_, Queue<T>> {
+        // A panicking consumer must not take the producer down with it
+        self.queue.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn metrics(&self) -> ChannelMetrics {
+        let c = &self.counters;
+        ChannelMetrics {
+            sent: c.sent.load(Ordering::Relaxed),
+            received: c.received.load(Ordering::Relaxed),
+            dropped: c.dropped.load(Ordering::Relaxed),
+            coalesced: c.coalesced.load(Ordering::Relaxed),
+            rate_limited: c.rate_limited.load(Ordering::Relaxed),
+            blocked: c.blocked.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Create a bounded channel
+pub fn bounded<T: Coalesce>(config: ChannelConfig) -> (EventSender<T>, EventReceiver<T>) {
+    let bucket = config.max_rate.map(TokenBucket::new);
+    let shared = Arc::new(Shared {
+        queue: Mutex::new(Queue {
+            events: VecDeque::with_capacity(config.capacity),
+            bucket,
+        }),
+        config,
+        space: Condvar::new(),
+        ready: Condvar::new(),
+        counters: Counters::default(),
+    });
+    (
+        EventSender {
+            shared: Arc::clone(&shared),
+        },
+        EventReceiver { shared },
+    )
+}
+
+/// Producer side; cheap to clone
+pub struct EventSender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Clone for EventSender<T> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<T: Coalesce> EventSender<T> {
+    /// Send an event; returns whether it was queued or merged
+    pub fn send(&self, event: T) -> bool {
+        let shared = &*self.shared;
+        let counters = &shared.counters;
+        counters.sent.fetch_add(1, Ordering::Relaxed);
+        let mut queue = shared.lock();
+
+        let within_rate = queue.bucket.as_mut().is_none_or(|b| b.try_take());
+        if !within_rate {
+            // Over the rate limit a coalescing channel still keeps the latest value
+            if shared.config.policy == BackpressurePolicy::Coalesce {
+                if let Some(newest) = queue.events.back_mut() {
+                    newest.coalesce(event);
+                    counters.coalesced.fetch_add(1, Ordering::Relaxed);
+                    return true;
+                }
+            }
+            counters.rate_limited.fetch_add(1, Ordering::Relaxed);
+            return false;
+        }
+
+        if queue.events.len() >= shared.config.capacity {
+            match shared.config.policy {
+                BackpressurePolicy::DropOldest => {
+                    queue.events.pop_front();
+                    counters.dropped.fetch_add(1, Ordering::Relaxed);
+                }
+                BackpressurePolicy::Coalesce => {
+                    if let Some(newest) = queue.events.back_mut() {
+                        newest.coalesce(event);
+                    }
+                    counters.coalesced.fetch_add(1, Ordering::Relaxed);
+                    shared.ready.notify_one();
+                    return true;
+                }
+                BackpressurePolicy::Block { timeout_ms } => {
+                    counters.blocked.fetch_add(1, Ordering::Relaxed);
+                    let capacity = shared.config.capacity;
+                    let (guard, _) = shared
+                        .space
+                        .wait_timeout_while(queue, Duration::from_millis(timeout_ms), |q| {
+                            q.events.len() >= capacity
+                        })
+                        .unwrap_or_else(|e| e.into_inner());
+                    queue = guard;
+                    if queue.events.len() >= capacity {
+                        counters.dropped.fetch_add(1, Ordering::Relaxed);
+                        return false;
+                    }
+                }
+            }
+        }
+
+        queue.events.push_back(event);
+        shared.ready.notify_one();
+        true
+    }
+
+    pub fn metrics(&self) -> ChannelMetrics {
+        self.shared.metrics()
+    }
+}
+
+/// Consumer side
+pub struct EventReceiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> EventReceiver<T> {
+    /// Take the oldest queued event without waiting
+    pub fn try_recv(&self) -> Option<T> {
+        let event = self.shared.lock().events.pop_front();
+        self.taken(event)
+    }
+
+    /// Take the oldest event, waiting up to `timeout` for one
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<T> {
+        let queue = self.shared.lock();
+        let (mut queue, _) = self
+            .shared
+            .ready
+            .wait_timeout_while(queue, timeout, |q| q.events.is_empty())
+            .unwrap_or_else(|e| e.into_inner());
+        let event = queue.events.pop_front();
+        drop(queue);
+        self.taken(event)
+    }
+
+    /// Take every queued event
+    pub fn drain(&self) -> Vec<T> {
+        let events: Vec<T> = self.shared.lock().events.drain(..).collect();
+        self.shared
+            .counters
+            .received
+            .fetch_add(events.len() as u64, Ordering::Relaxed);
+        self.shared.space.notify_all();
+        events
+    }
+
+    pub fn len(&self) -> usize {
+        self.shared.lock().events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn metrics(&self) -> ChannelMetrics {
+        self.shared.metrics()
+    }
+
+    fn taken(&self, event: Option<T>) -> Option<T> {
+        if event.is_some() {
+            self.shared
+                .counters
+                .received
+                .fetch_add(1, Ordering::Relaxed);
+            self.shared.space.notify_one();
+        }
+        event
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[derive(Debug, PartialEq)]
+    struct Delta(i64);
+
+    impl Coalesce for Delta {
+        fn coalesce(&mut self, newer: Self) {
+            self.0 += newer.0;
+        }
+    }
+
+    #[test]
+    fn test_drop_oldest() {
+        let (tx, rx) = bounded(ChannelConfig::new(3, BackpressurePolicy::DropOldest));
+        for i in 0..5 {
+            assert!(tx.send(Delta(i)));
+        }
+        assert_eq!(rx.drain(), vec![Delta(2), Delta(3), Delta(4)]);
+        let metrics = rx.metrics();
+        assert_eq!((metrics.sent, metrics.received, metrics.dropped), (5, 3, 2));
+    }
+
+    #[test]
+    fn test_deserialized_capacity_at_least_one() {
+        let config: ChannelConfig =
+            serde_json::from_str(r#"{"capacity":0,"policy":"coalesce","max_rate":null}"#).unwrap();
+        assert_eq!(config.capacity, 1);
+        let (tx, rx) = bounded(config);
+        assert!(tx.send(Delta(1)));
+        assert!(tx.send(Delta(2)));
+        assert_eq!(rx.drain(), vec![Delta(3)]);
+        assert_eq!(rx.metrics().coalesced, 1);
+    }
+
+    #[test]
+    fn test_coalesce_merges_deltas() {
+        let (tx, rx) = bounded(ChannelConfig::new(2, BackpressurePolicy::Coalesce));
+        for i in 1..=5 {
+            tx.send(Delta(i));
+        }
+        // 1, then 2 + 3 + 4 + 5 merged into the newest slot
+        assert_eq!(rx.drain(), vec![Delta(1), Delta(14)]);
+        assert_eq!(rx.metrics().coalesced, 3);
+        assert_eq!(rx.metrics().dropped, 0);
+    }
+
+    #[test]
+    fn test_block_waits_then_drops() {
+        let policy = BackpressurePolicy::Block { timeout_ms: 10 };
+        let (tx, rx) = bounded(ChannelConfig::new(1, policy));
+        assert!(tx.send(Delta(1)));
+        // Nobody consumes: the send times out and drops the event
+        assert!(!tx.send(Delta(2)));
+        assert_eq!(rx.metrics().blocked, 1);
+        assert_eq!(rx.metrics().dropped, 1);
+    }
+
+    #[test]
+    fn test_block_resumes_when_consumed() {
+        let policy = BackpressurePolicy::Block { timeout_ms: 5_000 };
+        let (tx, rx) = bounded(ChannelConfig::new(1, policy));
+        assert!(tx.send(Delta(1)));
+        let consumer = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            let first = rx.recv_timeout(Duration::from_secs(5));
+            let second = rx.recv_timeout(Duration::from_secs(5));
+            (first, second)
+        });
+        assert!(tx.send(Delta(2)));
+        assert_eq!(tx.metrics().blocked, 1);
+        assert_eq!(consumer.join().unwrap(), (Some(Delta(1)), Some(Delta(2))));
+    }
+
+    #[test]
+    fn test_rate_limit() {
+        let config = ChannelConfig::new(100, BackpressurePolicy::DropOldest).with_max_rate(1.0);
+        let (tx, rx) = bounded(config);
+        let queued = (0..10).filter(|&i| tx.send(Delta(i))).count();
+        assert_eq!(queued, 1);
+        assert_eq!(rx.metrics().rate_limited, 9);
+    }
+}
@@ -0,0 +1,128 @@
+//! Checkpoints
+//!
+//! Snapshot files for resuming long runs. A checkpoint is JSON holding the
+//! format number, the runtime version that wrote it and the full
+//! `DualRuntime`. Files are written to a temporary sibling and renamed into
+//! place, so a crash mid-write leaves the previous checkpoint intact.
+//! Observers are not part of the snapshot and must be registered again.
+//! `load_checked` refuses a checkpoint made with a different duality
+//! configuration, and every load refuses one whose J fails verification.
+
+use crate::dual_runtime::DualRuntime;
+use crate::projectors::DualityConfig;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Current checkpoint file format
+pub const CHECKPOINT_FORMAT: u32 = 1;
+
+/// Contents of a checkpoint file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Checkpoint {
+    /// File format, for refusing files from newer releases
+    pub format: u32,
+    /// Version of the runtime that wrote the file
+    pub version: String,
+    pub runtime: DualRuntime,
+}
+
+impl Checkpoint {
+    pub fn new(runtime: &DualRuntime) -> Self {
+        Self {
+            format: CHECKPOINT_FORMAT,
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            runtime: runtime.clone(),
+        }
+    }
+
+    /// Write to `path`, replacing it atomically
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let mut partial = path.as_os_str().to_owned();
+        partial.push(".partial");
+        fs::write(&partial, serde_json::to_string(self)?)?;
+        fs::rename(&partial, path)
+    }
+
+    /// Read from `path`, refusing unknown formats
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let checkpoint: Self = serde_json::from_str(&fs::read_to_string(path)?)?;
+        if checkpoint.format == 0 || checkpoint.format > CHECKPOINT_FORMAT {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "checkpoint format {} written by {} is not supported (expected 1..={})",
+                    checkpoint.format, checkpoint.version, CHECKPOINT_FORMAT
+                ),
+            ));
+        }
+        if !checkpoint.runtime.duality.verify().passed {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "checkpoint duality configuration {} fails verification",
+                    checkpoint.runtime.duality.fingerprint()
+                ),
+            ));
+        }
+        Ok(checkpoint)
+    }
+
+    /// Read from `path`, also refusing a duality configuration other than
+    /// `duality`
+    pub fn load_checked(path: impl AsRef<Path>, duality: &DualityConfig) -> io::Result<Self> {
+        let checkpoint = Self::load(path)?;
+        let recorded = &checkpoint.runtime.duality;
+        if !recorded.matches(duality) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "checkpoint duality configuration {} does not match {}",
+                    recorded.fingerprint(),
+                    duality.fingerprint()
+                ),
+            ));
+        }
+        Ok(checkpoint)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::perturbation::{Perturbation, PerturbationSchedule};
+
+    #[test]
+    fn test_checkpoint_roundtrip_resumes_identically() {
+        let path = std::env::temp_dir().join(format!("dna-checkpoint-{}.json", std::process::id()));
+        let schedule = PerturbationSchedule::new().at(0.3, Perturbation::FlipPolarity);
+        let mut runtime = DualRuntime::new().with_perturbations(schedule);
+        runtime.run(20, 0.01);
+        runtime.checkpoint(&path).unwrap();
+
+        let mut restored = DualRuntime::restore(&path).unwrap();
+        assert_eq!(restored.steps, 20);
+        runtime.run(20, 0.01);
+        restored.run(20, 0.01);
+        assert_eq!(
+            serde_json::to_value(&restored).unwrap(),
+            serde_json::to_value(&runtime).unwrap()
+        );
+
+        let exchange = DualityConfig::new(crate::projectors::InvolutionSpec::Matrix {
+            rows: vec![vec![0.0, 1.0], vec![1.0, 0.0]],
+        });
+        assert!(DualRuntime::restore_checked(&path, &DualityConfig::default()).is_ok());
+        let err = DualRuntime::restore_checked(&path, &exchange).unwrap_err();
+        assert!(err.to_string().contains("does not match"));
+
+        let mut future = Checkpoint::new(&runtime);
+        future.format = CHECKPOINT_FORMAT + 1;
+        future.save(&path).unwrap();
+        let err = DualRuntime::restore(&path).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,239 @@
+//! Collapse Rules
+//!
+//! A collapse rule pairs a condition on the runtime with an action taken
+//! when it holds. `DualRuntime` evaluates its `CollapseRules` in order after
+//! every step; the list starts with the two built-ins (Γ → 0 applies Π±,
+//! ΛΦ → max attempts Ω∞.seal()) and custom rules can be added, inserted
+//! before them or replace them.
+//!
+//! When a compiled program is loaded, its collapse rules take the place of
+//! the built-ins; custom rules still run, after the program's.
+
+use crate::dual_runtime::DualRuntime;
+use std::fmt;
+use std::sync::Arc;
+
+/// Name the built-in Γ → 0 rule logs under
+pub const GAMMA_TO_ZERO: &str = "gamma_to_zero";
+/// Name the built-in ΛΦ → max rule logs under
+pub const LAMBDA_PHI_MAX: &str = "lambda_phi_max";
+
+/// A condition checked after each step and the action it triggers
+pub trait CollapseRule: Send + Sync {
+    /// Name recorded in the event log when the rule fires
+    fn name(&self) -> &str;
+
+    fn condition(&self, runtime: &DualRuntime) -> bool;
+
+    fn apply(&self, runtime: &mut DualRuntime);
+
+    /// Built-ins stand in for a loaded program's rules
+    fn builtin(&self) -> bool {
+        false
+    }
+}
+
+/// Γ → 0 → apply the runtime's Π⁺ to Ψ
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GammaToZero;
+
+impl CollapseRule for GammaToZero {
+    fn name(&self) -> &str {
+        GAMMA_TO_ZERO
+    }
+
+    fn condition(&self, runtime: &DualRuntime) -> bool {
+        runtime.bifurcation_active()
+    }
+
+    fn apply(&self, runtime: &mut DualRuntime) {
+        runtime.project_psi();
+    }
+
+    fn builtin(&self) -> bool {
+        true
+    }
+}
+
+/// ΛΦ → max → Ω∞.seal() (only seals once sovereignty holds)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LambdaPhiMax;
+
+impl CollapseRule for LambdaPhiMax {
+    fn name(&self) -> &str {
+        LAMBDA_PHI_MAX
+    }
+
+    fn condition(&self, runtime: &DualRuntime) -> bool {
+        runtime.state.lambda * runtime.state.phi > runtime.config.seal_lambda_phi
+    }
+
+    fn apply(&self, runtime: &mut DualRuntime) {
+        runtime.seal();
+    }
+
+    fn builtin(&self) -> bool {
+        true
+    }
+}
+
+type Condition = dyn Fn(&DualRuntime) -> bool + Send + Sync;
+type Action = dyn Fn(&mut DualRuntime) + Send + Sync;
+
+/// A rule built from a condition closure and an action closure
+pub struct FnRule {
+    name: String,
+    condition: Box<Condition>,
+    action: Box<Action>,
+}
+
+impl FnRule {
+    pub fn new(
+        name: impl Into<String>,
+        condition: impl Fn(&DualRuntime) -> bool + Send + Sync + 'static,
+        action: impl Fn(&mut DualRuntime) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            condition: Box::new(condition),
+            action: Box::new(action),
+        }
+    }
+}
+
+impl CollapseRule for FnRule {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn condition(&self, runtime: &DualRuntime) -> bool {
+        (self.condition)(runtime)
+    }
+
+    fn apply(&self, runtime: &mut DualRuntime) {
+        (self.action)(runtime)
+    }
+}
+
+/// Ordered collapse rules (not serialized; restored runtimes get the
+/// built-ins)
+#[derive(Clone)]
+pub struct CollapseRules {
+    rules: Vec<Arc<dyn CollapseRule>>,
+}
+
+impl Default for CollapseRules {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+impl fmt::Debug for CollapseRules {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.names()).finish()
+    }
+}
+
+impl CollapseRules {
+    /// Γ → 0, then ΛΦ → max
+    pub fn builtin() -> Self {
+        Self {
+            rules: vec![Arc::new(GammaToZero), Arc::new(LambdaPhiMax)],
+        }
+    }
+
+    /// No rules at all
+    pub fn empty() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Evaluate `rule` after the existing rules
+    pub fn push(&mut self, rule: impl CollapseRule + 'static) {
+        self.rules.push(Arc::new(rule));
+    }
+
+    /// Evaluate `rule` at position `index` (clamped to the end)
+    pub fn insert(&mut self, index: usize, rule: impl CollapseRule + 'static) {
+        let index = index.min(self.rules.len());
+        self.rules.insert(index, Arc::new(rule));
+    }
+
+    /// Remove every rule named `name`; returns whether any was removed
+    pub fn remove(&mut self, name: &str) -> bool {
+        let before = self.rules.len();
+        self.rules.retain(|rule| rule.name() != name);
+        self.rules.len() != before
+    }
+
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Rule names in evaluation order
+    pub fn names(&self) -> Vec<&str> {
+        self.rules.iter().map(|rule| rule.name()).collect()
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &Arc<dyn CollapseRule>> {
+        self.rules.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::RuntimeEvent;
+
+    #[test]
+    fn test_custom_rules_run_in_order() {
+        let mut runtime = DualRuntime::new().with_collapse_rule(FnRule::new(
+            "phi_cap",
+            |rt| rt.state.phi > 7.0,
+            |rt| {
+                rt.state.phi = 7.0;
+                rt.state.compute_emergence();
+            },
+        ));
+        runtime
+            .collapse_rules
+            .insert(0, FnRule::new("always", |_| true, |_| {}));
+        assert_eq!(
+            runtime.collapse_rules.names(),
+            vec!["always", GAMMA_TO_ZERO, LAMBDA_PHI_MAX, "phi_cap"]
+        );
+
+        runtime.run(50, 0.1);
+        assert!(runtime.state.phi <= 7.0);
+        let fired: Vec<&str> = runtime
+            .events
+            .iter()
+            .filter_map(|e| match e {
+                RuntimeEvent::CollapseTriggered { step: 50, rule, .. } => Some(rule.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(fired.first(), Some(&"always"));
+        assert_eq!(fired.last(), Some(&"phi_cap"));
+    }
+
+    #[test]
+    fn test_builtins_can_be_removed() {
+        let mut runtime = DualRuntime::new();
+        runtime.state.gamma = runtime.config.gamma_tolerance;
+        runtime.state.lambda = 0.999;
+        runtime.state.phi = 100.0;
+        runtime.state.compute_emergence();
+
+        let mut unsealable = runtime.clone();
+        assert!(unsealable.collapse_rules.remove(LAMBDA_PHI_MAX));
+        unsealable.step(0.01);
+        assert!(!unsealable.sealed);
+
+        runtime.step(0.01);
+        assert!(runtime.sealed);
+    }
+}
@@ -0,0 +1,238 @@
+//! Runtime Configuration
+//!
+//! Tunable constants of state evolution, collapse and DMA execution. The
+//! defaults reproduce the historical hardcoded values; `DualRuntime`,
+//! `CRSM7State::evolve_with` and `OrganismExecutor` read them from here.
+
+use crate::manifold::GAMMA_TOLERANCE;
+use crate::precision::Real;
+use serde::{Deserialize, Serialize};
+
+/// How Φ accumulation saturates
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "model", rename_all = "snake_case")]
+pub enum PhiSaturation {
+    /// dΦ/dτ = c_Φ · Λ with no bound (the historical behaviour)
+    #[default]
+    Unbounded,
+    /// dΦ/dτ = c_Φ · Λ, Φ clamped at `max` after each step
+    HardCap { max: f64 },
+    /// dΦ/dτ = c_Φ · Λ · (1 − Φ/K), approaching the capacity K
+    Logistic { capacity: f64 },
+    /// dΦ/dτ = c_Φ · Λ − k · (Φ − Φ_eq), relaxing toward Φ_eq + c_Φ·Λ/k
+    Decay { equilibrium: f64, rate: f64 },
+}
+
+impl PhiSaturation {
+    /// dΦ/dτ given the unsaturated growth c_Φ · Λ at information `phi`
+    pub fn rate<T: Real>(&self, growth: T, phi: T) -> T {
+        match *self {
+            PhiSaturation::Unbounded | PhiSaturation::HardCap { .. } => growth,
+            PhiSaturation::Logistic { capacity } => growth * (T::ONE - phi / T::from_f64(capacity)),
+            PhiSaturation::Decay { equilibrium, rate } => {
+                growth - T::from_f64(rate) * (phi - T::from_f64(equilibrium))
+            }
+        }
+    }
+
+    /// Φ after applying a hard cap, if this is one
+    pub fn bound<T: Real>(&self, phi: T) -> T {
+        match *self {
+            PhiSaturation::HardCap { max } => phi.min(T::from_f64(max)),
+            _ => phi,
+        }
+    }
+}
+
+/// Constants used while evolving and collapsing states
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RuntimeConfig {
+    /// εΓ: floor of Γ and the sovereignty bound Γ ≤ εΓ
+    pub gamma_tolerance: f64,
+    /// Γ → 0 applies Π± once Γ ≤ margin · εΓ
+    pub bifurcation_margin: f64,
+    /// ΛΦ above which Ω∞.seal() is attempted
+    pub seal_lambda_phi: f64,
+    /// Ξ required for sovereignty
+    pub sovereignty_xi: f64,
+    /// Upper bound of Λ
+    pub lambda_cap: f64,
+    /// dΛ/dτ = rate · H
+    pub coherence_rate: f64,
+    /// dΦ/dτ = rate · Λ
+    pub information_rate: f64,
+    /// Scale of the temporal gradient ∂g/∂τ in DMA
+    pub dma_gradient_scale: f64,
+    /// Smallest duality factor used by DMA
+    pub dma_duality_floor: f64,
+    /// σΓ: Langevin noise amplitude on Γ (0 disables it)
+    pub noise_gamma: f64,
+    /// σΦ: Langevin noise amplitude on Φ (0 disables it)
+    pub noise_phi: f64,
+    /// Saturation of Φ accumulation
+    pub phi_saturation: PhiSaturation,
+    /// Steps over which an injected perturbation is undone (axiom A4;
+    /// 0 leaves it in place)
+    pub inversion_steps: u32,
+    /// Whether `DualRuntime::unseal` may clear the seal
+    pub allow_unseal: bool,
+}
+
+impl Default for RuntimeConfig {
+    fn default() -> Self {
+        Self {
+            gamma_tolerance: GAMMA_TOLERANCE,
+            bifurcation_margin: 10.0,
+            seal_lambda_phi: 10.0,
+            sovereignty_xi: 8.0,
+            lambda_cap: 0.999,
+            coherence_rate: 0.01,
+            information_rate: 0.01,
+            dma_gradient_scale: 0.1,
+            dma_duality_floor: 0.001,
+            noise_gamma: 0.0,
+            noise_phi: 0.0,
+            phi_saturation: PhiSaturation::Unbounded,
+            inversion_steps: 10,
+            allow_unseal: false,
+        }
+    }
+}
+
+impl RuntimeConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_gamma_tolerance(mut self, gamma_tolerance: f64) -> Self {
+        self.gamma_tolerance = gamma_tolerance;
+        self
+    }
+
+    pub fn with_seal_lambda_phi(mut self, seal_lambda_phi: f64) -> Self {
+        self.seal_lambda_phi = seal_lambda_phi;
+        self
+    }
+
+    pub fn with_lambda_cap(mut self, lambda_cap: f64) -> Self {
+        self.lambda_cap = lambda_cap;
+        self
+    }
+
+    pub fn with_information_rate(mut self, information_rate: f64) -> Self {
+        self.information_rate = information_rate;
+        self
+    }
+
+    pub fn with_phi_saturation(mut self, phi_saturation: PhiSaturation) -> Self {
+        self.phi_saturation = phi_saturation;
+        self
+    }
+
+    pub fn with_inversion_steps(mut self, inversion_steps: u32) -> Self {
+        self.inversion_steps = inversion_steps;
+        self
+    }
+
+    /// Permit `DualRuntime::unseal`
+    pub fn with_unseal_allowed(mut self) -> Self {
+        self.allow_unseal = true;
+        self
+    }
+
+    /// dΦ/dτ at coherence `lambda` and information `phi`
+    pub fn phi_rate(&self, lambda: f64, phi: f64) -> f64 {
+        self.phi_saturation
+            .rate(self.information_rate * lambda, phi)
+    }
+
+    /// Φ after applying a hard cap, if one is configured
+    pub fn bound_phi(&self, phi: f64) -> f64 {
+        self.phi_saturation.bound(phi)
+    }
+
+    /// Add Langevin noise σ·√dt·N(0, 1) to Γ and Φ each step
+    pub fn with_noise(mut self, noise_gamma: f64, noise_phi: f64) -> Self {
+        self.noise_gamma = noise_gamma;
+        self.noise_phi = noise_phi;
+        self
+    }
+
+    /// Whether any noise term is enabled
+    pub fn noise_enabled(&self) -> bool {
+        self.noise_gamma != 0.0 || self.noise_phi != 0.0
+    }
+
+    /// Whether Γ is low enough for the Γ → 0 collapse rule
+    pub fn bifurcation_active(&self, gamma: f64) -> bool {
+        gamma <= self.gamma_tolerance * self.bifurcation_margin
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_partial_config_keeps_defaults() {
+        let config: RuntimeConfig = serde_json::from_str(r#"{"lambda_cap": 0.9}"#).unwrap();
+        assert_eq!(config.lambda_cap, 0.9);
+        assert_eq!(config.seal_lambda_phi, 10.0);
+        assert!(config.bifurcation_active(GAMMA_TOLERANCE * 10.0));
+        assert!(!config.bifurcation_active(1e-3));
+    }
+
+    #[test]
+    fn test_phi_saturation_models() {
+        let run = |saturation| {
+            let config = RuntimeConfig::new()
+                .with_information_rate(1.0)
+                .with_phi_saturation(saturation);
+            let mut state = crate::CRSM7State::new();
+            state.phi = 0.0;
+            for _ in 0..5_000 {
+                state.evolve_with(0.01, 0.0, &config);
+            }
+            state.phi
+        };
+        let unbounded = run(PhiSaturation::Unbounded);
+        assert!(unbounded > 40.0);
+        assert_eq!(run(PhiSaturation::HardCap { max: 5.0 }), 5.0);
+        let logistic = run(PhiSaturation::Logistic { capacity: 5.0 });
+        assert!(logistic < 5.0 && logistic > 4.9);
+        let decay = run(PhiSaturation::Decay {
+            equilibrium: 2.0,
+            rate: 1.0,
+        });
+        let lambda = crate::CRSM7State::new().lambda;
+        assert!((decay - (2.0 + lambda)).abs() < 1e-2);
+
+        let json = serde_json::to_string(
+            &RuntimeConfig::new().with_phi_saturation(PhiSaturation::Logistic { capacity: 3.0 }),
+        )
+        .unwrap();
+        assert!(json.contains(r#""model":"logistic""#));
+        let config: RuntimeConfig = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            config.phi_saturation,
+            PhiSaturation::Logistic { capacity: 3.0 }
+        );
+    }
+
+    #[test]
+    fn test_runtime_uses_config() {
+        let config = RuntimeConfig::new().with_lambda_cap(0.87);
+        let mut runtime = crate::DualRuntime::new().with_config(config.clone());
+        runtime.run(500, 0.1);
+        assert!(runtime.state.lambda <= 0.87);
+
+        let mut executor = crate::OrganismExecutor::new().with_config(config);
+        let idx = executor.load_organism(crate::OrganismExecutor::create_standard_organism());
+        executor.elevate_coherence_info(idx, 2.0);
+        assert!(executor.organisms[idx]
+            .genes
+            .iter()
+            .all(|g| g.state.lambda <= 0.87));
+    }
+}
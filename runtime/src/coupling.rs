@@ -0,0 +1,174 @@
+//! Coupled Manifolds
+//!
+//! Additional CRSM7 manifolds stepped jointly with a runtime's own state,
+//! to model interacting CRSM systems. Manifold 0 is the runtime's state;
+//! the coupled manifolds follow in order. Each evolves under the same
+//! Hamiltonian, integrator and config (evolution noise reaches only
+//! manifold 0), then the coupling matrices feed the manifolds' Γ and Λ
+//! into each other diffusively:
+//!
+//! ΔΓ_i = dt · Σ_j K^Γ_ij (Γ_j − Γ_i),  ΔΛ_i = dt · Σ_j K^Λ_ij (Λ_j − Λ_i)
+//!
+//! computed from the values before the exchange, after which Γ is
+//! floored, Λ capped and Ξ recomputed. Entries outside a matrix are 0, so
+//! with no coupling the runtime's state evolves exactly as it would alone.
+//! Keep dt · Σ_j K_ij below 1 for a stable exchange. Collapse rules,
+//! observables and sealing see only manifold 0.
+
+use crate::config::RuntimeConfig;
+use crate::dual_runtime::Manifold;
+use crate::manifold::CRSM7State;
+use serde::{Deserialize, Serialize};
+
+/// Coupling strengths K_ij between manifolds i and j
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Coupling {
+    /// K^Γ by rows
+    pub gamma: Vec<Vec<f64>>,
+    /// K^Λ by rows
+    pub lambda: Vec<Vec<f64>>,
+}
+
+impl Coupling {
+    /// No coupling between `size` manifolds
+    pub fn new(size: usize) -> Self {
+        Self {
+            gamma: vec![vec![0.0; size]; size],
+            lambda: vec![vec![0.0; size]; size],
+        }
+    }
+
+    /// Every pair of `size` manifolds coupled with the same strengths
+    pub fn uniform(size: usize, gamma: f64, lambda: f64) -> Self {
+        let off_diagonal = |k: f64| -> Vec<Vec<f64>> {
+            (0..size)
+                .map(|i| (0..size).map(|j| if i == j { 0.0 } else { k }).collect())
+                .collect()
+        };
+        Self {
+            gamma: off_diagonal(gamma),
+            lambda: off_diagonal(lambda),
+        }
+    }
+
+    /// Set K^Γ and K^Λ from `from` into `to` (one direction), growing the
+    /// matrices as needed
+    pub fn with_link(mut self, from: usize, to: usize, gamma: f64, lambda: f64) -> Self {
+        let size = self.gamma.len().max(from + 1).max(to + 1);
+        for matrix in [&mut self.gamma, &mut self.lambda] {
+            matrix.resize(size, Vec::new());
+            for row in matrix.iter_mut() {
+                row.resize(size, 0.0);
+            }
+        }
+        self.gamma[to][from] = gamma;
+        self.lambda[to][from] = lambda;
+        self
+    }
+
+    fn entry(matrix: &[Vec<f64>], i: usize, j: usize) -> f64 {
+        matrix
+            .get(i)
+            .and_then(|row| row.get(j))
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.gamma
+            .iter()
+            .chain(&self.lambda)
+            .flatten()
+            .all(|k| *k == 0.0)
+    }
+
+    /// Exchange Γ and Λ between `states` over dt
+    pub fn apply(&self, states: &mut [&mut CRSM7State], dt: f64, config: &RuntimeConfig) {
+        if self.is_zero() {
+            return;
+        }
+        let before: Vec<(f64, f64)> = states.iter().map(|s| (s.gamma, s.lambda)).collect();
+        for (i, state) in states.iter_mut().enumerate() {
+            let (gamma_i, lambda_i) = before[i];
+            let (mut d_gamma, mut d_lambda) = (0.0, 0.0);
+            for (j, &(gamma_j, lambda_j)) in before.iter().enumerate() {
+                d_gamma += Self::entry(&self.gamma, i, j) * (gamma_j - gamma_i);
+                d_lambda += Self::entry(&self.lambda, i, j) * (lambda_j - lambda_i);
+            }
+            state.gamma = (gamma_i + dt * d_gamma).max(config.gamma_tolerance);
+            state.lambda = (lambda_i + dt * d_lambda).min(config.lambda_cap);
+            state.compute_emergence();
+        }
+    }
+}
+
+/// Manifolds stepped with a runtime, and how they couple
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CoupledManifolds {
+    /// Manifolds 1.. (manifold 0 is the runtime's state)
+    pub manifolds: Vec<Manifold>,
+    pub coupling: Coupling,
+}
+
+impl CoupledManifolds {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.manifolds.is_empty()
+    }
+
+    /// Manifolds including the runtime's own state
+    pub fn len(&self) -> usize {
+        self.manifolds.len() + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_runtime::DualRuntime;
+
+    fn manifold(gamma: f64) -> Manifold {
+        let mut state = CRSM7State::new();
+        state.gamma = gamma;
+        state.compute_emergence();
+        Manifold {
+            name: "partner".to_string(),
+            state,
+        }
+    }
+
+    #[test]
+    fn test_uncoupled_manifolds_leave_state_alone() {
+        let mut alone = DualRuntime::new();
+        let mut joint = DualRuntime::new().with_coupled_manifold(manifold(0.5));
+        alone.run(20, 0.01);
+        joint.run(20, 0.01);
+        assert!(joint.state.approx_eq(&alone.state, 0.0));
+        assert_eq!(joint.coupled.manifolds[0].state.tau, joint.state.tau);
+    }
+
+    #[test]
+    fn test_coupling_feeds_gamma_between_manifolds() {
+        let mut alone = DualRuntime::new();
+        let mut joint = DualRuntime::new()
+            .with_coupled_manifold(manifold(0.5))
+            .with_coupling(Coupling::uniform(2, 1.0, 0.0));
+        alone.run(20, 0.01);
+        joint.run(20, 0.01);
+        // The partner's higher Γ raises the runtime's; the gap narrows
+        assert!(joint.state.gamma > alone.state.gamma);
+        let partner = &joint.coupled.manifolds[0].state;
+        assert!(partner.gamma - joint.state.gamma < 0.5 - CRSM7State::new().gamma);
+
+        // One-way coupling leaves the source unaffected
+        let coupling = Coupling::new(2).with_link(0, 1, 0.0, 5.0);
+        let mut runtime = DualRuntime::new()
+            .with_coupled_manifold(manifold(0.5))
+            .with_coupling(coupling);
+        runtime.run(20, 0.01);
+        assert!(runtime.state.approx_eq(&alone.state, 0.0));
+    }
+}
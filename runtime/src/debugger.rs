@@ -0,0 +1,431 @@
+//! Debugger
+//!
+//! Conditional breakpoints over a `DualRuntime`. A breakpoint watches one
+//! component of the runtime's state vector, or of a gene's state, and
+//! fires either when a threshold is crossed (`gamma < 1e-6` fires on the
+//! step Γ first falls below 1e-6, and again only after it has risen back
+//! above) or whenever the value moves (`aura.xi drops` fires on every step
+//! AURA's Ξ decreases).
+//!
+//! Conditions are written `<field> < <value>`, `<field> > <value>`,
+//! `<field> drops` or `<field> rises`, where the field is a component
+//! name or symbol (`gamma`, `Γ`), optionally prefixed by a gene id or
+//! name (`aura.xi`, `gene AURA xi`). `Debugger::step` and `continue_for`
+//! advance the runtime and stop at the first step where a breakpoint
+//! fires; `inspect` reads states in between.
+
+use crate::dual_runtime::DualRuntime;
+use crate::manifold::CRSM7State;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A component of a CRSM7 state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StateField {
+    Lambda,
+    Gamma,
+    Phi,
+    Xi,
+    Rho,
+    Theta,
+    Tau,
+}
+
+impl StateField {
+    pub const ALL: [StateField; 7] = [
+        StateField::Lambda,
+        StateField::Gamma,
+        StateField::Phi,
+        StateField::Xi,
+        StateField::Rho,
+        StateField::Theta,
+        StateField::Tau,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            StateField::Lambda => "lambda",
+            StateField::Gamma => "gamma",
+            StateField::Phi => "phi",
+            StateField::Xi => "xi",
+            StateField::Rho => "rho",
+            StateField::Theta => "theta",
+            StateField::Tau => "tau",
+        }
+    }
+
+    pub fn symbol(self) -> &'static str {
+        match self {
+            StateField::Lambda => "Λ",
+            StateField::Gamma => "Γ",
+            StateField::Phi => "Φ",
+            StateField::Xi => "Ξ",
+            StateField::Rho => "ρ",
+            StateField::Theta => "θ",
+            StateField::Tau => "τ",
+        }
+    }
+
+    /// Field by name (any case) or symbol
+    pub fn named(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|f| f.name().eq_ignore_ascii_case(name) || f.symbol() == name)
+    }
+
+    pub fn of(self, state: &CRSM7State) -> f64 {
+        match self {
+            StateField::Lambda => state.lambda,
+            StateField::Gamma => state.gamma,
+            StateField::Phi => state.phi,
+            StateField::Xi => state.xi,
+            StateField::Rho => state.rho,
+            StateField::Theta => state.theta,
+            StateField::Tau => state.tau,
+        }
+    }
+}
+
+/// Whose state a breakpoint watches
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Subject {
+    /// The runtime's state vector
+    State,
+    /// A gene, by id or name (any case)
+    Gene(String),
+}
+
+impl Subject {
+    /// The watched state, `None` for a gene the organism lacks
+    pub fn state<'a>(&self, runtime: &'a DualRuntime) -> Option<&'a CRSM7State> {
+        match self {
+            Subject::State => Some(&runtime.state),
+            Subject::Gene(gene) => runtime
+                .organism
+                .genes
+                .iter()
+                .find(|g| g.id.eq_ignore_ascii_case(gene) || g.name.eq_ignore_ascii_case(gene))
+                .map(|g| &g.state),
+        }
+    }
+}
+
+/// When a breakpoint fires
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Trigger {
+    /// The value falls below the threshold
+    Below(f64),
+    /// The value rises above the threshold
+    Above(f64),
+    /// The value decreased over the step
+    Drops,
+    /// The value increased over the step
+    Rises,
+}
+
+impl Trigger {
+    /// Whether a step taking the value from `previous` to `value` fires
+    fn fires(self, previous: Option<f64>, value: f64) -> bool {
+        match (self, previous) {
+            (Trigger::Below(t), p) => value < t && p.is_none_or(|p| p >= t),
+            (Trigger::Above(t), p) => value > t && p.is_none_or(|p| p <= t),
+            (Trigger::Drops, Some(p)) => value < p,
+            (Trigger::Rises, Some(p)) => value > p,
+            (_, None) => false,
+        }
+    }
+}
+
+/// Why a condition could not be parsed
+#[derive(Debug, Clone, PartialEq)]
+pub enum BreakpointError {
+    /// Not `<field> <op> <value>` or `<field> drops|rises`
+    Malformed(String),
+    UnknownField(String),
+    /// The threshold is not a number
+    BadValue(String),
+}
+
+impl fmt::Display for BreakpointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BreakpointError::Malformed(condition) => write!(
+                f,
+                "expected `<field> < <value>`, `<field> > <value>`, `<field> drops` or \
+                 `<field> rises`, got `{}`",
+                condition
+            ),
+            BreakpointError::UnknownField(field) => write!(f, "unknown field `{}`", field),
+            BreakpointError::BadValue(value) => write!(f, "`{}` is not a number", value),
+        }
+    }
+}
+
+impl std::error::Error for BreakpointError {}
+
+/// A condition on one component of one state
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Breakpoint {
+    pub subject: Subject,
+    pub field: StateField,
+    pub trigger: Trigger,
+}
+
+impl Breakpoint {
+    pub fn new(subject: Subject, field: StateField, trigger: Trigger) -> Self {
+        Self {
+            subject,
+            field,
+            trigger,
+        }
+    }
+
+    /// Parse a condition such as `gamma < 1e-6` or `aura.xi drops`
+    pub fn parse(condition: &str) -> Result<Self, BreakpointError> {
+        let malformed = || BreakpointError::Malformed(condition.to_string());
+        let tokens: Vec<&str> = condition.split_whitespace().collect();
+        let (subject, field, rest) = match tokens.as_slice() {
+            ["gene", gene, field, rest @ ..] => (Subject::Gene(gene.to_string()), *field, rest),
+            [target, rest @ ..] => match target.split_once('.') {
+                Some((gene, field)) => (Subject::Gene(gene.to_string()), field, rest),
+                None => (Subject::State, *target, rest),
+            },
+            [] => return Err(malformed()),
+        };
+        let field = StateField::named(field)
+            .ok_or_else(|| BreakpointError::UnknownField(field.to_string()))?;
+        let threshold = |value: &str| {
+            value
+                .parse::<f64>()
+                .map_err(|_| BreakpointError::BadValue(value.to_string()))
+        };
+        let trigger = match rest {
+            ["drops"] => Trigger::Drops,
+            ["rises"] => Trigger::Rises,
+            ["<", value] => Trigger::Below(threshold(value)?),
+            [">", value] => Trigger::Above(threshold(value)?),
+            _ => return Err(malformed()),
+        };
+        Ok(Self::new(subject, field, trigger))
+    }
+
+    /// Current value of the watched component
+    pub fn value(&self, runtime: &DualRuntime) -> Option<f64> {
+        self.subject.state(runtime).map(|s| self.field.of(s))
+    }
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Subject::Gene(gene) = &self.subject {
+            write!(f, "{}.", gene)?;
+        }
+        write!(f, "{}", self.field.name())?;
+        match self.trigger {
+            Trigger::Below(t) => write!(f, " < {}", t),
+            Trigger::Above(t) => write!(f, " > {}", t),
+            Trigger::Drops => write!(f, " drops"),
+            Trigger::Rises => write!(f, " rises"),
+        }
+    }
+}
+
+/// A breakpoint that fired
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Hit {
+    /// Id returned by `Debugger::break_when`
+    pub breakpoint: usize,
+    /// Steps completed, counting the one that fired it
+    pub step: u64,
+    pub tau: f64,
+    pub previous: Option<f64>,
+    pub value: f64,
+}
+
+/// Why `continue_for` returned
+#[derive(Debug, Clone, PartialEq)]
+pub enum StopReason {
+    Breakpoint(Vec<Hit>),
+    /// The runtime sealed (or was already sealed)
+    Sealed,
+    /// An observer aborted evolution
+    Halted,
+    /// `max_steps` steps ran without a breakpoint firing
+    StepLimit,
+}
+
+/// A breakpoint and the value it saw after the previous step
+#[derive(Debug, Clone)]
+struct Armed {
+    breakpoint: Breakpoint,
+    last: Option<f64>,
+}
+
+/// A runtime stepped under breakpoints
+#[derive(Debug)]
+pub struct Debugger {
+    pub runtime: DualRuntime,
+    /// dt of each step
+    pub dt: f64,
+    /// Indexed by id; removed breakpoints leave a gap so ids stay stable
+    breakpoints: Vec<Option<Armed>>,
+}
+
+impl Debugger {
+    pub fn new(runtime: DualRuntime, dt: f64) -> Self {
+        Self {
+            runtime,
+            dt,
+            breakpoints: Vec::new(),
+        }
+    }
+
+    /// Add a breakpoint, armed at the current values; returns its id
+    pub fn break_when(&mut self, breakpoint: Breakpoint) -> usize {
+        let last = breakpoint.value(&self.runtime);
+        self.breakpoints.push(Some(Armed { breakpoint, last }));
+        self.breakpoints.len() - 1
+    }
+
+    /// Parse `condition` and add it as a breakpoint
+    pub fn break_on(&mut self, condition: &str) -> Result<usize, BreakpointError> {
+        Ok(self.break_when(Breakpoint::parse(condition)?))
+    }
+
+    pub fn remove(&mut self, id: usize) -> Option<Breakpoint> {
+        self.breakpoints
+            .get_mut(id)
+            .and_then(Option::take)
+            .map(|armed| armed.breakpoint)
+    }
+
+    /// Breakpoints with their ids
+    pub fn breakpoints(&self) -> impl Iterator<Item = (usize, &Breakpoint)> {
+        self.breakpoints
+            .iter()
+            .enumerate()
+            .filter_map(|(id, armed)| armed.as_ref().map(|a| (id, &a.breakpoint)))
+    }
+
+    /// Take one step; returns the breakpoints it fired
+    pub fn step(&mut self) -> Vec<Hit> {
+        if self.runtime.sealed || self.runtime.observers.halted() {
+            return Vec::new();
+        }
+        self.runtime.step(self.dt);
+        let runtime = &self.runtime;
+        let mut hits = Vec::new();
+        for (id, armed) in self.breakpoints.iter_mut().enumerate() {
+            let Some(armed) = armed else { continue };
+            let value = armed.breakpoint.value(runtime);
+            if let Some(value) = value {
+                if armed.breakpoint.trigger.fires(armed.last, value) {
+                    hits.push(Hit {
+                        breakpoint: id,
+                        step: runtime.steps,
+                        tau: runtime.state.tau,
+                        previous: armed.last,
+                        value,
+                    });
+                }
+            }
+            armed.last = value;
+        }
+        hits
+    }
+
+    /// Step until a breakpoint fires, the runtime seals or halts, or
+    /// `max_steps` steps have run
+    pub fn continue_for(&mut self, max_steps: usize) -> StopReason {
+        for _ in 0..max_steps {
+            if self.runtime.sealed {
+                return StopReason::Sealed;
+            }
+            if self.runtime.observers.halted() {
+                return StopReason::Halted;
+            }
+            let hits = self.step();
+            if !hits.is_empty() {
+                return StopReason::Breakpoint(hits);
+            }
+        }
+        StopReason::StepLimit
+    }
+
+    /// State of `subject` as it stands
+    pub fn inspect(&self, subject: &Subject) -> Option<&CRSM7State> {
+        subject.state(&self.runtime)
+    }
+
+    pub fn into_runtime(self) -> DualRuntime {
+        self.runtime
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_conditions() {
+        let bp = Breakpoint::parse("Γ < 1e-6").unwrap();
+        assert_eq!(
+            bp,
+            Breakpoint::new(Subject::State, StateField::Gamma, Trigger::Below(1e-6))
+        );
+        let bp = Breakpoint::parse("gene AURA Ξ drops").unwrap();
+        assert_eq!(bp.subject, Subject::Gene("AURA".to_string()));
+        assert_eq!(Breakpoint::parse(&bp.to_string()).unwrap(), bp);
+        assert_eq!(
+            Breakpoint::parse("aura.omega rises"),
+            Err(BreakpointError::UnknownField("omega".to_string()))
+        );
+        assert!(matches!(
+            Breakpoint::parse("gamma <= 1"),
+            Err(BreakpointError::Malformed(_))
+        ));
+        assert!(matches!(
+            Breakpoint::parse("gamma < small"),
+            Err(BreakpointError::BadValue(_))
+        ));
+    }
+
+    #[test]
+    fn test_continue_stops_at_breakpoints() {
+        let mut debugger = Debugger::new(DualRuntime::new(), 0.1);
+        let gamma = debugger.runtime.state.gamma;
+        let id = debugger
+            .break_on(&format!("gamma < {}", gamma / 2.0))
+            .unwrap();
+        debugger.break_on("aura.xi drops").unwrap();
+
+        let StopReason::Breakpoint(hits) = debugger.continue_for(100) else {
+            panic!("breakpoint did not fire");
+        };
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].breakpoint, id);
+        assert!(hits[0].value < gamma / 2.0 && hits[0].previous.unwrap() >= gamma / 2.0);
+        assert_eq!(hits[0].step, 7);
+
+        // A crossed threshold does not fire again while it stays crossed
+        assert!(debugger.step().is_empty());
+
+        // Genes do not evolve in the dual runtime, until a perturbation
+        let aura = debugger
+            .inspect(&Subject::Gene("AURA".to_string()))
+            .unwrap();
+        let xi = aura.xi;
+        debugger.runtime.organism.genes[0].state.gamma *= 2.0;
+        debugger.runtime.organism.genes[0].state.compute_emergence();
+        let hits = debugger.step();
+        assert_eq!(hits.len(), 1);
+        assert!(hits[0].value < xi);
+
+        assert!(debugger.remove(id).is_some());
+        assert_eq!(debugger.breakpoints().count(), 1);
+        assert_eq!(debugger.continue_for(5), StopReason::StepLimit);
+    }
+}
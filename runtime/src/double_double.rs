@@ -0,0 +1,324 @@
+//! Double-Double Arithmetic
+//!
+//! `DoubleDouble` represents a value as the unevaluated sum of two f64s
+//! (hi + lo, |lo| ≤ ulp(hi)/2), giving about 32 significant digits with
+//! plain f64 operations: error-free transforms for + and ×, Newton
+//! refinement for ÷, and range-reduced Taylor series for exp and sin.
+//!
+//! It implements `Real`, so `CompactState<DoubleDouble>` evolves Γ and
+//! Ξ = ΛΦ/Γ in high precision for studies near the Γ floor. Unlike f64
+//! and f32 states it does not cap Ξ at `EMERGENCE_MAX` once Γ reaches εΓ:
+//! the cap exists to hide precision loss that double-double does not
+//! suffer, so Ξ stays the exact ratio for any Γ > 0. Lower
+//! `RuntimeConfig::gamma_tolerance` to let Γ decay past 1e-9.
+//! `DualRuntime::with_high_precision` runs a runtime's own state this way.
+
+use crate::precision::Real;
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub};
+
+/// hi + lo with |lo| ≤ ulp(hi)/2
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DoubleDouble {
+    pub hi: f64,
+    pub lo: f64,
+}
+
+const LN_2: DoubleDouble = DoubleDouble {
+    hi: std::f64::consts::LN_2,
+    lo: 2.3190468138462996e-17,
+};
+const TAU: DoubleDouble = DoubleDouble {
+    hi: std::f64::consts::TAU,
+    lo: 2.4492935982947064e-16,
+};
+const DEGREE: DoubleDouble = DoubleDouble {
+    hi: 0.017453292519943295,
+    lo: 2.9486522708701687e-19,
+};
+
+/// Halvings of the reduced argument before the exp series
+const EXP_HALVINGS: i32 = 9;
+
+/// a + b exactly, assuming |a| ≥ |b|
+fn quick_two_sum(a: f64, b: f64) -> DoubleDouble {
+    let hi = a + b;
+    DoubleDouble {
+        hi,
+        lo: b - (hi - a),
+    }
+}
+
+/// a + b exactly
+fn two_sum(a: f64, b: f64) -> DoubleDouble {
+    let hi = a + b;
+    let v = hi - a;
+    DoubleDouble {
+        hi,
+        lo: (a - (hi - v)) + (b - v),
+    }
+}
+
+/// a × b exactly
+fn two_prod(a: f64, b: f64) -> DoubleDouble {
+    let hi = a * b;
+    DoubleDouble {
+        hi,
+        lo: a.mul_add(b, -hi),
+    }
+}
+
+impl DoubleDouble {
+    pub const ZERO: Self = Self { hi: 0.0, lo: 0.0 };
+
+    pub fn new(value: f64) -> Self {
+        Self { hi: value, lo: 0.0 }
+    }
+
+    /// Nearest f64
+    pub fn value(self) -> f64 {
+        self.hi + self.lo
+    }
+
+    pub fn abs(self) -> Self {
+        if self.hi < 0.0 {
+            -self
+        } else {
+            self
+        }
+    }
+
+    /// Multiply by 2ⁿ (exact)
+    fn scale_by_power_of_two(self, n: i32) -> Self {
+        let factor = 2f64.powi(n);
+        Self {
+            hi: self.hi * factor,
+            lo: self.lo * factor,
+        }
+    }
+
+    /// Σ terms of a power series in x starting at `first`, each term the
+    /// previous times x / next(k), until terms no longer change the sum
+    fn series(first: Self, x: Self, next: impl Fn(u32) -> f64) -> Self {
+        let mut sum = first;
+        let mut term = first;
+        for k in 1..64 {
+            term = term * x / Self::new(next(k));
+            let updated = sum + term;
+            if updated == sum {
+                break;
+            }
+            sum = updated;
+        }
+        sum
+    }
+
+    pub fn exp(self) -> Self {
+        if self.hi > 709.0 {
+            return Self::new(f64::INFINITY);
+        }
+        if self.hi < -745.0 {
+            return Self::ZERO;
+        }
+        // x = k·ln2 + r, |r| ≤ ln2/2; e^r = (e^(r/2⁹))^(2⁹), squared as
+        // e^y − 1 (2s + s²) so the leading 1 does not swamp the digits
+        let k = (self.hi / LN_2.hi).round();
+        let r = (self - LN_2 * Self::new(k)).scale_by_power_of_two(-EXP_HALVINGS);
+        let mut s = Self::series(r, r, |k| f64::from(k + 1));
+        for _ in 0..EXP_HALVINGS {
+            s = s * (s + Self::new(2.0));
+        }
+        (s + Self::ONE).scale_by_power_of_two(k as i32)
+    }
+
+    pub fn sin(self) -> Self {
+        // x = k·2π + r, |r| ≤ π
+        let k = (self.hi / TAU.hi).round();
+        let r = self - TAU * Self::new(k);
+        let r2 = -(r * r);
+        Self::series(r, r2, |k| f64::from((2 * k) * (2 * k + 1)))
+    }
+}
+
+impl From<f64> for DoubleDouble {
+    fn from(value: f64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl fmt::Display for DoubleDouble {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:e} + {:e}", self.hi, self.lo)
+    }
+}
+
+impl PartialOrd for DoubleDouble {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.hi.partial_cmp(&other.hi)? {
+            Ordering::Equal => self.lo.partial_cmp(&other.lo),
+            ordering => Some(ordering),
+        }
+    }
+}
+
+impl Add for DoubleDouble {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let s = two_sum(self.hi, other.hi);
+        let t = two_sum(self.lo, other.lo);
+        let s = quick_two_sum(s.hi, s.lo + t.hi);
+        quick_two_sum(s.hi, s.lo + t.lo)
+    }
+}
+
+impl Sub for DoubleDouble {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl Mul for DoubleDouble {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let p = two_prod(self.hi, other.hi);
+        quick_two_sum(p.hi, p.lo + (self.hi * other.lo + self.lo * other.hi))
+    }
+}
+
+impl Div for DoubleDouble {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        let q1 = self.hi / other.hi;
+        let r = self - other * Self::new(q1);
+        let q2 = r.hi / other.hi;
+        let r = r - other * Self::new(q2);
+        let q3 = r.hi / other.hi;
+        quick_two_sum(q1, q2) + Self::new(q3)
+    }
+}
+
+impl Neg for DoubleDouble {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+}
+
+impl AddAssign for DoubleDouble {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl MulAssign for DoubleDouble {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl Real for DoubleDouble {
+    const ONE: Self = Self { hi: 1.0, lo: 0.0 };
+    const CAPS_EMERGENCE: bool = false;
+
+    fn from_f64(value: f64) -> Self {
+        Self::new(value)
+    }
+
+    fn to_f64(self) -> f64 {
+        self.value()
+    }
+
+    fn exp(self) -> Self {
+        DoubleDouble::exp(self)
+    }
+
+    fn sin(self) -> Self {
+        DoubleDouble::sin(self)
+    }
+
+    fn to_radians(self) -> Self {
+        self * DEGREE
+    }
+
+    fn min(self, other: Self) -> Self {
+        if other < self {
+            other
+        } else {
+            self
+        }
+    }
+
+    fn max(self, other: Self) -> Self {
+        if other > self {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RuntimeConfig;
+    use crate::manifold::{CRSM7State, EMERGENCE_MAX};
+    use crate::precision::CompactState;
+
+    fn dd(value: f64) -> DoubleDouble {
+        DoubleDouble::new(value)
+    }
+
+    #[test]
+    fn test_arithmetic_beyond_f64() {
+        // 1 + 2⁻⁷⁰ is not representable in f64
+        let tiny = dd(2f64.powi(-70));
+        let sum = dd(1.0) + tiny;
+        assert_eq!((sum - dd(1.0)).hi, tiny.hi);
+
+        let third = dd(1.0) / dd(3.0);
+        assert!(((third * dd(3.0)) - dd(1.0)).abs().hi < 1e-31);
+        assert!(
+            (dd(1.0).exp()
+                - DoubleDouble {
+                    hi: std::f64::consts::E,
+                    lo: 1.4456468917292502e-16
+                })
+            .abs()
+            .hi < 1e-30
+        );
+        assert!((dd(30.0).to_radians().sin() - dd(0.5)).abs().hi < 1e-30);
+        assert!(dd(-1.0) < dd(-1.0) + tiny);
+    }
+
+    #[test]
+    fn test_gamma_decay_and_uncapped_emergence() {
+        let config = RuntimeConfig::default().with_gamma_tolerance(0.0);
+        let start = CRSM7State::new();
+        let mut high = CompactState::<DoubleDouble>::from_state(&start);
+        let mut plain = CompactState::<f64>::from_state(&start);
+        for _ in 0..3000 {
+            high.evolve_with(0.01, high.hamiltonian(), &config);
+            plain.evolve_with(0.01, plain.hamiltonian(), &config);
+        }
+        // Γ(τ) = Γ₀ e^(−τ), τ = 3000 · 0.01 (0.01 as the f64 nearest it)
+        let exact = dd(start.gamma) * (dd(-3000.0) * dd(0.01)).exp();
+        let error = |gamma: DoubleDouble| ((gamma - exact) / exact).abs().hi;
+        assert!(error(high.gamma) < 1e-28, "{:e}", error(high.gamma));
+        assert!(error(dd(plain.gamma)) > 1e-15);
+
+        // Γ is far below εΓ: f64 caps Ξ, double-double keeps ΛΦ/Γ
+        assert_eq!(plain.xi, EMERGENCE_MAX);
+        assert!(high.xi.hi > 1e12);
+        assert_eq!(high.xi, high.lambda * high.phi / high.gamma);
+    }
+}
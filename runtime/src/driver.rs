@@ -0,0 +1,186 @@
+//! Async Runtime Driver
+//!
+//! `AsyncDualRuntime` moves a `DualRuntime` into a tokio task that calls
+//! `step` on a fixed interval, enabled with the `async` feature. The handle
+//! pauses, resumes and cancels the task and streams the observables after
+//! every step. The stream only holds the latest snapshot, so a slow
+//! consumer skips intermediate steps instead of holding the runtime back.
+//!
+//! The task ends when the runtime seals, an observer aborts, `cancel` is
+//! called or the handle is dropped; `join` hands the runtime back.
+
+use crate::dual_runtime::DualRuntime;
+use crate::shadow::Observables;
+use std::time::Duration;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use tokio::time::{self, MissedTickBehavior};
+use tokio_stream::wrappers::WatchStream;
+
+/// Requested state of the driving task
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DriverState {
+    Running,
+    Paused,
+    Cancelled,
+}
+
+/// Handle to a runtime stepping in a tokio task
+pub struct AsyncDualRuntime {
+    control: watch::Sender<DriverState>,
+    snapshots: watch::Receiver<Observables>,
+    task: JoinHandle<DualRuntime>,
+}
+
+impl AsyncDualRuntime {
+    /// Spawn a task stepping `runtime` by dt every `interval`; must be
+    /// called within a tokio runtime
+    pub fn spawn(runtime: DualRuntime, interval: Duration, dt: f64) -> Self {
+        let (control, control_rx) = watch::channel(DriverState::Running);
+        let (snapshot_tx, snapshots) = watch::channel(Observables::capture(&runtime));
+        let task = tokio::spawn(drive(runtime, interval, dt, control_rx, snapshot_tx));
+        Self {
+            control,
+            snapshots,
+            task,
+        }
+    }
+
+    pub fn pause(&self) {
+        self.set(DriverState::Paused);
+    }
+
+    pub fn resume(&self) {
+        self.set(DriverState::Running);
+    }
+
+    /// Stop stepping; `join` then returns the runtime
+    pub fn cancel(&self) {
+        self.set(DriverState::Cancelled);
+    }
+
+    pub fn state(&self) -> DriverState {
+        *self.control.borrow()
+    }
+
+    /// Whether the task has stopped
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+
+    /// Observables after the most recent step
+    pub fn latest(&self) -> Observables {
+        *self.snapshots.borrow()
+    }
+
+    /// Stream of observables, starting with the latest
+    pub fn snapshots(&self) -> WatchStream<Observables> {
+        WatchStream::new(self.snapshots.clone())
+    }
+
+    /// Wait for the task to stop and take the runtime back
+    pub async fn join(self) -> DualRuntime {
+        let Self { control, task, .. } = self;
+        let runtime = task.await.expect("runtime task panicked");
+        drop(control);
+        runtime
+    }
+
+    fn set(&self, state: DriverState) {
+        self.control.send_if_modified(|current| {
+            let changed = *current != state && *current != DriverState::Cancelled;
+            if changed {
+                *current = state;
+            }
+            changed
+        });
+    }
+}
+
+async fn drive(
+    mut runtime: DualRuntime,
+    interval: Duration,
+    dt: f64,
+    mut control: watch::Receiver<DriverState>,
+    snapshots: watch::Sender<Observables>,
+) -> DualRuntime {
+    let mut ticker = time::interval(interval);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    loop {
+        let state = *control.borrow_and_update();
+        match state {
+            DriverState::Cancelled => break,
+            DriverState::Paused => {
+                if control.changed().await.is_err() {
+                    break;
+                }
+                ticker.reset();
+                continue;
+            }
+            DriverState::Running => {}
+        }
+
+        tokio::select! {
+            _ = ticker.tick() => {}
+            changed = control.changed() => {
+                if changed.is_err() {
+                    break;
+                }
+                continue;
+            }
+        }
+
+        runtime.step(dt);
+        snapshots.send_replace(Observables::capture(&runtime));
+        if runtime.sealed || runtime.observers.halted() {
+            break;
+        }
+    }
+    runtime
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio_stream::StreamExt;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_pause_resume_cancel() {
+        let driver = AsyncDualRuntime::spawn(DualRuntime::new(), Duration::from_millis(10), 0.01);
+        let mut stream = driver.snapshots();
+        stream.next().await.unwrap();
+        let first = stream.next().await.unwrap();
+        assert!(first.tau > 0.0);
+
+        driver.pause();
+        time::sleep(Duration::from_millis(5)).await;
+        let paused_at = driver.latest().tau;
+        time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(driver.latest().tau, paused_at);
+
+        driver.resume();
+        time::sleep(Duration::from_millis(55)).await;
+        assert!(driver.latest().tau > paused_at);
+
+        driver.cancel();
+        driver.resume();
+        assert_eq!(driver.state(), DriverState::Cancelled);
+        let runtime = driver.join().await;
+        assert!(runtime.steps > 0);
+        assert!((runtime.state.tau - runtime.steps as f64 * 0.01).abs() < 1e-9);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_task_ends_when_sealed() {
+        let mut runtime = DualRuntime::new();
+        runtime.state.gamma = runtime.config.gamma_tolerance;
+        runtime.state.lambda = 0.999;
+        runtime.state.phi = 100.0;
+        runtime.state.compute_emergence();
+
+        let driver = AsyncDualRuntime::spawn(runtime, Duration::from_millis(1), 0.01);
+        let runtime = driver.join().await;
+        assert!(runtime.sealed);
+        assert_eq!(runtime.steps, 1);
+    }
+}
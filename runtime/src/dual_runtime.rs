@@ -22,10 +22,42 @@
 //! - ∂τ C7D = H_CRSM(C7D)
 //! - H_CRSM = DΛ∇7D − KΓ + Π±Jθ + Ω∞
 
-use crate::manifold::{CRSM7State, EMERGENCE_THRESHOLD, GAMMA_TOLERANCE};
+use crate::adaptive::{AdaptiveReport, StepController};
+use crate::certificate::{extend_trajectory_hash, SovereigntyCertificate, TRAJECTORY_HASH_SEED};
+use crate::checkpoint::Checkpoint;
+use crate::collapse::{CollapseRule, CollapseRules};
+use crate::config::RuntimeConfig;
+use crate::coupling::{CoupledManifolds, Coupling};
+#[cfg(feature = "high-precision")]
+use crate::double_double::DoubleDouble;
+use crate::events::{EventLog, RuntimeEvent};
+#[cfg(feature = "wgpu")]
+use crate::gpu::GpuEvolver;
+use crate::interpreter::{self, ExecutionTrace};
+#[cfg(feature = "invariants")]
+use crate::invariants::InvariantMonitor;
+use crate::manifold::{CRSM7State, Integrator, IntegratorKind, Operators, EMERGENCE_THRESHOLD};
+use crate::observer::{Observer, Observers, StepEvent};
 use crate::organism::{Organism, OrganismExecutor};
-use crate::projectors::{bifurcate, involution_j, pi_minus, pi_plus};
+use crate::perturbation::{Inversion, Perturbation, PerturbationSchedule};
+use crate::precision::CompactMeshWeights;
+#[cfg(feature = "high-precision")]
+use crate::precision::{CompactState, Real};
+use crate::profile::{to_f32_precision, RuntimeProfile};
+use crate::profiler::{PhaseTimer, StepPhase, StepProfiler};
+use crate::projectors::{DualityConfig, InvolutionError};
+use crate::rewind::{RewindBuffer, RewindFrame};
+use crate::rng::SeededRng;
+use crate::shadow::Observables;
+use crate::unseal::UnsealRecord;
+use crate::watch::{ResetPolicy, SwapReport};
+use crate::watches::{Watch, Watches};
+use crate::wave::WaveFunction;
+use dnalang_compiler::ir::OmegaIR;
 use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::io;
+use std::path::Path;
 
 /// Manifold representation for the runtime
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -79,6 +111,20 @@ impl Complex {
         }
     }
 
+    pub fn add(&self, other: &Complex) -> Complex {
+        Complex {
+            re: self.re + other.re,
+            im: self.im + other.im,
+        }
+    }
+
+    pub fn conjugate(&self) -> Complex {
+        Complex {
+            re: self.re,
+            im: -self.im,
+        }
+    }
+
     pub fn exp_i(theta: f64) -> Complex {
         Complex {
             re: theta.cos(),
@@ -87,7 +133,7 @@ impl Complex {
     }
 }
 
-/// Z3 Mesh weights for topology
+/// Z3 Mesh weights for topology (`CompactMeshWeights` stores them in f32)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Z3MeshWeights {
     pub weights: Vec<f64>,
@@ -102,23 +148,16 @@ impl Default for Z3MeshWeights {
 }
 
 impl Z3MeshWeights {
+    /// Empty weights, filled per gene as the mesh is evolved
+    pub fn sparse() -> Self {
+        Self {
+            weights: Vec::new(),
+        }
+    }
+
     /// Compute mesh weight: w_ij = (ΔΛ)² + (ΔΓ)² + (ΔΦ)² + (ΔΞ)² + (Δρ)² + (Δθ)² + (Δτ)²
     pub fn compute_weight(state_i: &CRSM7State, state_j: &CRSM7State) -> f64 {
-        let d_lambda = state_i.lambda - state_j.lambda;
-        let d_gamma = state_i.gamma - state_j.gamma;
-        let d_phi = state_i.phi - state_j.phi;
-        let d_xi = state_i.xi - state_j.xi;
-        let d_rho = state_i.rho - state_j.rho;
-        let d_theta = state_i.theta - state_j.theta;
-        let d_tau = state_i.tau - state_j.tau;
-
-        d_lambda.powi(2)
-            + d_gamma.powi(2)
-            + d_phi.powi(2)
-            + d_xi.powi(2)
-            + d_rho.powi(2)
-            + d_theta.powi(2)
-            + d_tau.powi(2)
+        CompactMeshWeights::<f64>::compute_weight(state_i, state_j)
     }
 }
 
@@ -127,18 +166,109 @@ impl Z3MeshWeights {
 /// Executes organisms and manifolds together using the CRSM Hamiltonian.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DualRuntime {
-    /// Quantum state Ψ
-    pub psi: Complex,
+    /// Quantum state Ψ (one component per mode)
+    pub psi: WaveFunction,
     /// 7D CRSM state
     pub state: CRSM7State,
     /// Organism being executed
     pub organism: Organism,
     /// Manifold configuration
     pub manifold: Manifold,
+    /// Further manifolds stepped jointly with `state`, and their coupling
+    #[serde(default)]
+    pub coupled: CoupledManifolds,
     /// Sealed status (sovereignty achieved)
     pub sealed: bool,
     /// Z3 mesh weights
     pub mesh_weights: Z3MeshWeights,
+    /// Duality operator configuration, used by every Π± projection of Ψ
+    /// (checkpoints made before it existed load with the default JΨ = −Ψ)
+    #[serde(default)]
+    pub duality: DualityConfig,
+    /// Resource profile chosen at construction
+    #[serde(default)]
+    pub profile: RuntimeProfile,
+    /// Observables after each step, most recent last (bounded by the profile)
+    #[serde(default)]
+    pub history: VecDeque<Observables>,
+    /// Compiled program driving evolution and collapse, if one was loaded
+    #[serde(default)]
+    pub program: Option<OmegaIR>,
+    /// Scheme used to evolve the state (checkpoints without one use Euler)
+    #[serde(default)]
+    pub integrator: IntegratorKind,
+    /// Tunable evolution and collapse constants
+    #[serde(default)]
+    pub config: RuntimeConfig,
+    /// Timed interventions applied during `step`
+    #[serde(default)]
+    pub perturbations: PerturbationSchedule,
+    /// Inversions of injected perturbations still being applied
+    #[serde(default)]
+    pub inversions: Vec<Inversion>,
+    /// Steps completed
+    #[serde(default)]
+    pub steps: u64,
+    /// Source of the evolution noise; saved so resumed runs draw the same
+    /// sequence
+    #[serde(default)]
+    pub rng: SeededRng,
+    /// Structured per-step events (bounded by the profile)
+    #[serde(default)]
+    pub events: EventLog,
+    /// States before the most recent steps, for `rewind` (off by default)
+    #[serde(default)]
+    pub rewind: RewindBuffer,
+    /// Step-size controller used by `run_adaptive`
+    #[serde(default)]
+    pub step_controller: StepController,
+    /// Phase timings of each step (off unless `with_profiler`)
+    #[serde(skip)]
+    pub profiler: Option<StepProfiler>,
+    /// Hash of the observables of every step so far
+    #[serde(default = "trajectory_hash_seed")]
+    pub trajectory_hash: u64,
+    /// Issued when the runtime seals
+    #[serde(default)]
+    pub certificate: Option<SovereigntyCertificate>,
+    /// Every `unseal`, oldest first
+    #[serde(default)]
+    pub unseal_audit: Vec<UnsealRecord>,
+    /// Collapse rule that last fired during the current step
+    #[serde(skip)]
+    fired_rule: Option<String>,
+    /// Every collapse rule that fired during the most recent step, in order
+    #[serde(skip)]
+    fired_rules: Vec<String>,
+    /// Operators evolving the state in place of the integrator and the
+    /// program's Hamiltonian (not serialized; restored runtimes use the
+    /// integrator)
+    #[serde(skip)]
+    pub operators: Option<Operators>,
+    /// Collapse rules evaluated after each step, in order (not serialized;
+    /// restored runtimes get the built-ins)
+    #[serde(skip)]
+    pub collapse_rules: CollapseRules,
+    /// Callbacks invoked from `step` (not serialized or cloned)
+    #[serde(skip)]
+    pub observers: Observers,
+    /// Expressions evaluated after every step (not serialized; callbacks
+    /// are not cloned)
+    #[serde(skip)]
+    pub watches: Watches,
+    /// Identity checks run after every step
+    #[cfg(feature = "invariants")]
+    #[serde(skip)]
+    pub invariants: InvariantMonitor,
+    /// Double-double copy of `state` evolved in its place (off unless
+    /// `with_high_precision`; not serialized)
+    #[cfg(feature = "high-precision")]
+    #[serde(skip)]
+    pub high_precision: Option<CompactState<DoubleDouble>>,
+}
+
+fn trajectory_hash_seed() -> u64 {
+    TRAJECTORY_HASH_SEED
 }
 
 impl Default for DualRuntime {
@@ -150,14 +280,316 @@ impl Default for DualRuntime {
 impl DualRuntime {
     /// Create a new dual runtime
     pub fn new() -> Self {
-        Self {
-            psi: Complex::default(),
+        Self::with_profile(RuntimeProfile::standard())
+    }
+
+    /// Create a runtime with the given resource profile
+    pub fn with_profile(profile: RuntimeProfile) -> Self {
+        let mesh_weights = if profile.sparse_mesh {
+            Z3MeshWeights::sparse()
+        } else {
+            Z3MeshWeights::default()
+        };
+        let events = EventLog::new(profile.event_capacity);
+        let mut runtime = Self {
+            psi: WaveFunction::default(),
             state: CRSM7State::new(),
             organism: OrganismExecutor::create_standard_organism(),
             manifold: Manifold::default(),
+            coupled: CoupledManifolds::default(),
             sealed: false,
-            mesh_weights: Z3MeshWeights::default(),
+            mesh_weights,
+            duality: DualityConfig::default(),
+            profile,
+            history: VecDeque::new(),
+            program: None,
+            integrator: IntegratorKind::default(),
+            config: RuntimeConfig::default(),
+            perturbations: PerturbationSchedule::default(),
+            inversions: Vec::new(),
+            steps: 0,
+            rng: SeededRng::default(),
+            events,
+            rewind: RewindBuffer::default(),
+            step_controller: StepController::default(),
+            profiler: None,
+            trajectory_hash: TRAJECTORY_HASH_SEED,
+            certificate: None,
+            unseal_audit: Vec::new(),
+            fired_rule: None,
+            fired_rules: Vec::new(),
+            operators: None,
+            collapse_rules: CollapseRules::builtin(),
+            observers: Observers::default(),
+            watches: Watches::default(),
+            #[cfg(feature = "invariants")]
+            invariants: InvariantMonitor::default(),
+            #[cfg(feature = "high-precision")]
+            high_precision: None,
+        };
+        runtime.apply_precision();
+        runtime
+    }
+
+    /// Start from a (possibly multi-mode) wave function
+    pub fn with_wave_function(mut self, psi: WaveFunction) -> Self {
+        self.psi = psi;
+        self
+    }
+
+    /// Select the integrator used by `step`
+    pub fn with_integrator(mut self, integrator: IntegratorKind) -> Self {
+        self.integrator = integrator;
+        self
+    }
+
+    /// Evolve the state in double-double precision
+    ///
+    /// Γ and Ξ = ΛΦ/Γ are carried to ~32 digits between steps and Ξ is not
+    /// capped at `EMERGENCE_MAX` (lower `RuntimeConfig::gamma_tolerance`
+    /// to let Γ decay past 1e-9). Evolution uses the built-in law with the
+    /// program's Hamiltonian value, in place of operators and the
+    /// integrator; coupled manifolds evolve as before. Whenever anything
+    /// else changes the state (noise, coupling, perturbations, collapse)
+    /// the copy restarts from it.
+    #[cfg(feature = "high-precision")]
+    pub fn with_high_precision(mut self) -> Self {
+        self.high_precision = Some(CompactState::from_state(&self.state));
+        self
+    }
+
+    /// Replace the evolution and collapse constants (the built-in
+    /// evolution operators, if any, take theirs from `config` too)
+    pub fn with_config(mut self, config: RuntimeConfig) -> Self {
+        if let Some(operators) = &mut self.operators {
+            operators.configure(&config);
+        }
+        self.config = config;
+        self
+    }
+
+    /// Project Ψ with `duality`'s J, refusing one that fails verification
+    pub fn with_duality(mut self, duality: DualityConfig) -> Result<Self, InvolutionError> {
+        let verification = duality.verify();
+        if !verification.passed {
+            let error = verification
+                .j_squared_error
+                .max(verification.completeness_error)
+                .max(verification.idempotence_error)
+                .max(verification.orthogonality_error);
+            return Err(if error.is_finite() {
+                InvolutionError::NotInvolution { error }
+            } else {
+                InvolutionError::NotSquare
+            });
         }
+        self.duality = duality.verified();
+        Ok(self)
+    }
+
+    /// Seed the generator driving evolution noise
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = SeededRng::new(seed);
+        self
+    }
+
+    /// Time the phases of every step
+    pub fn with_profiler(mut self) -> Self {
+        self.profiler = Some(StepProfiler::new());
+        self
+    }
+
+    /// Choose dt for `run_adaptive` with `controller`
+    pub fn with_step_controller(mut self, controller: StepController) -> Self {
+        self.step_controller = controller;
+        self
+    }
+
+    /// Keep the states before the last `capacity` steps for `rewind`
+    pub fn with_rewind_capacity(mut self, capacity: usize) -> Self {
+        self.rewind = RewindBuffer::new(capacity);
+        self
+    }
+
+    /// Schedule timed interventions
+    pub fn with_perturbations(mut self, perturbations: PerturbationSchedule) -> Self {
+        self.perturbations = perturbations;
+        self
+    }
+
+    /// Evolve the state with `operators` instead of the integrator; the
+    /// built-in operators take their constants from the runtime's config
+    pub fn with_operators(mut self, mut operators: Operators) -> Self {
+        operators.configure(&self.config);
+        self.operators = Some(operators);
+        self
+    }
+
+    /// Step `manifold` jointly with the runtime's state
+    pub fn with_coupled_manifold(mut self, manifold: Manifold) -> Self {
+        self.coupled.manifolds.push(manifold);
+        self
+    }
+
+    /// Couple the runtime's state (index 0) and the coupled manifolds
+    pub fn with_coupling(mut self, coupling: Coupling) -> Self {
+        self.coupled.coupling = coupling;
+        self
+    }
+
+    /// Evaluate `rule` after the existing collapse rules
+    pub fn with_collapse_rule(mut self, rule: impl CollapseRule + 'static) -> Self {
+        self.collapse_rules.push(rule);
+        self
+    }
+
+    /// Register an observer
+    pub fn with_observer(mut self, observer: impl Observer + 'static) -> Self {
+        self.add_observer(Box::new(observer));
+        self
+    }
+
+    /// Evaluate `watch` after every step; returns its id
+    pub fn watch(&mut self, watch: Watch) -> usize {
+        self.watches.add(watch)
+    }
+
+    /// Register an observer on a running runtime
+    pub fn add_observer(&mut self, observer: Box<dyn Observer>) {
+        self.observers.push(observer);
+    }
+
+    /// Load a compiled program: Ψ and the state start from the bound Z3
+    /// state, the organism is rebuilt from the gene ops, the gene ops run
+    /// once, and later steps use the program's Hamiltonian terms and
+    /// collapse rules. The IR's metric is not read (g follows the state)
+    pub fn load_ir(&mut self, ir: &OmegaIR) -> ExecutionTrace {
+        let z3 = &ir.z3_state;
+        self.psi = WaveFunction::single(Complex::new(z3.psi_real, z3.psi_imag));
+        self.state.lambda = z3.lambda;
+        self.state.gamma = z3.gamma;
+        self.state.phi = z3.phi;
+        self.state.compute_emergence();
+        // IR from before the organism name was carried has none
+        let name = match ir.organism.as_str() {
+            "" => "Program",
+            name => name,
+        };
+        self.organism = Organism::from_ir(name, ir);
+        self.mesh_weights.weights.clear();
+        self.sealed = false;
+        self.certificate = None;
+        self.program = Some(ir.clone());
+
+        let trace = interpreter::execute_gene_ops(self, ir);
+        if self.sealed {
+            self.certificate = Some(SovereigntyCertificate::issue(self, None));
+        }
+        self.apply_precision();
+        trace
+    }
+
+    /// Swap in a recompiled program while running: Ψ and the state vector
+    /// are kept, genes whose op is unchanged keep their state, and new or
+    /// changed genes start from the state chosen by `policy`. Gene ops are
+    /// not re-run. The organism goes in through `swap_organism`, which
+    /// records `OrganismSwapped`. A sealed runtime is unsealed through
+    /// `unseal("hot swap")` so the new program evolves; without
+    /// `RuntimeConfig::allow_unseal` it stays sealed and the report says so.
+    pub fn hot_swap(&mut self, ir: &OmegaIR, policy: ResetPolicy) -> SwapReport {
+        let op_of = |ir: &OmegaIR, name: &str| {
+            ir.gene_ops
+                .iter()
+                .find(|op| op.name == name)
+                .map(|op| op.op_type.clone())
+        };
+        let name = self.organism.name.clone();
+        let mut organism = Organism::from_ir(&name, ir);
+        let mut report = SwapReport::default();
+
+        for gene in &mut organism.genes {
+            let unchanged = match &self.program {
+                Some(old) => op_of(old, &gene.id) == op_of(ir, &gene.id),
+                None => true,
+            };
+            match self.organism.genes.iter().find(|g| g.id == gene.id) {
+                Some(old) if unchanged => {
+                    gene.state = old.state.clone();
+                    gene.bound = old.bound;
+                    report.preserved.push(gene.id.clone());
+                }
+                _ => {
+                    gene.state = policy.initial_state(&self.state);
+                    report.reset.push(gene.id.clone());
+                }
+            }
+        }
+        report.removed = self
+            .organism
+            .genes
+            .iter()
+            .filter(|old| !organism.genes.iter().any(|g| g.id == old.id))
+            .map(|old| old.id.clone())
+            .collect();
+
+        self.swap_organism(organism);
+        self.program = Some(ir.clone());
+        if self.sealed {
+            report.unsealed = self.unseal("hot swap").is_ok();
+            report.still_sealed = !report.unsealed;
+        }
+        report
+    }
+
+    /// Replace the running organism without restarting evolution: τ, Ψ and
+    /// the state vector are kept, genes take the states `organism` carries,
+    /// and mesh weights are recomputed on the next step. Records an
+    /// `OrganismSwapped` event and returns the previous organism.
+    pub fn swap_organism(&mut self, organism: Organism) -> Organism {
+        let ids = |o: &Organism| -> Vec<String> { o.genes.iter().map(|g| g.id.clone()).collect() };
+        let (old_ids, new_ids) = (ids(&self.organism), ids(&organism));
+        self.events.record(RuntimeEvent::OrganismSwapped {
+            step: self.steps,
+            tau: self.state.tau,
+            organism: organism.name.clone(),
+            added: new_ids
+                .iter()
+                .filter(|id| !old_ids.contains(id))
+                .cloned()
+                .collect(),
+            removed: old_ids
+                .iter()
+                .filter(|id| !new_ids.contains(id))
+                .cloned()
+                .collect(),
+        });
+        self.mesh_weights.weights.clear();
+        std::mem::replace(&mut self.organism, organism)
+    }
+
+    /// Apply `perturbation` now and queue its inversion over the next
+    /// `config.inversion_steps` steps (axiom A4); false if it had no target
+    pub fn perturb(&mut self, perturbation: Perturbation) -> bool {
+        let inversion = Inversion::of(&perturbation, self, self.config.inversion_steps);
+        if !perturbation.apply(self) {
+            return false;
+        }
+        self.inversions.extend(inversion);
+        self.events.record(RuntimeEvent::Perturbed {
+            step: self.steps,
+            tau: self.state.tau,
+            perturbation,
+        });
+        true
+    }
+
+    fn advance_inversions(&mut self) {
+        if self.inversions.is_empty() {
+            return;
+        }
+        let mut inversions = std::mem::take(&mut self.inversions);
+        inversions.retain_mut(|inversion| !inversion.advance(self));
+        self.inversions = inversions;
     }
 
     /// Step the runtime forward by dt
@@ -165,44 +597,247 @@ impl DualRuntime {
     /// Implements:
     /// Ψ(τ+1) = stabilize(exp(∇7D − KΓ + Π±Jθ) Ψ(τ) ⊗ bind_Z3(C7D))
     pub fn step(&mut self, dt: f64) {
-        if self.sealed {
-            return; // No evolution after sealing
+        if self.sealed || self.observers.halted() {
+            return; // No evolution after sealing or an observer abort
+        }
+        let timer = PhaseTimer::new(self.profiler.is_some());
+        if self.rewind.capacity() > 0 {
+            self.rewind.push(RewindFrame::capture(self));
         }
+        self.events.record(RuntimeEvent::StepStarted {
+            step: self.steps + 1,
+            tau: self.state.tau,
+        });
+        self.fired_rule = None;
+        self.fired_rules.clear();
 
         // Apply Hamiltonian evolution
-        let h = self.state.hamiltonian();
-
-        // Compute evolution operator: exp(H * dt)
-        let evolution_phase = h * dt;
-        let evolution_factor = Complex::exp_i(evolution_phase);
-
-        // Apply to Ψ
-        self.psi = self.psi.multiply(&evolution_factor);
+        let program = &self.program;
+        let hamiltonian = |state: &CRSM7State| match program {
+            Some(ir) => interpreter::hamiltonian(&ir.evolution.hamiltonian_terms, state),
+            None => state.hamiltonian(),
+        };
+        let timed_hamiltonian =
+            |state: &CRSM7State| timer.timed(StepPhase::Hamiltonian, || hamiltonian(state));
+        timer.lap(StepPhase::Other);
+        let h = timed_hamiltonian(&self.state);
 
-        // Normalize for stability
-        let mag = self.psi.magnitude();
-        if mag > 1e-10 {
-            self.psi = self.psi.scale(1.0 / mag);
-        }
+        // Apply exp(iH·dt) to every mode of Ψ and renormalize for stability
+        self.psi.evolve(h, dt);
 
         // Evolve the state
-        self.state.evolve(dt);
+        let (operators, integrator, config) = (&self.operators, &self.integrator, &self.config);
+        let evolve = |state: &mut CRSM7State| match operators {
+            Some(operators) => operators.evolve(state, dt),
+            None => integrator.integrate(state, dt, &timed_hamiltonian, config),
+        };
+        #[cfg(feature = "high-precision")]
+        let precise =
+            Self::evolve_precise(&mut self.high_precision, &mut self.state, dt, h, config);
+        #[cfg(not(feature = "high-precision"))]
+        let precise = false;
+        if !precise {
+            evolve(&mut self.state);
+        }
+        if !self.coupled.is_empty() {
+            for manifold in &mut self.coupled.manifolds {
+                evolve(&mut manifold.state);
+            }
+            let mut states: Vec<&mut CRSM7State> = std::iter::once(&mut self.state)
+                .chain(self.coupled.manifolds.iter_mut().map(|m| &mut m.state))
+                .collect();
+            self.coupled.coupling.apply(&mut states, dt, &self.config);
+        }
+        self.apply_noise(dt);
+        timer.lap(StepPhase::Evolution);
 
         // Update mesh weights
         self.update_mesh_weights();
+        timer.lap(StepPhase::MeshWeights);
+
+        // Undo injected perturbations, then fire those due at the new epoch
+        self.advance_inversions();
+        for perturbation in self.perturbations.take_due(self.state.tau) {
+            perturbation.apply(self);
+        }
+        timer.lap(StepPhase::Other);
 
         // Check collapse conditions
+        let collapsed = self.bifurcation_active();
+        let was_sealed = self.sealed;
         self.check_collapse();
+        let fired_rule = self.fired_rule.take();
+        if self.sealed && !was_sealed {
+            self.events.record(RuntimeEvent::Sealed {
+                step: self.steps + 1,
+                tau: self.state.tau,
+            });
+        }
+        timer.lap(StepPhase::Collapse);
+
+        self.apply_precision();
+        self.record_history();
+        self.steps += 1;
+        self.trajectory_hash =
+            extend_trajectory_hash(self.trajectory_hash, &Observables::capture(self));
+        if self.sealed && !was_sealed {
+            self.certificate = Some(SovereigntyCertificate::issue(self, fired_rule));
+        }
+        self.evaluate_watches();
+        #[cfg(feature = "invariants")]
+        {
+            let mut monitor = std::mem::take(&mut self.invariants);
+            monitor.check(self);
+            self.invariants = monitor;
+        }
+        self.notify_observers(collapsed, self.sealed && !was_sealed);
+        if let Some(profiler) = &mut self.profiler {
+            timer.lap(StepPhase::Other);
+            profiler.record(&timer);
+        }
+    }
+
+    /// Go back `steps` steps (at most the rewind capacity), dropping their
+    /// history entries; returns the number of steps undone, 0 when fewer
+    /// than `steps` are buffered
+    pub fn rewind(&mut self, steps: usize) -> usize {
+        let Some(frame) = self.rewind.pop(steps) else {
+            return 0;
+        };
+        let undone = (self.steps - frame.steps) as usize;
+        frame.restore(self);
+        let keep = self.history.len().saturating_sub(undone);
+        self.history.truncate(keep);
+        undone
+    }
+
+    /// Evolve `state` through its double-double copy, if there is one;
+    /// returns whether it did
+    #[cfg(feature = "high-precision")]
+    fn evolve_precise(
+        precise: &mut Option<CompactState<DoubleDouble>>,
+        state: &mut CRSM7State,
+        dt: f64,
+        h: f64,
+        config: &RuntimeConfig,
+    ) -> bool {
+        let Some(precise) = precise else {
+            return false;
+        };
+        if !precise.to_state().approx_eq(state, 0.0) {
+            *precise = CompactState::from_state(state);
+        }
+        precise.evolve_with(dt, DoubleDouble::from_f64(h), config);
+        *state = precise.to_state();
+        true
+    }
+
+    /// Langevin kicks dΓ = σΓ·dW, dΦ = σΦ·dW; Γ stays above εΓ and Φ ≥ 0
+    fn apply_noise(&mut self, dt: f64) {
+        if !self.config.noise_enabled() {
+            return;
+        }
+        let scale = dt.abs().sqrt();
+        let state = &mut self.state;
+        state.gamma += self.config.noise_gamma * scale * self.rng.gaussian();
+        state.gamma = state.gamma.max(self.config.gamma_tolerance);
+        state.phi += self.config.noise_phi * scale * self.rng.gaussian();
+        state.phi = state.phi.max(0.0);
+        state.compute_emergence();
+    }
+
+    /// Hand the step to every observer
+    fn notify_observers(&mut self, collapsed: bool, sealed: bool) {
+        if self.observers.is_empty() {
+            return;
+        }
+        let mut observers = std::mem::take(&mut self.observers);
+        let event = StepEvent {
+            step: self.steps,
+            psi: &self.psi,
+            state: &self.state,
+        };
+        observers.notify(&event, |o, e| o.on_step(e));
+        if collapsed {
+            observers.notify(&event, |o, e| o.on_collapse(e));
+        }
+        if sealed {
+            observers.notify(&event, |o, e| o.on_seal(e));
+        }
+        self.observers = observers;
+    }
+
+    fn evaluate_watches(&mut self) {
+        if self.watches.is_empty() {
+            return;
+        }
+        for crossing in self.watches.evaluate(self.steps, &self.state) {
+            self.events.record(RuntimeEvent::WatchCrossed {
+                step: crossing.step,
+                tau: self.state.tau,
+                watch: crossing.name,
+                threshold: crossing.threshold,
+                direction: crossing.direction,
+                value: crossing.value,
+            });
+        }
+    }
+
+    /// Round Ψ and the state vector to f32 when the profile asks for it
+    fn apply_precision(&mut self) {
+        if !self.profile.f32_state {
+            return;
+        }
+        for c in self.psi.components_mut() {
+            c.re = to_f32_precision(c.re);
+            c.im = to_f32_precision(c.im);
+        }
+        let state = &mut self.state;
+        for value in [
+            &mut state.lambda,
+            &mut state.gamma,
+            &mut state.phi,
+            &mut state.xi,
+            &mut state.rho,
+            &mut state.theta,
+            &mut state.tau,
+        ] {
+            *value = to_f32_precision(*value);
+        }
+    }
+
+    fn record_history(&mut self) {
+        let capacity = self.profile.history_capacity;
+        if capacity == 0 {
+            return;
+        }
+        while self.history.len() >= capacity {
+            self.history.pop_front();
+        }
+        self.history.push_back(Observables::capture(self));
     }
 
     /// Update Z3 mesh weights based on current state
+    ///
+    /// Only genes without a weight yet are weighed; with the `wgpu` feature
+    /// large batches are computed on the GPU (on the CPU if that fails).
     fn update_mesh_weights(&mut self) {
-        for gene in &self.organism.genes {
-            let weight = Z3MeshWeights::compute_weight(&self.state, &gene.state);
-            if self.mesh_weights.weights.len() < self.organism.genes.len() {
-                self.mesh_weights.weights.push(weight);
+        let genes = &self.organism.genes;
+        let missing = genes.len().saturating_sub(self.mesh_weights.weights.len());
+        let pending = &genes[..missing];
+        #[cfg(feature = "wgpu")]
+        if let Some(gpu) = GpuEvolver::offload(pending.len()) {
+            if let Ok(weights) = gpu.mesh_weights(&self.state, pending) {
+                self.mesh_weights.weights.extend(weights);
+                return;
             }
         }
+        let state = &self.state;
+        self.mesh_weights.weights.extend(
+            pending
+                .iter()
+                .map(|gene| Z3MeshWeights::compute_weight(state, &gene.state)),
+        );
     }
 
     /// Check and apply collapse conditions
@@ -210,18 +845,49 @@ impl DualRuntime {
     /// Collapse rules:
     /// - if Γ → 0 → Π±
     /// - if ΛΦ → max → Ω∞.seal()
+    ///
+    /// A loaded program's rules replace the built-ins; registered rules are
+    /// evaluated in order after them.
     fn check_collapse(&mut self) {
-        // if Γ → 0 → apply Π±
-        if self.state.gamma <= GAMMA_TOLERANCE * 10.0 {
-            let (plus, _minus) = bifurcate(self.psi.re);
-            self.psi.re = plus;
+        let program = self.program.take();
+        if let Some(ir) = &program {
+            interpreter::apply_collapse_rules(self, ir);
         }
+        self.program = program;
 
-        // if ΛΦ → max → seal
-        let lambda_phi = self.state.lambda * self.state.phi;
-        if lambda_phi > 10.0 && self.check_sovereignty() {
-            self.seal();
+        let rules = std::mem::replace(&mut self.collapse_rules, CollapseRules::empty());
+        for rule in rules.iter() {
+            if rule.builtin() && self.program.is_some() {
+                continue;
+            }
+            if rule.condition(self) {
+                self.log_collapse(rule.name());
+                rule.apply(self);
+            }
         }
+        self.collapse_rules = rules;
+    }
+
+    /// Record that a collapse rule fired during the current step
+    pub(crate) fn log_collapse(&mut self, rule: &str) {
+        self.events.record(RuntimeEvent::CollapseTriggered {
+            step: self.steps + 1,
+            tau: self.state.tau,
+            rule: rule.to_string(),
+        });
+        self.fired_rule = Some(rule.to_string());
+        self.fired_rules.push(rule.to_string());
+    }
+
+    /// Names of the collapse rules that fired during the most recent step,
+    /// in order
+    pub fn fired_rules(&self) -> &[String] {
+        &self.fired_rules
+    }
+
+    /// Whether the Γ → 0 collapse rule applies Π± on this step
+    pub fn bifurcation_active(&self) -> bool {
+        self.config.bifurcation_active(self.state.gamma)
     }
 
     /// Check if sovereignty conditions are met
@@ -230,34 +896,50 @@ impl DualRuntime {
     /// - Ξ ≥ 8.0
     /// - Γ ≤ εΓ
     pub fn check_sovereignty(&self) -> bool {
-        self.state.xi >= 8.0 && self.state.gamma <= GAMMA_TOLERANCE
+        self.state.xi >= self.config.sovereignty_xi
+            && self.state.gamma <= self.config.gamma_tolerance
     }
 
     /// Seal the runtime (Ω∞.seal())
+    ///
+    /// A runtime that seals issues a `SovereigntyCertificate`
     pub fn seal(&mut self) {
-        if self.check_sovereignty() {
+        if self.check_sovereignty() && !self.sealed {
             self.sealed = true;
+            let rule = self.fired_rule.clone();
+            self.certificate = Some(SovereigntyCertificate::issue(self, rule));
         }
     }
 
-    /// Apply the Π⁺ projector
+    /// Apply the Π⁺ projector to a single-mode value
     pub fn apply_pi_plus(&self, value: f64) -> f64 {
-        pi_plus(value)
+        self.duality.pi_plus(&[value])[0]
     }
 
-    /// Apply the Π⁻ projector
+    /// Apply the Π⁻ projector to a single-mode value
     pub fn apply_pi_minus(&self, value: f64) -> f64 {
-        pi_minus(value)
+        self.duality.pi_minus(&[value])[0]
     }
 
-    /// Apply the J involution
+    /// Apply the J involution to a single-mode value
     pub fn apply_involution(&self, value: f64) -> f64 {
-        involution_j(value)
+        self.duality.involution.apply(&[value])[0]
     }
 
-    /// Bifurcate a value into Π⁺ and Π⁻ branches
+    /// Bifurcate a single-mode value into Π⁺ and Π⁻ branches
     pub fn bifurcate_value(&self, value: f64) -> (f64, f64) {
-        bifurcate(value)
+        (self.apply_pi_plus(value), self.apply_pi_minus(value))
+    }
+
+    /// Project Ψ onto Π⁺ (the real parts of its modes); returns Π⁺ and Π⁻
+    /// of the primary mode
+    pub fn project_psi(&mut self) -> (f64, f64) {
+        let re: Vec<f64> = self.psi.components().iter().map(|c| c.re).collect();
+        let (plus, minus) = self.duality.bifurcate(&re);
+        for (c, value) in self.psi.components_mut().iter_mut().zip(&plus) {
+            c.re = *value;
+        }
+        (plus[0], minus[0])
     }
 
     /// Compute sovereignty index Ω_sov
@@ -266,16 +948,53 @@ impl DualRuntime {
         self.state.lambda * (1.0 - self.state.gamma) * emergence_factor
     }
 
+    /// Write a checkpoint of the runtime to `path`
+    pub fn checkpoint(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        Checkpoint::new(self).save(path)
+    }
+
+    /// Resume a runtime from a checkpoint file
+    pub fn restore(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Checkpoint::load(path)?.runtime)
+    }
+
+    /// Resume a runtime from a checkpoint file, refusing one made with a
+    /// duality configuration other than `duality`
+    pub fn restore_checked(path: impl AsRef<Path>, duality: &DualityConfig) -> io::Result<Self> {
+        Ok(Checkpoint::load_checked(path, duality)?.runtime)
+    }
+
     /// Run evolution for multiple steps
     pub fn run(&mut self, steps: usize, dt: f64) {
         for _ in 0..steps {
-            if self.sealed {
+            if self.sealed || self.observers.halted() {
                 break;
             }
             self.step(dt);
         }
     }
 
+    /// Run until τ reaches `max_tau` (or the runtime seals or halts), with
+    /// dt chosen before each step by the step controller
+    pub fn run_adaptive(&mut self, max_tau: f64) -> AdaptiveReport {
+        let mut report = AdaptiveReport::default();
+        while self.state.tau < max_tau && !self.sealed && !self.observers.halted() {
+            let dt = self.step_controller.dt.min(max_tau - self.state.tau);
+            let (tau, gamma, lambda) = (self.state.tau, self.state.gamma, self.state.lambda);
+            self.step(dt);
+            if self.state.tau <= tau {
+                break; // dt below the state's precision
+            }
+            let change = (self.state.gamma - gamma)
+                .abs()
+                .max((self.state.lambda - lambda).abs());
+            let adjustment = self.step_controller.adjust(change);
+            report.record(dt, adjustment);
+        }
+        report.tau = self.state.tau;
+        report
+    }
+
     /// Run until sovereignty is achieved or max steps reached
     pub fn run_to_sovereignty(&mut self, max_steps: usize, dt: f64) -> bool {
         for _ in 0..max_steps {
@@ -283,6 +1002,9 @@ impl DualRuntime {
             if self.sealed {
                 return true;
             }
+            if self.observers.halted() {
+                break;
+            }
         }
         false
     }
@@ -291,6 +1013,7 @@ impl DualRuntime {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::organism::Gene;
 
     #[test]
     fn test_runtime_creation() {
@@ -307,6 +1030,33 @@ mod tests {
         assert!(runtime.state.tau > initial_tau);
     }
 
+    #[test]
+    fn test_multi_mode_step() {
+        let mut runtime = DualRuntime::new().with_wave_function(WaveFunction::uniform(4));
+        runtime.run(20, 0.05);
+        assert_eq!(runtime.psi.len(), 4);
+        assert!((runtime.psi.norm() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_seeded_noise_is_reproducible() {
+        let noisy = |seed| {
+            let mut runtime = DualRuntime::new()
+                .with_config(RuntimeConfig::new().with_noise(0.05, 0.2))
+                .with_seed(seed);
+            runtime.run(50, 0.01);
+            (runtime.state.gamma, runtime.state.phi)
+        };
+        assert_eq!(noisy(7), noisy(7));
+        assert_ne!(noisy(7), noisy(8));
+
+        let mut quiet = DualRuntime::new().with_seed(7);
+        let mut plain = DualRuntime::new();
+        quiet.run(50, 0.01);
+        plain.run(50, 0.01);
+        assert_eq!(quiet.state.phi, plain.state.phi);
+    }
+
     #[test]
     fn test_check_sovereignty() {
         let mut runtime = DualRuntime::new();
@@ -339,6 +1089,34 @@ mod tests {
         assert!((result - psi).abs() < 1e-10);
     }
 
+    #[test]
+    fn test_configured_duality_projects_psi() {
+        use crate::projectors::InvolutionSpec;
+
+        let exchange = DualityConfig::new(InvolutionSpec::Matrix {
+            rows: vec![vec![0.0, 1.0], vec![1.0, 0.0]],
+        });
+        let mut runtime = DualRuntime::new()
+            .with_wave_function(WaveFunction::new(vec![
+                Complex::new(0.8, 0.0),
+                Complex::new(0.6, 0.0),
+            ]))
+            .with_duality(exchange)
+            .unwrap();
+        assert!(runtime.duality.verification.as_ref().unwrap().passed);
+        let (plus, minus) = runtime.project_psi();
+        assert!((plus - 0.7).abs() < 1e-12 && (minus - 0.1).abs() < 1e-12);
+        assert!((runtime.psi.components()[1].re - 0.7).abs() < 1e-12);
+
+        // Under JΨ = −Ψ the scalar projectors are unchanged
+        assert_eq!(DualRuntime::new().bifurcate_value(2.0), (0.0, 2.0));
+
+        let skew = DualityConfig::new(InvolutionSpec::Matrix {
+            rows: vec![vec![2.0, 0.0], vec![0.0, 1.0]],
+        });
+        assert!(DualRuntime::new().with_duality(skew).is_err());
+    }
+
     #[test]
     fn test_involution_squared() {
         let runtime = DualRuntime::new();
@@ -354,6 +1132,54 @@ mod tests {
         assert!(runtime.state.tau > 0.0);
     }
 
+    #[test]
+    fn test_lite_profile() {
+        let mut lite = DualRuntime::with_profile(RuntimeProfile::lite());
+        assert!(lite.mesh_weights.weights.is_empty());
+        lite.run(20, 0.1);
+        assert!(lite.history.is_empty());
+        assert_eq!(lite.mesh_weights.weights.len(), lite.organism.genes.len());
+        assert_eq!(lite.state.phi, lite.state.phi as f32 as f64);
+
+        let mut standard = DualRuntime::with_profile(RuntimeProfile {
+            history_capacity: 5,
+            ..RuntimeProfile::standard()
+        });
+        standard.run(20, 0.1);
+        assert_eq!(standard.history.len(), 5);
+        assert_eq!(standard.history.back().unwrap().tau, standard.state.tau);
+    }
+
+    #[test]
+    fn test_swap_organism_keeps_evolution() {
+        let mut runtime = DualRuntime::new();
+        runtime.run(10, 0.1);
+        let (tau, psi) = (runtime.state.tau, runtime.psi.primary());
+
+        let mut organism = Organism::new("updated");
+        organism.add_gene(Gene::new("aura", "Aura"));
+        organism.add_gene(Gene::new("probe", "Probe"));
+        let old = runtime.swap_organism(organism);
+        assert_eq!(old.name, "CRSM7_Z3MESH");
+        assert_eq!(runtime.state.tau, tau);
+        assert_eq!(runtime.psi.primary().re, psi.re);
+        match runtime.events.iter().last() {
+            Some(RuntimeEvent::OrganismSwapped {
+                step: 10,
+                added,
+                removed,
+                ..
+            }) => {
+                assert_eq!(added, &vec!["probe".to_string()]);
+                assert_eq!(removed.len(), old.genes.len() - 1);
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
+
+        runtime.step(0.1);
+        assert_eq!(runtime.mesh_weights.weights.len(), 2);
+    }
+
     #[test]
     fn test_z3_mesh_weight() {
         let state1 = CRSM7State::new();
@@ -374,4 +1200,36 @@ mod tests {
         assert!((c.re - 1.0).abs() < 1e-10);
         assert!((c.im - 0.0).abs() < 1e-10);
     }
+
+    #[test]
+    fn test_operators_follow_config() {
+        let config = RuntimeConfig::default().with_gamma_tolerance(0.5);
+        let mut operated = DualRuntime::new()
+            .with_operators(Operators::default())
+            .with_config(config.clone());
+        let mut integrated = DualRuntime::new().with_config(config);
+        operated.run(20, 0.01);
+        integrated.run(20, 0.01);
+        assert_eq!(operated.state.gamma, 0.5);
+        assert!(operated.state.approx_eq(&integrated.state, 0.0));
+    }
+
+    #[cfg(feature = "high-precision")]
+    #[test]
+    fn test_high_precision_runtime_lifts_emergence_cap() {
+        use crate::manifold::EMERGENCE_MAX;
+
+        let config = RuntimeConfig::default().with_gamma_tolerance(1e-20);
+        let mut capped = DualRuntime::new().with_config(config.clone());
+        let mut precise = DualRuntime::new().with_config(config).with_high_precision();
+        capped.run(3000, 0.01);
+        precise.run(3000, 0.01);
+
+        assert!(capped.state.gamma < 1e-9 && precise.state.gamma < 1e-9);
+        assert_eq!(capped.state.xi, EMERGENCE_MAX);
+        let state = &precise.state;
+        assert!(state.xi > EMERGENCE_MAX);
+        assert!((state.xi - state.lambda * state.phi / state.gamma).abs() <= 1e-12 * state.xi);
+        assert!(!precise.sealed);
+    }
 }
@@ -0,0 +1,188 @@
+//! Ensembles
+//!
+//! N runtimes stepped together, typically from varied initial conditions,
+//! with aggregate statistics over the members: mean and variance of Ξ, the
+//! fraction sealed and the distribution of time to sovereignty. With the
+//! `parallel` feature members step on the rayon thread pool.
+
+use crate::dual_runtime::DualRuntime;
+use crate::manifold::CRSM7State;
+use crate::rng::SeededRng;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Aggregate statistics over an ensemble
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EnsembleStats {
+    pub members: usize,
+    pub xi_mean: f64,
+    /// Population variance of Ξ
+    pub xi_variance: f64,
+    pub sealed_fraction: f64,
+    /// Elapsed τ until sealing, for each sealed member, ascending
+    pub time_to_sovereignty: Vec<f64>,
+}
+
+impl EnsembleStats {
+    /// Quantile q ∈ [0, 1] of the time to sovereignty (nearest rank)
+    pub fn time_to_sovereignty_quantile(&self, q: f64) -> Option<f64> {
+        let times = &self.time_to_sovereignty;
+        if times.is_empty() {
+            return None;
+        }
+        let rank = (q.clamp(0.0, 1.0) * (times.len() - 1) as f64).round() as usize;
+        Some(times[rank])
+    }
+}
+
+/// A set of runtimes evolved together
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Ensemble {
+    pub members: Vec<DualRuntime>,
+    /// τ of each member when it joined
+    started: Vec<f64>,
+}
+
+impl Ensemble {
+    pub fn new(members: Vec<DualRuntime>) -> Self {
+        let started = members.iter().map(|m| m.state.tau).collect();
+        Self { members, started }
+    }
+
+    /// `size` copies of `base` with Λ, Γ and Φ scaled by independent
+    /// factors in [1 − spread, 1 + spread]; member i also gets noise seed
+    /// `seed + i`
+    pub fn varied(base: &DualRuntime, size: usize, spread: f64, seed: u64) -> Self {
+        let mut rng = SeededRng::new(seed);
+        let members = (0..size as u64)
+            .map(|i| {
+                let mut member = base.clone().with_seed(seed.wrapping_add(i));
+                let mut jitter = || rng.range(1.0 - spread, 1.0 + spread);
+                let state = &mut member.state;
+                state.lambda = (state.lambda * jitter()).min(member.config.lambda_cap);
+                state.gamma = (state.gamma * jitter()).max(member.config.gamma_tolerance);
+                state.phi *= jitter();
+                state.compute_emergence();
+                member
+            })
+            .collect();
+        Self::new(members)
+    }
+
+    /// `size` copies of `base` whose states are spaced evenly along
+    /// `CRSM7State::interpolate` from `from` to `to`
+    pub fn between(base: &DualRuntime, from: &CRSM7State, to: &CRSM7State, size: usize) -> Self {
+        let members = CRSM7State::interpolate_path(from, to, size)
+            .into_iter()
+            .map(|state| {
+                let mut member = base.clone();
+                member.state = state;
+                member
+            })
+            .collect();
+        Self::new(members)
+    }
+
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Step every member by dt
+    pub fn step(&mut self, dt: f64) {
+        #[cfg(feature = "parallel")]
+        self.members.par_iter_mut().for_each(|m| m.step(dt));
+        #[cfg(not(feature = "parallel"))]
+        self.members.iter_mut().for_each(|m| m.step(dt));
+    }
+
+    /// Run up to `steps` steps, stopping once every member has sealed
+    pub fn run(&mut self, steps: usize, dt: f64) {
+        for _ in 0..steps {
+            if self.members.iter().all(|m| m.sealed) {
+                break;
+            }
+            self.step(dt);
+        }
+    }
+
+    pub fn stats(&self) -> EnsembleStats {
+        let n = self.members.len().max(1) as f64;
+        let xi_mean = self.members.iter().map(|m| m.state.xi).sum::<f64>() / n;
+        let xi_variance = self
+            .members
+            .iter()
+            .map(|m| (m.state.xi - xi_mean).powi(2))
+            .sum::<f64>()
+            / n;
+        let mut time_to_sovereignty: Vec<f64> = self
+            .members
+            .iter()
+            .zip(&self.started)
+            .filter(|(m, _)| m.sealed)
+            .map(|(m, start)| m.state.tau - start)
+            .collect();
+        time_to_sovereignty.sort_by(f64::total_cmp);
+
+        EnsembleStats {
+            members: self.members.len(),
+            xi_mean,
+            xi_variance,
+            sealed_fraction: time_to_sovereignty.len() as f64 / n,
+            time_to_sovereignty,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_varied_ensemble_statistics() {
+        let mut base = DualRuntime::new();
+        base.state.lambda = 0.999;
+        base.state.phi = 100.0;
+        base.state.gamma = base.config.gamma_tolerance * 2.0;
+        base.state.compute_emergence();
+
+        let mut ensemble = Ensemble::varied(&base, 16, 0.5, 3);
+        assert_eq!(ensemble.len(), 16);
+        assert!(ensemble.stats().xi_variance > 0.0);
+
+        ensemble.run(200, 0.01);
+        let stats = ensemble.stats();
+        assert_eq!(stats.members, 16);
+        assert!(stats.sealed_fraction > 0.0 && stats.sealed_fraction <= 1.0);
+        assert_eq!(
+            stats.time_to_sovereignty.len() as f64,
+            stats.sealed_fraction * 16.0
+        );
+        let median = stats.time_to_sovereignty_quantile(0.5).unwrap();
+        assert!(stats.time_to_sovereignty[0] <= median);
+        assert!(median <= stats.time_to_sovereignty_quantile(1.0).unwrap());
+
+        let lambdas = |e: &Ensemble| e.members.iter().map(|m| m.state.lambda).collect::<Vec<_>>();
+        let (a, b) = (
+            Ensemble::varied(&base, 4, 0.5, 9),
+            Ensemble::varied(&base, 4, 0.5, 9),
+        );
+        assert_eq!(lambdas(&a), lambdas(&b));
+    }
+
+    #[test]
+    fn test_ensemble_between_states() {
+        let from = CRSM7State::new();
+        let mut to = from.clone();
+        to.gamma = from.gamma / 100.0;
+        to.compute_emergence();
+        let ensemble = Ensemble::between(&DualRuntime::new(), &from, &to, 3);
+        assert_eq!(ensemble.len(), 3);
+        assert!((ensemble.members[1].state.gamma - from.gamma / 10.0).abs() < 1e-12);
+        assert!(ensemble.members[2].state.approx_eq(&to, 1e-12));
+    }
+}
@@ -0,0 +1,198 @@
+//! Event Log
+//!
+//! Structured record of what happened during each step: the step starting,
+//! every collapse rule that fired, the step that sealed the runtime, watch
+//! thresholds crossed, and organisms swapped, perturbations injected or
+//! seals cleared in between steps.
+//! Events go into a ring buffer bounded by `RuntimeProfile::event_capacity`;
+//! the oldest events are dropped first and counted. The log exports as JSON Lines for post-hoc analysis.
+
+use crate::perturbation::Perturbation;
+use crate::watches::Crossing;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::io::{self, Write};
+
+/// One entry of the event log
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum RuntimeEvent {
+    StepStarted {
+        step: u64,
+        tau: f64,
+    },
+    CollapseTriggered {
+        step: u64,
+        tau: f64,
+        /// Name of the rule that fired (`CollapseRule::name`)
+        rule: String,
+    },
+    Sealed {
+        step: u64,
+        tau: f64,
+    },
+    /// A new organism replaced the running one after `step`
+    OrganismSwapped {
+        step: u64,
+        tau: f64,
+        organism: String,
+        /// Gene ids the previous organism lacked
+        added: Vec<String>,
+        /// Gene ids the new organism lacks
+        removed: Vec<String>,
+    },
+    /// `DualRuntime::perturb` injected a perturbation after `step`
+    Perturbed {
+        step: u64,
+        tau: f64,
+        perturbation: Perturbation,
+    },
+    /// `DualRuntime::unseal` cleared the seal after `step`
+    Unsealed {
+        step: u64,
+        tau: f64,
+        reason: String,
+    },
+    /// A watch expression crossed one of its thresholds
+    WatchCrossed {
+        step: u64,
+        tau: f64,
+        watch: String,
+        threshold: f64,
+        direction: Crossing,
+        value: f64,
+    },
+}
+
+impl RuntimeEvent {
+    pub fn step(&self) -> u64 {
+        match self {
+            RuntimeEvent::StepStarted { step, .. }
+            | RuntimeEvent::CollapseTriggered { step, .. }
+            | RuntimeEvent::Sealed { step, .. }
+            | RuntimeEvent::OrganismSwapped { step, .. }
+            | RuntimeEvent::Perturbed { step, .. }
+            | RuntimeEvent::Unsealed { step, .. }
+            | RuntimeEvent::WatchCrossed { step, .. } => *step,
+        }
+    }
+}
+
+/// Bounded ring buffer of runtime events
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EventLog {
+    capacity: usize,
+    events: VecDeque<RuntimeEvent>,
+    /// Events evicted to stay within capacity
+    dropped: u64,
+}
+
+impl EventLog {
+    /// Log holding at most `capacity` events (0 records nothing)
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            events: VecDeque::new(),
+            dropped: 0,
+        }
+    }
+
+    pub fn record(&mut self, event: RuntimeEvent) {
+        if self.capacity == 0 {
+            return;
+        }
+        while self.events.len() >= self.capacity {
+            self.events.pop_front();
+            self.dropped += 1;
+        }
+        self.events.push_back(event);
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    pub fn dropped(&self) -> u64 {
+        self.dropped
+    }
+
+    /// Events, oldest first
+    pub fn iter(&self) -> impl Iterator<Item = &RuntimeEvent> {
+        self.events.iter()
+    }
+
+    /// Remove and return every buffered event
+    pub fn drain(&mut self) -> Vec<RuntimeEvent> {
+        self.events.drain(..).collect()
+    }
+
+    /// One JSON object per line, oldest first
+    pub fn write_json_lines(&self, mut out: impl Write) -> io::Result<()> {
+        for event in &self.events {
+            serde_json::to_writer(&mut out, event)?;
+            writeln!(out)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collapse::{GAMMA_TO_ZERO, LAMBDA_PHI_MAX};
+    use crate::DualRuntime;
+
+    #[test]
+    fn test_log_reconstructs_collapse_sequence() {
+        let mut runtime = DualRuntime::new();
+        runtime.state.gamma = runtime.config.gamma_tolerance;
+        runtime.state.lambda = 0.999;
+        runtime.state.phi = 100.0;
+        runtime.state.compute_emergence();
+        runtime.run(3, 0.01);
+
+        let events: Vec<RuntimeEvent> = runtime.events.iter().cloned().collect();
+        assert!(matches!(
+            events[0],
+            RuntimeEvent::StepStarted { step: 1, .. }
+        ));
+        assert!(matches!(
+            &events[1],
+            RuntimeEvent::CollapseTriggered { step: 1, rule, .. } if rule == GAMMA_TO_ZERO
+        ));
+        assert!(matches!(
+            &events[2],
+            RuntimeEvent::CollapseTriggered { rule, .. } if rule == LAMBDA_PHI_MAX
+        ));
+        assert!(
+            matches!(events[3], RuntimeEvent::Sealed { step: 1, tau } if tau == runtime.state.tau)
+        );
+        assert_eq!(events.len(), 4);
+
+        let mut out = Vec::new();
+        runtime.events.write_json_lines(&mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        assert!(text
+            .lines()
+            .nth(2)
+            .unwrap()
+            .contains(r#""rule":"lambda_phi_max""#));
+    }
+
+    #[test]
+    fn test_ring_buffer_drops_oldest() {
+        let mut log = EventLog::new(2);
+        for step in 1..=5 {
+            log.record(RuntimeEvent::StepStarted { step, tau: 0.0 });
+        }
+        assert_eq!(log.dropped(), 3);
+        let steps: Vec<u64> = log.iter().map(RuntimeEvent::step).collect();
+        assert_eq!(steps, vec![4, 5]);
+        assert_eq!(log.drain().len(), 2);
+        assert!(log.is_empty());
+    }
+}
@@ -0,0 +1,304 @@
+//! Evolutionary Search over Organisms
+//!
+//! A generational genetic algorithm: a population grown from a seed
+//! organism is scored by a `Fitness`, parents are selected, recombined with
+//! `Organism::crossover` and mutated with a `Mutator`, and the fittest
+//! organisms carry over unchanged (elitism). The default fitness evolves a
+//! copy of the organism and scores its mean gene Ω_sov, so the search favours
+//! gene states that drive towards sovereignty. Every random choice is drawn
+//! from generators seeded by `GaConfig::seed`, so a run is reproducible.
+
+use crate::config::RuntimeConfig;
+use crate::organism::{CrossoverStrategy, MutationRates, Mutator, Organism, OrganismExecutor};
+use crate::rng::SeededRng;
+use serde::{Deserialize, Serialize};
+
+/// Scores an organism; higher is fitter
+pub trait Fitness {
+    fn evaluate(&self, organism: &Organism) -> f64;
+}
+
+impl<F: Fn(&Organism) -> f64> Fitness for F {
+    fn evaluate(&self, organism: &Organism) -> f64 {
+        self(organism)
+    }
+}
+
+/// Mean gene Ω_sov (the organism's own when it has no genes) after
+/// evolving a copy for `steps` steps of `dt`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SovereigntyFitness {
+    pub steps: usize,
+    pub dt: f64,
+    pub config: RuntimeConfig,
+}
+
+impl Default for SovereigntyFitness {
+    fn default() -> Self {
+        Self::new(100, 0.01)
+    }
+}
+
+impl SovereigntyFitness {
+    pub fn new(steps: usize, dt: f64) -> Self {
+        Self {
+            steps,
+            dt,
+            config: RuntimeConfig::default(),
+        }
+    }
+
+    pub fn with_config(mut self, config: RuntimeConfig) -> Self {
+        self.config = config;
+        self
+    }
+}
+
+impl Fitness for SovereigntyFitness {
+    fn evaluate(&self, organism: &Organism) -> f64 {
+        let mut executor = OrganismExecutor::new().with_config(self.config.clone());
+        let idx = executor.load_organism(organism.clone());
+        for _ in 0..self.steps {
+            executor.evolve(idx, self.dt);
+        }
+
+        let evolved = &executor.organisms[idx];
+        if evolved.genes.is_empty() {
+            return evolved.state.compute_sovereignty();
+        }
+        let total: f64 = evolved
+            .genes
+            .iter()
+            .map(|g| g.state.compute_sovereignty())
+            .sum();
+        total / evolved.genes.len() as f64
+    }
+}
+
+/// How parents are drawn from the scored population
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "selection", rename_all = "snake_case")]
+pub enum Selection {
+    /// Fittest of `size` organisms drawn uniformly
+    Tournament { size: usize },
+    /// Uniformly from the fittest `fraction` of the population
+    Truncation { fraction: f64 },
+}
+
+/// Genetic algorithm parameters
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GaConfig {
+    pub population: usize,
+    /// Fittest organisms copied unchanged into the next generation
+    pub elite: usize,
+    pub selection: Selection,
+    /// Probability a pair of parents is recombined rather than cloned
+    pub crossover_rate: f64,
+    /// Recombination strategy; a `Uniform` seed is redrawn for every pair
+    pub crossover: CrossoverStrategy,
+    pub mutation: MutationRates,
+    pub seed: u64,
+}
+
+impl Default for GaConfig {
+    fn default() -> Self {
+        Self {
+            population: 20,
+            elite: 2,
+            selection: Selection::Tournament { size: 3 },
+            crossover_rate: 0.7,
+            crossover: CrossoverStrategy::Uniform { seed: 0 },
+            mutation: MutationRates::default().with_point(0.2, 0.1),
+            seed: 0,
+        }
+    }
+}
+
+/// Outcome of a GA run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GaResult {
+    pub best: Organism,
+    pub best_fitness: f64,
+    pub generations: usize,
+    pub evaluations: usize,
+    /// Best fitness so far, for the initial population and after each
+    /// generation
+    pub history: Vec<f64>,
+}
+
+/// Generational GA driver
+pub struct GaDriver<F: Fitness> {
+    pub config: GaConfig,
+    pub fitness: F,
+    /// Current population (offspring keep the seed organism's name)
+    pub population: Vec<Organism>,
+    mutator: Mutator,
+    rng: SeededRng,
+}
+
+impl<F: Fitness> GaDriver<F> {
+    /// Population of `seed` plus mutated copies of it
+    pub fn new(seed: Organism, fitness: F, config: GaConfig) -> Self {
+        let mut mutator = Mutator::new(config.mutation, config.seed);
+        let mut population = vec![seed.clone()];
+        while population.len() < config.population.max(1) {
+            let mut copy = seed.clone();
+            mutator.mutate(&mut copy);
+            population.push(copy);
+        }
+        Self {
+            rng: SeededRng::new(config.seed ^ 0x6A09_E667_F3BC_C908),
+            config,
+            fitness,
+            population,
+            mutator,
+        }
+    }
+
+    /// Evolve for `generations` generations and return the fittest organism
+    /// seen
+    pub fn run(&mut self, generations: usize) -> GaResult {
+        let mut scores = self.score();
+        let mut evaluations = scores.len();
+        let (mut best, mut best_fitness) = self.fittest(&scores);
+        let mut history = vec![best_fitness];
+
+        for _ in 0..generations {
+            self.population = self.breed(&scores);
+            scores = self.score();
+            evaluations += scores.len();
+
+            let (candidate, fitness) = self.fittest(&scores);
+            if fitness > best_fitness {
+                best = candidate;
+                best_fitness = fitness;
+            }
+            history.push(best_fitness);
+        }
+
+        GaResult {
+            best,
+            best_fitness,
+            generations,
+            evaluations,
+            history,
+        }
+    }
+
+    fn score(&self) -> Vec<f64> {
+        self.population
+            .iter()
+            .map(|o| self.fitness.evaluate(o))
+            .collect()
+    }
+
+    fn fittest(&self, scores: &[f64]) -> (Organism, f64) {
+        let idx = ranked(scores)[0];
+        (self.population[idx].clone(), scores[idx])
+    }
+
+    /// Elites, then mutated offspring of selected parents
+    fn breed(&mut self, scores: &[f64]) -> Vec<Organism> {
+        let ranking = ranked(scores);
+        let size = self.population.len();
+        let name = self.population[0].name.clone();
+        let mut next: Vec<Organism> = ranking
+            .iter()
+            .take(self.config.elite.min(size))
+            .map(|&i| self.population[i].clone())
+            .collect();
+
+        while next.len() < size {
+            let (i, j) = (self.select(scores, &ranking), self.select(scores, &ranking));
+            let (a, b) = (&self.population[i], &self.population[j]);
+            let (first, second) = if self.rng.next_f64() < self.config.crossover_rate {
+                let strategy = match self.config.crossover {
+                    CrossoverStrategy::Uniform { .. } => CrossoverStrategy::Uniform {
+                        seed: self.rng.next_u64(),
+                    },
+                    strategy => strategy,
+                };
+                a.crossover(b, strategy)
+            } else {
+                (a.clone(), b.clone())
+            };
+
+            for mut child in [first, second] {
+                if next.len() < size {
+                    child.name = name.clone();
+                    self.mutator.mutate(&mut child);
+                    next.push(child);
+                }
+            }
+        }
+        next
+    }
+
+    fn select(&mut self, scores: &[f64], ranking: &[usize]) -> usize {
+        match self.config.selection {
+            Selection::Tournament { size } => (0..size.max(1))
+                .map(|_| self.rng.index(scores.len()))
+                .max_by(|&a, &b| scores[a].total_cmp(&scores[b]))
+                .unwrap_or(0),
+            Selection::Truncation { fraction } => {
+                let pool =
+                    ((ranking.len() as f64 * fraction).ceil() as usize).clamp(1, ranking.len());
+                ranking[self.rng.index(pool)]
+            }
+        }
+    }
+}
+
+/// Population indices, fittest first
+fn ranked(scores: &[f64]) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..scores.len()).collect();
+    order.sort_by(|&a, &b| scores[b].total_cmp(&scores[a]));
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ga_improves_and_is_reproducible() {
+        let config = GaConfig {
+            population: 12,
+            mutation: MutationRates::default().with_point(0.5, 0.3),
+            seed: 5,
+            ..GaConfig::default()
+        };
+        let fitness = SovereigntyFitness::new(20, 0.01);
+        let seed = OrganismExecutor::create_standard_organism();
+
+        let mut driver = GaDriver::new(seed.clone(), fitness.clone(), config.clone());
+        let result = driver.run(8);
+        assert_eq!(result.history.len(), 9);
+        assert_eq!(result.evaluations, 12 * 9);
+        assert!(result.history.windows(2).all(|w| w[1] >= w[0]));
+        assert!(result.best_fitness >= fitness.evaluate(&seed));
+        assert_eq!(result.best.name, seed.name);
+
+        let again = GaDriver::new(seed, fitness, config).run(8);
+        assert_eq!(result.history, again.history);
+    }
+
+    #[test]
+    fn test_custom_fitness_and_truncation() {
+        let config = GaConfig {
+            population: 10,
+            selection: Selection::Truncation { fraction: 0.3 },
+            crossover: CrossoverStrategy::OnePoint { cut: 2 },
+            mutation: MutationRates::default().with_point(1.0, 0.2),
+            seed: 11,
+            ..GaConfig::default()
+        };
+        let lambda = |o: &Organism| o.genes.iter().map(|g| g.state.lambda).sum::<f64>();
+        let seed = OrganismExecutor::create_standard_organism();
+        let start = lambda(&seed);
+
+        let result = GaDriver::new(seed, lambda, config).run(15);
+        assert!(result.best_fitness > start);
+        assert_eq!(result.best_fitness, lambda(&result.best));
+    }
+}
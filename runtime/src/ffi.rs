@@ -0,0 +1,212 @@
+//! C ABI
+//!
+//! `extern "C"` functions for embedding the runtime in C and C++ hosts,
+//! enabled with the `ffi` feature. The build generates the C header from
+//! this module with cbindgen; `include/dnalang_runtime.h` is the committed
+//! copy (see `build.rs` for regenerating it).
+//!
+//! A runtime is an opaque `DnaRuntime*` owned by the caller between
+//! `dna_runtime_new` and `dna_runtime_free`. Every function accepts a null
+//! pointer and treats it as a no-op (or returns zero/false). The runtime is
+//! not thread-safe; hosts must serialize calls on one handle.
+
+use crate::dual_runtime::DualRuntime;
+use crate::manifold::CRSM7State;
+use crate::profile::RuntimeProfile;
+
+/// Bumped whenever a signature or `DnaState`'s layout changes
+pub const DNA_ABI_VERSION: u32 = 1;
+
+/// Opaque runtime handle
+pub struct DnaRuntime(DualRuntime);
+
+/// The 7D CRSM state C7D = (Λ, Γ, Φ, Ξ, ρ±, θ, τ)
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct DnaState {
+    pub lambda: f64,
+    pub gamma: f64,
+    pub phi: f64,
+    pub xi: f64,
+    pub rho: f64,
+    pub theta: f64,
+    pub tau: f64,
+}
+
+impl From<&CRSM7State> for DnaState {
+    fn from(s: &CRSM7State) -> Self {
+        Self {
+            lambda: s.lambda,
+            gamma: s.gamma,
+            phi: s.phi,
+            xi: s.xi,
+            rho: s.rho,
+            theta: s.theta,
+            tau: s.tau,
+        }
+    }
+}
+
+/// ABI version of the loaded library (`DNA_ABI_VERSION`)
+#[no_mangle]
+pub extern "C" fn dna_abi_version() -> u32 {
+    DNA_ABI_VERSION
+}
+
+/// Create a runtime with the standard profile; free with `dna_runtime_free`
+#[no_mangle]
+pub extern "C" fn dna_runtime_new() -> *mut DnaRuntime {
+    Box::into_raw(Box::new(DnaRuntime(DualRuntime::new())))
+}
+
+/// Create a runtime with the lite profile (no event log, f32-rounded state)
+#[no_mangle]
+pub extern "C" fn dna_runtime_new_lite() -> *mut DnaRuntime {
+    Box::into_raw(Box::new(DnaRuntime(DualRuntime::with_profile(
+        RuntimeProfile::lite(),
+    ))))
+}
+
+/// Destroy a runtime
+///
+/// # Safety
+///
+/// `runtime` must be null or come from `dna_runtime_new*` and not have
+/// been freed.
+#[no_mangle]
+pub unsafe extern "C" fn dna_runtime_free(runtime: *mut DnaRuntime) {
+    if !runtime.is_null() {
+        drop(Box::from_raw(runtime));
+    }
+}
+
+/// Step the runtime forward by dt
+///
+/// # Safety
+///
+/// `runtime` must be null or a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn dna_runtime_step(runtime: *mut DnaRuntime, dt: f64) {
+    if let Some(runtime) = runtime.as_mut() {
+        runtime.0.step(dt);
+    }
+}
+
+/// Step up to `steps` times, stopping early once sealed
+///
+/// # Safety
+///
+/// `runtime` must be null or a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn dna_runtime_run(runtime: *mut DnaRuntime, steps: usize, dt: f64) {
+    if let Some(runtime) = runtime.as_mut() {
+        runtime.0.run(steps, dt);
+    }
+}
+
+/// Copy the current state into `out`; false if either pointer is null
+///
+/// # Safety
+///
+/// `runtime` must be null or a live handle; `out` must be null or valid
+/// for writes.
+#[no_mangle]
+pub unsafe extern "C" fn dna_runtime_state(runtime: *const DnaRuntime, out: *mut DnaState) -> bool {
+    match (runtime.as_ref(), out.as_mut()) {
+        (Some(runtime), Some(out)) => {
+            *out = DnaState::from(&runtime.0.state);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Steps completed
+///
+/// # Safety
+///
+/// `runtime` must be null or a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn dna_runtime_steps(runtime: *const DnaRuntime) -> u64 {
+    runtime.as_ref().map_or(0, |r| r.0.steps)
+}
+
+/// Whether sovereignty has been sealed
+///
+/// # Safety
+///
+/// `runtime` must be null or a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn dna_runtime_is_sealed(runtime: *const DnaRuntime) -> bool {
+    runtime.as_ref().is_some_and(|r| r.0.sealed)
+}
+
+/// Attempt Ω∞.seal(); returns whether the runtime is sealed afterwards
+///
+/// # Safety
+///
+/// `runtime` must be null or a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn dna_runtime_seal(runtime: *mut DnaRuntime) -> bool {
+    match runtime.as_mut() {
+        Some(runtime) => {
+            runtime.0.seal();
+            runtime.0.sealed
+        }
+        None => false,
+    }
+}
+
+/// Sovereignty index Ω_sov of the current state
+///
+/// # Safety
+///
+/// `runtime` must be null or a live handle.
+#[no_mangle]
+pub unsafe extern "C" fn dna_runtime_sovereignty(runtime: *const DnaRuntime) -> f64 {
+    runtime.as_ref().map_or(0.0, |r| r.0.compute_sovereignty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ptr;
+
+    #[test]
+    fn test_c_abi_lifecycle() {
+        unsafe {
+            let runtime = dna_runtime_new();
+            dna_runtime_run(runtime, 10, 0.01);
+            assert_eq!(dna_runtime_steps(runtime), 10);
+
+            let mut state = DnaState::default();
+            assert!(dna_runtime_state(runtime, &mut state));
+            assert_eq!(state, DnaState::from(&(*runtime).0.state));
+            assert!((state.tau - 0.1).abs() < 1e-12);
+            assert_eq!(dna_runtime_is_sealed(runtime), (*runtime).0.sealed);
+            dna_runtime_free(runtime);
+
+            assert!(!dna_runtime_state(ptr::null(), &mut state));
+            assert!(!dna_runtime_seal(ptr::null_mut()));
+            dna_runtime_step(ptr::null_mut(), 0.01);
+            dna_runtime_free(ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn test_header_matches_abi_version() {
+        let header = include_str!("../include/dnalang_runtime.h");
+        assert!(header.contains(&format!("#define DNA_ABI_VERSION {}", DNA_ABI_VERSION)));
+        assert!(header.contains("void dna_runtime_step(struct DnaRuntime *runtime, double dt);"));
+    }
+
+    #[test]
+    fn test_committed_header_is_current() {
+        let generated = include_str!(concat!(env!("OUT_DIR"), "/dnalang_runtime.h"));
+        assert_eq!(
+            include_str!("../include/dnalang_runtime.h"),
+            generated,
+            "stale header; rebuild with DNA_UPDATE_HEADER=1"
+        );
+    }
+}
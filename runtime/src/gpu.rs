@@ -0,0 +1,443 @@
+//! GPU Backend
+//!
+//! Compute-shader versions of per-gene state evolution and Z3 mesh weight
+//! computation, enabled with the `wgpu` feature. Organisms with at least
+//! `GPU_MIN_GENES` genes are offloaded when an adapter is available; smaller
+//! organisms, and every organism on machines without one, take the scalar
+//! (or rayon) path. A dispatch whose results cannot be read back (e.g. the
+//! device was lost) returns a `GpuError` and leaves its input untouched,
+//! and the callers fall back to the scalar path.
+//!
+//! The shader works in f32, so offloaded results agree with the scalar path
+//! to about 1e-6 relative rather than bit for bit. Runs that must replay
+//! exactly (shadow runs, seeded ensembles) should stay below the threshold
+//! or build without the feature.
+
+use crate::config::{PhiSaturation, RuntimeConfig};
+use crate::manifold::CRSM7State;
+use crate::organism::Gene;
+use bytemuck::{Pod, Zeroable};
+use std::fmt;
+use std::sync::{mpsc, OnceLock};
+use wgpu::util::DeviceExt;
+
+/// Genes needed before evolution is offloaded to the GPU
+pub const GPU_MIN_GENES: usize = 100_000;
+
+/// Invocations per workgroup (matches `@workgroup_size` in the shader)
+const WORKGROUP_SIZE: usize = 256;
+
+/// Why a GPU dispatch produced no results
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GpuError {
+    /// The map callback never ran (the device was lost or dropped)
+    CallbackDropped,
+    /// The output buffer could not be mapped for reading
+    Readback(wgpu::BufferAsyncError),
+}
+
+impl fmt::Display for GpuError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GpuError::CallbackDropped => write!(f, "GPU readback callback dropped"),
+            GpuError::Readback(err) => write!(f, "failed to read back GPU buffer: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for GpuError {}
+
+/// CRSM7 state as laid out in the shader
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Default, Pod, Zeroable)]
+struct GpuState {
+    lambda: f32,
+    gamma: f32,
+    phi: f32,
+    xi: f32,
+    rho: f32,
+    theta: f32,
+    tau: f32,
+    _pad: f32,
+}
+
+impl GpuState {
+    fn from_state(s: &CRSM7State) -> Self {
+        Self {
+            lambda: s.lambda as f32,
+            gamma: s.gamma as f32,
+            phi: s.phi as f32,
+            xi: s.xi as f32,
+            rho: s.rho as f32,
+            theta: s.theta as f32,
+            tau: s.tau as f32,
+            _pad: 0.0,
+        }
+    }
+
+    fn write_to(self, s: &mut CRSM7State) {
+        s.lambda = self.lambda as f64;
+        s.gamma = self.gamma as f64;
+        s.phi = self.phi as f64;
+        s.xi = self.xi as f64;
+        s.rho = self.rho as f64;
+        s.theta = self.theta as f64;
+        s.tau = self.tau as f64;
+    }
+}
+
+/// Uniform block shared by both entry points
+#[repr(C)]
+#[derive(Debug, Clone, Copy, Pod, Zeroable)]
+struct GpuParams {
+    reference: GpuState,
+    dt: f32,
+    gamma_tolerance: f32,
+    coherence_rate: f32,
+    lambda_cap: f32,
+    information_rate: f32,
+    count: u32,
+    /// 0 unbounded, 1 hard cap, 2 logistic, 3 decay
+    phi_model: u32,
+    /// Cap, capacity or equilibrium
+    phi_a: f32,
+    /// Decay rate
+    phi_b: f32,
+    _pad: [u32; 3],
+}
+
+impl GpuParams {
+    fn new(reference: &CRSM7State, dt: f64, config: &RuntimeConfig, count: usize) -> Self {
+        let (phi_model, phi_a, phi_b) = match config.phi_saturation {
+            PhiSaturation::Unbounded => (0, 0.0, 0.0),
+            PhiSaturation::HardCap { max } => (1, max, 0.0),
+            PhiSaturation::Logistic { capacity } => (2, capacity, 0.0),
+            PhiSaturation::Decay { equilibrium, rate } => (3, equilibrium, rate),
+        };
+        Self {
+            reference: GpuState::from_state(reference),
+            dt: dt as f32,
+            gamma_tolerance: config.gamma_tolerance as f32,
+            coherence_rate: config.coherence_rate as f32,
+            lambda_cap: config.lambda_cap as f32,
+            information_rate: config.information_rate as f32,
+            count: count as u32,
+            phi_model,
+            phi_a: phi_a as f32,
+            phi_b: phi_b as f32,
+            _pad: [0; 3],
+        }
+    }
+}
+
+/// Compute kernels in the shader
+#[derive(Debug, Clone, Copy)]
+enum Kernel {
+    Evolve,
+    MeshWeights,
+}
+
+/// A device with the gene evolution and mesh weight pipelines compiled
+pub struct GpuEvolver {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    layout: wgpu::BindGroupLayout,
+    evolve: wgpu::ComputePipeline,
+    mesh_weights: wgpu::ComputePipeline,
+    /// Genes per dispatch, bounded by the storage binding size and the
+    /// workgroup count limit
+    max_genes: usize,
+}
+
+impl GpuEvolver {
+    /// Open the default adapter; `None` when the machine has none
+    pub fn new() -> Option<Self> {
+        pollster::block_on(Self::request())
+    }
+
+    /// Process-wide evolver, opened on first use
+    pub fn shared() -> Option<&'static GpuEvolver> {
+        static SHARED: OnceLock<Option<GpuEvolver>> = OnceLock::new();
+        SHARED.get_or_init(Self::new).as_ref()
+    }
+
+    /// The shared evolver if `genes` is large enough to offload
+    pub fn offload(genes: usize) -> Option<&'static GpuEvolver> {
+        if genes < GPU_MIN_GENES {
+            return None;
+        }
+        Self::shared()
+    }
+
+    async fn request() -> Option<Self> {
+        let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor::default());
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions {
+                power_preference: wgpu::PowerPreference::HighPerformance,
+                ..Default::default()
+            })
+            .await?;
+        let limits = adapter.limits();
+        let (device, queue) = adapter
+            .request_device(
+                &wgpu::DeviceDescriptor {
+                    label: Some("dnalang-gpu"),
+                    required_features: wgpu::Features::empty(),
+                    required_limits: limits.clone(),
+                    memory_hints: wgpu::MemoryHints::Performance,
+                },
+                None,
+            )
+            .await
+            .ok()?;
+
+        let storage = |binding| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::COMPUTE,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Storage { read_only: false },
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        };
+        let layout = device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some("dnalang-gpu"),
+            entries: &[
+                wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
+                storage(1),
+                storage(2),
+            ],
+        });
+        let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+            label: Some("dnalang-gpu"),
+            bind_group_layouts: &[&layout],
+            push_constant_ranges: &[],
+        });
+        let module = device.create_shader_module(wgpu::include_wgsl!("gpu.wgsl"));
+        let pipeline = |entry_point| {
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some(entry_point),
+                layout: Some(&pipeline_layout),
+                module: &module,
+                entry_point: Some(entry_point),
+                compilation_options: Default::default(),
+                cache: None,
+            })
+        };
+        let evolve = pipeline("evolve");
+        let mesh_weights = pipeline("mesh_weights");
+
+        let by_binding =
+            limits.max_storage_buffer_binding_size as usize / std::mem::size_of::<GpuState>();
+        let by_dispatch = limits.max_compute_workgroups_per_dimension as usize * WORKGROUP_SIZE;
+
+        Some(Self {
+            device,
+            queue,
+            layout,
+            evolve,
+            mesh_weights,
+            max_genes: by_binding.min(by_dispatch).max(1),
+        })
+    }
+
+    /// Evolve every gene by dt, as `CRSM7State::evolve_with` under its own
+    /// Hamiltonian; on error no gene is changed
+    pub fn evolve_genes(
+        &self,
+        genes: &mut [Gene],
+        dt: f64,
+        config: &RuntimeConfig,
+    ) -> Result<(), GpuError> {
+        let mut evolved = Vec::with_capacity(genes.len());
+        for chunk in genes.chunks(self.max_genes) {
+            let states: Vec<GpuState> = chunk
+                .iter()
+                .map(|g| GpuState::from_state(&g.state))
+                .collect();
+            let params = GpuParams::new(&CRSM7State::new(), dt, config, chunk.len());
+            evolved.extend(self.dispatch::<GpuState>(Kernel::Evolve, &params, &states)?);
+        }
+        for (gene, state) in genes.iter_mut().zip(evolved) {
+            state.write_to(&mut gene.state);
+        }
+        Ok(())
+    }
+
+    /// Mesh weight between `reference` and each gene, as
+    /// `Z3MeshWeights::compute_weight`
+    pub fn mesh_weights(
+        &self,
+        reference: &CRSM7State,
+        genes: &[Gene],
+    ) -> Result<Vec<f64>, GpuError> {
+        let config = RuntimeConfig::default();
+        let mut weights = Vec::with_capacity(genes.len());
+        for chunk in genes.chunks(self.max_genes) {
+            let states: Vec<GpuState> = chunk
+                .iter()
+                .map(|g| GpuState::from_state(&g.state))
+                .collect();
+            let params = GpuParams::new(reference, 0.0, &config, chunk.len());
+            let chunk_weights: Vec<f32> = self.dispatch(Kernel::MeshWeights, &params, &states)?;
+            weights.extend(chunk_weights.into_iter().map(f64::from));
+        }
+        Ok(weights)
+    }
+
+    /// Run one kernel over `states` and read back its output buffer
+    fn dispatch<T: Pod>(
+        &self,
+        kernel: Kernel,
+        params: &GpuParams,
+        states: &[GpuState],
+    ) -> Result<Vec<T>, GpuError> {
+        use wgpu::BufferUsages as Usage;
+
+        let device = &self.device;
+        let params_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("params"),
+            contents: bytemuck::bytes_of(params),
+            usage: Usage::UNIFORM,
+        });
+        let states_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+            label: Some("states"),
+            contents: bytemuck::cast_slice(states),
+            usage: Usage::STORAGE | Usage::COPY_SRC,
+        });
+        let weights_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("weights"),
+            size: (states.len() * std::mem::size_of::<f32>()) as u64,
+            usage: Usage::STORAGE | Usage::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let (pipeline, output) = match kernel {
+            Kernel::Evolve => (&self.evolve, &states_buffer),
+            Kernel::MeshWeights => (&self.mesh_weights, &weights_buffer),
+        };
+        let readback = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("readback"),
+            size: output.size(),
+            usage: Usage::MAP_READ | Usage::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &self.layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: params_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: states_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: weights_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+            pass.set_pipeline(pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups(states.len().div_ceil(WORKGROUP_SIZE) as u32, 1, 1);
+        }
+        encoder.copy_buffer_to_buffer(output, 0, &readback, 0, output.size());
+        self.queue.submit([encoder.finish()]);
+
+        let slice = readback.slice(..);
+        let (tx, rx) = mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = tx.send(result);
+        });
+        device.poll(wgpu::Maintain::Wait);
+        rx.recv()
+            .map_err(|_| GpuError::CallbackDropped)?
+            .map_err(GpuError::Readback)?;
+        let data = bytemuck::cast_slice(&slice.get_mapped_range()).to_vec();
+        readback.unmap();
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_runtime::Z3MeshWeights;
+
+    fn genes(n: usize) -> Vec<Gene> {
+        (0..n)
+            .map(|i| {
+                let x = i as f64 / n as f64;
+                Gene::with_state(
+                    &format!("g{}", i),
+                    "G",
+                    CRSM7State::with_values(
+                        0.5 + 0.4 * x,
+                        0.01 + 0.1 * x,
+                        8.0 * x,
+                        1.0,
+                        51.843,
+                        0.0,
+                    ),
+                )
+            })
+            .collect()
+    }
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() <= 1e-4 * a.abs().max(b.abs()).max(1.0)
+    }
+
+    #[test]
+    #[ignore = "needs a GPU adapter; run with --ignored"]
+    fn test_gpu_matches_scalar_path() {
+        let gpu = GpuEvolver::new().expect("no GPU adapter");
+        let config = RuntimeConfig::default();
+        let mut scalar = genes(5_000);
+        let mut offloaded = scalar.clone();
+        for _ in 0..10 {
+            for gene in &mut scalar {
+                let h = gene.state.hamiltonian();
+                gene.state.evolve_with(0.01, h, &config);
+            }
+            gpu.evolve_genes(&mut offloaded, 0.01, &config).unwrap();
+        }
+        for (a, b) in scalar.iter().zip(&offloaded) {
+            assert!(close(a.state.lambda, b.state.lambda));
+            assert!(close(a.state.gamma, b.state.gamma));
+            assert!(close(a.state.phi, b.state.phi));
+            assert!(close(a.state.xi, b.state.xi));
+            assert!(close(a.state.tau, b.state.tau));
+        }
+
+        let reference = CRSM7State::new();
+        let weights = gpu.mesh_weights(&reference, &scalar).unwrap();
+        for (gene, weight) in scalar.iter().zip(weights) {
+            assert!(close(
+                Z3MeshWeights::compute_weight(&reference, &gene.state),
+                weight
+            ));
+        }
+    }
+
+    #[test]
+    fn test_small_organisms_stay_on_cpu() {
+        assert!(GpuEvolver::offload(GPU_MIN_GENES - 1).is_none());
+    }
+}
@@ -0,0 +1,91 @@
+// Gene evolution and Z3 mesh weights, one invocation per gene.
+// Mirrors CompactState::evolve_with and Z3MeshWeights::compute_weight in f32.
+
+struct State {
+    lambda: f32,
+    gamma: f32,
+    phi: f32,
+    xi: f32,
+    rho: f32,
+    theta: f32,
+    tau: f32,
+    _pad: f32,
+}
+
+struct Params {
+    reference: State,
+    dt: f32,
+    gamma_tolerance: f32,
+    coherence_rate: f32,
+    lambda_cap: f32,
+    information_rate: f32,
+    count: u32,
+    phi_model: u32,
+    phi_a: f32,
+    phi_b: f32,
+    _pad0: u32,
+    _pad1: u32,
+    _pad2: u32,
+}
+
+const GAMMA_TOLERANCE: f32 = 1e-9;
+const EMERGENCE_MAX: f32 = 1e12;
+const DEGREES: f32 = 0.017453292519943295;
+
+@group(0) @binding(0) var<uniform> params: Params;
+@group(0) @binding(1) var<storage, read_write> states: array<State>;
+@group(0) @binding(2) var<storage, read_write> weights: array<f32>;
+
+// dΦ/dτ under the configured saturation model (see PhiSaturation)
+fn phi_rate(lambda: f32, phi: f32) -> f32 {
+    let growth = params.information_rate * lambda;
+    switch params.phi_model {
+        case 2u: {
+            return growth * (1.0 - phi / params.phi_a);
+        }
+        case 3u: {
+            return growth - params.phi_b * (phi - params.phi_a);
+        }
+        default: {
+            return growth;
+        }
+    }
+}
+
+@compute @workgroup_size(256)
+fn evolve(@builtin(global_invocation_id) id: vec3<u32>) {
+    let i = id.x;
+    if i >= params.count {
+        return;
+    }
+    var s = states[i];
+    let dt = params.dt;
+    let h = s.lambda - s.gamma + sin(s.theta * DEGREES);
+
+    s.tau += dt;
+    s.gamma = max(s.gamma * exp(-dt), params.gamma_tolerance);
+    s.lambda = min(s.lambda + h * dt * params.coherence_rate, params.lambda_cap);
+    s.phi += phi_rate(s.lambda, s.phi) * dt;
+    if params.phi_model == 1u {
+        s.phi = min(s.phi, params.phi_a);
+    }
+    if s.gamma > GAMMA_TOLERANCE {
+        s.xi = s.lambda * s.phi / s.gamma;
+    } else {
+        s.xi = EMERGENCE_MAX;
+    }
+    states[i] = s;
+}
+
+@compute @workgroup_size(256)
+fn mesh_weights(@builtin(global_invocation_id) id: vec3<u32>) {
+    let i = id.x;
+    if i >= params.count {
+        return;
+    }
+    let s = states[i];
+    let r = params.reference;
+    let d0 = vec4<f32>(r.lambda - s.lambda, r.gamma - s.gamma, r.phi - s.phi, r.xi - s.xi);
+    let d1 = vec3<f32>(r.rho - s.rho, r.theta - s.theta, r.tau - s.tau);
+    weights[i] = dot(d0, d0) + dot(d1, d1);
+}
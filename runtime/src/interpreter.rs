@@ -0,0 +1,293 @@
+//! Omega IR Interpreter
+//!
+//! Executes compiled `OmegaIR` inside a `DualRuntime`:
+//! - Gene ops: emits are collected for the sinks, bifurcations apply Π⁺ to Ψ,
+//!   sovereign genes try Ω∞.seal(), calls run the callee gene or evaluate a
+//!   builtin against the live state, folded builtins yield their value
+//!   (a folded `seal()` still seals the live runtime)
+//! - Evolution: H_CRSM is assembled from the IR's Hamiltonian terms
+//! - Collapse: the IR's collapse rules replace the built-in ones
+//!
+//! `DualRuntime::load_ir` is the entry point.
+
+use crate::dual_runtime::DualRuntime;
+use crate::collapse::{GAMMA_TO_ZERO, LAMBDA_PHI_MAX};
+use crate::manifold::{CRSM7State, EMERGENCE_MAX, GAMMA_TOLERANCE};
+use crate::sink::EmitRouter;
+use dnalang_compiler::ir::{
+    CollapseActionIR, CollapseConditionIR, CollapseRuleIR, GeneOpType, HamiltonianTermIR, OmegaIR,
+};
+use dnalang_compiler::Payload;
+use std::io;
+
+/// Nesting limit for gene calls (recursive genes stop here)
+pub const MAX_CALL_DEPTH: usize = 32;
+
+/// What executing the gene ops produced
+#[derive(Debug, Clone, Default)]
+pub struct ExecutionTrace {
+    /// Payloads emitted, with the emitting gene
+    pub emits: Vec<(String, Payload)>,
+    /// Π⁺ and Π⁻ branches of Re Ψ₀ at each bifurcation
+    pub bifurcations: Vec<(String, f64, f64)>,
+    /// Values of folded and runtime-evaluated builtins
+    pub values: Vec<(String, f64)>,
+    /// Calls that named neither a gene nor a builtin, or exceeded the depth
+    pub unresolved: Vec<String>,
+}
+
+impl ExecutionTrace {
+    /// Route the collected emits; returns deliveries
+    pub fn route(&self, router: &mut EmitRouter) -> io::Result<usize> {
+        let mut delivered = 0;
+        for (gene, payload) in &self.emits {
+            delivered += router.emit(gene, payload)?;
+        }
+        Ok(delivered)
+    }
+}
+
+/// Execute every gene op of `ir` in order
+pub fn execute_gene_ops(runtime: &mut DualRuntime, ir: &OmegaIR) -> ExecutionTrace {
+    let mut trace = ExecutionTrace::default();
+    for index in 0..ir.gene_ops.len() {
+        execute_op(runtime, ir, index, 0, &mut trace);
+    }
+    trace
+}
+
+fn execute_op(
+    runtime: &mut DualRuntime,
+    ir: &OmegaIR,
+    index: usize,
+    depth: usize,
+    trace: &mut ExecutionTrace,
+) {
+    let op = &ir.gene_ops[index];
+    match &op.op_type {
+        GeneOpType::Emit(payload) => trace.emits.push((op.name.clone(), payload.clone())),
+        GeneOpType::Bifurcate => {
+            let (plus, minus) = runtime.project_psi();
+            trace.bifurcations.push((op.name.clone(), plus, minus));
+        }
+        GeneOpType::Sovereign => {
+            seal(runtime, ir);
+            if let Some(gene) = runtime.organism.genes.iter_mut().find(|g| g.id == op.name) {
+                gene.bound = true;
+            }
+        }
+        // The compiler folds `seal()` against the bound state; sealing is a
+        // side effect on the runtime, so it runs here
+        GeneOpType::Folded { builtin, .. } if builtin == "seal" => {
+            let sealed = seal(runtime, ir);
+            trace
+                .values
+                .push((builtin.clone(), if sealed { 1.0 } else { 0.0 }));
+        }
+        GeneOpType::Folded { builtin, value } => trace.values.push((builtin.clone(), *value)),
+        GeneOpType::Call(name, args) => {
+            if let Some(callee) = ir.gene_ops.iter().position(|g| &g.name == name) {
+                if depth < MAX_CALL_DEPTH {
+                    execute_op(runtime, ir, callee, depth + 1, trace);
+                } else {
+                    trace.unresolved.push(name.clone());
+                }
+            } else if let Some(value) = eval_builtin(runtime, ir, name, args) {
+                trace.values.push((name.clone(), value));
+            } else {
+                trace.unresolved.push(name.clone());
+            }
+        }
+    }
+}
+
+/// Evaluate a builtin the compiler could not fold, against the live state
+fn eval_builtin(
+    runtime: &mut DualRuntime,
+    ir: &OmegaIR,
+    name: &str,
+    args: &[String],
+) -> Option<f64> {
+    let values = args
+        .iter()
+        .map(|arg| argument_value(&runtime.state, ir, arg))
+        .collect::<Option<Vec<f64>>>()?;
+    let state = &runtime.state;
+    match (name, values.as_slice()) {
+        ("emergence", []) => Some(state.xi),
+        ("emergence", [lambda, phi, gamma]) => Some(if *gamma > GAMMA_TOLERANCE {
+            lambda * phi / gamma
+        } else {
+            EMERGENCE_MAX
+        }),
+        ("hamiltonian", []) => Some(hamiltonian(&ir.evolution.hamiltonian_terms, state)),
+        ("hamiltonian", [lambda, gamma, theta]) => Some(lambda - gamma + theta.to_radians().sin()),
+        ("seal", []) => Some(if seal(runtime, ir) { 1.0 } else { 0.0 }),
+        _ => None,
+    }
+}
+
+/// Value of a call argument: a coordinate of M⁷ by name or symbol, or a
+/// field bound to a coordinate
+fn argument_value(state: &CRSM7State, ir: &OmegaIR, name: &str) -> Option<f64> {
+    if let Some(field) = ir.field_coords.iter().find(|f| f.field_name == name) {
+        return Some(field.coord_value);
+    }
+    match name {
+        "lambda" | "Λ" => Some(state.lambda),
+        "gamma" | "Γ" => Some(state.gamma),
+        "phi" | "Φ" => Some(state.phi),
+        "xi" | "Ξ" => Some(state.xi),
+        "rho" | "ρ" | "ρ±" => Some(state.rho),
+        "theta" | "θ" => Some(state.theta),
+        "tau" | "τ" => Some(state.tau),
+        _ => None,
+    }
+}
+
+/// H_CRSM assembled from IR terms: c_Λ·Λ − c_Γ·Γ + c_θ·sin θ
+pub fn hamiltonian(terms: &[HamiltonianTermIR], state: &CRSM7State) -> f64 {
+    terms
+        .iter()
+        .map(|term| match term {
+            HamiltonianTermIR::CoherenceGradient { coefficient } => coefficient * state.lambda,
+            HamiltonianTermIR::DecoherenceSuppression { coefficient } => -coefficient * state.gamma,
+            HamiltonianTermIR::DualityTorsion { coefficient, theta } => {
+                coefficient * theta.to_radians().sin()
+            }
+            // Ω∞ gates sealing and contributes no energy
+            HamiltonianTermIR::Sovereignty { .. } => 0.0,
+        })
+        .sum()
+}
+
+/// Ξ threshold of the IR's Ω∞ term, if it has one
+pub fn sovereignty_threshold(terms: &[HamiltonianTermIR]) -> Option<f64> {
+    terms
+        .iter()
+        .find_map(|term| match term {
+            HamiltonianTermIR::Sovereignty { threshold } => Some(*threshold),
+            _ => None,
+        })
+}
+
+/// Ω∞.seal() under the IR's sovereignty threshold (the runtime config's
+/// without one); returns whether sealed
+fn seal(runtime: &mut DualRuntime, ir: &OmegaIR) -> bool {
+    let config = &runtime.config;
+    let threshold =
+        sovereignty_threshold(&ir.evolution.hamiltonian_terms).unwrap_or(config.sovereignty_xi);
+    if runtime.state.xi >= threshold && runtime.state.gamma <= config.gamma_tolerance {
+        runtime.sealed = true;
+    }
+    runtime.sealed
+}
+
+/// Evaluate collapse rules in order, applying the action of each that holds
+pub fn apply_collapse_rules(runtime: &mut DualRuntime, ir: &OmegaIR) {
+    for CollapseRuleIR { condition, action } in &ir.collapse_rules {
+        let holds = match condition {
+            CollapseConditionIR::GammaToZero { threshold } => {
+                runtime.state.gamma <= threshold * runtime.config.bifurcation_margin
+            }
+            CollapseConditionIR::LambdaPhiMax { threshold } => {
+                runtime.state.lambda * runtime.state.phi > *threshold
+            }
+        };
+        if !holds {
+            continue;
+        }
+        runtime.log_collapse(match condition {
+            CollapseConditionIR::GammaToZero { .. } => GAMMA_TO_ZERO,
+            CollapseConditionIR::LambdaPhiMax { .. } => LAMBDA_PHI_MAX,
+        });
+        match action {
+            CollapseActionIR::ApplyProjector => {
+                runtime.project_psi();
+            }
+            CollapseActionIR::SealSovereignty => {
+                seal(runtime, ir);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dnalang_compiler::contract::FIXTURES;
+
+    #[test]
+    fn test_ir_terms_match_builtin_hamiltonian() {
+        let terms = [
+            HamiltonianTermIR::CoherenceGradient { coefficient: 1.0 },
+            HamiltonianTermIR::DecoherenceSuppression { coefficient: 1.0 },
+            HamiltonianTermIR::DualityTorsion {
+                coefficient: 1.0,
+                theta: crate::manifold::THETA_CRITICAL,
+            },
+            HamiltonianTermIR::Sovereignty { threshold: 9.0 },
+        ];
+        let state = CRSM7State::new();
+        assert!((hamiltonian(&terms, &state) - state.hamiltonian()).abs() < 1e-12);
+        assert_eq!(sovereignty_threshold(&terms), Some(9.0));
+        assert_eq!(sovereignty_threshold(&[]), None);
+    }
+
+    #[test]
+    fn test_contract_fixtures_run() {
+        for fixture in FIXTURES {
+            let ir = fixture.compile();
+            let mut runtime = DualRuntime::new();
+            let trace = runtime.load_ir(&ir);
+            assert!(
+                trace.unresolved.is_empty(),
+                "{}: {:?}",
+                fixture.name,
+                trace.unresolved
+            );
+            runtime.run(50, ir.evolution.dt);
+            assert!(runtime.psi.norm().is_finite(), "{}", fixture.name);
+            assert!(
+                runtime.state.tau > 0.0 || runtime.sealed,
+                "{}",
+                fixture.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_gene_ops_execute() {
+        let ir = FIXTURES[0].compile();
+        let mut runtime = DualRuntime::new();
+        let trace = runtime.load_ir(&ir);
+        assert_eq!(trace.emits.len(), 3);
+        let mut router = EmitRouter::new();
+        router.add_sink(Box::new(crate::sink::MemorySink::default()));
+        assert_eq!(trace.route(&mut router).unwrap(), 3);
+
+        let ir = FIXTURES[2].compile();
+        let trace = DualRuntime::new().load_ir(&ir);
+        // hamiltonian() folds; emergence(psi, ..) reads the field at run time
+        let names: Vec<&str> = trace.values.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, vec!["hamiltonian", "emergence", "seal"]);
+        assert!(trace.unresolved.is_empty());
+    }
+
+    #[test]
+    fn test_folded_seal_seals_runtime() {
+        let parsed = dnalang_compiler::parse("organism S {\n    gene main { seal() }\n}");
+        let mut ir = dnalang_compiler::generate_omega_ir(&parsed.dna, &parsed.crsm);
+        assert!(matches!(
+            &ir.gene_ops[0].op_type,
+            GeneOpType::Folded { builtin, .. } if builtin == "seal"
+        ));
+        ir.z3_state.gamma = GAMMA_TOLERANCE;
+
+        let mut runtime = DualRuntime::new();
+        let trace = runtime.load_ir(&ir);
+        assert!(runtime.sealed);
+        assert!(runtime.certificate.is_some());
+        assert_eq!(trace.values, vec![("seal".to_string(), 1.0)]);
+    }
+}
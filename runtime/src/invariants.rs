@@ -0,0 +1,283 @@
+//! Invariant Monitor
+//!
+//! Checks the identities the runtime relies on after every step and records
+//! each one that fails, rather than letting a run drift silently:
+//! - Π⁺ + Π⁻ = I, Π±² = Π± with Π⁻Π⁺ = 0, and J² = I on the real parts of
+//!   Ψ, measured on the outputs of the runtime's `DualityConfig` (the same
+//!   `pi_plus`, `pi_minus` and `bifurcate` the step uses; on the standard
+//!   basis when a matrix involution has more modes than Ψ); with
+//!   `strict-invariants` those methods assert the projector laws first
+//! - ‖Ψ‖ = 1
+//! - Γ ≥ εΓ (the configured `gamma_tolerance`; the error is the shortfall
+//!   relative to εΓ)
+//! - the CRSM7 metric keeps signature (6, 1): every diagonal entry but the
+//!   time-like one (g₅₅ = −1) strictly positive and that one negative (the
+//!   error is the number of entries that are not)
+//!
+//! The monitor only exists with the `invariants` feature, which is meant
+//! for debug and test builds.
+
+use crate::dual_runtime::DualRuntime;
+use crate::projectors::InvolutionSpec;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Index of the time-like (negative) entry of the metric, g₅₅ = −1 in
+/// diag(1, 1, 1, sin²θ, sin²θ, −1, Λ)
+const METRIC_TIME_SLOT: usize = 5;
+
+/// An identity the monitor checks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Invariant {
+    Completeness,
+    Idempotence,
+    JSquared,
+    Normalization,
+    GammaFloor,
+    MetricSignature,
+}
+
+/// One failed check
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Violation {
+    pub step: u64,
+    pub tau: f64,
+    pub invariant: Invariant,
+    /// How far the identity is off (0 when it holds)
+    pub error: f64,
+}
+
+/// Summary of a monitored run
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct InvariantReport {
+    pub steps_checked: u64,
+    /// Violations per invariant
+    pub counts: BTreeMap<Invariant, u64>,
+    /// Largest error seen per violated invariant
+    pub worst: BTreeMap<Invariant, f64>,
+    /// First step with any violation
+    pub first_step: Option<u64>,
+}
+
+impl InvariantReport {
+    /// Whether every check passed
+    pub fn is_clean(&self) -> bool {
+        self.counts.is_empty()
+    }
+}
+
+/// Checks invariants after each step, keeping the most recent violations
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InvariantMonitor {
+    /// Largest error accepted
+    pub tolerance: f64,
+    /// Violations kept (oldest dropped first)
+    pub capacity: usize,
+    violations: Vec<Violation>,
+    report: InvariantReport,
+}
+
+impl Default for InvariantMonitor {
+    fn default() -> Self {
+        Self::new(1e-9)
+    }
+}
+
+impl InvariantMonitor {
+    pub fn new(tolerance: f64) -> Self {
+        Self {
+            tolerance,
+            capacity: 1024,
+            violations: Vec::new(),
+            report: InvariantReport::default(),
+        }
+    }
+
+    /// Check `runtime` as it stands; returns the violations found
+    pub fn check(&mut self, runtime: &DualRuntime) -> Vec<Violation> {
+        let found: Vec<Violation> = measure(runtime)
+            .into_iter()
+            .filter(|&(_, error)| error > self.tolerance)
+            .map(|(invariant, error)| Violation {
+                step: runtime.steps,
+                tau: runtime.state.tau,
+                invariant,
+                error,
+            })
+            .collect();
+
+        self.report.steps_checked += 1;
+        for v in &found {
+            *self.report.counts.entry(v.invariant).or_default() += 1;
+            let worst = self.report.worst.entry(v.invariant).or_default();
+            *worst = worst.max(v.error);
+            self.report.first_step.get_or_insert(v.step);
+        }
+        self.violations.extend(found.iter().cloned());
+        let excess = self.violations.len().saturating_sub(self.capacity);
+        self.violations.drain(..excess);
+        found
+    }
+
+    /// Most recent violations, oldest first
+    pub fn violations(&self) -> &[Violation] {
+        &self.violations
+    }
+
+    pub fn report(&self) -> &InvariantReport {
+        &self.report
+    }
+
+    pub fn clear(&mut self) {
+        self.violations.clear();
+        self.report = InvariantReport::default();
+    }
+}
+
+/// max |a − b| over paired components
+fn max_diff(a: &[f64], b: &[f64]) -> f64 {
+    a.iter()
+        .zip(b)
+        .fold(0.0f64, |error, (x, y)| error.max((x - y).abs()))
+}
+
+/// Error of every invariant on `runtime`
+fn measure(runtime: &DualRuntime) -> Vec<(Invariant, f64)> {
+    let psi: Vec<f64> = runtime.psi.components().iter().map(|c| c.re).collect();
+    let duality = &runtime.duality;
+    let (completeness, idempotence, j_squared) = match &duality.involution {
+        InvolutionSpec::Matrix { rows } if rows.len() > psi.len() => {
+            let verification = duality.verify();
+            (
+                verification.completeness_error,
+                verification
+                    .idempotence_error
+                    .max(verification.orthogonality_error),
+                verification.j_squared_error,
+            )
+        }
+        involution => {
+            let (plus, minus) = duality.bifurcate(&psi);
+            let sum: Vec<f64> = plus.iter().zip(&minus).map(|(p, m)| p + m).collect();
+            let completeness = max_diff(&sum, &psi)
+                .max(max_diff(&duality.pi_plus(&psi), &plus))
+                .max(max_diff(&duality.pi_minus(&psi), &minus));
+            let zeros = vec![0.0; psi.len()];
+            let idempotence = max_diff(&duality.pi_plus(&plus), &plus)
+                .max(max_diff(&duality.pi_minus(&minus), &minus))
+                .max(max_diff(&duality.pi_minus(&plus), &zeros));
+            let jj = involution.apply(&involution.apply(&psi));
+            (completeness, idempotence, max_diff(&jj, &psi))
+        }
+    };
+
+    let metric = runtime.state.metric();
+    let signature = (0..7)
+        .filter(|&i| {
+            let g = metric[i][i];
+            if i == METRIC_TIME_SLOT {
+                g >= 0.0
+            } else {
+                g <= 0.0
+            }
+        })
+        .count() as f64;
+
+    vec![
+        (Invariant::Completeness, completeness),
+        (Invariant::Idempotence, idempotence),
+        (Invariant::JSquared, j_squared),
+        (Invariant::Normalization, (runtime.psi.norm() - 1.0).abs()),
+        (
+            Invariant::GammaFloor,
+            (1.0 - runtime.state.gamma / runtime.config.gamma_tolerance).max(0.0),
+        ),
+        (Invariant::MetricSignature, signature),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_run_reports_nothing() {
+        let mut runtime = DualRuntime::new();
+        runtime.run(20, 0.01);
+        let report = runtime.invariants.report();
+        assert_eq!(report.steps_checked, 20);
+        assert!(report.is_clean(), "{:?}", report);
+    }
+
+    #[test]
+    fn test_violations_are_collected() {
+        let mut runtime = DualRuntime::new();
+        runtime.state.theta = 0.0;
+        runtime.state.gamma = 0.0;
+        let mut monitor = InvariantMonitor::default();
+        let found: Vec<Invariant> = monitor
+            .check(&runtime)
+            .iter()
+            .map(|v| v.invariant)
+            .collect();
+        assert_eq!(
+            found,
+            vec![Invariant::GammaFloor, Invariant::MetricSignature]
+        );
+
+        // Γ → 0 applies Π⁺, which leaves Ψ unnormalized
+        runtime.state.gamma = runtime.config.gamma_tolerance;
+        runtime.state.theta = 51.843;
+        runtime.step(0.01);
+        let report = runtime.invariants.report();
+        assert_eq!(report.counts.get(&Invariant::Normalization), Some(&1));
+        assert_eq!(report.first_step, Some(1));
+    }
+
+    #[test]
+    fn test_metric_time_slot_is_the_negative_entry() {
+        let runtime = DualRuntime::new();
+        let metric = runtime.state.metric();
+        for (i, row) in metric.iter().enumerate() {
+            assert_eq!(row[i] < 0.0, i == METRIC_TIME_SLOT, "g[{}][{}]", i, i);
+        }
+        let error = |runtime: &DualRuntime| {
+            measure(runtime)
+                .into_iter()
+                .find(|(invariant, _)| *invariant == Invariant::MetricSignature)
+                .unwrap()
+                .1
+        };
+        assert_eq!(error(&runtime), 0.0);
+
+        // θ = 0 zeroes both sin²θ entries
+        let mut flat = DualRuntime::new();
+        flat.state.theta = 0.0;
+        assert_eq!(error(&flat), 2.0);
+    }
+
+    #[test]
+    #[cfg_attr(
+        all(feature = "strict-invariants", debug_assertions),
+        should_panic(expected = "J² ≠ I")
+    )]
+    fn test_non_involution_duality_is_reported() {
+        let mut runtime = DualRuntime::new();
+        let modes = runtime.psi.components().len();
+        let mut rows: Vec<Vec<f64>> = (0..modes)
+            .map(|i| (0..modes).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+            .collect();
+        rows[0][0] = 2.0;
+        runtime.duality.involution = InvolutionSpec::Matrix { rows };
+        runtime.psi.components_mut()[0].re = 1.0;
+
+        let found: Vec<Invariant> = InvariantMonitor::default()
+            .check(&runtime)
+            .iter()
+            .map(|v| v.invariant)
+            .collect();
+        assert!(found.contains(&Invariant::Idempotence), "{:?}", found);
+        assert!(found.contains(&Invariant::JSquared), "{:?}", found);
+    }
+}
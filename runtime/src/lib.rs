@@ -6,23 +6,162 @@
 //!
 //! ## Core Components
 //! - Dual Runtime: Unified execution environment
-//! - Projectors: Π⁺, Π⁻, and J involution
-//! - Manifold: CRSM7 state evolution
-//! - Organism: Gene execution and DMA operations
+//! - Wave: Multi-mode state vector Ψ
+//! - Collapse: Ordered built-in and custom collapse rules
+//! - Checkpoints: Versioned snapshots for resuming runs
+//! - Certificates: Auditable sovereignty certificates with trajectory hashes
+//! - Unseal: Capability-gated unsealing with an audit trail
+//! - Config: Tunable evolution, collapse and DMA constants
+//! - Projectors: Π⁺, Π⁻, and J involution over scalar, Complex and matrix values
+//! - Coupling: Further manifolds stepped with the runtime, exchanging Γ and Λ through a coupling matrix
+//! - Manifold: CRSM7 state evolution with Euler, RK4 and RK45 integrators or pluggable operators, metric geometry and curvature, state diffs and interpolation
+//! - Adaptive: Step-size control for runs to a target τ
+//! - Organism: Gene execution, scheduling, mutation, crossover, inter-organism messages, arena gene storage, .organism files and DMA operations (rayon with the `parallel` feature)
+//! - Events: Ring buffer of step, collapse and seal events
+//! - Interpreter: Executes compiled Omega IR in the dual runtime
+//! - Ensemble: Many runtimes from varied initial conditions, with statistics
+//! - Anneal: Simulated annealing over runtime parameters
+//! - Evolutionary: Genetic-algorithm search over organisms
+//! - Channels: Bounded event channels with backpressure policies
+//! - Sinks: Typed emit payloads with negotiated encodings
+//! - Observers: Step, collapse and seal callbacks on the runtime
+//! - Perturbations: Scheduled interventions at given epochs
+//! - Precision: f32 or f64 compact states for memory-bound large meshes
+//! - Profiles: Standard and lite (no event log, f32-rounded) runtime presets
+//! - Profiler: Opt-in per-phase timing of runtime steps
+//! - Debugger: Conditional breakpoints with step, continue and inspect
+//! - Rewind: Bounded buffer of recent states for stepping backwards
+//! - Shadow: Replay recorded runs to verify upgrades
+//! - Workloads: Canonical, seeded benchmark workloads
+//! - Trajectory: Sampled evolution curves with CSV and JSON Lines export
+//! - Watch: Recompile on source change and hot-swap into a running runtime
+//! - Watches: Expressions over state fields, recorded each step with threshold callbacks
+//! - Scripting: Rhai hooks on runtime events (`scripting` feature)
+//! - Driver: Async stepping on a tokio interval (`async` feature)
+//! - GPU: Compute-shader gene evolution for very large organisms (`wgpu` feature)
+//! - Wasm: wasm-bindgen exports for browser demos (`wasm` feature)
+//! - FFI: Stable C ABI for C and C++ hosts (`ffi` feature)
+//! - Invariants: Per-step identity checks with violation reports (`invariants` feature)
+//! - Double-double: ~32-digit Γ and Ξ evolution without the Ξ cap, for
+//!   `CompactState` and `DualRuntime::with_high_precision` (`high-precision` feature)
 
+pub mod adaptive;
+pub mod anneal;
+pub mod certificate;
+pub mod channel;
+pub mod checkpoint;
+pub mod collapse;
+pub mod config;
+pub mod coupling;
+pub mod debugger;
 pub mod dual_runtime;
+pub mod ensemble;
+pub mod events;
+pub mod evolutionary;
+pub mod interpreter;
 pub mod manifold;
+pub mod observer;
 pub mod organism;
+pub mod perturbation;
+pub mod precision;
+pub mod profile;
+pub mod profiler;
 pub mod projectors;
+pub mod rewind;
+pub mod rng;
+pub mod shadow;
+pub mod sink;
+pub mod trajectory;
+pub mod unseal;
+pub mod watch;
+pub mod watches;
+pub mod wave;
+pub mod workloads;
+#[cfg(feature = "async")]
+pub mod driver;
+#[cfg(feature = "high-precision")]
+pub mod double_double;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "wgpu")]
+pub mod gpu;
+#[cfg(feature = "invariants")]
+pub mod invariants;
+#[cfg(feature = "scripting")]
+pub mod scripting;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 // Re-exports for convenience
+pub use adaptive::{AdaptiveReport, Adjustment, StepController};
+pub use anneal::{AnnealError, AnnealParam, AnnealResult, AnnealSchedule, Annealer, ParameterSet};
+pub use channel::{bounded, BackpressurePolicy, ChannelConfig, ChannelMetrics, Coalesce, EventReceiver, EventSender};
+pub use certificate::{
+    verify_certificate, CertificateError, SovereigntyCertificate, TRAJECTORY_HASH_SEED,
+};
+pub use checkpoint::{Checkpoint, CHECKPOINT_FORMAT};
+pub use collapse::{
+    CollapseRule, CollapseRules, FnRule, GammaToZero, LambdaPhiMax, GAMMA_TO_ZERO, LAMBDA_PHI_MAX,
+};
+pub use config::{PhiSaturation, RuntimeConfig};
+pub use coupling::{CoupledManifolds, Coupling};
+pub use debugger::{
+    Breakpoint, BreakpointError, Debugger, Hit, StateField, StopReason, Subject, Trigger,
+};
 pub use dual_runtime::{Complex, DualRuntime, Manifold, Z3MeshWeights};
+pub use ensemble::{Ensemble, EnsembleStats};
+pub use events::{EventLog, RuntimeEvent};
+pub use evolutionary::{Fitness, GaConfig, GaDriver, GaResult, Selection, SovereigntyFitness};
+pub use interpreter::ExecutionTrace;
 pub use manifold::{
-    CRSM7State, DET_CRITICAL, EMERGENCE_MAX, EMERGENCE_THRESHOLD, GAMMA_TOLERANCE,
-    OMEGA_SOV_THRESHOLD, THETA_CRITICAL,
+    CRSM7State, Christoffel, ComponentDelta, Euler, FnOperator, Integrator, IntegratorKind,
+    Matrix7, Operator, Operators, Rk4, Rk45, StateDelta, DET_CRITICAL, EMERGENCE_MAX,
+    EMERGENCE_THRESHOLD, GAMMA_TOLERANCE, OMEGA_SOV_THRESHOLD, THETA_CRITICAL,
+};
+pub use observer::{Observer, ObserverControl, Observers, StepEvent};
+pub use organism::{
+    CrossoverStrategy, DmaReport, Gene, GeneArena, GeneDma, GeneField, Mailbox, Message, Mutation,
+    MutationRates, Mutator, Organism, OrganismExecutor, OrganismFile, Scheduler, SchedulingPolicy,
+    ORGANISM_EXTENSION, ORGANISM_FORMAT,
+};
+pub use perturbation::{
+    Inversion, InversionTarget, Perturbation, PerturbationSchedule, ScheduledPerturbation,
+};
+pub use precision::{CompactMeshWeights, CompactState, Real};
+pub use profile::{RuntimeProfile, DEFAULT_EVENT_CAPACITY};
+pub use profiler::{PhaseReport, ProfileReport, StepPhase, StepProfiler};
+pub use projectors::{
+    bifurcate, involution_j, pi_minus, pi_plus, verify_completeness, verify_j_squared, Amplitude,
+    DualityConfig, DualityVerification, InvolutionError, InvolutionSpec, LinearInvolution, Matrix,
+    Polarity, Projector,
+};
+pub use rewind::{RewindBuffer, RewindFrame};
+pub use rng::SeededRng;
+pub use shadow::{Divergence, Observables, Recording, ShadowReport, ShadowRun};
+pub use sink::{EmitRouter, EmitSink, MemorySink, TextSink};
+pub use trajectory::{TrajectoryRecorder, TrajectorySample};
+pub use unseal::{UnsealError, UnsealRecord};
+pub use watch::{compile_source, compile_source_with, ResetPolicy, SourceWatcher, SwapReport};
+pub use watches::{
+    BinaryOp, Crossing, CrossingCallback, Expr, ExprError, Function, Watch, WatchCrossing,
+    Watches, WATCH_CAPACITY,
 };
-pub use organism::{Gene, Organism, OrganismExecutor};
-pub use projectors::{bifurcate, involution_j, pi_minus, pi_plus, verify_completeness, verify_j_squared};
+pub use wave::WaveFunction;
+pub use workloads::{Workload, WorkloadRun};
+#[cfg(feature = "async")]
+pub use driver::{AsyncDualRuntime, DriverState};
+#[cfg(feature = "high-precision")]
+pub use double_double::DoubleDouble;
+#[cfg(feature = "ffi")]
+pub use ffi::{DnaRuntime, DnaState, DNA_ABI_VERSION};
+#[cfg(feature = "wgpu")]
+pub use gpu::{GpuError, GpuEvolver, GPU_MIN_GENES};
+#[cfg(feature = "invariants")]
+pub use invariants::{Invariant, InvariantMonitor, InvariantReport, Violation};
+#[cfg(feature = "scripting")]
+pub use scripting::{ScriptBudget, ScriptError, ScriptEvent, ScriptHost};
+#[cfg(feature = "wasm")]
+pub use wasm::{WasmRuntime, WasmState};
 
 #[cfg(test)]
 mod tests {
@@ -3,6 +3,9 @@
 //! 7-dimensional Consciousness Resonance State Machine state vector:
 //! C7D = (Λ, Γ, Φ, Ξ, ρ±, θ51.843°, τ)
 
+use super::operators::Operators;
+use crate::config::RuntimeConfig;
+use crate::precision::CompactState;
 use serde::{Deserialize, Serialize};
 
 /// Critical torsion angle (51.843°)
@@ -115,27 +118,19 @@ impl CRSM7State {
         d_lambda - k_gamma + torsion_term
     }
 
-    /// Evolve the state by time step dt
+    /// Evolve the state by time step dt with the built-in operators
     /// ∂τ C7D = H_CRSM(C7D)
     pub fn evolve(&mut self, dt: f64) {
-        let h = self.hamiltonian();
-
-        // Epoch advancement
-        self.tau += dt;
-
-        // Decoherence suppression: Γ decays exponentially
-        self.gamma *= (-dt).exp();
-        self.gamma = self.gamma.max(GAMMA_TOLERANCE);
-
-        // Coherence evolution
-        self.lambda += h * dt * 0.01;
-        self.lambda = self.lambda.min(0.999);
-
-        // Information accumulation
-        self.phi += 0.01 * self.lambda * dt;
+        Operators::default().evolve(self, dt);
+    }
 
-        // Recompute emergence
-        self.compute_emergence();
+    /// Evolve the state by time step dt under a given Hamiltonian value,
+    /// with rates and bounds taken from `config`
+    pub fn evolve_with(&mut self, dt: f64, h: f64, config: &RuntimeConfig) {
+        // The evolution law is defined once, on `CompactState`
+        let mut compact = CompactState::<f64>::from_state(self);
+        compact.evolve_with(dt, h, config);
+        *self = compact.to_state();
     }
 
     /// Get the 7D metric tensor
@@ -0,0 +1,134 @@
+//! State Differences
+//!
+//! Component-wise comparison of two CRSM7 states, for tests and for tools
+//! that compare snapshots. Relative changes are taken against the first
+//! state's value (absolute when that value is 0).
+
+use crate::manifold::crsm7::CRSM7State;
+use serde::Serialize;
+use std::fmt;
+
+/// Change in one component
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct ComponentDelta {
+    pub name: &'static str,
+    pub from: f64,
+    pub to: f64,
+    /// to − from
+    pub delta: f64,
+    /// |to − from| / |from| (|to − from| when from is 0)
+    pub relative: f64,
+}
+
+impl ComponentDelta {
+    fn new(name: &'static str, from: f64, to: f64) -> Self {
+        let delta = to - from;
+        let relative = if from == 0.0 {
+            delta.abs()
+        } else {
+            (delta / from).abs()
+        };
+        Self {
+            name,
+            from,
+            to,
+            delta,
+            relative,
+        }
+    }
+}
+
+/// Per-component changes from one state to another, in C7D order
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StateDelta {
+    pub components: [ComponentDelta; 7],
+}
+
+impl StateDelta {
+    /// Largest relative change
+    pub fn max_relative(&self) -> f64 {
+        self.components
+            .iter()
+            .map(|c| c.relative)
+            .fold(0.0, f64::max)
+    }
+
+    /// Component with the largest relative change
+    pub fn largest(&self) -> &ComponentDelta {
+        self.components
+            .iter()
+            .max_by(|a, b| a.relative.total_cmp(&b.relative))
+            .expect("seven components")
+    }
+
+    /// Components whose relative change exceeds `tolerance`
+    pub fn changed(&self, tolerance: f64) -> Vec<&ComponentDelta> {
+        self.components
+            .iter()
+            .filter(|c| c.relative > tolerance || c.relative.is_nan())
+            .collect()
+    }
+}
+
+impl fmt::Display for StateDelta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, c) in self.components.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(
+                f,
+                "{}: {} → {} (Δ {:+e}, {:.3e} rel)",
+                c.name, c.from, c.to, c.delta, c.relative
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl CRSM7State {
+    /// Changes from `self` to `other`
+    pub fn diff(&self, other: &CRSM7State) -> StateDelta {
+        StateDelta {
+            components: [
+                ComponentDelta::new("Λ", self.lambda, other.lambda),
+                ComponentDelta::new("Γ", self.gamma, other.gamma),
+                ComponentDelta::new("Φ", self.phi, other.phi),
+                ComponentDelta::new("Ξ", self.xi, other.xi),
+                ComponentDelta::new("ρ", self.rho, other.rho),
+                ComponentDelta::new("θ", self.theta, other.theta),
+                ComponentDelta::new("τ", self.tau, other.tau),
+            ],
+        }
+    }
+
+    /// Whether every component agrees within `tolerance` (relative, and
+    /// absolute for components that are 0); NaN never agrees
+    pub fn approx_eq(&self, other: &CRSM7State, tolerance: f64) -> bool {
+        self.diff(other).changed(tolerance).is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_and_approx_eq() {
+        let a = CRSM7State::new();
+        let mut b = a.clone();
+        assert!(a.approx_eq(&b, 0.0));
+
+        b.phi = a.phi * 1.01;
+        b.tau = 0.5;
+        let delta = a.diff(&b);
+        assert_eq!(delta.largest().name, "τ");
+        let changed: Vec<&str> = delta.changed(1e-3).iter().map(|c| c.name).collect();
+        assert_eq!(changed, vec!["Φ", "τ"]);
+        assert!((delta.components[2].relative - 0.01).abs() < 1e-12);
+        assert!(a.approx_eq(&b, 0.5) && !a.approx_eq(&b, 1e-3));
+
+        b.lambda = f64::NAN;
+        assert!(!a.approx_eq(&b, f64::INFINITY));
+    }
+}
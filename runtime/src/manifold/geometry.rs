@@ -0,0 +1,350 @@
+//! Manifold Geometry
+//!
+//! Quantities derived from the state-dependent 7D metric
+//! g = diag(1, 1, 1, sin²θ, sin²θ, −1, Λ) returned by `CRSM7State::metric`.
+//! The metric is diagonal today, so the determinant and inverse take a
+//! diagonal fast path; general matrices (a metric with off-diagonal
+//! couplings) fall back to elimination with partial pivoting.
+//!
+//! At the critical torsion angle sin²θ ≈ 1/φ = `DET_CRITICAL`, which is
+//! where that constant comes from; the DMA gradient now uses the volume
+//! factor |det g|^(−1/2) of the actual state instead, with |det g| held at
+//! `DET_FLOOR` or above so the factor stays bounded as Λ or θ → 0.
+//!
+//! Connection and curvature treat the seven components (Λ, Γ, Φ, Ξ, ρ, θ,
+//! τ) as coordinates, θ in degrees as stored, and differentiate the metric
+//! by central differences, so they follow whatever `metric` returns:
+//! - Christoffel symbols Γᵏᵢⱼ = ½ gᵏˡ (∂ᵢ gₗⱼ + ∂ⱼ gₗᵢ − ∂ₗ gᵢⱼ)
+//! - Ricci tensor Rᵢⱼ and scalar curvature R = gⁱʲ Rᵢⱼ
+//! - the Independence Criterion term (∂μ − Γμ)(∇α Ψ), the covariant
+//!   Hessian of a scalar field Ψ over the manifold
+
+use super::crsm7::CRSM7State;
+
+/// 7×7 matrix, row-major
+pub type Matrix7 = [[f64; 7]; 7];
+
+/// Christoffel symbols of the second kind, `c[k][i][j]` = Γᵏᵢⱼ
+pub type Christoffel = [[[f64; 7]; 7]; 7];
+
+/// Smallest |det g| used by the volume factor, which therefore never
+/// exceeds 10³
+pub const DET_FLOOR: f64 = 1e-6;
+
+/// Central-difference step, relative to max(1, |coordinate|)
+const DIFFERENCE_STEP: f64 = 1e-4;
+
+fn is_diagonal(m: &Matrix7) -> bool {
+    (0..7).all(|i| (0..7).all(|j| i == j || m[i][j] == 0.0))
+}
+
+/// det m
+pub fn determinant(m: &Matrix7) -> f64 {
+    if is_diagonal(m) {
+        return (0..7).map(|i| m[i][i]).product();
+    }
+    let mut a = *m;
+    let mut det = 1.0;
+    for col in 0..7 {
+        let pivot = (col..7)
+            .max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))
+            .expect("non-empty range");
+        if a[pivot][col] == 0.0 {
+            return 0.0;
+        }
+        if pivot != col {
+            a.swap(pivot, col);
+            det = -det;
+        }
+        det *= a[col][col];
+        let (upper, lower) = a.split_at_mut(col + 1);
+        let pivot_row = &upper[col];
+        for row in lower {
+            let factor = row[col] / pivot_row[col];
+            for (x, p) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                *x -= factor * p;
+            }
+        }
+    }
+    det
+}
+
+/// m⁻¹, or `None` when m is singular
+pub fn inverse(m: &Matrix7) -> Option<Matrix7> {
+    let mut inv = [[0.0; 7]; 7];
+    if is_diagonal(m) {
+        for i in 0..7 {
+            if m[i][i] == 0.0 {
+                return None;
+            }
+            inv[i][i] = 1.0 / m[i][i];
+        }
+        return Some(inv);
+    }
+
+    // Gauss–Jordan on [m | I]
+    let mut a = *m;
+    for (i, row) in inv.iter_mut().enumerate() {
+        row[i] = 1.0;
+    }
+    for col in 0..7 {
+        let pivot = (col..7)
+            .max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))
+            .expect("non-empty range");
+        if a[pivot][col] == 0.0 {
+            return None;
+        }
+        a.swap(pivot, col);
+        inv.swap(pivot, col);
+        let scale = a[col][col];
+        for k in 0..7 {
+            a[col][k] /= scale;
+            inv[col][k] /= scale;
+        }
+        for row in (0..7).filter(|&row| row != col) {
+            let factor = a[row][col];
+            if factor != 0.0 {
+                for k in 0..7 {
+                    a[row][k] -= factor * a[col][k];
+                    inv[row][k] -= factor * inv[col][k];
+                }
+            }
+        }
+    }
+    Some(inv)
+}
+
+impl CRSM7State {
+    /// Components as coordinates, Ξ uncapped
+    pub fn coordinates(&self) -> [f64; 7] {
+        [
+            self.lambda,
+            self.gamma,
+            self.phi,
+            self.xi,
+            self.rho,
+            self.theta,
+            self.tau,
+        ]
+    }
+
+    /// State at coordinates `x`, taken as given (Ξ is not recomputed)
+    pub fn from_coordinates(x: [f64; 7]) -> Self {
+        Self {
+            lambda: x[0],
+            gamma: x[1],
+            phi: x[2],
+            xi: x[3],
+            rho: x[4],
+            theta: x[5],
+            tau: x[6],
+        }
+    }
+
+    /// ∂f/∂xᵘ for every μ by central differences
+    fn differentiate<T, const N: usize>(&self, f: impl Fn(&Self) -> [T; N]) -> [[T; N]; 7]
+    where
+        T: Copy + Difference,
+    {
+        let x = self.coordinates();
+        std::array::from_fn(|mu| {
+            let h = DIFFERENCE_STEP * x[mu].abs().max(1.0);
+            let (mut up, mut down) = (x, x);
+            up[mu] += h;
+            down[mu] -= h;
+            let (fu, fd) = (
+                f(&Self::from_coordinates(up)),
+                f(&Self::from_coordinates(down)),
+            );
+            std::array::from_fn(|i| T::difference(fu[i], fd[i], 2.0 * h))
+        })
+    }
+
+    /// det g = −Λ sin⁴θ
+    pub fn metric_determinant(&self) -> f64 {
+        determinant(&self.metric())
+    }
+
+    /// g⁻¹, or `None` where the metric degenerates (θ a multiple of 180°,
+    /// or Λ = 0)
+    pub fn metric_inverse(&self) -> Option<Matrix7> {
+        inverse(&self.metric())
+    }
+
+    /// Γᵏᵢⱼ, or `None` where the metric degenerates
+    pub fn christoffel(&self) -> Option<Christoffel> {
+        let inv = self.metric_inverse()?;
+        // dg[l][i][j] = ∂ₗ gᵢⱼ
+        let dg = self.differentiate(|s| s.metric());
+        Some(std::array::from_fn(|k| {
+            std::array::from_fn(|i| {
+                std::array::from_fn(|j| {
+                    0.5 * (0..7)
+                        .map(|l| inv[k][l] * (dg[i][l][j] + dg[j][l][i] - dg[l][i][j]))
+                        .sum::<f64>()
+                })
+            })
+        }))
+    }
+
+    /// Rᵢⱼ = ∂ₖ Γᵏᵢⱼ − ∂ⱼ Γᵏᵢₖ + Γᵏₖₗ Γˡᵢⱼ − Γᵏⱼₗ Γˡᵢₖ
+    pub fn ricci(&self) -> Option<Matrix7> {
+        let c = self.christoffel()?;
+        // dc[m][k][i][j] = ∂ₘ Γᵏᵢⱼ (degenerate neighbours contribute 0)
+        let dc = self.differentiate(|s| s.christoffel().unwrap_or_default());
+        Some(std::array::from_fn(|i| {
+            std::array::from_fn(|j| {
+                (0..7)
+                    .map(|k| {
+                        dc[k][k][i][j] - dc[j][k][i][k]
+                            + (0..7)
+                                .map(|l| c[k][k][l] * c[l][i][j] - c[k][j][l] * c[l][i][k])
+                                .sum::<f64>()
+                    })
+                    .sum()
+            })
+        }))
+    }
+
+    /// Scalar curvature R = gⁱʲ Rᵢⱼ, or `None` where the metric
+    /// degenerates
+    pub fn curvature(&self) -> Option<f64> {
+        let inv = self.metric_inverse()?;
+        let ricci = self.ricci()?;
+        Some(
+            (0..7)
+                .flat_map(|i| (0..7).map(move |j| (i, j)))
+                .map(|(i, j)| inv[i][j] * ricci[i][j])
+                .sum(),
+        )
+    }
+
+    /// (∂μ − Γμ)(∇α Ψ) = ∂μ∂α Ψ − Γᵝμα ∂β Ψ, the covariant Hessian of
+    /// `psi` at this state
+    pub fn covariant_hessian(&self, psi: impl Fn(&Self) -> f64) -> Option<Matrix7> {
+        let c = self.christoffel()?;
+        let gradient = |s: &Self| s.differentiate(|t| [psi(t)]).map(|d| d[0]);
+        let du = gradient(self);
+        let ddu = self.differentiate(gradient);
+        Some(std::array::from_fn(|mu| {
+            std::array::from_fn(|alpha| {
+                ddu[mu][alpha] - (0..7).map(|b| c[b][mu][alpha] * du[b]).sum::<f64>()
+            })
+        }))
+    }
+
+    /// Volume factor |det g|^(−1/2) in ∂τΨ = α · det(g)^(−1/2) Ψ, with
+    /// |det g| clamped to `DET_FLOOR` as the metric degenerates
+    pub fn volume_factor(&self) -> f64 {
+        // f64::max also maps a NaN determinant to the floor
+        self.metric_determinant().abs().max(DET_FLOOR).powf(-0.5)
+    }
+}
+
+/// (a − b) / h, element-wise for arrays
+trait Difference {
+    fn difference(a: Self, b: Self, h: f64) -> Self;
+}
+
+impl Difference for f64 {
+    fn difference(a: f64, b: f64, h: f64) -> f64 {
+        (a - b) / h
+    }
+}
+
+impl<T: Difference + Copy, const N: usize> Difference for [T; N] {
+    fn difference(a: Self, b: Self, h: f64) -> Self {
+        std::array::from_fn(|i| T::difference(a[i], b[i], h))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manifold::DET_CRITICAL;
+
+    #[test]
+    fn test_metric_determinant_and_inverse() {
+        let state = CRSM7State::new();
+        let sin2 = state.theta.to_radians().sin().powi(2);
+        assert!((sin2 - DET_CRITICAL).abs() < 1e-3);
+        let det = state.metric_determinant();
+        assert!((det + state.lambda * sin2 * sin2).abs() < 1e-15);
+
+        let g = state.metric();
+        let inv = state.metric_inverse().unwrap();
+        for i in 0..7 {
+            assert!((g[i][i] * inv[i][i] - 1.0).abs() < 1e-15);
+        }
+
+        let degenerate = CRSM7State::with_values(0.0, 0.01, 1.0, 1.0, 51.843, 0.0);
+        assert!(degenerate.metric_inverse().is_none());
+        assert_eq!(degenerate.volume_factor(), DET_FLOOR.powf(-0.5));
+        let nearly = CRSM7State::with_values(1e-9, 0.01, 1.0, 1.0, 51.843, 0.0);
+        assert_eq!(nearly.volume_factor(), degenerate.volume_factor());
+        assert!(state.volume_factor() < degenerate.volume_factor());
+    }
+
+    #[test]
+    fn test_christoffel_and_curvature() {
+        // g = diag(1, 1, 1, sin²(cθ), sin²(cθ), −1, Λ) with c = π/180 splits
+        // into dΛ² + Λ dτ² (R = 1/(2Λ²)) and −dθ² + sin²(cθ)(dΞ² + dρ²)
+        // (R = c²(2 cot²(cθ) − 4))
+        let state = CRSM7State::new();
+        let c = 1f64.to_radians();
+        let (sin, cos) = (
+            state.theta.to_radians().sin(),
+            state.theta.to_radians().cos(),
+        );
+
+        let symbols = state.christoffel().unwrap();
+        assert!((symbols[0][6][6] + 0.5).abs() < 1e-8);
+        assert!((symbols[6][0][6] - 0.5 / state.lambda).abs() < 1e-8);
+        assert!((symbols[5][3][3] - c * sin * cos).abs() < 1e-10);
+        assert!((symbols[3][3][5] - c * cos / sin).abs() < 1e-10);
+        assert_eq!(symbols[1][1][1], 0.0);
+
+        let expected = 0.5 / state.lambda.powi(2) + c * c * (2.0 * (cos / sin).powi(2) - 4.0);
+        let curvature = state.curvature().unwrap();
+        assert!(
+            (curvature - expected).abs() < 1e-6,
+            "{} vs {}",
+            curvature,
+            expected
+        );
+        let degenerate = CRSM7State::with_values(0.0, 0.01, 1.0, 1.0, 51.843, 0.0);
+        assert!(degenerate.curvature().is_none());
+
+        // Ψ = Λ²/2: ∂μ∂α Ψ is 1 at (Λ, Λ) only; Γᴧ₆₆ = −½ adds ½Λ at (τ, τ)
+        let hessian = state
+            .covariant_hessian(|s| s.lambda * s.lambda / 2.0)
+            .unwrap();
+        assert!((hessian[0][0] - 1.0).abs() < 1e-6);
+        assert!((hessian[6][6] - 0.5 * state.lambda).abs() < 1e-6);
+        assert!(hessian[2][2].abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_general_fallback() {
+        // Couple the first two axes: det = (2 · 1 − 0.5²) · Π g_ii (i ≥ 2)
+        let g = CRSM7State::new().metric();
+        let mut m = g;
+        m[0][0] = 2.0;
+        m[0][1] = 0.5;
+        m[1][0] = 0.5;
+        let expected = 1.75 * (2..7).map(|i| g[i][i]).product::<f64>();
+        assert!((determinant(&m) - expected).abs() < 1e-14);
+
+        let inv = inverse(&m).unwrap();
+        for (i, row) in m.iter().enumerate() {
+            for j in 0..7 {
+                let product: f64 = row.iter().zip(&inv).map(|(x, r)| x * r[j]).sum();
+                let identity = if i == j { 1.0 } else { 0.0 };
+                assert!((product - identity).abs() < 1e-12);
+            }
+        }
+        m[1] = m[0];
+        assert_eq!(determinant(&m), 0.0);
+        assert!(inverse(&m).is_none());
+    }
+}
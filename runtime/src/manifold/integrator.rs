@@ -0,0 +1,382 @@
+//! ODE Integrators
+//!
+//! ∂τ C7D = H_CRSM(C7D) written as an ODE in (Λ, Γ, Φ):
+//! - dΛ/dτ = c_Λ · H(C7D)
+//! - dΓ/dτ = −Γ
+//! - dΦ/dτ = c_Φ · Λ, saturated as configured by `PhiSaturation`
+//!
+//! Integrators:
+//! - Euler: the original update (exact Γ decay, explicit Λ, Φ from the new Λ);
+//!   first order, cheap, drifts for large dt
+//! - RK4: classical fourth-order Runge–Kutta
+//! - RK45: adaptive Dormand–Prince 5(4), substepping within each dt until the
+//!   local error estimate is below the tolerance; if the substep limit is
+//!   reached, the rest of dt is taken as one fixed step
+//!
+//! Rates and bounds come from `RuntimeConfig`. After every step τ advances by
+//! dt, Γ is floored at εΓ, Λ is capped (as is Φ under a hard cap) and Ξ is
+//! recomputed.
+
+use super::crsm7::CRSM7State;
+use crate::config::RuntimeConfig;
+use serde::{Deserialize, Serialize};
+
+/// H_CRSM as a function of the state
+pub type HamiltonianFn<'a> = dyn Fn(&CRSM7State) -> f64 + 'a;
+
+/// A scheme advancing the CRSM7 state by one time step
+pub trait Integrator {
+    fn name(&self) -> &'static str;
+
+    /// Advance `state` by `dt` under the Hamiltonian `h`
+    fn integrate(&self, state: &mut CRSM7State, dt: f64, h: &HamiltonianFn, config: &RuntimeConfig);
+}
+
+/// Original first-order update
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Euler;
+
+/// Classical fourth-order Runge–Kutta
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Rk4;
+
+/// Adaptive Dormand–Prince 5(4)
+#[derive(Debug, Clone, Copy)]
+pub struct Rk45 {
+    /// Local error tolerance per substep (absolute + relative)
+    pub tolerance: f64,
+    /// Smallest substep; reached substeps are accepted regardless of error
+    pub min_step: f64,
+}
+
+impl Default for Rk45 {
+    fn default() -> Self {
+        Self {
+            tolerance: 1e-9,
+            min_step: 1e-8,
+        }
+    }
+}
+
+impl Rk45 {
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+}
+
+/// Serializable integrator choice stored in a runtime
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum IntegratorKind {
+    #[default]
+    Euler,
+    Rk4,
+    Rk45 {
+        tolerance: f64,
+    },
+}
+
+impl IntegratorKind {
+    /// Integrator by name (`euler`, `rk4`, `rk45`)
+    pub fn named(name: &str) -> Option<Self> {
+        match name {
+            "euler" => Some(IntegratorKind::Euler),
+            "rk4" => Some(IntegratorKind::Rk4),
+            "rk45" => Some(IntegratorKind::Rk45 {
+                tolerance: Rk45::default().tolerance,
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl Integrator for IntegratorKind {
+    fn name(&self) -> &'static str {
+        match self {
+            IntegratorKind::Euler => Euler.name(),
+            IntegratorKind::Rk4 => Rk4.name(),
+            IntegratorKind::Rk45 { .. } => Rk45::default().name(),
+        }
+    }
+
+    fn integrate(
+        &self,
+        state: &mut CRSM7State,
+        dt: f64,
+        h: &HamiltonianFn,
+        config: &RuntimeConfig,
+    ) {
+        match *self {
+            IntegratorKind::Euler => Euler.integrate(state, dt, h, config),
+            IntegratorKind::Rk4 => Rk4.integrate(state, dt, h, config),
+            IntegratorKind::Rk45 { tolerance } => Rk45::default()
+                .with_tolerance(tolerance)
+                .integrate(state, dt, h, config),
+        }
+    }
+}
+
+impl Integrator for Euler {
+    fn name(&self) -> &'static str {
+        "euler"
+    }
+
+    fn integrate(
+        &self,
+        state: &mut CRSM7State,
+        dt: f64,
+        h: &HamiltonianFn,
+        config: &RuntimeConfig,
+    ) {
+        let value = h(state);
+        state.evolve_with(dt, value, config);
+    }
+}
+
+/// (Λ, Γ, Φ)
+type Vector = [f64; 3];
+
+/// Right-hand side of the ODE at `y`, other coordinates taken from `state`
+fn derivative(state: &CRSM7State, y: Vector, h: &HamiltonianFn, config: &RuntimeConfig) -> Vector {
+    let mut at = state.clone();
+    at.lambda = y[0];
+    at.gamma = y[1];
+    at.phi = y[2];
+    [
+        config.coherence_rate * h(&at),
+        -y[1],
+        config.phi_rate(y[0], y[2]),
+    ]
+}
+
+/// y + Σ coefficient_i · dt · k_i
+fn combine(y: Vector, dt: f64, terms: &[(f64, Vector)]) -> Vector {
+    std::array::from_fn(|i| y[i] + dt * terms.iter().map(|(c, k)| c * k[i]).sum::<f64>())
+}
+
+fn finish(state: &mut CRSM7State, y: Vector, dt: f64, config: &RuntimeConfig) {
+    state.tau += dt;
+    state.lambda = y[0].min(config.lambda_cap);
+    state.gamma = y[1].max(config.gamma_tolerance);
+    state.phi = config.bound_phi(y[2]);
+    state.compute_emergence();
+}
+
+impl Integrator for Rk4 {
+    fn name(&self) -> &'static str {
+        "rk4"
+    }
+
+    fn integrate(
+        &self,
+        state: &mut CRSM7State,
+        dt: f64,
+        h: &HamiltonianFn,
+        config: &RuntimeConfig,
+    ) {
+        let y = [state.lambda, state.gamma, state.phi];
+        let k1 = derivative(state, y, h, config);
+        let k2 = derivative(state, combine(y, dt, &[(0.5, k1)]), h, config);
+        let k3 = derivative(state, combine(y, dt, &[(0.5, k2)]), h, config);
+        let k4 = derivative(state, combine(y, dt, &[(1.0, k3)]), h, config);
+        let next = combine(
+            y,
+            dt,
+            &[
+                (1.0 / 6.0, k1),
+                (1.0 / 3.0, k2),
+                (1.0 / 3.0, k3),
+                (1.0 / 6.0, k4),
+            ],
+        );
+        finish(state, next, dt, config);
+    }
+}
+
+/// Substep limit per `integrate` call
+const MAX_SUBSTEPS: usize = 100_000;
+
+impl Integrator for Rk45 {
+    fn name(&self) -> &'static str {
+        "rk45"
+    }
+
+    fn integrate(
+        &self,
+        state: &mut CRSM7State,
+        dt: f64,
+        h: &HamiltonianFn,
+        config: &RuntimeConfig,
+    ) {
+        let mut y = [state.lambda, state.gamma, state.phi];
+        let mut elapsed = 0.0;
+        let mut step = dt;
+        for _ in 0..MAX_SUBSTEPS {
+            let remaining = dt - elapsed;
+            if remaining <= 0.0 {
+                break;
+            }
+            step = step.min(remaining);
+
+            let (next, error) = dormand_prince(state, y, step, h, config);
+            let scale = next.iter().fold(0.0f64, |m, v| m.max(v.abs())) + 1.0;
+            let ratio = error / (self.tolerance * scale);
+            if ratio <= 1.0 || step <= self.min_step {
+                y = next;
+                elapsed += step;
+            }
+            let factor = if ratio > 0.0 {
+                (0.9 * ratio.powf(-0.2)).clamp(0.2, 5.0)
+            } else {
+                5.0
+            };
+            step = (step * factor).max(self.min_step);
+        }
+        // Out of substeps: integrate whatever is left in one step, so τ
+        // does not advance past the integrated interval
+        let remaining = dt - elapsed;
+        if remaining > 0.0 {
+            y = dormand_prince(state, y, remaining, h, config).0;
+        }
+        finish(state, y, dt, config);
+    }
+}
+
+/// One Dormand–Prince step: fifth-order solution and error estimate
+fn dormand_prince(
+    state: &CRSM7State,
+    y: Vector,
+    dt: f64,
+    h: &HamiltonianFn,
+    config: &RuntimeConfig,
+) -> (Vector, f64) {
+    let k1 = derivative(state, y, h, config);
+    let k2 = derivative(state, combine(y, dt, &[(1.0 / 5.0, k1)]), h, config);
+    let k3 = derivative(
+        state,
+        combine(y, dt, &[(3.0 / 40.0, k1), (9.0 / 40.0, k2)]),
+        h,
+        config,
+    );
+    let k4 = derivative(
+        state,
+        combine(
+            y,
+            dt,
+            &[(44.0 / 45.0, k1), (-56.0 / 15.0, k2), (32.0 / 9.0, k3)],
+        ),
+        h,
+        config,
+    );
+    let k5 = derivative(
+        state,
+        combine(
+            y,
+            dt,
+            &[
+                (19372.0 / 6561.0, k1),
+                (-25360.0 / 2187.0, k2),
+                (64448.0 / 6561.0, k3),
+                (-212.0 / 729.0, k4),
+            ],
+        ),
+        h,
+        config,
+    );
+    let k6 = derivative(
+        state,
+        combine(
+            y,
+            dt,
+            &[
+                (9017.0 / 3168.0, k1),
+                (-355.0 / 33.0, k2),
+                (46732.0 / 5247.0, k3),
+                (49.0 / 176.0, k4),
+                (-5103.0 / 18656.0, k5),
+            ],
+        ),
+        h,
+        config,
+    );
+    let fifth = combine(
+        y,
+        dt,
+        &[
+            (35.0 / 384.0, k1),
+            (500.0 / 1113.0, k3),
+            (125.0 / 192.0, k4),
+            (-2187.0 / 6784.0, k5),
+            (11.0 / 84.0, k6),
+        ],
+    );
+    let k7 = derivative(state, fifth, h, config);
+    let fourth = combine(
+        y,
+        dt,
+        &[
+            (5179.0 / 57600.0, k1),
+            (7571.0 / 16695.0, k3),
+            (393.0 / 640.0, k4),
+            (-92097.0 / 339200.0, k5),
+            (187.0 / 2100.0, k6),
+            (1.0 / 40.0, k7),
+        ],
+    );
+    let error = (0..3).fold(0.0f64, |m, i| m.max((fifth[i] - fourth[i]).abs()));
+    (fifth, error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solve(integrator: &dyn Integrator, dt: f64, duration: f64) -> CRSM7State {
+        let mut state = CRSM7State::new();
+        let steps = (duration / dt).round() as usize;
+        for _ in 0..steps {
+            let h = |s: &CRSM7State| s.hamiltonian();
+            integrator.integrate(&mut state, dt, &h, &RuntimeConfig::default());
+        }
+        state
+    }
+
+    #[test]
+    fn test_higher_order_reduces_large_dt_error() {
+        let reference = solve(&Rk4, 1e-3, 4.0);
+        let error =
+            |s: CRSM7State| (s.lambda - reference.lambda).abs() + (s.phi - reference.phi).abs();
+
+        let euler = error(solve(&Euler, 0.5, 4.0));
+        let rk4 = error(solve(&Rk4, 0.5, 4.0));
+        let rk45 = error(solve(&Rk45::default(), 0.5, 4.0));
+        assert!(rk4 < euler / 100.0, "rk4 {} euler {}", rk4, euler);
+        assert!(rk45 < 1e-8, "rk45 {}", rk45);
+        assert!((reference.tau - 4.0).abs() < 1e-9);
+        assert!((reference.gamma - 0.012 * (-4.0f64).exp()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_rk45_finishes_interval_past_substep_limit() {
+        // A zero tolerance rejects every substep down to a negligible
+        // min_step, so the limit is reached with almost all of dt left
+        let starved = Rk45 {
+            tolerance: 0.0,
+            min_step: 1e-300,
+        };
+        let reference = solve(&Rk45::default(), 0.1, 0.1);
+        let state = solve(&starved, 0.1, 0.1);
+        assert!((state.gamma - reference.gamma).abs() < 1e-9);
+        assert!((state.lambda - reference.lambda).abs() < 1e-9);
+        assert_eq!(state.tau, reference.tau);
+    }
+
+    #[test]
+    fn test_kind_by_name() {
+        assert_eq!(IntegratorKind::named("rk4"), Some(IntegratorKind::Rk4));
+        assert_eq!(IntegratorKind::named("rk45").unwrap().name(), "rk45");
+        assert!(IntegratorKind::named("leapfrog").is_none());
+        assert_eq!(IntegratorKind::default(), IntegratorKind::Euler);
+    }
+}
@@ -0,0 +1,115 @@
+//! State Interpolation
+//!
+//! Paths between two CRSM7 states that respect the geometry of each
+//! component instead of lerping all seven:
+//! - Λ, Φ and τ: linear (flat directions of the metric)
+//! - Γ: geometric; its decay is exponential, so interpolating two states
+//!   of one decaying trajectory stays on it
+//! - θ: along the shorter arc, in degrees
+//! - ρ±: along S¹_polarity with ρ = cos φ, so opposite polarities pass
+//!   through ρ = 0 at the midpoint rather than jumping
+//! - Ξ: recomputed from the interpolated Λ, Φ and Γ
+
+use super::crsm7::CRSM7State;
+use std::f64::consts::PI;
+
+fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
+
+/// Γ on the exponential path from `a` to `b`; linear when either is not
+/// positive
+fn interpolate_gamma(a: f64, b: f64, t: f64) -> f64 {
+    if a > 0.0 && b > 0.0 {
+        a * (b / a).powf(t)
+    } else {
+        lerp(a, b, t)
+    }
+}
+
+/// θ along the shorter arc, in [0°, 360°) when the endpoints differ
+fn interpolate_theta(a: f64, b: f64, t: f64) -> f64 {
+    let delta = (b - a + 180.0).rem_euclid(360.0) - 180.0;
+    if delta == 0.0 {
+        return a;
+    }
+    (a + delta * t).rem_euclid(360.0)
+}
+
+/// ρ± along the polarity circle
+fn interpolate_rho(a: f64, b: f64, t: f64) -> f64 {
+    let phase = |rho: f64| rho.clamp(-1.0, 1.0).acos();
+    let phi = lerp(phase(a), phase(b), t);
+    // cos(π/2) is 6e-17, not 0
+    if (phi - PI / 2.0).abs() < 1e-12 {
+        0.0
+    } else {
+        phi.cos()
+    }
+}
+
+impl CRSM7State {
+    /// State a fraction `t` ∈ [0, 1] of the way from `a` to `b`
+    /// (t outside the range extrapolates)
+    pub fn interpolate(a: &CRSM7State, b: &CRSM7State, t: f64) -> CRSM7State {
+        let mut state = CRSM7State {
+            lambda: lerp(a.lambda, b.lambda, t),
+            gamma: interpolate_gamma(a.gamma, b.gamma, t),
+            phi: lerp(a.phi, b.phi, t),
+            xi: 0.0,
+            rho: interpolate_rho(a.rho, b.rho, t),
+            theta: interpolate_theta(a.theta, b.theta, t),
+            tau: lerp(a.tau, b.tau, t),
+        };
+        state.compute_emergence();
+        state
+    }
+
+    /// `count` states evenly spaced from `a` to `b`, both included
+    pub fn interpolate_path(a: &CRSM7State, b: &CRSM7State, count: usize) -> Vec<CRSM7State> {
+        match count {
+            0 => Vec::new(),
+            1 => vec![a.clone()],
+            _ => (0..count)
+                .map(|i| Self::interpolate(a, b, i as f64 / (count - 1) as f64))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RuntimeConfig;
+
+    #[test]
+    fn test_interpolate_components() {
+        let a = CRSM7State::with_values(0.8, 0.1, 4.0, 1.0, 350.0, 0.0);
+        let b = CRSM7State::with_values(0.9, 0.001, 8.0, -1.0, 10.0, 2.0);
+        assert!(CRSM7State::interpolate(&a, &b, 0.0).approx_eq(&a, 1e-12));
+        assert!(CRSM7State::interpolate(&a, &b, 1.0).approx_eq(&b, 1e-12));
+
+        let mid = CRSM7State::interpolate(&a, &b, 0.5);
+        assert!((mid.lambda - 0.85).abs() < 1e-12);
+        assert!((mid.gamma - 0.01).abs() < 1e-12);
+        assert!((mid.theta - 0.0).abs() < 1e-9);
+        assert_eq!(mid.rho, 0.0);
+        assert!((mid.xi - mid.lambda * mid.phi / mid.gamma).abs() < 1e-9);
+
+        let path = CRSM7State::interpolate_path(&a, &b, 5);
+        assert_eq!(path.len(), 5);
+        assert!(path.windows(2).all(|w| w[1].gamma < w[0].gamma));
+    }
+
+    #[test]
+    fn test_gamma_stays_on_decay_trajectory() {
+        let config = RuntimeConfig::default();
+        let start = CRSM7State::new();
+        let mut mid = start.clone();
+        mid.evolve_with(0.5, 0.0, &config);
+        let mut end = mid.clone();
+        end.evolve_with(0.5, 0.0, &config);
+        let halfway = CRSM7State::interpolate(&start, &end, 0.5);
+        assert!((halfway.gamma / mid.gamma - 1.0).abs() < 1e-12);
+    }
+}
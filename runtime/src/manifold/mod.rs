@@ -3,8 +3,20 @@
 //! 7-dimensional manifold implementations for CRSM
 
 pub mod crsm7;
+pub mod delta;
+pub mod geometry;
+pub mod integrator;
+pub mod interpolate;
+pub mod operators;
 
 pub use crsm7::{
     CRSM7State, DET_CRITICAL, EMERGENCE_MAX, EMERGENCE_THRESHOLD, GAMMA_TOLERANCE,
     OMEGA_SOV_THRESHOLD, THETA_CRITICAL,
 };
+pub use delta::{ComponentDelta, StateDelta};
+pub use geometry::{Christoffel, Matrix7, DET_FLOOR};
+pub use integrator::{Euler, Integrator, IntegratorKind, Rk4, Rk45};
+pub use operators::{
+    CoherenceDrive, DecoherenceSuppression, Epoch, FnOperator, InformationAccumulation, Operator,
+    Operators,
+};
@@ -0,0 +1,335 @@
+//! Evolution Operators
+//!
+//! H_CRSM evolution as an ordered list of operators, each advancing part
+//! of the state by dt, in place of the fixed update of `evolve_with`. The
+//! built-ins apply the terms of `CompactState` and reproduce it exactly:
+//! - τ: epoch advancement
+//! - DΛ: Λ += c_Λ · H · dt, capped (H from `CRSM7State::hamiltonian`,
+//!   so it runs before KΓ changes Γ)
+//! - KΓ: Γ decays as e^(−dt), floored at εΓ
+//! - Φ: Φ += dΦ/dτ · dt under the configured saturation
+//!
+//! Replace or insert operators to try other dynamics (a custom KΓ, a Jθ
+//! that rotates θ). Ξ is recomputed after the last operator.
+
+use super::crsm7::CRSM7State;
+use crate::config::{PhiSaturation, RuntimeConfig};
+use crate::precision::CompactState;
+use std::fmt;
+use std::sync::Arc;
+
+/// Name of the built-in epoch operator
+pub const EPOCH: &str = "τ";
+/// Name of the built-in coherence operator
+pub const COHERENCE: &str = "DΛ";
+/// Name of the built-in decoherence operator
+pub const DECOHERENCE: &str = "KΓ";
+/// Name of the built-in information operator
+pub const INFORMATION: &str = "Φ";
+
+/// One term of the evolution, applied in place
+pub trait Operator: Send + Sync {
+    fn name(&self) -> &str;
+
+    fn apply(&self, state: &mut CRSM7State, dt: f64);
+
+    /// This operator with its constants taken from `config`; `None` if it
+    /// reads none
+    fn configured(&self, _config: &RuntimeConfig) -> Option<Arc<dyn Operator>> {
+        None
+    }
+}
+
+/// τ += dt
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Epoch;
+
+impl Operator for Epoch {
+    fn name(&self) -> &str {
+        EPOCH
+    }
+
+    fn apply(&self, state: &mut CRSM7State, dt: f64) {
+        apply_term(state, |s| s.advance_epoch(dt));
+    }
+}
+
+/// DΛ: Λ driven by H_CRSM
+#[derive(Debug, Clone, Copy)]
+pub struct CoherenceDrive {
+    pub rate: f64,
+    pub cap: f64,
+}
+
+impl Operator for CoherenceDrive {
+    fn name(&self) -> &str {
+        COHERENCE
+    }
+
+    fn apply(&self, state: &mut CRSM7State, dt: f64) {
+        let h = state.hamiltonian();
+        apply_term(state, |s| s.drive_coherence(h, dt, self.rate, self.cap));
+    }
+
+    fn configured(&self, config: &RuntimeConfig) -> Option<Arc<dyn Operator>> {
+        Some(Arc::new(CoherenceDrive {
+            rate: config.coherence_rate,
+            cap: config.lambda_cap,
+        }))
+    }
+}
+
+/// KΓ: exponential decoherence suppression
+#[derive(Debug, Clone, Copy)]
+pub struct DecoherenceSuppression {
+    pub floor: f64,
+}
+
+impl Operator for DecoherenceSuppression {
+    fn name(&self) -> &str {
+        DECOHERENCE
+    }
+
+    fn apply(&self, state: &mut CRSM7State, dt: f64) {
+        apply_term(state, |s| s.suppress_decoherence(dt, self.floor));
+    }
+
+    fn configured(&self, config: &RuntimeConfig) -> Option<Arc<dyn Operator>> {
+        Some(Arc::new(DecoherenceSuppression {
+            floor: config.gamma_tolerance,
+        }))
+    }
+}
+
+/// Φ accumulation from Λ
+#[derive(Debug, Clone, Copy)]
+pub struct InformationAccumulation {
+    pub rate: f64,
+    pub saturation: PhiSaturation,
+}
+
+impl Operator for InformationAccumulation {
+    fn name(&self) -> &str {
+        INFORMATION
+    }
+
+    fn apply(&self, state: &mut CRSM7State, dt: f64) {
+        apply_term(state, |s| {
+            s.accumulate_information(dt, self.rate, self.saturation)
+        });
+    }
+
+    fn configured(&self, config: &RuntimeConfig) -> Option<Arc<dyn Operator>> {
+        Some(Arc::new(InformationAccumulation {
+            rate: config.information_rate,
+            saturation: config.phi_saturation,
+        }))
+    }
+}
+
+/// Apply one term of the `CompactState` evolution to `state`
+fn apply_term(state: &mut CRSM7State, term: impl FnOnce(&mut CompactState<f64>)) {
+    let mut compact = CompactState::from_state(state);
+    term(&mut compact);
+    *state = compact.to_state();
+}
+
+type Apply = dyn Fn(&mut CRSM7State, f64) + Send + Sync;
+
+/// Operator from a closure
+pub struct FnOperator {
+    name: String,
+    apply: Box<Apply>,
+}
+
+impl FnOperator {
+    pub fn new(
+        name: impl Into<String>,
+        apply: impl Fn(&mut CRSM7State, f64) + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            apply: Box::new(apply),
+        }
+    }
+}
+
+impl Operator for FnOperator {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn apply(&self, state: &mut CRSM7State, dt: f64) {
+        (self.apply)(state, dt)
+    }
+}
+
+/// Ordered evolution operators
+#[derive(Clone)]
+pub struct Operators {
+    operators: Vec<Arc<dyn Operator>>,
+}
+
+impl Default for Operators {
+    fn default() -> Self {
+        Self::builtin(&RuntimeConfig::default())
+    }
+}
+
+impl fmt::Debug for Operators {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.names()).finish()
+    }
+}
+
+impl Operators {
+    /// τ, DΛ, KΓ, Φ with constants from `config`
+    pub fn builtin(config: &RuntimeConfig) -> Self {
+        Self {
+            operators: vec![
+                Arc::new(Epoch),
+                Arc::new(CoherenceDrive {
+                    rate: config.coherence_rate,
+                    cap: config.lambda_cap,
+                }),
+                Arc::new(DecoherenceSuppression {
+                    floor: config.gamma_tolerance,
+                }),
+                Arc::new(InformationAccumulation {
+                    rate: config.information_rate,
+                    saturation: config.phi_saturation,
+                }),
+            ],
+        }
+    }
+
+    /// No operators at all
+    pub fn empty() -> Self {
+        Self {
+            operators: Vec::new(),
+        }
+    }
+
+    /// Apply `operator` after the existing operators
+    pub fn push(&mut self, operator: impl Operator + 'static) {
+        self.operators.push(Arc::new(operator));
+    }
+
+    /// Apply `operator` at position `index` (clamped to the end)
+    pub fn insert(&mut self, index: usize, operator: impl Operator + 'static) {
+        let index = index.min(self.operators.len());
+        self.operators.insert(index, Arc::new(operator));
+    }
+
+    /// Replace every operator named `name` with `operator`; returns whether
+    /// any was replaced
+    pub fn replace(&mut self, name: &str, operator: impl Operator + 'static) -> bool {
+        let operator: Arc<dyn Operator> = Arc::new(operator);
+        let mut replaced = false;
+        for slot in self.operators.iter_mut().filter(|op| op.name() == name) {
+            *slot = operator.clone();
+            replaced = true;
+        }
+        replaced
+    }
+
+    /// Take the constants of every operator that reads them (the built-ins)
+    /// from `config`, leaving the others as they are
+    pub fn configure(&mut self, config: &RuntimeConfig) {
+        for slot in &mut self.operators {
+            if let Some(operator) = slot.configured(config) {
+                *slot = operator;
+            }
+        }
+    }
+
+    /// Remove every operator named `name`; returns whether any was removed
+    pub fn remove(&mut self, name: &str) -> bool {
+        let before = self.operators.len();
+        self.operators.retain(|op| op.name() != name);
+        self.operators.len() != before
+    }
+
+    pub fn len(&self) -> usize {
+        self.operators.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.operators.is_empty()
+    }
+
+    /// Operator names in application order
+    pub fn names(&self) -> Vec<&str> {
+        self.operators.iter().map(|op| op.name()).collect()
+    }
+
+    /// Apply every operator in order, then recompute Ξ
+    pub fn evolve(&self, state: &mut CRSM7State, dt: f64) {
+        for operator in &self.operators {
+            operator.apply(state, dt);
+        }
+        state.compute_emergence();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_operators_match_evolve_with() {
+        let config =
+            RuntimeConfig::default().with_phi_saturation(PhiSaturation::Logistic { capacity: 9.0 });
+        let operators = Operators::builtin(&config);
+        assert_eq!(
+            operators.names(),
+            vec![EPOCH, COHERENCE, DECOHERENCE, INFORMATION]
+        );
+
+        let mut expected = CRSM7State::new();
+        let mut state = expected.clone();
+        for _ in 0..100 {
+            let h = expected.hamiltonian();
+            expected.evolve_with(0.05, h, &config);
+            operators.evolve(&mut state, 0.05);
+        }
+        assert!(state.approx_eq(&expected, 0.0));
+    }
+
+    #[test]
+    fn test_custom_operators() {
+        let mut operators = Operators::default();
+        assert!(operators.replace(DECOHERENCE, FnOperator::new("KΓ'", |_, _| {})));
+        operators.push(FnOperator::new("Jθ", |state, dt| {
+            state.theta = (state.theta + 90.0 * dt).rem_euclid(360.0)
+        }));
+        assert_eq!(
+            operators.names(),
+            vec![EPOCH, COHERENCE, "KΓ'", INFORMATION, "Jθ"]
+        );
+
+        let mut state = CRSM7State::new();
+        let (gamma, theta) = (state.gamma, state.theta);
+        operators.evolve(&mut state, 1.0);
+        assert_eq!(state.gamma, gamma);
+        assert_eq!(state.theta, (theta + 90.0).rem_euclid(360.0));
+        assert!(operators.remove(INFORMATION));
+        assert_eq!(operators.len(), 4);
+    }
+
+    #[test]
+    fn test_configure_updates_builtins_only() {
+        let config = RuntimeConfig::default().with_gamma_tolerance(0.5);
+        let mut operators = Operators::default();
+        operators.push(FnOperator::new("Jθ", |state, _| state.theta = 0.0));
+        operators.configure(&config);
+        assert_eq!(
+            operators.names(),
+            vec![EPOCH, COHERENCE, DECOHERENCE, INFORMATION, "Jθ"]
+        );
+
+        let mut state = CRSM7State::new();
+        operators.evolve(&mut state, 1.0);
+        assert_eq!((state.gamma, state.theta), (0.5, 0.0));
+    }
+}
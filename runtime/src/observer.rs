@@ -0,0 +1,189 @@
+//! Observers
+//!
+//! Callbacks registered on a `DualRuntime` and invoked from `step`:
+//! `on_step` after every step, `on_collapse` when Π± is applied and
+//! `on_seal` when the runtime seals. Each callback sees the step index,
+//! Ψ and the CRSM7 state, and returns whether evolution should continue.
+//! An `Abort` halts the runtime: later steps do nothing until `resume`.
+//!
+//! Observers are not serialized, and a cloned runtime starts without any.
+
+use crate::manifold::CRSM7State;
+use crate::wave::WaveFunction;
+use std::fmt;
+
+/// What an observer wants the runtime to do next
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ObserverControl {
+    #[default]
+    Continue,
+    /// Halt evolution after this step
+    Abort,
+}
+
+/// The runtime as seen by an observer
+#[derive(Debug, Clone, Copy)]
+pub struct StepEvent<'a> {
+    /// Steps completed, counting this one
+    pub step: u64,
+    pub psi: &'a WaveFunction,
+    pub state: &'a CRSM7State,
+}
+
+/// Runtime event callbacks; every method defaults to `Continue`. Observers
+/// are `Send` so a runtime can move between threads and async tasks.
+pub trait Observer: Send {
+    fn on_step(&mut self, _event: &StepEvent) -> ObserverControl {
+        ObserverControl::Continue
+    }
+
+    fn on_collapse(&mut self, _event: &StepEvent) -> ObserverControl {
+        ObserverControl::Continue
+    }
+
+    fn on_seal(&mut self, _event: &StepEvent) -> ObserverControl {
+        ObserverControl::Continue
+    }
+}
+
+/// Observers registered on a runtime, and whether one aborted
+#[derive(Default)]
+pub struct Observers {
+    observers: Vec<Box<dyn Observer>>,
+    halted: bool,
+}
+
+impl Clone for Observers {
+    fn clone(&self) -> Self {
+        Self {
+            observers: Vec::new(),
+            halted: self.halted,
+        }
+    }
+}
+
+impl fmt::Debug for Observers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Observers")
+            .field("len", &self.observers.len())
+            .field("halted", &self.halted)
+            .finish()
+    }
+}
+
+impl Observers {
+    pub fn push(&mut self, observer: Box<dyn Observer>) {
+        self.observers.push(observer);
+    }
+
+    pub fn len(&self) -> usize {
+        self.observers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.observers.is_empty()
+    }
+
+    /// Whether an observer aborted evolution
+    pub fn halted(&self) -> bool {
+        self.halted
+    }
+
+    /// Allow evolution again after an abort
+    pub fn resume(&mut self) {
+        self.halted = false;
+    }
+
+    /// Notify every observer; all are called even if one aborts
+    pub fn notify(
+        &mut self,
+        event: &StepEvent,
+        callback: impl Fn(&mut dyn Observer, &StepEvent) -> ObserverControl,
+    ) {
+        for observer in &mut self.observers {
+            if callback(observer.as_mut(), event) == ObserverControl::Abort {
+                self.halted = true;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DualRuntime;
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Default)]
+    struct Recorder {
+        events: Arc<Mutex<Vec<(&'static str, u64)>>>,
+        abort_at: Option<u64>,
+    }
+
+    impl Observer for Recorder {
+        fn on_step(&mut self, event: &StepEvent) -> ObserverControl {
+            self.events.lock().unwrap().push(("step", event.step));
+            match self.abort_at {
+                Some(step) if event.step >= step => ObserverControl::Abort,
+                _ => ObserverControl::Continue,
+            }
+        }
+
+        fn on_collapse(&mut self, event: &StepEvent) -> ObserverControl {
+            self.events.lock().unwrap().push(("collapse", event.step));
+            ObserverControl::Continue
+        }
+
+        fn on_seal(&mut self, event: &StepEvent) -> ObserverControl {
+            self.events.lock().unwrap().push(("seal", event.step));
+            ObserverControl::Continue
+        }
+    }
+
+    #[test]
+    fn test_observer_aborts_evolution() {
+        let recorder = Recorder {
+            abort_at: Some(3),
+            ..Recorder::default()
+        };
+        let events = Arc::clone(&recorder.events);
+        let mut runtime = DualRuntime::new().with_observer(recorder);
+        runtime.run(10, 0.01);
+
+        let steps: Vec<u64> = events
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(kind, _)| *kind == "step")
+            .map(|(_, step)| *step)
+            .collect();
+        assert_eq!(steps, vec![1, 2, 3]);
+        assert!(runtime.observers.halted());
+        let tau = runtime.state.tau;
+        runtime.step(0.01);
+        assert_eq!(runtime.state.tau, tau);
+
+        runtime.observers.resume();
+        runtime.step(0.01);
+        assert!(runtime.state.tau > tau);
+        assert_eq!(runtime.clone().observers.len(), 0);
+    }
+
+    #[test]
+    fn test_collapse_and_seal_events() {
+        let recorder = Recorder::default();
+        let events = Arc::clone(&recorder.events);
+        let mut runtime = DualRuntime::new().with_observer(recorder);
+        runtime.state.gamma = runtime.config.gamma_tolerance;
+        runtime.state.lambda = 0.999;
+        runtime.state.phi = 100.0;
+        runtime.state.compute_emergence();
+        runtime.step(0.01);
+
+        assert!(runtime.sealed);
+        assert_eq!(
+            *events.lock().unwrap(),
+            vec![("step", 1), ("collapse", 1), ("seal", 1)]
+        );
+    }
+}
@@ -0,0 +1,377 @@
+//! Gene Arena
+//!
+//! Compact storage for organisms with hundreds of thousands of genes. A
+//! `Vec<Gene>` costs two `String` allocations per gene and interleaves the
+//! seven state components; the arena instead keeps
+//! - ids and names interned in one shared buffer (`Interner`), so a name
+//!   used by many genes is stored once and no gene owns an allocation
+//! - each CRSM7 component in its own contiguous column (structure of
+//!   arrays), which is what evolution sweeps over, in f64 or f32 (`Real`)
+//! - annotations only for the genes that have any
+//!
+//! In f64, `GeneArena::from_genes` and `into_genes` convert losslessly and
+//! `evolve` advances every gene exactly as a `Vec<Gene>` would, which is
+//! how `OrganismExecutor` stores large organisms (see
+//! `OrganismExecutor::with_gene_arena`). `GeneArena<f32>` halves the state
+//! columns at the cost of the divergence described in `precision`.
+
+use crate::config::RuntimeConfig;
+use crate::manifold::CRSM7State;
+use crate::organism::executor::{Gene, Organism};
+use crate::precision::{CompactState, Real};
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Range;
+
+/// Handle of an interned string
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Symbol(u32);
+
+/// Strings stored back to back in one buffer, each once
+#[derive(Debug, Clone, Default)]
+pub struct Interner {
+    buffer: String,
+    spans: Vec<Range<u32>>,
+    /// FNV-1a hash → symbols with that hash
+    index: HashMap<u64, Vec<Symbol>>,
+}
+
+fn fnv1a(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |h, b| {
+        (h ^ b as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+impl Interner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Symbol of `text`, adding it if new
+    pub fn intern(&mut self, text: &str) -> Symbol {
+        if let Some(symbol) = self.get(text) {
+            return symbol;
+        }
+        let start = self.buffer.len() as u32;
+        self.buffer.push_str(text);
+        let symbol = Symbol(self.spans.len() as u32);
+        self.spans.push(start..self.buffer.len() as u32);
+        self.index.entry(fnv1a(text)).or_default().push(symbol);
+        symbol
+    }
+
+    /// Symbol of `text`, if interned
+    pub fn get(&self, text: &str) -> Option<Symbol> {
+        self.index
+            .get(&fnv1a(text))?
+            .iter()
+            .copied()
+            .find(|&symbol| self.resolve(symbol) == text)
+    }
+
+    pub fn resolve(&self, symbol: Symbol) -> &str {
+        let span = &self.spans[symbol.0 as usize];
+        &self.buffer[span.start as usize..span.end as usize]
+    }
+
+    /// Distinct strings interned
+    pub fn len(&self) -> usize {
+        self.spans.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+}
+
+/// Genes in structure-of-arrays layout, with state components in `T`
+#[derive(Debug, Clone, Default)]
+pub struct GeneArena<T: Real = f64> {
+    strings: Interner,
+    ids: Vec<Symbol>,
+    names: Vec<Symbol>,
+    lambda: Vec<T>,
+    gamma: Vec<T>,
+    phi: Vec<T>,
+    xi: Vec<T>,
+    rho: Vec<T>,
+    theta: Vec<T>,
+    tau: Vec<T>,
+    bound: Vec<bool>,
+    /// Annotations of the genes that have any, by index
+    annotations: BTreeMap<usize, BTreeMap<String, String>>,
+}
+
+impl<T: Real> GeneArena<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Arena with room for `capacity` genes
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            ids: Vec::with_capacity(capacity),
+            names: Vec::with_capacity(capacity),
+            lambda: Vec::with_capacity(capacity),
+            gamma: Vec::with_capacity(capacity),
+            phi: Vec::with_capacity(capacity),
+            xi: Vec::with_capacity(capacity),
+            rho: Vec::with_capacity(capacity),
+            theta: Vec::with_capacity(capacity),
+            tau: Vec::with_capacity(capacity),
+            bound: Vec::with_capacity(capacity),
+            ..Self::default()
+        }
+    }
+
+    pub fn from_genes(genes: &[Gene]) -> Self {
+        let mut arena = Self::with_capacity(genes.len());
+        for gene in genes {
+            arena.push(gene);
+        }
+        arena
+    }
+
+    /// Append a gene; returns its index
+    pub fn push(&mut self, gene: &Gene) -> usize {
+        let index = self.len();
+        let id = self.strings.intern(&gene.id);
+        let name = self.strings.intern(&gene.name);
+        self.ids.push(id);
+        self.names.push(name);
+        let state = CompactState::from_state(&gene.state);
+        let columns = [
+            (&mut self.lambda, state.lambda),
+            (&mut self.gamma, state.gamma),
+            (&mut self.phi, state.phi),
+            (&mut self.xi, state.xi),
+            (&mut self.rho, state.rho),
+            (&mut self.theta, state.theta),
+            (&mut self.tau, state.tau),
+        ];
+        for (column, value) in columns {
+            column.push(value);
+        }
+        self.bound.push(gene.bound);
+        if !gene.annotations.is_empty() {
+            self.annotations.insert(index, gene.annotations.clone());
+        }
+        index
+    }
+
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    pub fn id(&self, index: usize) -> &str {
+        self.strings.resolve(self.ids[index])
+    }
+
+    pub fn name(&self, index: usize) -> &str {
+        self.strings.resolve(self.names[index])
+    }
+
+    pub fn bound(&self, index: usize) -> bool {
+        self.bound[index]
+    }
+
+    /// Index of the first gene with `id`
+    pub fn find(&self, id: &str) -> Option<usize> {
+        let symbol = self.strings.get(id)?;
+        self.ids.iter().position(|&s| s == symbol)
+    }
+
+    pub fn state(&self, index: usize) -> CRSM7State {
+        self.compact(index).to_state()
+    }
+
+    /// Gene `index`'s state as stored
+    pub fn compact(&self, index: usize) -> CompactState<T> {
+        CompactState {
+            lambda: self.lambda[index],
+            gamma: self.gamma[index],
+            phi: self.phi[index],
+            xi: self.xi[index],
+            rho: self.rho[index],
+            theta: self.theta[index],
+            tau: self.tau[index],
+        }
+    }
+
+    /// Overwrite gene `index`'s state; panics if `index >= len()`
+    pub fn set_state(&mut self, index: usize, state: &CRSM7State) {
+        self.set_compact(index, &CompactState::from_state(state));
+    }
+
+    fn set_compact(&mut self, index: usize, state: &CompactState<T>) {
+        assert!(index < self.len(), "gene index {} out of range", index);
+        self.lambda[index] = state.lambda;
+        self.gamma[index] = state.gamma;
+        self.phi[index] = state.phi;
+        self.xi[index] = state.xi;
+        self.rho[index] = state.rho;
+        self.theta[index] = state.theta;
+        self.tau[index] = state.tau;
+    }
+
+    /// Γ of every gene, in order
+    pub fn gamma(&self) -> &[T] {
+        &self.gamma
+    }
+
+    /// Ξ of every gene, in order
+    pub fn xi(&self) -> &[T] {
+        &self.xi
+    }
+
+    /// Bytes held by the seven state columns
+    pub fn state_bytes(&self) -> usize {
+        7 * self.len() * std::mem::size_of::<T>()
+    }
+
+    /// Evolve every gene by dt under its own Hamiltonian
+    pub fn evolve(&mut self, dt: f64, config: &RuntimeConfig) {
+        for index in 0..self.len() {
+            self.evolve_gene(index, dt, config);
+        }
+    }
+
+    /// Evolve gene `index` by dt under its own Hamiltonian
+    pub fn evolve_gene(&mut self, index: usize, dt: f64, config: &RuntimeConfig) {
+        let mut state = self.compact(index);
+        state.evolve_with(dt, state.hamiltonian(), config);
+        self.set_compact(index, &state);
+    }
+
+    /// Mesh weights from gene `index` to every gene, in order
+    pub fn mesh_weights(&self, index: usize) -> Vec<T> {
+        let from = self.compact(index);
+        (0..self.len())
+            .map(|to| from.mesh_weight(&self.compact(to)))
+            .collect()
+    }
+
+    pub fn gene(&self, index: usize) -> Gene {
+        Gene {
+            id: self.id(index).to_string(),
+            name: self.name(index).to_string(),
+            state: self.state(index),
+            bound: self.bound[index],
+            annotations: self.annotations.get(&index).cloned().unwrap_or_default(),
+        }
+    }
+
+    pub fn into_genes(self) -> Vec<Gene> {
+        (0..self.len()).map(|index| self.gene(index)).collect()
+    }
+
+    /// Distinct ids and names stored
+    pub fn interned(&self) -> usize {
+        self.strings.len()
+    }
+}
+
+impl Organism {
+    /// Move the genes into an arena, leaving the organism without genes
+    pub fn pack_genes<T: Real>(&mut self) -> GeneArena<T> {
+        GeneArena::from_genes(&std::mem::take(&mut self.genes))
+    }
+
+    /// Replace the genes with those of `arena`
+    pub fn unpack_genes<T: Real>(&mut self, arena: GeneArena<T>) {
+        self.genes = arena.into_genes();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::organism::OrganismExecutor;
+
+    #[test]
+    fn test_interner_stores_each_string_once() {
+        let mut interner = Interner::new();
+        let a = interner.intern("AURA");
+        assert_eq!(interner.intern("AIDEN"), Symbol(1));
+        assert_eq!(interner.intern("AURA"), a);
+        assert_eq!(interner.resolve(a), "AURA");
+        assert_eq!((interner.len(), interner.get("Z3BRA")), (2, None));
+    }
+
+    #[test]
+    fn test_arena_round_trips_and_evolves_like_executor() {
+        let mut organism = OrganismExecutor::create_standard_organism();
+        organism.genes[1].bound = true;
+        organism.genes[2]
+            .annotations
+            .insert("hint".to_string(), "fast".to_string());
+        let genes = organism.genes.clone();
+
+        let mut arena: GeneArena = organism.pack_genes();
+        assert!(organism.genes.is_empty());
+        assert_eq!(arena.len(), 5);
+        assert_eq!(arena.find("cccce"), Some(2));
+        assert_eq!(arena.name(2), "CCCcE");
+        assert!(arena.bound(1));
+
+        let mut executor = OrganismExecutor::new();
+        let idx = executor.load_organism(Organism::new("reference"));
+        executor.organisms[idx].genes = genes;
+        for _ in 0..10 {
+            executor.evolve(idx, 0.01);
+            arena.evolve(0.01, &executor.config);
+        }
+        organism.unpack_genes(arena);
+        for (gene, expected) in organism.genes.iter().zip(&executor.organisms[idx].genes) {
+            assert_eq!(
+                (&gene.id, &gene.name, gene.bound),
+                (&expected.id, &expected.name, expected.bound)
+            );
+            assert_eq!(gene.annotations, expected.annotations);
+            assert!(gene.state.approx_eq(&expected.state, 0.0));
+        }
+    }
+
+    #[test]
+    fn test_executor_evolves_packed_organisms_like_unpacked() {
+        use crate::organism::SchedulingPolicy;
+
+        let policy = SchedulingPolicy::RoundRobin { batch: 3 };
+        let mut packed = OrganismExecutor::new()
+            .with_scheduling(policy)
+            .with_gene_arena(5);
+        let mut plain = OrganismExecutor::new().with_scheduling(policy);
+        for executor in [&mut packed, &mut plain] {
+            executor.load_organism(OrganismExecutor::create_standard_organism());
+            for _ in 0..10 {
+                executor.evolve_all(0.01);
+            }
+            executor.suppress_decoherence(0, 0.5);
+            executor.elevate_coherence_info(0, 1.1);
+        }
+        assert!(packed.organisms[0].genes.is_empty());
+        assert_eq!(packed.arena(0).unwrap().len(), 5);
+        assert!(plain.arena(0).is_none());
+        assert_eq!(packed.execute_dma_all(), plain.execute_dma_all());
+
+        packed.unpack(0);
+        for (gene, expected) in packed.organisms[0]
+            .genes
+            .iter()
+            .zip(&plain.organisms[0].genes)
+        {
+            assert_eq!(gene.id, expected.id);
+            assert!(gene.state.approx_eq(&expected.state, 0.0));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn test_set_state_past_end_panics() {
+        let mut arena: GeneArena = OrganismExecutor::create_standard_organism().pack_genes();
+        arena.set_state(5, &CRSM7State::new());
+    }
+}
@@ -0,0 +1,168 @@
+//! Crossover
+//!
+//! Recombines two parent organisms into two offspring. Genes are aligned
+//! by id into loci (the first parent's order, then genes only the second
+//! parent carries); exchange strategies pass each locus whole from one
+//! parent or the other, so a gene absent in the contributing parent is
+//! absent in the child. Averaging keeps every gene and blends the CRSM7
+//! states of genes both parents carry.
+
+use crate::manifold::CRSM7State;
+use crate::organism::executor::{Gene, Organism};
+use crate::rng::SeededRng;
+use serde::{Deserialize, Serialize};
+
+/// How offspring inherit genes
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "strategy", rename_all = "snake_case")]
+pub enum CrossoverStrategy {
+    /// Loci before `cut` from one parent, the rest from the other
+    OnePoint { cut: usize },
+    /// Each locus from either parent with equal probability
+    Uniform { seed: u64 },
+    /// Every gene, states blended `weight` toward the child's own parent
+    Average { weight: f64 },
+}
+
+impl Organism {
+    /// Two offspring of `self` and `other`; the first takes its name,
+    /// annotations and (for exchange strategies) organism state from
+    /// `self`, the second from `other`
+    pub fn crossover(&self, other: &Organism, strategy: CrossoverStrategy) -> (Organism, Organism) {
+        let loci = loci(self, other);
+        let mut first = offspring(self, other);
+        let mut second = offspring(other, self);
+
+        match strategy {
+            CrossoverStrategy::OnePoint { cut } => {
+                for (i, (a, b)) in loci.into_iter().enumerate() {
+                    let (x, y) = if i < cut { (a, b) } else { (b, a) };
+                    first.genes.extend(x.cloned());
+                    second.genes.extend(y.cloned());
+                }
+            }
+            CrossoverStrategy::Uniform { seed } => {
+                let mut rng = SeededRng::new(seed);
+                for (a, b) in loci {
+                    let (x, y) = if rng.next_f64() < 0.5 { (a, b) } else { (b, a) };
+                    first.genes.extend(x.cloned());
+                    second.genes.extend(y.cloned());
+                }
+            }
+            CrossoverStrategy::Average { weight } => {
+                for (a, b) in loci {
+                    first.genes.extend(blend_gene(a, b, weight));
+                    second.genes.extend(blend_gene(b, a, weight));
+                }
+                first.state = blend(&self.state, &other.state, weight);
+                second.state = blend(&other.state, &self.state, weight);
+            }
+        }
+        (first, second)
+    }
+}
+
+/// Each parent's copy of one gene, if it carries one
+type Locus<'a> = (Option<&'a Gene>, Option<&'a Gene>);
+
+fn loci<'a>(a: &'a Organism, b: &'a Organism) -> Vec<Locus<'a>> {
+    let find = |o: &'a Organism, id: &str| o.genes.iter().find(|g| g.id == id);
+    let mut loci: Vec<Locus<'a>> = a.genes.iter().map(|g| (Some(g), find(b, &g.id))).collect();
+    loci.extend(
+        b.genes
+            .iter()
+            .filter(|g| find(a, &g.id).is_none())
+            .map(|g| (None, Some(g))),
+    );
+    loci
+}
+
+/// An organism with `parent`'s metadata and no genes yet
+fn offspring(parent: &Organism, mate: &Organism) -> Organism {
+    let mut child = Organism::new(&format!("{}x{}", parent.name, mate.name));
+    child.state = parent.state.clone();
+    child.operators = parent.operators.clone();
+    child.annotations = parent.annotations.clone();
+    child
+}
+
+fn blend_gene(own: Option<&Gene>, mate: Option<&Gene>, weight: f64) -> Option<Gene> {
+    match (own, mate) {
+        (Some(own), Some(mate)) => {
+            let mut gene = own.clone();
+            gene.state = blend(&own.state, &mate.state, weight);
+            Some(gene)
+        }
+        (gene, None) | (None, gene) => gene.cloned(),
+    }
+}
+
+/// `weight`·own + (1 − weight)·mate, with ρ from the heavier parent and Ξ
+/// recomputed
+fn blend(own: &CRSM7State, mate: &CRSM7State, weight: f64) -> CRSM7State {
+    let mix = |a: f64, b: f64| weight * a + (1.0 - weight) * b;
+    let mut state = CRSM7State {
+        lambda: mix(own.lambda, mate.lambda),
+        gamma: mix(own.gamma, mate.gamma),
+        phi: mix(own.phi, mate.phi),
+        xi: 0.0,
+        rho: if weight >= 0.5 { own.rho } else { mate.rho },
+        theta: mix(own.theta, mate.theta),
+        tau: mix(own.tau, mate.tau),
+    };
+    state.compute_emergence();
+    state
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parent(name: &str, ids: &[&str], lambda: f64) -> Organism {
+        let mut organism = Organism::new(name);
+        for id in ids {
+            let mut state = CRSM7State::new();
+            state.lambda = lambda;
+            organism.add_gene(Gene::with_state(id, id, state));
+        }
+        organism
+    }
+
+    fn ids(organism: &Organism) -> Vec<&str> {
+        organism.genes.iter().map(|g| g.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_exchange_strategies() {
+        let a = parent("a", &["x", "y", "z"], 0.9);
+        let b = parent("b", &["y", "w"], 0.5);
+
+        let (first, second) = a.crossover(&b, CrossoverStrategy::OnePoint { cut: 1 });
+        assert_eq!(first.name, "axb");
+        assert_eq!(ids(&first), vec!["x", "y", "w"]);
+        assert_eq!(ids(&second), vec!["y", "z"]);
+        assert_eq!(first.genes[1].state.lambda, 0.5);
+
+        let strategy = CrossoverStrategy::Uniform { seed: 3 };
+        let (first, second) = a.crossover(&b, strategy);
+        let (again, _) = a.crossover(&b, strategy);
+        assert_eq!(ids(&first), ids(&again));
+        // Every locus a parent carries lands in exactly one child
+        assert_eq!(first.genes.len() + second.genes.len(), 5);
+    }
+
+    #[test]
+    fn test_average_blends_shared_genes() {
+        let a = parent("a", &["x", "y"], 0.9);
+        let b = parent("b", &["y", "w"], 0.5);
+
+        let (first, second) = a.crossover(&b, CrossoverStrategy::Average { weight: 0.75 });
+        assert_eq!(ids(&first), vec!["x", "y", "w"]);
+        assert!((first.genes[1].state.lambda - 0.8).abs() < 1e-12);
+        assert!((second.genes[1].state.lambda - 0.6).abs() < 1e-12);
+        assert_eq!(first.genes[0].state.lambda, 0.9);
+        let expected =
+            first.genes[1].state.lambda * first.genes[1].state.phi / first.genes[1].state.gamma;
+        assert!((first.genes[1].state.xi - expected).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,51 @@
+//! DMA Reports
+//!
+//! Per-gene breakdown of E_DMA(O) = Σ_g∈O (∂g/∂τ − Γ(g)) ⊗ Π±, so the genes
+//! whose decoherence outweighs their gradient, and so drag the organism's
+//! emergence down, can be picked out.
+
+use serde::{Deserialize, Serialize};
+
+/// One gene's term of the DMA sum
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GeneDma {
+    pub gene: String,
+    /// Temporal gradient ∂g/∂τ
+    pub gradient: f64,
+    /// Decoherence Γ(g)
+    pub gamma: f64,
+    /// Π± factor after the configured floor
+    pub duality: f64,
+    /// (∂g/∂τ − Γ(g)) · duality
+    pub contribution: f64,
+}
+
+/// Result of `OrganismExecutor::execute_dma`
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DmaReport {
+    /// One entry per gene, in organism order
+    pub genes: Vec<GeneDma>,
+    /// Σ ∂g/∂τ
+    pub gradient: f64,
+    /// Σ Γ(g)
+    pub gamma: f64,
+    /// E_DMA, the sum of the contributions
+    pub total: f64,
+}
+
+impl DmaReport {
+    pub fn push(&mut self, gene: GeneDma) {
+        self.gradient += gene.gradient;
+        self.gamma += gene.gamma;
+        self.total += gene.contribution;
+        self.genes.push(gene);
+    }
+
+    /// Genes with a negative contribution, most negative first
+    pub fn draggers(&self) -> Vec<&GeneDma> {
+        let mut draggers: Vec<&GeneDma> =
+            self.genes.iter().filter(|g| g.contribution < 0.0).collect();
+        draggers.sort_by(|a, b| a.contribution.total_cmp(&b.contribution));
+        draggers
+    }
+}
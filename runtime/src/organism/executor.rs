@@ -1,11 +1,35 @@
 //! Organism Executor
 //!
 //! Executes DNA organisms within the dual runtime environment.
-//! Handles gene expression and state evolution.
-
+//! Handles gene expression and state evolution. With the `parallel`
+//! feature, genes and organisms evolve on the rayon thread pool; genes are
+//! independent during a step, so results match the serial build exactly.
+//! With the `wgpu` feature, organisms of `GPU_MIN_GENES` genes or more
+//! evolve in a compute shader when an adapter is available. A
+//! `SchedulingPolicy` can restrict each tick to a subset of the genes, and
+//! a seeded `Mutator` mutates loaded organisms between ticks. Genes can
+//! emit messages to other organisms through the executor's `Mailbox`;
+//! each organism receives them from its next step on. With
+//! `with_gene_arena`, organisms loaded with enough genes keep them in a
+//! `GeneArena` (interned ids, one column per component) instead of a
+//! `Vec<Gene>`; those evolve on the CPU and are unpacked on demand.
+
+use crate::config::RuntimeConfig;
+#[cfg(feature = "wgpu")]
+use crate::gpu::GpuEvolver;
 use crate::manifold::CRSM7State;
+use crate::organism::arena::GeneArena;
+use crate::organism::dma::{DmaReport, GeneDma};
+use crate::organism::messages::{Mailbox, Message};
+use crate::organism::mutation::{Mutation, MutationRates, Mutator};
+use crate::organism::scheduler::{Scheduler, SchedulingPolicy};
 use crate::projectors::{bifurcate, pi_minus};
+use dnalang_compiler::ir::{GeneOpType, OmegaIR};
+use dnalang_compiler::Payload;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// A gene vertex in the organism
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -14,6 +38,9 @@ pub struct Gene {
     pub name: String,
     pub state: CRSM7State,
     pub bound: bool,
+    /// Annotations carried over from the source gene
+    #[serde(default)]
+    pub annotations: BTreeMap<String, String>,
 }
 
 impl Gene {
@@ -23,6 +50,7 @@ impl Gene {
             name: name.to_string(),
             state: CRSM7State::new(),
             bound: false,
+            annotations: BTreeMap::new(),
         }
     }
 
@@ -32,6 +60,7 @@ impl Gene {
             name: name.to_string(),
             state,
             bound: false,
+            annotations: BTreeMap::new(),
         }
     }
 }
@@ -43,6 +72,9 @@ pub struct Organism {
     pub genes: Vec<Gene>,
     pub state: CRSM7State,
     pub operators: Vec<String>,
+    /// Annotations carried over from the source organism
+    #[serde(default)]
+    pub annotations: BTreeMap<String, String>,
 }
 
 impl Default for Organism {
@@ -65,9 +97,30 @@ impl Organism {
                 "Jθ".to_string(),
                 "Ω∞".to_string(),
             ],
+            annotations: BTreeMap::new(),
         }
     }
 
+    /// Build an organism from compiled IR, one gene per distinct gene op
+    pub fn from_ir(name: &str, ir: &OmegaIR) -> Self {
+        let mut organism = Self::new(name);
+        organism.annotations = ir
+            .organism_annotations
+            .get(name)
+            .cloned()
+            .unwrap_or_default();
+
+        for op in &ir.gene_ops {
+            if organism.genes.iter().any(|g| g.id == op.name) {
+                continue;
+            }
+            let mut gene = Gene::new(&op.name, &op.name);
+            gene.annotations = op.annotations.clone();
+            organism.add_gene(gene);
+        }
+        organism
+    }
+
     pub fn add_gene(&mut self, gene: Gene) {
         self.genes.push(gene);
     }
@@ -78,10 +131,91 @@ impl Organism {
     }
 }
 
+/// Fewest genes handed to one rayon task; a gene step is a few dozen
+/// flops, so smaller batches cost more in scheduling than they save
+#[cfg(feature = "parallel")]
+const PARALLEL_MIN_GENES: usize = 1024;
+
+/// Evolve the genes the scheduler picks (from `arena` when the organism
+/// is packed), then the organism state
+fn evolve_organism(
+    organism: &mut Organism,
+    arena: Option<&mut GeneArena>,
+    scheduler: &mut Scheduler,
+    dt: f64,
+    config: &RuntimeConfig,
+) {
+    if let Some(arena) = arena {
+        match scheduler.schedule_by(arena.len(), |i| arena.id(i), |i| arena.xi()[i]) {
+            None => arena.evolve(dt, config),
+            Some(picked) => {
+                for (i, elapsed) in picked {
+                    arena.evolve_gene(i, dt * elapsed as f64, config);
+                }
+            }
+        }
+    } else {
+        match scheduler.schedule(&organism.genes) {
+            None => evolve_all_genes(&mut organism.genes, dt, config),
+            Some(picked) => {
+                for (i, elapsed) in picked {
+                    let state = &mut organism.genes[i].state;
+                    let h = state.hamiltonian();
+                    state.evolve_with(dt * elapsed as f64, h, config);
+                }
+            }
+        }
+    }
+
+    let h = organism.state.hamiltonian();
+    organism.state.evolve_with(dt, h, config);
+}
+
+/// Every gene by dt, on the GPU when the organism is large enough (and
+/// on the CPU if the GPU dispatch fails)
+fn evolve_all_genes(genes: &mut [Gene], dt: f64, config: &RuntimeConfig) {
+    #[cfg(feature = "wgpu")]
+    if let Some(gpu) = GpuEvolver::offload(genes.len()) {
+        if gpu.evolve_genes(genes, dt, config).is_ok() {
+            return;
+        }
+    }
+    evolve_genes(genes, dt, config);
+}
+
+/// Scalar (or rayon) gene evolution
+fn evolve_genes(genes: &mut [Gene], dt: f64, config: &RuntimeConfig) {
+    let evolve_gene = |gene: &mut Gene| {
+        let h = gene.state.hamiltonian();
+        gene.state.evolve_with(dt, h, config);
+    };
+    #[cfg(feature = "parallel")]
+    genes
+        .par_iter_mut()
+        .with_min_len(PARALLEL_MIN_GENES)
+        .for_each(evolve_gene);
+    #[cfg(not(feature = "parallel"))]
+    genes.iter_mut().for_each(evolve_gene);
+}
+
 /// Organism executor for DMA operations
 pub struct OrganismExecutor {
     pub organisms: Vec<Organism>,
     pub epoch: f64,
+    /// Evolution and DMA constants
+    pub config: RuntimeConfig,
+    /// Policy for organisms loaded from now on
+    pub policy: SchedulingPolicy,
+    /// Scheduling state, one per organism
+    schedulers: Vec<Scheduler>,
+    /// Mutation rates and generator used by `mutate` (rates 0 by default)
+    pub mutator: Mutator,
+    /// Messages between organisms
+    pub mailbox: Mailbox,
+    /// Organisms loaded with at least this many genes are packed
+    arena_min_genes: Option<usize>,
+    /// Packed genes, one slot per organism
+    arenas: Vec<Option<GeneArena>>,
 }
 
 impl Default for OrganismExecutor {
@@ -95,16 +229,120 @@ impl OrganismExecutor {
         Self {
             organisms: Vec::new(),
             epoch: 0.0,
+            config: RuntimeConfig::default(),
+            policy: SchedulingPolicy::default(),
+            schedulers: Vec::new(),
+            mutator: Mutator::default(),
+            mailbox: Mailbox::new(),
+            arena_min_genes: None,
+            arenas: Vec::new(),
         }
     }
 
-    /// Load an organism into the executor
-    pub fn load_organism(&mut self, organism: Organism) -> usize {
+    /// Mutate at `rates`, drawing from a generator seeded with `seed`
+    pub fn with_mutation(mut self, rates: MutationRates, seed: u64) -> Self {
+        self.mutator = Mutator::new(rates, seed);
+        self
+    }
+
+    /// Pick genes each tick with `policy`
+    pub fn with_scheduling(mut self, policy: SchedulingPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    pub fn with_config(mut self, config: RuntimeConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Keep the genes of organisms loaded with at least `min_genes` genes
+    /// in a `GeneArena`; their `genes` stay empty until `unpack`
+    pub fn with_gene_arena(mut self, min_genes: usize) -> Self {
+        self.arena_min_genes = Some(min_genes);
+        self
+    }
+
+    /// Send messages emitted by organism `from` to organism `to`
+    pub fn with_route(mut self, from: &str, to: &str) -> Self {
+        self.mailbox.connect(from, to);
+        self
+    }
+
+    /// Emit `payload` from `gene` of an organism along its routes; returns
+    /// the number of recipients
+    pub fn emit(&mut self, organism_idx: usize, gene: &str, payload: Payload) -> usize {
+        let Some(organism) = self.organisms.get(organism_idx) else {
+            return 0;
+        };
+        self.mailbox.emit(Message {
+            from: organism.name.clone(),
+            gene: gene.to_string(),
+            payload,
+            epoch: self.epoch,
+        })
+    }
+
+    /// Emit the payload of every emit op in `ir` from an organism, in
+    /// order; returns the number of messages sent
+    pub fn emit_ir(&mut self, organism_idx: usize, ir: &OmegaIR) -> usize {
+        let mut sent = 0;
+        for op in &ir.gene_ops {
+            if let GeneOpType::Emit(payload) = &op.op_type {
+                sent += self.emit(organism_idx, &op.name, payload.clone());
+            }
+        }
+        sent
+    }
+
+    /// Take the messages delivered to an organism, oldest first
+    pub fn receive(&mut self, organism_idx: usize) -> Vec<Message> {
+        match self.organisms.get(organism_idx) {
+            Some(organism) => self.mailbox.receive(&organism.name),
+            None => Vec::new(),
+        }
+    }
+
+    /// Load an organism into the executor, packing its genes if it has at
+    /// least the `with_gene_arena` threshold
+    pub fn load_organism(&mut self, mut organism: Organism) -> usize {
         let idx = self.organisms.len();
+        let packed = self
+            .arena_min_genes
+            .is_some_and(|min| organism.genes.len() >= min)
+            .then(|| organism.pack_genes());
         self.organisms.push(organism);
+        self.sync_schedulers();
+        self.arenas[idx] = packed;
         idx
     }
 
+    /// Scheduling state of an organism
+    pub fn scheduler(&self, organism_idx: usize) -> Option<&Scheduler> {
+        self.schedulers.get(organism_idx)
+    }
+
+    /// Packed genes of an organism, if it was loaded into an arena
+    pub fn arena(&self, organism_idx: usize) -> Option<&GeneArena> {
+        self.arenas.get(organism_idx)?.as_ref()
+    }
+
+    /// Move an organism's packed genes back into its `genes`
+    pub fn unpack(&mut self, organism_idx: usize) {
+        if let Some(arena) = self.arenas.get_mut(organism_idx).and_then(Option::take) {
+            self.organisms[organism_idx].unpack_genes(arena);
+        }
+    }
+
+    /// One scheduler and arena slot per organism, including any pushed onto
+    /// `organisms` directly
+    fn sync_schedulers(&mut self) {
+        let policy = self.policy;
+        self.schedulers
+            .resize_with(self.organisms.len(), || Scheduler::new(policy));
+        self.arenas.resize_with(self.organisms.len(), || None);
+    }
+
     /// Create the standard CRSM7_Z3MESH organism
     pub fn create_standard_organism() -> Organism {
         let mut organism = Organism::new("CRSM7_Z3MESH");
@@ -139,79 +377,183 @@ impl OrganismExecutor {
         organism
     }
 
-    /// Execute DMA on an organism
+    /// Execute DMA on an organism, reporting each gene's term
     /// E_DMA(O) = Σ_g∈O (∂g/∂τ - Γ(g)) ⊗ Π±
-    pub fn execute_dma(&self, organism: &Organism) -> f64 {
-        let mut total = 0.0;
-
+    pub fn execute_dma(&self, organism: &Organism) -> DmaReport {
+        let mut report = DmaReport::default();
         for gene in &organism.genes {
-            // Compute temporal gradient ∂g/∂τ
-            let gradient = 0.1 * crate::manifold::DET_CRITICAL.powf(-0.5) * gene.state.lambda;
-
-            // Get decoherence Γ(g)
-            let gamma = gene.state.gamma;
-
-            // Apply duality Π±
-            let (pi_plus_val, _) = bifurcate(gene.state.lambda);
-            let duality_factor = if gene.state.rho >= 0.0 {
-                pi_plus_val
-            } else {
-                pi_minus(gene.state.lambda)
-            };
-
-            // DMA operator: (∂g/∂τ - Γ(g)) ⊗ Π±
-            let result = (gradient - gamma) * duality_factor.max(0.001);
-            total += result;
+            report.push(self.gene_dma(&gene.id, &gene.state));
         }
+        report
+    }
+
+    /// `execute_dma` on a loaded organism, packed or not
+    fn execute_dma_at(&self, organism_idx: usize) -> DmaReport {
+        match self.arena(organism_idx) {
+            Some(arena) => {
+                let mut report = DmaReport::default();
+                for i in 0..arena.len() {
+                    report.push(self.gene_dma(arena.id(i), &arena.state(i)));
+                }
+                report
+            }
+            None => self.execute_dma(&self.organisms[organism_idx]),
+        }
+    }
 
-        total
+    /// One gene's DMA term
+    fn gene_dma(&self, id: &str, state: &CRSM7State) -> GeneDma {
+        // Compute temporal gradient ∂g/∂τ
+        let gradient = self.config.dma_gradient_scale * state.volume_factor() * state.lambda;
+
+        // Get decoherence Γ(g)
+        let gamma = state.gamma;
+
+        // Apply duality Π±
+        let (pi_plus_val, _) = bifurcate(state.lambda);
+        let duality_factor = if state.rho >= 0.0 {
+            pi_plus_val
+        } else {
+            pi_minus(state.lambda)
+        };
+
+        // DMA operator: (∂g/∂τ - Γ(g)) ⊗ Π±
+        let duality = duality_factor.max(self.config.dma_duality_floor);
+        GeneDma {
+            gene: id.to_string(),
+            gradient,
+            gamma,
+            duality,
+            contribution: (gradient - gamma) * duality,
+        }
+    }
+
+    /// Execute DMA on every loaded organism (in parallel with the
+    /// `parallel` feature); organisms sharing a name keep the last report
+    pub fn execute_dma_all(&self) -> BTreeMap<String, DmaReport> {
+        #[cfg(feature = "parallel")]
+        let reports: Vec<DmaReport> = (0..self.organisms.len())
+            .into_par_iter()
+            .map(|idx| self.execute_dma_at(idx))
+            .collect();
+        #[cfg(not(feature = "parallel"))]
+        let reports: Vec<DmaReport> = (0..self.organisms.len())
+            .map(|idx| self.execute_dma_at(idx))
+            .collect();
+        self.organisms
+            .iter()
+            .map(|organism| organism.name.clone())
+            .zip(reports)
+            .collect()
     }
 
     /// Evolve an organism
     pub fn evolve(&mut self, organism_idx: usize, dt: f64) {
         if organism_idx < self.organisms.len() {
-            let organism = &mut self.organisms[organism_idx];
-
-            // Evolve each gene
-            for gene in &mut organism.genes {
-                gene.state.evolve(dt);
-            }
-
-            // Evolve organism state
-            organism.state.evolve(dt);
+            self.sync_schedulers();
+            self.mailbox.deliver(&self.organisms[organism_idx].name);
+            evolve_organism(
+                &mut self.organisms[organism_idx],
+                self.arenas[organism_idx].as_mut(),
+                &mut self.schedulers[organism_idx],
+                dt,
+                &self.config,
+            );
 
             // Update executor epoch
             self.epoch += dt;
         }
     }
 
+    /// Evolve every loaded organism by one step
+    pub fn evolve_all(&mut self, dt: f64) {
+        self.sync_schedulers();
+        self.mailbox.deliver_all();
+        let config = &self.config;
+        #[cfg(feature = "parallel")]
+        self.organisms
+            .par_iter_mut()
+            .zip(&mut self.arenas)
+            .zip(&mut self.schedulers)
+            .for_each(|((organism, arena), scheduler)| {
+                evolve_organism(organism, arena.as_mut(), scheduler, dt, config)
+            });
+        #[cfg(not(feature = "parallel"))]
+        for ((organism, arena), scheduler) in self
+            .organisms
+            .iter_mut()
+            .zip(&mut self.arenas)
+            .zip(&mut self.schedulers)
+        {
+            evolve_organism(organism, arena.as_mut(), scheduler, dt, config);
+        }
+        self.epoch += dt;
+    }
+
+    /// Apply one round of mutations to an organism; returns what changed.
+    /// A packed organism is unpacked for the round and packed again.
+    pub fn mutate(&mut self, organism_idx: usize) -> Vec<Mutation> {
+        if organism_idx >= self.organisms.len() {
+            return Vec::new();
+        }
+        self.sync_schedulers();
+        let packed = self.arenas[organism_idx].is_some();
+        self.unpack(organism_idx);
+        let organism = &mut self.organisms[organism_idx];
+        let mutations = self.mutator.mutate(organism);
+        if packed {
+            self.arenas[organism_idx] = Some(organism.pack_genes());
+        }
+        mutations
+    }
+
+    /// Apply `f` to the state of every gene of a loaded organism
+    fn update_genes(&mut self, organism_idx: usize, mut f: impl FnMut(&mut CRSM7State)) {
+        self.sync_schedulers();
+        match &mut self.arenas[organism_idx] {
+            Some(arena) => {
+                for i in 0..arena.len() {
+                    let mut state = arena.state(i);
+                    f(&mut state);
+                    arena.set_state(i, &state);
+                }
+            }
+            None => self.organisms[organism_idx]
+                .genes
+                .iter_mut()
+                .for_each(|gene| f(&mut gene.state)),
+        }
+    }
+
     /// Suppress decoherence across organism
     pub fn suppress_decoherence(&mut self, organism_idx: usize, factor: f64) {
         if organism_idx < self.organisms.len() {
-            let organism = &mut self.organisms[organism_idx];
+            let floor = self.config.gamma_tolerance;
+            self.update_genes(organism_idx, |state| {
+                state.gamma *= factor;
+                state.gamma = state.gamma.max(floor);
+            });
 
-            for gene in &mut organism.genes {
-                gene.state.gamma *= factor;
-                gene.state.gamma = gene.state.gamma.max(crate::manifold::GAMMA_TOLERANCE);
-            }
+            let organism = &mut self.organisms[organism_idx];
 
             organism.state.gamma *= factor;
-            organism.state.gamma = organism.state.gamma.max(crate::manifold::GAMMA_TOLERANCE);
+            organism.state.gamma = organism.state.gamma.max(floor);
         }
     }
 
     /// Elevate coherence-information product
     pub fn elevate_coherence_info(&mut self, organism_idx: usize, factor: f64) {
         if organism_idx < self.organisms.len() {
-            let organism = &mut self.organisms[organism_idx];
+            let cap = self.config.lambda_cap;
+            self.update_genes(organism_idx, |state| {
+                state.lambda = (state.lambda * factor).min(cap);
+                state.phi *= factor;
+                state.compute_emergence();
+            });
 
-            for gene in &mut organism.genes {
-                gene.state.lambda = (gene.state.lambda * factor).min(0.999);
-                gene.state.phi *= factor;
-                gene.state.compute_emergence();
-            }
+            let organism = &mut self.organisms[organism_idx];
 
-            organism.state.lambda = (organism.state.lambda * factor).min(0.999);
+            organism.state.lambda = (organism.state.lambda * factor).min(cap);
             organism.state.phi *= factor;
             organism.state.compute_emergence();
         }
@@ -243,6 +585,121 @@ mod tests {
         assert_eq!(organism.genes.len(), 5);
     }
 
+    #[test]
+    fn test_evolve_all_matches_single_evolution() {
+        let mut executor = OrganismExecutor::new();
+        for _ in 0..3 {
+            executor.load_organism(OrganismExecutor::create_standard_organism());
+        }
+        let mut single = OrganismExecutor::new();
+        single.load_organism(OrganismExecutor::create_standard_organism());
+
+        for _ in 0..10 {
+            executor.evolve_all(0.01);
+            single.evolve(0, 0.01);
+        }
+        for organism in &executor.organisms {
+            for (gene, expected) in organism.genes.iter().zip(&single.organisms[0].genes) {
+                assert_eq!(gene.state.lambda, expected.state.lambda);
+                assert_eq!(gene.state.tau, expected.state.tau);
+            }
+        }
+        assert_eq!(executor.epoch, single.epoch);
+    }
+
+    #[test]
+    fn test_scheduled_genes_catch_up_when_run() {
+        let mut executor =
+            OrganismExecutor::new().with_scheduling(SchedulingPolicy::RoundRobin { batch: 2 });
+        let idx = executor.load_organism(OrganismExecutor::create_standard_organism());
+        let start: Vec<f64> = executor.organisms[idx]
+            .genes
+            .iter()
+            .map(|g| g.state.tau)
+            .collect();
+        for _ in 0..5 {
+            executor.evolve(idx, 0.01);
+        }
+
+        let scheduler = executor.scheduler(idx).unwrap();
+        assert_eq!(scheduler.tick(), 5);
+        for (gene, tau0) in executor.organisms[idx].genes.iter().zip(start) {
+            let last = scheduler.last_executed(&gene.id).unwrap() as f64;
+            assert!((gene.state.tau - tau0 - 0.01 * last).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_organism_from_ir_keeps_annotations() {
+        let dna = dnalang_compiler::OrganismBuilder::new("Annotated")
+            .annotate("author", "ENKI-420")
+            .gene("main", |g| g.annotate("hint", "fast"))
+            .program();
+        let ir = dnalang_compiler::generate_omega_ir(&dna, &dnalang_compiler::CrsmProgram::new());
+
+        let organism = Organism::from_ir("Annotated", &ir);
+        assert_eq!(organism.annotations["author"], "ENKI-420");
+        assert_eq!(organism.genes[0].annotations["hint"], "fast");
+    }
+
+    #[test]
+    fn test_contract_fixtures_execute() {
+        use crate::sink::{EmitRouter, MemorySink};
+        use dnalang_compiler::contract::FIXTURES;
+        use dnalang_compiler::ir::GeneOpType;
+
+        for fixture in FIXTURES {
+            let ir = fixture.compile();
+            let json = serde_json::to_string(&ir).unwrap();
+            let ir: OmegaIR = serde_json::from_str(&json).unwrap();
+
+            let organism = Organism::from_ir(fixture.name, &ir);
+            assert!(!organism.genes.is_empty(), "{}", fixture.name);
+            let mut executor = OrganismExecutor::new();
+            let idx = executor.load_organism(organism);
+            executor.evolve(idx, 0.1);
+            assert!(executor
+                .execute_dma(&executor.organisms[idx])
+                .total
+                .is_finite());
+
+            let emits = ir
+                .gene_ops
+                .iter()
+                .filter(|op| matches!(op.op_type, GeneOpType::Emit(_)))
+                .count();
+            let mut router = EmitRouter::new();
+            router.add_sink(Box::new(MemorySink::default()));
+            assert_eq!(router.emit_ir(&ir).unwrap(), emits, "{}", fixture.name);
+        }
+    }
+
+    #[test]
+    fn test_messages_arrive_on_next_step() {
+        let mut aura = Organism::new("AURA");
+        aura.add_gene(Gene::new("signal", "SIGNAL"));
+        let mut executor = OrganismExecutor::new().with_route("AURA", "AIDEN");
+        let from = executor.load_organism(aura);
+        let to = executor.load_organism(Organism::new("AIDEN"));
+
+        let dna = dnalang_compiler::OrganismBuilder::new("AURA")
+            .gene("signal", |g| g.emit("coherent"))
+            .program();
+        let ir = dnalang_compiler::generate_omega_ir(&dna, &dnalang_compiler::CrsmProgram::new());
+        assert_eq!(executor.emit_ir(from, &ir), 1);
+        assert!(executor.receive(to).is_empty());
+
+        executor.evolve_all(0.01);
+        let messages = executor.receive(to);
+        assert_eq!(messages.len(), 1);
+        assert_eq!(
+            (messages[0].from.as_str(), messages[0].gene.as_str()),
+            ("AURA", "signal")
+        );
+        assert_eq!(messages[0].payload, Payload::Text("coherent".to_string()));
+        assert!(executor.receive(from).is_empty());
+    }
+
     #[test]
     fn test_executor_load() {
         let mut executor = OrganismExecutor::new();
@@ -255,9 +712,29 @@ mod tests {
     #[test]
     fn test_execute_dma() {
         let executor = OrganismExecutor::new();
-        let organism = OrganismExecutor::create_standard_organism();
-        let result = executor.execute_dma(&organism);
-        assert!(result.is_finite());
+        let mut organism = OrganismExecutor::create_standard_organism();
+        organism.genes[2].state.gamma = 5.0;
+        let report = executor.execute_dma(&organism);
+        assert!(report.total.is_finite());
+        assert_eq!(report.genes.len(), organism.genes.len());
+        let sum: f64 = report.genes.iter().map(|g| g.contribution).sum();
+        assert!((report.total - sum).abs() < 1e-12);
+        assert_eq!(report.draggers()[0].gene, organism.genes[2].id);
+    }
+
+    #[test]
+    fn test_execute_dma_all() {
+        let mut executor = OrganismExecutor::new();
+        executor.load_organism(OrganismExecutor::create_standard_organism());
+        let mut other = Organism::new("Other");
+        other.add_gene(Gene::new("g", "G"));
+        executor.load_organism(other);
+
+        let reports = executor.execute_dma_all();
+        assert_eq!(reports.len(), 2);
+        for organism in &executor.organisms {
+            assert_eq!(reports[&organism.name], executor.execute_dma(organism));
+        }
     }
 
     #[test]
@@ -0,0 +1,113 @@
+//! Organism Files
+//!
+//! The `.organism` format for persisting hand-tuned or GA-evolved organisms
+//! and sharing them between tools. Like checkpoints, a file is JSON holding
+//! the format number, the runtime version that wrote it and the organism
+//! (genes, states, operators and annotations), and is written to a
+//! temporary sibling and renamed into place.
+
+use crate::organism::executor::Organism;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Current organism file format
+pub const ORGANISM_FORMAT: u32 = 1;
+
+/// Conventional extension of organism files
+pub const ORGANISM_EXTENSION: &str = "organism";
+
+/// Contents of an organism file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrganismFile {
+    /// File format, for refusing files from newer releases
+    pub format: u32,
+    /// Version of the runtime that wrote the file
+    pub version: String,
+    pub organism: Organism,
+}
+
+impl OrganismFile {
+    pub fn new(organism: &Organism) -> Self {
+        Self {
+            format: ORGANISM_FORMAT,
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            organism: organism.clone(),
+        }
+    }
+
+    /// Parse file contents, refusing unknown formats
+    pub fn parse(contents: &str) -> io::Result<Self> {
+        let file: Self = serde_json::from_str(contents)?;
+        if file.format == 0 || file.format > ORGANISM_FORMAT {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "organism format {} written by {} is not supported (expected 1..={})",
+                    file.format, file.version, ORGANISM_FORMAT
+                ),
+            ));
+        }
+        Ok(file)
+    }
+
+    /// Write to `path`, replacing it atomically
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        let mut partial = path.as_os_str().to_owned();
+        partial.push(".partial");
+        fs::write(&partial, serde_json::to_string_pretty(self)?)?;
+        fs::rename(&partial, path)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::parse(&fs::read_to_string(path)?)
+    }
+}
+
+impl Organism {
+    /// Write an organism file to `path`
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        OrganismFile::new(self).save(path)
+    }
+
+    /// Read the organism from an organism file
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(OrganismFile::load(path)?.organism)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::organism::OrganismExecutor;
+
+    #[test]
+    fn test_organism_file_roundtrip() {
+        let path = std::env::temp_dir().join(format!(
+            "dna-organism-{}.{}",
+            std::process::id(),
+            ORGANISM_EXTENSION
+        ));
+        let mut organism = OrganismExecutor::create_standard_organism();
+        organism.genes[0].state.lambda = 0.123456789;
+        organism
+            .annotations
+            .insert("origin".into(), "hand-tuned".into());
+        organism.save(&path).unwrap();
+
+        let loaded = Organism::load(&path).unwrap();
+        assert_eq!(
+            serde_json::to_value(&loaded).unwrap(),
+            serde_json::to_value(&organism).unwrap()
+        );
+
+        let mut future = OrganismFile::new(&organism);
+        future.format = ORGANISM_FORMAT + 1;
+        future.save(&path).unwrap();
+        let err = Organism::load(&path).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        fs::remove_file(&path).unwrap();
+    }
+}
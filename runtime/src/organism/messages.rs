@@ -0,0 +1,154 @@
+//! Inter-Organism Messages
+//!
+//! A typed channel between organisms in one executor. A gene emits a
+//! `Payload`; the executor sends it along every route leaving the gene's
+//! organism, and each recipient can take it once its next step has
+//! started. Messages are owned copies, so organisms compose (AURA feeding
+//! AIDEN) without sharing mutable state.
+//!
+//! Organisms are addressed by name; organisms sharing a name share an
+//! inbox. Delivered messages wait in the inbox until received.
+
+use dnalang_compiler::Payload;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
+
+/// A payload emitted by one organism's gene
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Message {
+    /// Sending organism
+    pub from: String,
+    /// Emitting gene
+    pub gene: String,
+    pub payload: Payload,
+    /// Executor epoch when sent
+    pub epoch: f64,
+}
+
+/// Routes between organisms and the messages in flight
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Mailbox {
+    /// (from, to) organism names
+    routes: Vec<(String, String)>,
+    /// Sent messages awaiting the recipient's next step, with the recipient
+    pending: Vec<(String, Message)>,
+    /// Delivered messages by recipient, oldest first
+    inboxes: BTreeMap<String, VecDeque<Message>>,
+}
+
+impl Mailbox {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Send everything `from` emits to `to` as well
+    pub fn connect(&mut self, from: &str, to: &str) {
+        let route = (from.to_string(), to.to_string());
+        if !self.routes.contains(&route) {
+            self.routes.push(route);
+        }
+    }
+
+    pub fn disconnect(&mut self, from: &str, to: &str) {
+        self.routes.retain(|(f, t)| !(f == from && t == to));
+    }
+
+    pub fn routes(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.routes.iter().map(|(f, t)| (f.as_str(), t.as_str()))
+    }
+
+    /// Queue `message` for `to`
+    pub fn send(&mut self, to: &str, message: Message) {
+        self.pending.push((to.to_string(), message));
+    }
+
+    /// Queue `message` along every route from its sender; returns the
+    /// number of recipients
+    pub fn emit(&mut self, message: Message) -> usize {
+        let recipients: Vec<String> = self
+            .routes
+            .iter()
+            .filter(|(from, _)| *from == message.from)
+            .map(|(_, to)| to.clone())
+            .collect();
+        for to in &recipients {
+            self.send(to, message.clone());
+        }
+        recipients.len()
+    }
+
+    /// Move pending messages for `recipient` into its inbox
+    pub fn deliver(&mut self, recipient: &str) {
+        let (due, rest): (Vec<_>, Vec<_>) = std::mem::take(&mut self.pending)
+            .into_iter()
+            .partition(|(to, _)| to == recipient);
+        self.pending = rest;
+        if !due.is_empty() {
+            let inbox = self.inboxes.entry(recipient.to_string()).or_default();
+            inbox.extend(due.into_iter().map(|(_, message)| message));
+        }
+    }
+
+    /// Move every pending message into its recipient's inbox
+    pub fn deliver_all(&mut self) {
+        for (to, message) in std::mem::take(&mut self.pending) {
+            self.inboxes.entry(to).or_default().push_back(message);
+        }
+    }
+
+    /// Take the delivered messages for `recipient`, oldest first
+    pub fn receive(&mut self, recipient: &str) -> Vec<Message> {
+        self.inboxes
+            .remove(recipient)
+            .map(Vec::from)
+            .unwrap_or_default()
+    }
+
+    /// Delivered messages for `recipient`, without taking them
+    pub fn peek(&self, recipient: &str) -> impl Iterator<Item = &Message> {
+        self.inboxes.get(recipient).into_iter().flatten()
+    }
+
+    /// Messages sent but not yet delivered
+    pub fn in_flight(&self) -> usize {
+        self.pending.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(from: &str, text: &str) -> Message {
+        Message {
+            from: from.to_string(),
+            gene: "g".to_string(),
+            payload: Payload::Text(text.to_string()),
+            epoch: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_messages_follow_routes_on_delivery() {
+        let mut mailbox = Mailbox::new();
+        mailbox.connect("AURA", "AIDEN");
+        mailbox.connect("AURA", "AIDEN");
+        mailbox.connect("AURA", "SENTINEL");
+        assert_eq!(mailbox.routes().count(), 2);
+
+        assert_eq!(mailbox.emit(message("AURA", "hello")), 2);
+        assert_eq!(mailbox.emit(message("AIDEN", "unrouted")), 0);
+        assert!(mailbox.receive("AIDEN").is_empty());
+
+        mailbox.deliver("AIDEN");
+        assert_eq!(mailbox.in_flight(), 1);
+        assert_eq!(mailbox.peek("AIDEN").count(), 1);
+        let received = mailbox.receive("AIDEN");
+        assert_eq!(received[0].payload, Payload::Text("hello".to_string()));
+        assert!(mailbox.receive("AIDEN").is_empty());
+
+        mailbox.disconnect("AURA", "SENTINEL");
+        mailbox.deliver_all();
+        assert_eq!(mailbox.receive("SENTINEL").len(), 1);
+    }
+}
@@ -2,6 +2,20 @@
 //!
 //! DNA organism execution and management
 
+pub mod arena;
+pub mod crossover;
+pub mod dma;
 pub mod executor;
+pub mod file;
+pub mod messages;
+pub mod mutation;
+pub mod scheduler;
 
+pub use arena::{GeneArena, Interner, Symbol};
+pub use crossover::CrossoverStrategy;
+pub use dma::{DmaReport, GeneDma};
 pub use executor::{Gene, Organism, OrganismExecutor};
+pub use file::{OrganismFile, ORGANISM_EXTENSION, ORGANISM_FORMAT};
+pub use messages::{Mailbox, Message};
+pub use mutation::{GeneField, Mutation, MutationRates, Mutator};
+pub use scheduler::{Scheduler, SchedulingPolicy};
@@ -0,0 +1,241 @@
+//! Mutation Operators
+//!
+//! Seeded mutation of organisms for evolutionary experiments. Each gene
+//! independently undergoes, with configured probabilities, a point
+//! mutation of one state field, duplication (the copy is appended under a
+//! fresh id) and deletion. The same seed, rates and organism always produce
+//! the same mutations.
+
+use crate::manifold::{CRSM7State, GAMMA_TOLERANCE};
+use crate::organism::executor::{Gene, Organism};
+use crate::rng::SeededRng;
+use serde::{Deserialize, Serialize};
+
+/// Per-gene, per-call mutation probabilities (all 0 by default)
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct MutationRates {
+    pub point: f64,
+    /// Relative σ of a point mutation: x → x·(1 + σ·N(0, 1))
+    pub point_scale: f64,
+    pub duplication: f64,
+    pub deletion: f64,
+}
+
+impl MutationRates {
+    pub fn with_point(mut self, rate: f64, scale: f64) -> Self {
+        self.point = rate;
+        self.point_scale = scale;
+        self
+    }
+
+    pub fn with_duplication(mut self, rate: f64) -> Self {
+        self.duplication = rate;
+        self
+    }
+
+    pub fn with_deletion(mut self, rate: f64) -> Self {
+        self.deletion = rate;
+        self
+    }
+}
+
+/// State fields a point mutation can change (Ξ is recomputed, τ is left
+/// alone)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GeneField {
+    Lambda,
+    Gamma,
+    Phi,
+    Rho,
+    Theta,
+}
+
+impl GeneField {
+    const ALL: [GeneField; 5] = [
+        GeneField::Lambda,
+        GeneField::Gamma,
+        GeneField::Phi,
+        GeneField::Rho,
+        GeneField::Theta,
+    ];
+
+    fn value_mut(self, state: &mut CRSM7State) -> &mut f64 {
+        match self {
+            GeneField::Lambda => &mut state.lambda,
+            GeneField::Gamma => &mut state.gamma,
+            GeneField::Phi => &mut state.phi,
+            GeneField::Rho => &mut state.rho,
+            GeneField::Theta => &mut state.theta,
+        }
+    }
+}
+
+/// One applied mutation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "mutation", rename_all = "snake_case")]
+pub enum Mutation {
+    Point {
+        gene: String,
+        field: GeneField,
+        from: f64,
+        to: f64,
+    },
+    Duplication {
+        gene: String,
+        copy: String,
+    },
+    Deletion {
+        gene: String,
+    },
+}
+
+/// Applies mutations at fixed rates from a seeded generator
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Mutator {
+    pub rates: MutationRates,
+    rng: SeededRng,
+}
+
+impl Mutator {
+    pub fn new(rates: MutationRates, seed: u64) -> Self {
+        Self {
+            rates,
+            rng: SeededRng::new(seed),
+        }
+    }
+
+    /// Mutate every gene of `organism` once; returns what changed, in
+    /// order
+    pub fn mutate(&mut self, organism: &mut Organism) -> Vec<Mutation> {
+        let mut mutations = Vec::new();
+        let mut deleted = Vec::new();
+        let original = organism.genes.len();
+        for i in 0..original {
+            if self.rng.next_f64() < self.rates.point {
+                mutations.push(self.point_mutation(&mut organism.genes[i]));
+            }
+            if self.rng.next_f64() < self.rates.duplication {
+                mutations.push(duplicate(organism, i));
+            }
+            if self.rng.next_f64() < self.rates.deletion {
+                deleted.push(organism.genes[i].id.clone());
+            }
+        }
+        for gene in deleted {
+            if let Some(mutation) = delete(organism, &gene) {
+                mutations.push(mutation);
+            }
+        }
+        mutations
+    }
+
+    /// Scale one randomly chosen field (ρ flips sign instead), keeping Λ in
+    /// [0, 1], Γ ≥ εΓ and Φ ≥ 0
+    pub fn point_mutation(&mut self, gene: &mut Gene) -> Mutation {
+        let field = GeneField::ALL[self.rng.index(GeneField::ALL.len())];
+        let factor = 1.0 + self.rates.point_scale * self.rng.gaussian();
+        let value = field.value_mut(&mut gene.state);
+        let from = *value;
+        *value = match field {
+            GeneField::Rho => -from,
+            GeneField::Lambda => (from * factor).clamp(0.0, 1.0),
+            GeneField::Gamma => (from * factor).max(GAMMA_TOLERANCE),
+            GeneField::Phi => (from * factor).max(0.0),
+            GeneField::Theta => from * factor,
+        };
+        let to = *value;
+        gene.state.compute_emergence();
+        Mutation::Point {
+            gene: gene.id.clone(),
+            field,
+            from,
+            to,
+        }
+    }
+}
+
+/// Append a copy of gene `index` under an id not yet in the organism
+pub fn duplicate(organism: &mut Organism, index: usize) -> Mutation {
+    let source = &organism.genes[index];
+    let id = (1..)
+        .map(|n| format!("{}~{}", source.id, n))
+        .find(|id| organism.genes.iter().all(|g| &g.id != id))
+        .expect("unbounded id search");
+    let mut copy = source.clone();
+    copy.id = id.clone();
+    let gene = source.id.clone();
+    organism.add_gene(copy);
+    Mutation::Duplication { gene, copy: id }
+}
+
+/// Remove the first gene with id `gene`
+pub fn delete(organism: &mut Organism, gene: &str) -> Option<Mutation> {
+    let index = organism.genes.iter().position(|g| g.id == gene)?;
+    organism.genes.remove(index);
+    Some(Mutation::Deletion {
+        gene: gene.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::organism::OrganismExecutor;
+
+    #[test]
+    fn test_seeded_mutations_are_reproducible() {
+        let rates = MutationRates::default()
+            .with_point(0.5, 0.2)
+            .with_duplication(0.3)
+            .with_deletion(0.2);
+        let run = |seed| {
+            let mut organism = OrganismExecutor::create_standard_organism();
+            let mut mutator = Mutator::new(rates, seed);
+            let mutations: Vec<Mutation> = (0..10)
+                .flat_map(|_| mutator.mutate(&mut organism))
+                .collect();
+            (organism, mutations)
+        };
+
+        let (organism, mutations) = run(7);
+        let (again, repeated) = run(7);
+        assert_eq!(mutations, repeated);
+        assert_eq!(organism.genes.len(), again.genes.len());
+        assert!(mutations
+            .iter()
+            .any(|m| matches!(m, Mutation::Duplication { .. })));
+
+        let added = mutations
+            .iter()
+            .filter(|m| matches!(m, Mutation::Duplication { .. }))
+            .count();
+        let removed = mutations
+            .iter()
+            .filter(|m| matches!(m, Mutation::Deletion { .. }))
+            .count();
+        assert_eq!(organism.genes.len(), 5 + added - removed);
+        for gene in &organism.genes {
+            assert!((0.0..=1.0).contains(&gene.state.lambda));
+            assert!(gene.state.gamma >= GAMMA_TOLERANCE);
+        }
+    }
+
+    #[test]
+    fn test_operators() {
+        let mut organism = OrganismExecutor::create_standard_organism();
+        assert_eq!(
+            duplicate(&mut organism, 0),
+            Mutation::Duplication {
+                gene: "aura".into(),
+                copy: "aura~1".into()
+            }
+        );
+        assert!(
+            matches!(duplicate(&mut organism, 0), Mutation::Duplication { copy, .. } if copy == "aura~2")
+        );
+        assert!(delete(&mut organism, "aura~1").is_some());
+        assert!(delete(&mut organism, "aura~1").is_none());
+        assert_eq!(organism.genes.len(), 6);
+    }
+}
@@ -0,0 +1,183 @@
+//! Gene Scheduling
+//!
+//! Chooses which genes of an organism evolve on each executor tick. The
+//! default policy evolves every gene; the others let large organisms spend
+//! a tick on a subset: a rotating window (round-robin), the highest-Ξ genes
+//! (Ξ-priority) or the longest-waiting genes that have rested at least a
+//! minimum interval (rate-limited). A gene that sat out evolves by the time
+//! it missed when it next runs, so its τ catches up with the executor.
+//!
+//! Ξ-priority can starve low-emergence genes indefinitely; that is the
+//! point of it, but pair it with periodic round-robin ticks if every gene
+//! must eventually advance.
+
+use crate::organism::executor::Gene;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// How genes are picked each tick
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(tag = "policy", rename_all = "snake_case")]
+pub enum SchedulingPolicy {
+    /// Every gene, every tick
+    #[default]
+    All,
+    /// The next `batch` genes, wrapping around
+    RoundRobin { batch: usize },
+    /// The `top` genes with the highest Ξ
+    XiPriority { top: usize },
+    /// Up to `max_per_tick` genes, longest-waiting first, each at most once
+    /// every `min_interval` ticks
+    RateLimited {
+        max_per_tick: usize,
+        min_interval: u64,
+    },
+}
+
+/// Per-organism scheduling state
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Scheduler {
+    policy: SchedulingPolicy,
+    /// Ticks scheduled so far
+    tick: u64,
+    /// Next gene index for round-robin
+    cursor: usize,
+    /// Tick each gene (by id) last evolved on
+    last_executed: BTreeMap<String, u64>,
+}
+
+impl Scheduler {
+    pub fn new(policy: SchedulingPolicy) -> Self {
+        Self {
+            policy,
+            ..Self::default()
+        }
+    }
+
+    pub fn policy(&self) -> SchedulingPolicy {
+        self.policy
+    }
+
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    /// Tick `gene_id` last evolved on, if it has run
+    pub fn last_executed(&self, gene_id: &str) -> Option<u64> {
+        match self.policy {
+            SchedulingPolicy::All => (self.tick > 0).then_some(self.tick),
+            _ => self.last_executed.get(gene_id).copied(),
+        }
+    }
+
+    /// Advance one tick and pick the genes to evolve, as (index, ticks
+    /// elapsed since the gene last ran) in index order; `None` means every
+    /// gene, one tick each
+    pub fn schedule(&mut self, genes: &[Gene]) -> Option<Vec<(usize, u64)>> {
+        self.schedule_by(genes.len(), |i| &genes[i].id, |i| genes[i].state.xi)
+    }
+
+    /// `schedule` for `n` genes stored elsewhere (e.g. a `GeneArena`),
+    /// given each gene's id and Ξ by index
+    pub fn schedule_by<'a>(
+        &mut self,
+        n: usize,
+        id: impl Fn(usize) -> &'a str,
+        xi: impl Fn(usize) -> f64,
+    ) -> Option<Vec<(usize, u64)>> {
+        self.tick += 1;
+        let last = |s: &Self, i: usize| s.last_executed.get(id(i)).copied().unwrap_or(0);
+
+        let mut picked: Vec<usize> = match self.policy {
+            SchedulingPolicy::All => return None,
+            SchedulingPolicy::RoundRobin { batch } => {
+                let batch = batch.min(n);
+                let start = if n == 0 { 0 } else { self.cursor % n };
+                self.cursor = start + batch;
+                (start..start + batch).map(|i| i % n).collect()
+            }
+            SchedulingPolicy::XiPriority { top } => {
+                let mut order: Vec<usize> = (0..n).collect();
+                order.sort_by(|&a, &b| xi(b).total_cmp(&xi(a)));
+                order.truncate(top);
+                order
+            }
+            SchedulingPolicy::RateLimited {
+                max_per_tick,
+                min_interval,
+            } => {
+                let mut ready: Vec<usize> = (0..n)
+                    .filter(|&i| match self.last_executed.get(id(i)) {
+                        Some(&t) => self.tick - t >= min_interval.max(1),
+                        None => true,
+                    })
+                    .collect();
+                ready.sort_by_key(|&i| last(self, i));
+                ready.truncate(max_per_tick);
+                ready
+            }
+        };
+        picked.sort_unstable();
+        picked.dedup();
+
+        Some(
+            picked
+                .into_iter()
+                .map(|i| {
+                    let elapsed = self.tick - last(self, i);
+                    self.last_executed.insert(id(i).to_string(), self.tick);
+                    (i, elapsed)
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::manifold::CRSM7State;
+
+    fn genes(xis: &[f64]) -> Vec<Gene> {
+        xis.iter()
+            .enumerate()
+            .map(|(i, &xi)| {
+                let mut state = CRSM7State::new();
+                state.xi = xi;
+                Gene::with_state(&format!("g{}", i), "G", state)
+            })
+            .collect()
+    }
+
+    fn indices(schedule: Option<Vec<(usize, u64)>>) -> Vec<usize> {
+        schedule.unwrap().into_iter().map(|(i, _)| i).collect()
+    }
+
+    #[test]
+    fn test_policies_pick_expected_genes() {
+        let genes = genes(&[1.0, 9.0, 3.0, 7.0, 5.0]);
+
+        let mut round_robin = Scheduler::new(SchedulingPolicy::RoundRobin { batch: 2 });
+        assert_eq!(indices(round_robin.schedule(&genes)), vec![0, 1]);
+        assert_eq!(indices(round_robin.schedule(&genes)), vec![2, 3]);
+        assert_eq!(round_robin.schedule(&genes), Some(vec![(0, 2), (4, 3)]));
+        assert_eq!(round_robin.last_executed("g0"), Some(3));
+        assert_eq!(round_robin.last_executed("g2"), Some(2));
+
+        let mut priority = Scheduler::new(SchedulingPolicy::XiPriority { top: 2 });
+        assert_eq!(indices(priority.schedule(&genes)), vec![1, 3]);
+        assert_eq!(priority.last_executed("g0"), None);
+
+        let mut limited = Scheduler::new(SchedulingPolicy::RateLimited {
+            max_per_tick: 3,
+            min_interval: 2,
+        });
+        assert_eq!(indices(limited.schedule(&genes)), vec![0, 1, 2]);
+        assert_eq!(indices(limited.schedule(&genes)), vec![3, 4]);
+        assert_eq!(limited.schedule(&genes), Some(vec![(0, 2), (1, 2), (2, 2)]));
+
+        let mut all = Scheduler::default();
+        assert_eq!(all.schedule(&genes), None);
+        assert_eq!(all.last_executed("g4"), Some(1));
+    }
+}
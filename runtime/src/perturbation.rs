@@ -0,0 +1,361 @@
+//! Perturbation Schedules
+//!
+//! Timed interventions applied by the runtime during `step`. Each entry
+//! fires once, on the first step whose epoch τ reaches the entry's τ,
+//! after the state has evolved and before the collapse rules run.
+//! Entries due at the same τ fire in insertion order.
+//!
+//! A schedule is stored in the `DualRuntime`, so it is saved with
+//! checkpoints and recordings. Its cursor is saved too, and a resumed run
+//! does not fire an entry twice.
+//!
+//! `DualRuntime::perturb` injects a perturbation immediately instead and,
+//! following axiom A4 (E → E⁻¹), queues its `Inversion`: the runtime undoes
+//! the perturbation over the next `RuntimeConfig::inversion_steps` steps.
+//! Γ is restored geometrically (its evolution is linear in Γ, so the
+//! unperturbed Γ is recovered exactly in the absence of noise), mesh edges
+//! linearly, and polarity flips back on the last step. A perturbation that
+//! takes Γ to zero or below has no finite ratio, so its Γ change is undone
+//! linearly instead. A killed gene is not restored. Scheduled perturbations
+//! are not inverted.
+
+use crate::dual_runtime::DualRuntime;
+use serde::{Deserialize, Serialize};
+
+/// Side of the dense 7×7 Z3 mesh
+const MESH_SIDE: usize = 7;
+
+/// An intervention on a running runtime
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum Perturbation {
+    /// Set Γ (and recompute Ξ)
+    SetGamma { value: f64 },
+    /// Raise Γ by `amount` (and recompute Ξ)
+    DecoherenceSpike { amount: f64 },
+    /// ρ± → ∓
+    FlipPolarity,
+    /// Set the symmetric mesh weight between two vertices
+    InjectMeshEdge { from: usize, to: usize, weight: f64 },
+    /// Remove a gene from the organism
+    KillGene { gene: String },
+}
+
+impl Perturbation {
+    /// Apply to a runtime; false if it had no target (unknown gene or
+    /// vertex outside the mesh)
+    pub fn apply(&self, runtime: &mut DualRuntime) -> bool {
+        match self {
+            Perturbation::SetGamma { value } => {
+                runtime.state.gamma = *value;
+                runtime.state.compute_emergence();
+                true
+            }
+            Perturbation::DecoherenceSpike { amount } => {
+                runtime.state.gamma += amount;
+                runtime.state.compute_emergence();
+                true
+            }
+            Perturbation::FlipPolarity => {
+                runtime.state.rho = -runtime.state.rho;
+                true
+            }
+            Perturbation::InjectMeshEdge { from, to, weight } => {
+                if *from >= MESH_SIDE || *to >= MESH_SIDE {
+                    return false;
+                }
+                let weights = &mut runtime.mesh_weights.weights;
+                if weights.len() < MESH_SIDE * MESH_SIDE {
+                    weights.resize(MESH_SIDE * MESH_SIDE, 0.0);
+                }
+                weights[from * MESH_SIDE + to] = *weight;
+                weights[to * MESH_SIDE + from] = *weight;
+                true
+            }
+            Perturbation::KillGene { gene } => {
+                let genes = &mut runtime.organism.genes;
+                let before = genes.len();
+                genes.retain(|g| &g.id != gene);
+                genes.len() < before
+            }
+        }
+    }
+}
+
+/// What an inversion restores
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "target", rename_all = "snake_case")]
+pub enum InversionTarget {
+    /// Divide Γ by `ratio` (perturbed / unperturbed Γ)
+    Gamma { ratio: f64 },
+    /// Subtract `delta` (perturbed - unperturbed Γ) from Γ, when the ratio
+    /// is not positive and finite
+    GammaShift { delta: f64 },
+    /// ρ± → ∓ once the inversion completes
+    Polarity,
+    /// Subtract `excess` from the symmetric mesh weight
+    MeshEdge { from: usize, to: usize, excess: f64 },
+}
+
+/// E⁻¹ of an injected perturbation, spread over the steps after it
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Inversion {
+    pub target: InversionTarget,
+    pub steps: u32,
+    /// Steps still to apply
+    pub remaining: u32,
+}
+
+impl Inversion {
+    /// Inverse of `perturbation` about to be applied to `runtime`; `None`
+    /// when it cannot be undone or `steps` is 0
+    pub fn of(perturbation: &Perturbation, runtime: &DualRuntime, steps: u32) -> Option<Self> {
+        if steps == 0 {
+            return None;
+        }
+        let gamma = runtime.state.gamma;
+        let restore_gamma = |perturbed: f64| {
+            let ratio = perturbed / gamma;
+            if ratio > 0.0 && ratio.is_finite() {
+                InversionTarget::Gamma { ratio }
+            } else {
+                InversionTarget::GammaShift {
+                    delta: perturbed - gamma,
+                }
+            }
+        };
+        let target = match perturbation {
+            Perturbation::SetGamma { value } => restore_gamma(*value),
+            Perturbation::DecoherenceSpike { amount } => restore_gamma(gamma + amount),
+            Perturbation::FlipPolarity => InversionTarget::Polarity,
+            Perturbation::InjectMeshEdge { from, to, weight } => {
+                let previous = runtime
+                    .mesh_weights
+                    .weights
+                    .get(from * MESH_SIDE + to)
+                    .copied()
+                    .unwrap_or(0.0);
+                InversionTarget::MeshEdge {
+                    from: *from,
+                    to: *to,
+                    excess: weight - previous,
+                }
+            }
+            Perturbation::KillGene { .. } => return None,
+        };
+        Some(Self {
+            target,
+            steps,
+            remaining: steps,
+        })
+    }
+
+    /// Apply one step's share; true once the inversion is complete
+    pub(crate) fn advance(&mut self, runtime: &mut DualRuntime) -> bool {
+        if self.remaining == 0 {
+            return true;
+        }
+        self.remaining -= 1;
+        let share = 1.0 / self.steps as f64;
+        match self.target {
+            InversionTarget::Gamma { ratio } => {
+                let gamma = runtime.state.gamma / ratio.powf(share);
+                runtime.state.gamma = gamma.max(runtime.config.gamma_tolerance);
+                runtime.state.compute_emergence();
+            }
+            InversionTarget::GammaShift { delta } => {
+                let gamma = runtime.state.gamma - delta * share;
+                runtime.state.gamma = gamma.max(runtime.config.gamma_tolerance);
+                runtime.state.compute_emergence();
+            }
+            InversionTarget::Polarity => {
+                if self.remaining == 0 {
+                    runtime.state.rho = -runtime.state.rho;
+                }
+            }
+            InversionTarget::MeshEdge { from, to, excess } => {
+                let weights = &mut runtime.mesh_weights.weights;
+                if weights.len() >= MESH_SIDE * MESH_SIDE {
+                    weights[from * MESH_SIDE + to] -= excess * share;
+                    if from != to {
+                        weights[to * MESH_SIDE + from] -= excess * share;
+                    }
+                }
+            }
+        }
+        self.remaining == 0
+    }
+}
+
+/// A perturbation and the epoch it fires at
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduledPerturbation {
+    pub tau: f64,
+    #[serde(flatten)]
+    pub perturbation: Perturbation,
+}
+
+/// Perturbations ordered by τ
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PerturbationSchedule {
+    entries: Vec<ScheduledPerturbation>,
+    /// Entries already fired
+    #[serde(default)]
+    fired: usize,
+}
+
+impl PerturbationSchedule {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a perturbation at τ
+    pub fn at(mut self, tau: f64, perturbation: Perturbation) -> Self {
+        let index = self.entries.partition_point(|e| e.tau <= tau);
+        self.entries
+            .insert(index, ScheduledPerturbation { tau, perturbation });
+        self
+    }
+
+    pub fn entries(&self) -> &[ScheduledPerturbation] {
+        &self.entries
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Entries that have not fired yet
+    pub fn pending(&self) -> &[ScheduledPerturbation] {
+        &self.entries[self.fired..]
+    }
+
+    /// Take the entries due at epoch τ, marking them fired
+    pub fn take_due(&mut self, tau: f64) -> Vec<Perturbation> {
+        let due = self.pending().iter().take_while(|e| e.tau <= tau).count();
+        let start = self.fired;
+        self.fired += due;
+        self.entries[start..self.fired]
+            .iter()
+            .map(|e| e.perturbation.clone())
+            .collect()
+    }
+
+    /// Make every entry pending again
+    pub fn rewind(&mut self) {
+        self.fired = 0;
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        let mut schedule: Self = serde_json::from_str(json)?;
+        schedule.entries.sort_by(|a, b| a.tau.total_cmp(&b.tau));
+        schedule.fired = schedule.fired.min(schedule.entries.len());
+        Ok(schedule)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule() -> PerturbationSchedule {
+        PerturbationSchedule::new()
+            .at(0.5, Perturbation::FlipPolarity)
+            .at(0.2, Perturbation::SetGamma { value: 0.05 })
+            .at(
+                0.5,
+                Perturbation::KillGene {
+                    gene: "aura".to_string(),
+                },
+            )
+            .at(
+                0.3,
+                Perturbation::InjectMeshEdge {
+                    from: 1,
+                    to: 4,
+                    weight: 2.5,
+                },
+            )
+    }
+
+    #[test]
+    fn test_perturbations_fire_once_at_their_epoch() {
+        let mut runtime = DualRuntime::new().with_perturbations(schedule());
+        runtime.run(35, 0.01);
+        assert_eq!(runtime.perturbations.pending().len(), 2);
+        assert_eq!(runtime.mesh_weights.weights[4 * 7 + 1], 2.5);
+        assert_eq!(runtime.state.rho, 1.0);
+
+        runtime.run(20, 0.01);
+        assert!(runtime.perturbations.pending().is_empty());
+        assert_eq!(runtime.state.rho, -1.0);
+        assert!(runtime.organism.genes.iter().all(|g| g.id != "aura"));
+    }
+
+    #[test]
+    fn test_injected_perturbation_is_inverted() {
+        let config = crate::RuntimeConfig::new().with_inversion_steps(10);
+        let mut baseline = DualRuntime::new().with_config(config);
+        baseline.run(5, 0.01);
+        let mut runtime = baseline.clone();
+
+        assert!(runtime.perturb(Perturbation::DecoherenceSpike { amount: 0.5 }));
+        assert!(runtime.perturb(Perturbation::FlipPolarity));
+        assert!(runtime.state.gamma > baseline.state.gamma + 0.4);
+        assert_eq!(runtime.inversions.len(), 2);
+
+        runtime.run(9, 0.01);
+        baseline.run(9, 0.01);
+        assert!(runtime.state.gamma > baseline.state.gamma);
+        assert_eq!(runtime.state.rho, -baseline.state.rho);
+
+        runtime.run(1, 0.01);
+        baseline.run(1, 0.01);
+        assert!(runtime.inversions.is_empty());
+        assert!((runtime.state.gamma / baseline.state.gamma - 1.0).abs() < 1e-9);
+        assert_eq!(runtime.state.rho, baseline.state.rho);
+
+        assert!(!runtime.perturb(Perturbation::KillGene {
+            gene: "missing".to_string(),
+        }));
+        assert!(runtime.inversions.is_empty());
+    }
+
+    #[test]
+    fn test_zeroed_gamma_is_restored_finite() {
+        let config = crate::RuntimeConfig::new().with_inversion_steps(10);
+        let mut baseline = DualRuntime::new().with_config(config);
+        baseline.run(5, 0.01);
+        let mut runtime = baseline.clone();
+
+        assert!(runtime.perturb(Perturbation::SetGamma { value: 0.0 }));
+        runtime.run(10, 0.01);
+        baseline.run(10, 0.01);
+        assert!(runtime.inversions.is_empty());
+        assert!(runtime.state.gamma.is_finite() && runtime.state.xi.is_finite());
+        // Restored linearly, so only close to the geometric decay
+        assert!((runtime.state.gamma / baseline.state.gamma - 1.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_schedule_roundtrip_keeps_cursor() {
+        let mut schedule = schedule();
+        assert_eq!(
+            schedule.take_due(0.25),
+            vec![Perturbation::SetGamma { value: 0.05 }]
+        );
+        let json = schedule.to_json().unwrap();
+        assert!(json.contains(r#""action": "inject_mesh_edge""#));
+        let back = PerturbationSchedule::from_json(&json).unwrap();
+        assert_eq!(back, schedule);
+        assert_eq!(back.pending().len(), 3);
+    }
+}
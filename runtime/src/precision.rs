@@ -0,0 +1,315 @@
+//! Reduced Precision
+//!
+//! `Real` abstracts over f32 and f64 so the memory-bound parts of large
+//! meshes — gene states (`GeneArena<f32>`), mesh weights
+//! (`CompactMeshWeights<f32>`) and projectors (`Amplitude` for f32) — can be
+//! stored and computed in f32, halving their footprint. `CompactState<T>`
+//! is the CRSM7 state vector in `T` and holds the one definition of the
+//! evolution law: `CRSM7State::evolve_with` and the built-in `Operators`
+//! run it as `CompactState<f64>`. (The `wgpu` shader is a WGSL port of it,
+//! checked against the scalar path by its test.)
+//!
+//! f32 carries about 7 significant digits. Φ grows by increments of
+//! c_Φ·Λ·dt that are small next to Φ, so each is rounded to the f32
+//! spacing at Φ's magnitude and the error accumulates; Ξ = ΛΦ/Γ inherits
+//! it until Γ reaches εΓ. Over 10⁴ steps (dt = 0.01) of the standard
+//! organism the divergence from f64 is about 2.5·10⁻⁴ relative in Λ, Φ
+//! and Ξ (the tests bound it by 10⁻³); runs that depend on exact seal
+//! timing should stay in f64. The `lite` profile's `f32_state`
+//! rounds the runtime's own state to f32 after each step instead.
+
+use crate::config::{PhiSaturation, RuntimeConfig};
+use crate::manifold::{CRSM7State, EMERGENCE_MAX, GAMMA_TOLERANCE};
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub};
+
+/// Floating-point type a compact state is stored in
+pub trait Real:
+    Copy
+    + Debug
+    + Default
+    + PartialOrd
+    + Send
+    + Sync
+    + 'static
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + MulAssign
+{
+    const ONE: Self;
+    /// Whether Ξ is capped at `EMERGENCE_MAX` once Γ reaches εΓ, to hide
+    /// the precision lost dividing by a Γ that small
+    const CAPS_EMERGENCE: bool = true;
+
+    fn from_f64(value: f64) -> Self;
+    fn to_f64(self) -> f64;
+    fn exp(self) -> Self;
+    fn sin(self) -> Self;
+    fn to_radians(self) -> Self;
+    fn min(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+}
+
+macro_rules! impl_real {
+    ($t:ty) => {
+        impl Real for $t {
+            const ONE: Self = 1.0;
+
+            #[inline]
+            fn from_f64(value: f64) -> Self {
+                value as $t
+            }
+            #[inline]
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+            #[inline]
+            fn exp(self) -> Self {
+                <$t>::exp(self)
+            }
+            #[inline]
+            fn sin(self) -> Self {
+                <$t>::sin(self)
+            }
+            #[inline]
+            fn to_radians(self) -> Self {
+                <$t>::to_radians(self)
+            }
+            #[inline]
+            fn min(self, other: Self) -> Self {
+                <$t>::min(self, other)
+            }
+            #[inline]
+            fn max(self, other: Self) -> Self {
+                <$t>::max(self, other)
+            }
+        }
+    };
+}
+
+impl_real!(f32);
+impl_real!(f64);
+
+/// CRSM7 state vector stored in `T`
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CompactState<T: Real> {
+    pub lambda: T,
+    pub gamma: T,
+    pub phi: T,
+    pub xi: T,
+    pub rho: T,
+    pub theta: T,
+    pub tau: T,
+}
+
+impl<T: Real> CompactState<T> {
+    pub fn from_state(state: &CRSM7State) -> Self {
+        Self {
+            lambda: T::from_f64(state.lambda),
+            gamma: T::from_f64(state.gamma),
+            phi: T::from_f64(state.phi),
+            xi: T::from_f64(state.xi),
+            rho: T::from_f64(state.rho),
+            theta: T::from_f64(state.theta),
+            tau: T::from_f64(state.tau),
+        }
+    }
+
+    pub fn to_state(&self) -> CRSM7State {
+        CRSM7State {
+            lambda: self.lambda.to_f64(),
+            gamma: self.gamma.to_f64(),
+            phi: self.phi.to_f64(),
+            xi: self.xi.to_f64(),
+            rho: self.rho.to_f64(),
+            theta: self.theta.to_f64(),
+            tau: self.tau.to_f64(),
+        }
+    }
+
+    /// Ξ = ΛΦ/Γ, capped at `EMERGENCE_MAX` once Γ reaches εΓ (or 0 when
+    /// `T` does not cap)
+    pub fn compute_emergence(&mut self) {
+        let floor = if T::CAPS_EMERGENCE {
+            T::from_f64(GAMMA_TOLERANCE)
+        } else {
+            T::default()
+        };
+        if self.gamma > floor {
+            self.xi = (self.lambda * self.phi) / self.gamma;
+        } else {
+            self.xi = T::from_f64(EMERGENCE_MAX);
+        }
+    }
+
+    /// H_CRSM = DΛ − KΓ + sin θ
+    pub fn hamiltonian(&self) -> T {
+        self.lambda - self.gamma + self.theta.to_radians().sin()
+    }
+
+    /// Evolve by dt under the Hamiltonian value `h`: τ, then KΓ, DΛ and Φ
+    /// with constants from `config`, then Ξ
+    pub fn evolve_with(&mut self, dt: f64, h: T, config: &RuntimeConfig) {
+        self.advance_epoch(dt);
+        self.suppress_decoherence(dt, config.gamma_tolerance);
+        self.drive_coherence(h, dt, config.coherence_rate, config.lambda_cap);
+        self.accumulate_information(dt, config.information_rate, config.phi_saturation);
+        self.compute_emergence();
+    }
+
+    /// τ += dt
+    pub fn advance_epoch(&mut self, dt: f64) {
+        self.tau += T::from_f64(dt);
+    }
+
+    /// KΓ: Γ decays as e^(−dt), floored at `floor`
+    pub fn suppress_decoherence(&mut self, dt: f64, floor: f64) {
+        self.gamma *= (-T::from_f64(dt)).exp();
+        self.gamma = self.gamma.max(T::from_f64(floor));
+    }
+
+    /// DΛ: Λ += rate · h · dt, capped at `cap`
+    pub fn drive_coherence(&mut self, h: T, dt: f64, rate: f64, cap: f64) {
+        self.lambda += h * T::from_f64(dt) * T::from_f64(rate);
+        self.lambda = self.lambda.min(T::from_f64(cap));
+    }
+
+    /// Φ += dΦ/dτ · dt, with growth rate · Λ under `saturation`
+    pub fn accumulate_information(&mut self, dt: f64, rate: f64, saturation: PhiSaturation) {
+        let growth = T::from_f64(rate) * self.lambda;
+        self.phi += saturation.rate(growth, self.phi) * T::from_f64(dt);
+        self.phi = saturation.bound(self.phi);
+    }
+
+    /// Z3 mesh weight to `other`: the squared distance over all seven
+    /// components
+    pub fn mesh_weight(&self, other: &Self) -> T {
+        let square = |a: T, b: T| (a - b) * (a - b);
+        square(self.lambda, other.lambda)
+            + square(self.gamma, other.gamma)
+            + square(self.phi, other.phi)
+            + square(self.xi, other.xi)
+            + square(self.rho, other.rho)
+            + square(self.theta, other.theta)
+            + square(self.tau, other.tau)
+    }
+}
+
+/// Z3 mesh weights stored and computed in `T`; the runtime keeps its own
+/// in f64 (`Z3MeshWeights`), and f32 halves the footprint of a large mesh
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CompactMeshWeights<T: Real> {
+    pub weights: Vec<T>,
+}
+
+impl<T: Real> CompactMeshWeights<T> {
+    pub fn new() -> Self {
+        Self {
+            weights: Vec::new(),
+        }
+    }
+
+    /// w_ij = (ΔΛ)² + (ΔΓ)² + (ΔΦ)² + (ΔΞ)² + (Δρ)² + (Δθ)² + (Δτ)², in `T`
+    pub fn compute_weight(state_i: &CRSM7State, state_j: &CRSM7State) -> T {
+        CompactState::<T>::from_state(state_i).mesh_weight(&CompactState::from_state(state_j))
+    }
+
+    /// Append the weight from `reference` to each of `states`
+    pub fn extend_from<'a>(
+        &mut self,
+        reference: &CRSM7State,
+        states: impl IntoIterator<Item = &'a CRSM7State>,
+    ) {
+        let reference = CompactState::<T>::from_state(reference);
+        self.weights.extend(
+            states
+                .into_iter()
+                .map(|state| reference.mesh_weight(&CompactState::from_state(state))),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_runtime::Z3MeshWeights;
+    use crate::organism::{GeneArena, OrganismExecutor};
+    use crate::projectors::{Polarity, Projector};
+
+    /// Largest relative error of Λ, Φ and Ξ
+    fn divergence(a: &CRSM7State, b: &CRSM7State) -> f64 {
+        [(a.lambda, b.lambda), (a.phi, b.phi), (a.xi, b.xi)]
+            .iter()
+            .map(|(x, y)| ((x - y) / y).abs())
+            .fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn test_f64_compact_state_matches_crsm7() {
+        let config = RuntimeConfig::default().with_phi_saturation(PhiSaturation::Decay {
+            equilibrium: 8.0,
+            rate: 0.1,
+        });
+        let mut state = CRSM7State::new();
+        let mut compact = CompactState::<f64>::from_state(&state);
+        for _ in 0..1000 {
+            let h = state.hamiltonian();
+            state.evolve_with(0.01, h, &config);
+            compact.evolve_with(0.01, compact.hamiltonian(), &config);
+        }
+        assert!(compact.to_state().approx_eq(&state, 0.0));
+        let other = CRSM7State::with_values(0.5, 0.2, 3.0, -1.0, 10.0, 1.0);
+        assert_eq!(
+            compact.mesh_weight(&CompactState::from_state(&other)),
+            Z3MeshWeights::compute_weight(&state, &other)
+        );
+    }
+
+    #[test]
+    fn test_f32_divergence_over_long_runs() {
+        let config = RuntimeConfig::default();
+        let mut reference: GeneArena = OrganismExecutor::create_standard_organism().pack_genes();
+        let mut reduced: GeneArena<f32> = OrganismExecutor::create_standard_organism().pack_genes();
+        assert_eq!(reduced.state_bytes() * 2, reference.state_bytes());
+
+        let mut worst = 0.0f64;
+        for step in 1..=10_000 {
+            reference.evolve(0.01, &config);
+            reduced.evolve(0.01, &config);
+            if step % 1000 == 0 {
+                for i in 0..reference.len() {
+                    worst = worst.max(divergence(&reduced.state(i), &reference.state(i)));
+                }
+            }
+        }
+        assert!(worst < 1e-3, "f32 diverged by {:e}", worst);
+        assert!(worst > 0.0);
+
+        let weights = reduced.mesh_weights(0);
+        let exact = reference.mesh_weights(0);
+        for (w, e) in weights.iter().zip(&exact) {
+            assert!((*w as f64 - e).abs() <= 1e-4 * e.max(1.0));
+        }
+
+        let mut mesh = CompactMeshWeights::<f32>::new();
+        let mut exact = CompactMeshWeights::<f64>::new();
+        let states: Vec<CRSM7State> = (0..reference.len()).map(|i| reference.state(i)).collect();
+        mesh.extend_from(&states[0], &states);
+        exact.extend_from(&states[0], &states);
+        assert_eq!(
+            std::mem::size_of_val(&mesh.weights[..]) * 2,
+            std::mem::size_of_val(&exact.weights[..])
+        );
+        for (w, e) in mesh.weights.iter().zip(&exact.weights) {
+            assert!((*w as f64 - e).abs() <= 1e-4 * e.max(1.0));
+        }
+
+        let (plus, minus) = Polarity.bifurcate(&0.75f32);
+        assert_eq!((plus, minus), (0.0, 0.75));
+    }
+}
@@ -0,0 +1,99 @@
+//! Runtime Profiles
+//!
+//! Presets chosen when a `DualRuntime` is constructed:
+//! - Standard: no trajectory history, an event log, dense 7×7 mesh
+//!   weights and f64 state
+//! - Lite: no event log, mesh weights allocated per gene on demand, and
+//!   state rounded to f32 after every step so results match an f32 host.
+//!   It trims telemetry and mesh allocation only: the state is still
+//!   stored as f64 and neither preset records history (compact storage is
+//!   `CompactState<f32>` and `GeneArena<f32>`, see `precision`)
+
+use serde::{Deserialize, Serialize};
+
+/// Events kept by the standard profile's log
+pub const DEFAULT_EVENT_CAPACITY: usize = 1024;
+
+fn default_event_capacity() -> usize {
+    DEFAULT_EVENT_CAPACITY
+}
+
+/// Resource profile of a runtime
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RuntimeProfile {
+    pub name: String,
+    /// Observables kept in the trajectory history (0 disables recording)
+    pub history_capacity: usize,
+    /// Events kept in the event log (0 disables it)
+    #[serde(default = "default_event_capacity")]
+    pub event_capacity: usize,
+    /// Allocate mesh weights per gene instead of a dense 7×7 block
+    pub sparse_mesh: bool,
+    /// Round Ψ and the state vector to f32 precision after every step
+    /// (storage stays f64)
+    pub f32_state: bool,
+}
+
+impl Default for RuntimeProfile {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl RuntimeProfile {
+    pub fn standard() -> Self {
+        Self {
+            name: "standard".to_string(),
+            history_capacity: 0,
+            event_capacity: DEFAULT_EVENT_CAPACITY,
+            sparse_mesh: false,
+            f32_state: false,
+        }
+    }
+
+    pub fn lite() -> Self {
+        Self {
+            name: "lite".to_string(),
+            history_capacity: 0,
+            event_capacity: 0,
+            sparse_mesh: true,
+            f32_state: true,
+        }
+    }
+
+    /// Preset by name
+    pub fn named(name: &str) -> Option<Self> {
+        match name {
+            "standard" => Some(Self::standard()),
+            "lite" => Some(Self::lite()),
+            _ => None,
+        }
+    }
+
+    pub fn records_history(&self) -> bool {
+        self.history_capacity > 0
+    }
+}
+
+/// Round a value to the nearest f32
+#[inline]
+pub fn to_f32_precision(value: f64) -> f64 {
+    value as f32 as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presets() {
+        let lite = RuntimeProfile::named("lite").unwrap();
+        assert!(!lite.records_history());
+        assert!(lite.sparse_mesh && lite.f32_state);
+        assert_eq!(lite.event_capacity, 0);
+        assert!(!RuntimeProfile::standard().records_history());
+        assert_eq!(RuntimeProfile::default(), RuntimeProfile::standard());
+        assert!(RuntimeProfile::named("huge").is_none());
+        assert_eq!(to_f32_precision(0.1), 0.1f32 as f64);
+    }
+}
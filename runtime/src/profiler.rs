@@ -0,0 +1,219 @@
+//! Step Profiler
+//!
+//! Opt-in wall-clock timing of the phases of `DualRuntime::step`:
+//! Hamiltonian evaluation (including every evaluation made by the
+//! integrator), state and Ψ evolution, mesh weight updates and collapse
+//! checks; bookkeeping such as events, perturbations, history and observers
+//! is counted as "other". A runtime without a profiler never reads the
+//! clock. Timing uses `std::time::Instant`, so the profiler cannot be
+//! enabled on wasm32-unknown-unknown.
+
+use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// A timed part of a step
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StepPhase {
+    Hamiltonian,
+    Evolution,
+    MeshWeights,
+    Collapse,
+    Other,
+}
+
+impl StepPhase {
+    pub const ALL: [StepPhase; 5] = [
+        StepPhase::Hamiltonian,
+        StepPhase::Evolution,
+        StepPhase::MeshWeights,
+        StepPhase::Collapse,
+        StepPhase::Other,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            StepPhase::Hamiltonian => "hamiltonian",
+            StepPhase::Evolution => "evolution",
+            StepPhase::MeshWeights => "mesh_weights",
+            StepPhase::Collapse => "collapse",
+            StepPhase::Other => "other",
+        }
+    }
+}
+
+/// Times of one step, by phase; inert unless enabled
+pub(crate) struct PhaseTimer {
+    enabled: bool,
+    mark: Cell<Option<Instant>>,
+    /// Time attributed by `timed` since the last mark
+    nested: Cell<Duration>,
+    times: [Cell<Duration>; 5],
+}
+
+impl PhaseTimer {
+    pub(crate) fn new(enabled: bool) -> Self {
+        Self {
+            enabled,
+            mark: Cell::new(enabled.then(Instant::now)),
+            nested: Cell::new(Duration::ZERO),
+            times: Default::default(),
+        }
+    }
+
+    fn add(&self, phase: StepPhase, elapsed: Duration) {
+        let time = &self.times[phase as usize];
+        time.set(time.get() + elapsed);
+    }
+
+    /// Attribute the time since the previous lap to `phase`, less what
+    /// `timed` already attributed elsewhere
+    pub(crate) fn lap(&self, phase: StepPhase) {
+        if let Some(mark) = self.mark.get() {
+            let now = Instant::now();
+            self.add(phase, (now - mark).saturating_sub(self.nested.take()));
+            self.mark.set(Some(now));
+        }
+    }
+
+    /// Run `f`, attributing its time to `phase`
+    pub(crate) fn timed<T>(&self, phase: StepPhase, f: impl FnOnce() -> T) -> T {
+        if !self.enabled {
+            return f();
+        }
+        let start = Instant::now();
+        let value = f();
+        let elapsed = start.elapsed();
+        self.add(phase, elapsed);
+        self.nested.set(self.nested.get() + elapsed);
+        value
+    }
+}
+
+/// Accumulated phase times across steps
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct StepProfiler {
+    pub steps: u64,
+    totals: [Duration; 5],
+}
+
+impl StepProfiler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn record(&mut self, timer: &PhaseTimer) {
+        self.steps += 1;
+        for (total, time) in self.totals.iter_mut().zip(&timer.times) {
+            *total += time.get();
+        }
+    }
+
+    pub fn total(&self, phase: StepPhase) -> Duration {
+        self.totals[phase as usize]
+    }
+
+    /// Phase totals, hottest first
+    pub fn report(&self) -> ProfileReport {
+        let total: Duration = self.totals.iter().sum();
+        let mut phases: Vec<PhaseReport> = StepPhase::ALL
+            .iter()
+            .map(|&phase| {
+                let time = self.total(phase);
+                PhaseReport {
+                    phase,
+                    total: time,
+                    mean: if self.steps == 0 {
+                        Duration::ZERO
+                    } else {
+                        time.div_f64(self.steps as f64)
+                    },
+                    share: if total.is_zero() {
+                        0.0
+                    } else {
+                        time.as_secs_f64() / total.as_secs_f64()
+                    },
+                }
+            })
+            .collect();
+        phases.sort_by_key(|p| std::cmp::Reverse(p.total));
+        ProfileReport {
+            steps: self.steps,
+            total,
+            phases,
+        }
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Time spent in one phase
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PhaseReport {
+    pub phase: StepPhase,
+    pub total: Duration,
+    /// Per step
+    pub mean: Duration,
+    /// Fraction of the profiled step time
+    pub share: f64,
+}
+
+/// Summary of a profiled run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProfileReport {
+    pub steps: u64,
+    pub total: Duration,
+    /// Hottest phase first
+    pub phases: Vec<PhaseReport>,
+}
+
+impl ProfileReport {
+    pub fn hottest(&self) -> Option<StepPhase> {
+        self.phases.first().map(|p| p.phase)
+    }
+}
+
+impl fmt::Display for ProfileReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[PROFILE] {} steps in {:?}", self.steps, self.total)?;
+        for p in &self.phases {
+            write!(
+                f,
+                "\n  {:<13} {:>6.1}%  {:?} ({:?}/step)",
+                p.phase.name(),
+                100.0 * p.share,
+                p.total,
+                p.mean
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_runtime::DualRuntime;
+
+    #[test]
+    fn test_profiled_steps() {
+        let mut runtime = DualRuntime::new().with_profiler();
+        runtime.run(50, 0.01);
+        let profiler = runtime.profiler.as_ref().unwrap();
+        assert_eq!(profiler.steps, 50);
+        assert!(!profiler.total(StepPhase::Hamiltonian).is_zero());
+
+        let report = profiler.report();
+        assert_eq!(report.phases.len(), StepPhase::ALL.len());
+        assert!(report.phases.windows(2).all(|w| w[0].total >= w[1].total));
+        let shares: f64 = report.phases.iter().map(|p| p.share).sum();
+        assert!((shares - 1.0).abs() < 1e-9);
+        assert!(report.to_string().starts_with("[PROFILE] 50 steps"));
+
+        assert!(DualRuntime::new().profiler.is_none());
+    }
+}
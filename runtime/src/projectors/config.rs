@@ -0,0 +1,261 @@
+//! Duality Configuration
+//!
+//! Serializable description of the duality operators in use: the J
+//! involution, the tolerance its checks run at, and the last verification
+//! result. Experiments exchange these as JSON to share exact operator
+//! definitions, and checkpoints carry one so that a runtime configured with
+//! a different J refuses to resume them.
+//!
+//! `DualRuntime` projects Ψ through its configuration: J acts on the real
+//! parts of Ψ's modes. A matrix J of dimension n acts on the first n modes
+//! and leaves any further modes unchanged; a Ψ with fewer modes is padded
+//! with zeros.
+
+use serde::{Deserialize, Serialize};
+
+/// Definition of the J involution
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum InvolutionSpec {
+    /// JΨ = −Ψ
+    Negation,
+    /// JΨ = MΨ for a square matrix M
+    Matrix { rows: Vec<Vec<f64>> },
+}
+
+impl InvolutionSpec {
+    /// Apply J to a state vector
+    pub fn apply(&self, psi: &[f64]) -> Vec<f64> {
+        match self {
+            InvolutionSpec::Negation => psi.iter().map(|x| -x).collect(),
+            InvolutionSpec::Matrix { rows } => (0..psi.len())
+                .map(|i| match rows.get(i) {
+                    Some(row) => row.iter().zip(psi).map(|(m, x)| m * x).sum(),
+                    None => psi[i],
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Outcome of checking J² = I and the projector identities
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DualityVerification {
+    /// max |J²Ψ − Ψ| over the basis
+    pub j_squared_error: f64,
+    /// max |(Π⁺ + Π⁻)Ψ − Ψ| over the basis
+    pub completeness_error: f64,
+    /// max |Π±²Ψ − Π±Ψ| over the basis
+    #[serde(default)]
+    pub idempotence_error: f64,
+    /// max |Π⁺Π⁻Ψ| over the basis
+    #[serde(default)]
+    pub orthogonality_error: f64,
+    pub passed: bool,
+}
+
+/// Full duality configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DualityConfig {
+    pub involution: InvolutionSpec,
+    /// Largest error accepted by verification
+    pub tolerance: f64,
+    /// Result of the last `verify`
+    #[serde(default)]
+    pub verification: Option<DualityVerification>,
+}
+
+impl Default for DualityConfig {
+    fn default() -> Self {
+        Self::new(InvolutionSpec::Negation)
+    }
+}
+
+impl DualityConfig {
+    pub fn new(involution: InvolutionSpec) -> Self {
+        Self {
+            involution,
+            tolerance: 1e-10,
+            verification: None,
+        }
+    }
+
+    /// Check the involution and projector identities on the standard basis
+    pub fn verify(&self) -> DualityVerification {
+        let dimension = match &self.involution {
+            InvolutionSpec::Negation => 1,
+            InvolutionSpec::Matrix { rows } => rows.len(),
+        };
+        if let InvolutionSpec::Matrix { rows } = &self.involution {
+            if rows.iter().any(|row| row.len() != dimension) {
+                return DualityVerification {
+                    j_squared_error: f64::INFINITY,
+                    completeness_error: f64::INFINITY,
+                    idempotence_error: f64::INFINITY,
+                    orthogonality_error: f64::INFINITY,
+                    passed: false,
+                };
+            }
+        }
+
+        let max_error = |a: &[f64], b: &[f64]| {
+            a.iter()
+                .zip(b)
+                .fold(0.0f64, |error, (x, y)| error.max((x - y).abs()))
+        };
+        let zero = vec![0.0; dimension];
+        let mut verification = DualityVerification {
+            j_squared_error: 0.0,
+            completeness_error: 0.0,
+            idempotence_error: 0.0,
+            orthogonality_error: 0.0,
+            passed: false,
+        };
+        for i in 0..dimension {
+            let mut basis = vec![0.0; dimension];
+            basis[i] = 1.0;
+            let jj = self.involution.apply(&self.involution.apply(&basis));
+            let (plus, minus) = (self.project(&basis, 1.0), self.project(&basis, -1.0));
+            let sum: Vec<f64> = plus.iter().zip(&minus).map(|(p, m)| p + m).collect();
+            let v = &mut verification;
+            v.j_squared_error = v.j_squared_error.max(max_error(&jj, &basis));
+            v.completeness_error = v.completeness_error.max(max_error(&sum, &basis));
+            v.idempotence_error = v
+                .idempotence_error
+                .max(max_error(&self.project(&plus, 1.0), &plus))
+                .max(max_error(&self.project(&minus, -1.0), &minus));
+            v.orthogonality_error = v
+                .orthogonality_error
+                .max(max_error(&self.project(&minus, 1.0), &zero))
+                .max(max_error(&self.project(&plus, -1.0), &zero));
+        }
+
+        verification.passed = [
+            verification.j_squared_error,
+            verification.completeness_error,
+            verification.idempotence_error,
+            verification.orthogonality_error,
+        ]
+        .iter()
+        .all(|error| *error <= self.tolerance);
+        verification
+    }
+
+    /// Π⁺Ψ = (Ψ + JΨ)/2
+    pub fn pi_plus(&self, psi: &[f64]) -> Vec<f64> {
+        #[cfg(feature = "strict-invariants")]
+        super::strict::assert_projector_laws(
+            |x: &Vec<f64>| self.involution.apply(x),
+            &psi.to_vec(),
+        );
+        self.project(psi, 1.0)
+    }
+
+    /// Π⁻Ψ = (Ψ − JΨ)/2
+    pub fn pi_minus(&self, psi: &[f64]) -> Vec<f64> {
+        #[cfg(feature = "strict-invariants")]
+        super::strict::assert_projector_laws(
+            |x: &Vec<f64>| self.involution.apply(x),
+            &psi.to_vec(),
+        );
+        self.project(psi, -1.0)
+    }
+
+    /// (Ψ ± JΨ)/2 without the `strict-invariants` assertions
+    fn project(&self, psi: &[f64], sign: f64) -> Vec<f64> {
+        let j = self.involution.apply(psi);
+        psi.iter()
+            .zip(j)
+            .map(|(x, jx)| 0.5 * (x + sign * jx))
+            .collect()
+    }
+
+    /// B(Ψ) = (Π⁺Ψ, Π⁻Ψ)
+    pub fn bifurcate(&self, psi: &[f64]) -> (Vec<f64>, Vec<f64>) {
+        (self.pi_plus(psi), self.pi_minus(psi))
+    }
+
+    /// Copy with the verification result filled in
+    pub fn verified(mut self) -> Self {
+        self.verification = Some(self.verify());
+        self
+    }
+
+    /// Stable hash of the operator definition (verification excluded)
+    pub fn fingerprint(&self) -> String {
+        let definition = serde_json::json!({
+            "involution": self.involution,
+            "tolerance": self.tolerance,
+        });
+        // FNV-1a over the canonical JSON
+        let hash = definition
+            .to_string()
+            .bytes()
+            .fold(0xcbf2_9ce4_8422_2325u64, |h, b| {
+                (h ^ b as u64).wrapping_mul(0x0100_0000_01b3)
+            });
+        format!("{:016x}", hash)
+    }
+
+    /// Whether two configurations define the same operators
+    pub fn matches(&self, other: &DualityConfig) -> bool {
+        self.involution == other.involution && self.tolerance == other.tolerance
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_import_roundtrip() {
+        let config = DualityConfig::new(InvolutionSpec::Matrix {
+            rows: vec![vec![0.0, 1.0], vec![1.0, 0.0]],
+        })
+        .verified();
+        assert!(config.verification.as_ref().unwrap().passed);
+
+        let imported = DualityConfig::from_json(&config.to_json().unwrap()).unwrap();
+        assert_eq!(imported, config);
+        assert_eq!(imported.fingerprint(), config.fingerprint());
+        assert_ne!(config.fingerprint(), DualityConfig::default().fingerprint());
+    }
+
+    #[test]
+    fn test_non_involution_fails_verification() {
+        let config = DualityConfig::new(InvolutionSpec::Matrix {
+            rows: vec![vec![2.0, 0.0], vec![0.0, 1.0]],
+        });
+        let verification = config.verify();
+        assert!(!verification.passed);
+        assert_eq!(verification.j_squared_error, 3.0);
+        // Π⁺ = diag(1.5, 1) is not idempotent
+        assert_eq!(verification.idempotence_error, 0.75);
+        assert!(verification.orthogonality_error > 0.0);
+        assert_eq!(verification.completeness_error, 0.0);
+
+        let ragged = DualityConfig::new(InvolutionSpec::Matrix {
+            rows: vec![vec![1.0], vec![0.0, 1.0]],
+        });
+        assert!(!ragged.verify().passed);
+        assert!(DualityConfig::default().verify().passed);
+    }
+
+    #[test]
+    #[cfg(feature = "strict-invariants")]
+    #[cfg_attr(debug_assertions, should_panic(expected = "J² ≠ I"))]
+    fn test_strict_config_projection_asserts() {
+        let config = DualityConfig::new(InvolutionSpec::Matrix {
+            rows: vec![vec![2.0, 0.0], vec![0.0, 1.0]],
+        });
+        config.bifurcate(&[1.0, 0.0]);
+    }
+}
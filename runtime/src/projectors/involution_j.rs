@@ -36,6 +36,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn test_j_squared_various_values() {
         for psi in [-5.0, -1.0, 0.0, 1.0, 3.14159, 100.0] {
             assert!(verify_j_squared(psi));
@@ -0,0 +1,190 @@
+//! Linear Involutions
+//!
+//! J given by an n×n matrix with J² = I. Unlike the sign flip, whose Π⁺
+//! is identically zero, a general involution splits the state space into
+//! two non-trivial eigenspaces: Π± = (I ± J)/2 project onto the +1 and −1
+//! eigenvectors of J.
+
+use super::config::InvolutionSpec;
+use super::projector::{Amplitude, Matrix, Projector};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Tolerance on J² = I used by `LinearInvolution::new`
+pub const INVOLUTION_TOLERANCE: f64 = 1e-10;
+
+/// Reasons a matrix cannot serve as J
+#[derive(Debug, Clone, PartialEq)]
+pub enum InvolutionError {
+    /// Rows of unequal length or not one per column
+    NotSquare,
+    /// max |J² − I| above the tolerance
+    NotInvolution { error: f64 },
+}
+
+impl fmt::Display for InvolutionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvolutionError::NotSquare => write!(f, "involution matrix is not square"),
+            InvolutionError::NotInvolution { error } => {
+                write!(f, "J² differs from I by {:e}", error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InvolutionError {}
+
+/// A verified matrix involution
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "Matrix", into = "Matrix")]
+pub struct LinearInvolution {
+    j: Matrix,
+}
+
+impl TryFrom<Matrix> for LinearInvolution {
+    type Error = InvolutionError;
+
+    fn try_from(j: Matrix) -> Result<Self, Self::Error> {
+        Self::new(j)
+    }
+}
+
+impl From<LinearInvolution> for Matrix {
+    fn from(involution: LinearInvolution) -> Self {
+        involution.j
+    }
+}
+
+impl From<LinearInvolution> for InvolutionSpec {
+    fn from(involution: LinearInvolution) -> Self {
+        InvolutionSpec::Matrix {
+            rows: involution.j.rows().to_vec(),
+        }
+    }
+}
+
+impl LinearInvolution {
+    /// Involution from J, verified to `INVOLUTION_TOLERANCE`
+    pub fn new(j: Matrix) -> Result<Self, InvolutionError> {
+        Self::with_tolerance(j, INVOLUTION_TOLERANCE)
+    }
+
+    /// Involution from J, verified to `tolerance`
+    pub fn with_tolerance(j: Matrix, tolerance: f64) -> Result<Self, InvolutionError> {
+        let n = j.dimension();
+        if j.rows().iter().any(|row| row.len() != n) {
+            return Err(InvolutionError::NotSquare);
+        }
+        let error = j_squared_error(&j);
+        if error > tolerance {
+            return Err(InvolutionError::NotInvolution { error });
+        }
+        Ok(Self { j })
+    }
+
+    /// Involution from the rows of J
+    pub fn from_rows(rows: Vec<Vec<f64>>) -> Result<Self, InvolutionError> {
+        Self::new(Matrix::new(rows).ok_or(InvolutionError::NotSquare)?)
+    }
+
+    /// JΨ = −Ψ in n dimensions
+    pub fn negation(n: usize) -> Self {
+        Self {
+            j: Matrix::identity(n).scale(-1.0),
+        }
+    }
+
+    /// J reverses the order of the n coordinates
+    pub fn exchange(n: usize) -> Self {
+        Self {
+            j: Matrix::new(
+                (0..n)
+                    .map(|i| {
+                        (0..n)
+                            .map(|k| if i + k + 1 == n { 1.0 } else { 0.0 })
+                            .collect()
+                    })
+                    .collect(),
+            )
+            .expect("exchange matrix is square"),
+        }
+    }
+
+    pub fn dimension(&self) -> usize {
+        self.j.dimension()
+    }
+
+    pub fn matrix(&self) -> &Matrix {
+        &self.j
+    }
+
+    /// Π⁺ = (I + J)/2
+    pub fn pi_plus_operator(&self) -> Matrix {
+        Matrix::identity(self.dimension()).add(&self.j).scale(0.5)
+    }
+
+    /// Π⁻ = (I − J)/2
+    pub fn pi_minus_operator(&self) -> Matrix {
+        Matrix::identity(self.dimension())
+            .add(&self.j.scale(-1.0))
+            .scale(0.5)
+    }
+}
+
+/// max |J² − I| (infinite for a non-square J)
+fn j_squared_error(j: &Matrix) -> f64 {
+    j.multiply(j).distance(&Matrix::identity(j.dimension()))
+}
+
+impl Projector<Vec<f64>> for LinearInvolution {
+    fn involution(&self, psi: &Vec<f64>) -> Vec<f64> {
+        self.j.apply(psi)
+    }
+}
+
+/// On operators J acts by left multiplication
+impl Projector<Matrix> for LinearInvolution {
+    fn involution(&self, psi: &Matrix) -> Matrix {
+        self.j.multiply(psi)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exchange_splits_symmetric_and_antisymmetric() {
+        let j = LinearInvolution::exchange(2);
+        let psi = vec![3.0, 1.0];
+        let (plus, minus) = j.bifurcate(&psi);
+        assert_eq!(plus, vec![2.0, 2.0]);
+        assert_eq!(minus, vec![1.0, -1.0]);
+        assert!(j.verify(&psi, 1e-12));
+
+        // Π± are complementary orthogonal idempotents
+        let (p, m) = (j.pi_plus_operator(), j.pi_minus_operator());
+        let zero = Matrix::identity(2).scale(0.0);
+        assert_eq!(p.multiply(&p), p);
+        assert_eq!(p.multiply(&m), zero);
+        assert_eq!(p.add(&m), Matrix::identity(2));
+        assert_eq!(j.pi_plus(&Matrix::identity(2)), p);
+    }
+
+    #[test]
+    fn test_rejects_non_involutions() {
+        assert_eq!(
+            LinearInvolution::from_rows(vec![vec![2.0, 0.0], vec![0.0, 1.0]]),
+            Err(InvolutionError::NotInvolution { error: 3.0 })
+        );
+        assert_eq!(
+            LinearInvolution::from_rows(vec![vec![1.0, 0.0]]),
+            Err(InvolutionError::NotSquare)
+        );
+
+        let json = serde_json::to_string(&LinearInvolution::negation(2)).unwrap();
+        assert!(serde_json::from_str::<LinearInvolution>(&json).is_ok());
+        assert!(serde_json::from_str::<LinearInvolution>(r#"{"rows":[[0.5]]}"#).is_err());
+    }
+}
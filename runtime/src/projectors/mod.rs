@@ -4,14 +4,29 @@
 //! - Π⁺ = (I + J) / 2
 //! - Π⁻ = (I - J) / 2
 //! - J: polarity involution (J² = I, JΨ = -Ψ)
+//! - Config: serializable duality configuration
+//! - Linear: matrix involutions with verified J² = I
+//! - Projector: the same algebra over Complex and matrix values
+//! - Strict: debug assertions of the projector laws (`strict-invariants`
+//!   feature)
 
+pub mod config;
 pub mod involution_j;
+pub mod linear;
 pub mod pi_minus;
 pub mod pi_plus;
+pub mod projector;
+#[cfg(feature = "strict-invariants")]
+pub mod strict;
 
+pub use config::{DualityConfig, DualityVerification, InvolutionSpec};
 pub use involution_j::{involution_j, verify_j_squared};
+pub use linear::{InvolutionError, LinearInvolution, INVOLUTION_TOLERANCE};
 pub use pi_minus::{pi_minus, pi_minus_with_j};
 pub use pi_plus::{pi_plus, pi_plus_with_j};
+pub use projector::{Amplitude, Matrix, Polarity, Projector};
+#[cfg(feature = "strict-invariants")]
+pub use strict::{assert_projector_laws, STRICT_TOLERANCE};
 
 /// Perform bifurcation: B(Ψ) = (Π⁺Ψ, Π⁻Ψ)
 pub fn bifurcate(psi: f64) -> (f64, f64) {
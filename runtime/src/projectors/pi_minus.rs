@@ -12,6 +12,8 @@ use super::involution_j::involution_j;
 /// Π⁻(Ψ) = 0.5(Ψ - (-Ψ)) = Ψ
 #[inline]
 pub fn pi_minus(psi: f64) -> f64 {
+    #[cfg(feature = "strict-invariants")]
+    super::strict::assert_projector_laws(|x: &f64| involution_j(*x), &psi);
     0.5 * (psi - involution_j(psi))
 }
 
@@ -20,6 +22,8 @@ pub fn pi_minus_with_j<F>(psi: f64, j: F) -> f64
 where
     F: Fn(f64) -> f64,
 {
+    #[cfg(feature = "strict-invariants")]
+    super::strict::assert_projector_laws(|x: &f64| j(*x), &psi);
     0.5 * (psi - j(psi))
 }
 
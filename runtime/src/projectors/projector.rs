@@ -0,0 +1,254 @@
+//! Generic Projectors
+//!
+//! The free functions `pi_plus`, `pi_minus` and `involution_j` act on a
+//! scalar f64. `Projector<T>` carries the same algebra over any value that
+//! can be added and scaled (`Amplitude`): f64, f32, `Complex` amplitudes, real
+//! state vectors and small square matrices. An involution only defines J;
+//! Π± = (I ± J)/2 and the bifurcation follow from it.
+
+use crate::dual_runtime::Complex;
+use serde::{Deserialize, Serialize};
+
+/// Values the projectors can act on
+pub trait Amplitude: Clone {
+    /// a + b
+    fn add(&self, other: &Self) -> Self;
+    /// c·a
+    fn scale(&self, factor: f64) -> Self;
+    /// Largest componentwise |a − b|
+    fn distance(&self, other: &Self) -> f64;
+}
+
+impl Amplitude for f64 {
+    fn add(&self, other: &Self) -> Self {
+        self + other
+    }
+
+    fn scale(&self, factor: f64) -> Self {
+        self * factor
+    }
+
+    fn distance(&self, other: &Self) -> f64 {
+        (self - other).abs()
+    }
+}
+
+impl Amplitude for f32 {
+    fn add(&self, other: &Self) -> Self {
+        self + other
+    }
+
+    fn scale(&self, factor: f64) -> Self {
+        self * factor as f32
+    }
+
+    fn distance(&self, other: &Self) -> f64 {
+        (self - other).abs() as f64
+    }
+}
+
+impl Amplitude for Complex {
+    fn add(&self, other: &Self) -> Self {
+        Complex::add(self, other)
+    }
+
+    fn scale(&self, factor: f64) -> Self {
+        Complex::scale(self, factor)
+    }
+
+    fn distance(&self, other: &Self) -> f64 {
+        (self.re - other.re).abs().max((self.im - other.im).abs())
+    }
+}
+
+impl Amplitude for Vec<f64> {
+    fn add(&self, other: &Self) -> Self {
+        self.iter().zip(other).map(|(a, b)| a + b).collect()
+    }
+
+    fn scale(&self, factor: f64) -> Self {
+        self.iter().map(|a| a * factor).collect()
+    }
+
+    fn distance(&self, other: &Self) -> f64 {
+        if self.len() != other.len() {
+            return f64::INFINITY;
+        }
+        self.iter()
+            .zip(other)
+            .fold(0.0, |max: f64, (a, b)| max.max((a - b).abs()))
+    }
+}
+
+impl Amplitude for Matrix {
+    fn add(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a + b)
+    }
+
+    fn scale(&self, factor: f64) -> Self {
+        self.map(|a| a * factor)
+    }
+
+    fn distance(&self, other: &Self) -> f64 {
+        if self.dimension() != other.dimension() {
+            return f64::INFINITY;
+        }
+        self.zip_with(other, |a, b| (a - b).abs())
+            .rows
+            .iter()
+            .flatten()
+            .fold(0.0, |max: f64, x| max.max(*x))
+    }
+}
+
+/// Square matrix of reals, stored by rows
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Matrix {
+    rows: Vec<Vec<f64>>,
+}
+
+impl Matrix {
+    /// Matrix from rows; None unless every row has one entry per row
+    pub fn new(rows: Vec<Vec<f64>>) -> Option<Self> {
+        let n = rows.len();
+        rows.iter()
+            .all(|row| row.len() == n)
+            .then_some(Self { rows })
+    }
+
+    pub fn identity(n: usize) -> Self {
+        Self {
+            rows: (0..n)
+                .map(|i| (0..n).map(|j| if i == j { 1.0 } else { 0.0 }).collect())
+                .collect(),
+        }
+    }
+
+    pub fn dimension(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn rows(&self) -> &[Vec<f64>] {
+        &self.rows
+    }
+
+    pub fn get(&self, row: usize, column: usize) -> f64 {
+        self.rows[row][column]
+    }
+
+    /// Matrix product self·other
+    pub fn multiply(&self, other: &Matrix) -> Matrix {
+        let n = self.dimension();
+        Matrix {
+            rows: (0..n)
+                .map(|i| {
+                    (0..n)
+                        .map(|j| (0..n).map(|k| self.rows[i][k] * other.rows[k][j]).sum())
+                        .collect()
+                })
+                .collect(),
+        }
+    }
+
+    /// Matrix–vector product M·v
+    pub fn apply(&self, v: &[f64]) -> Vec<f64> {
+        self.rows
+            .iter()
+            .map(|row| row.iter().zip(v).map(|(m, x)| m * x).sum())
+            .collect()
+    }
+
+    fn map(&self, f: impl Fn(f64) -> f64) -> Matrix {
+        Matrix {
+            rows: self
+                .rows
+                .iter()
+                .map(|row| row.iter().map(|x| f(*x)).collect())
+                .collect(),
+        }
+    }
+
+    fn zip_with(&self, other: &Matrix, f: impl Fn(f64, f64) -> f64) -> Matrix {
+        Matrix {
+            rows: self
+                .rows
+                .iter()
+                .zip(&other.rows)
+                .map(|(a, b)| a.iter().zip(b).map(|(x, y)| f(*x, *y)).collect())
+                .collect(),
+        }
+    }
+}
+
+/// An involution J on values of type T, with the projectors it induces
+pub trait Projector<T: Amplitude> {
+    /// JΨ
+    fn involution(&self, psi: &T) -> T;
+
+    /// Π⁺Ψ = (Ψ + JΨ)/2
+    fn pi_plus(&self, psi: &T) -> T {
+        #[cfg(feature = "strict-invariants")]
+        super::strict::assert_projector_laws(|x| self.involution(x), psi);
+        psi.add(&self.involution(psi)).scale(0.5)
+    }
+
+    /// Π⁻Ψ = (Ψ − JΨ)/2
+    fn pi_minus(&self, psi: &T) -> T {
+        #[cfg(feature = "strict-invariants")]
+        super::strict::assert_projector_laws(|x| self.involution(x), psi);
+        psi.add(&self.involution(psi).scale(-1.0)).scale(0.5)
+    }
+
+    /// B(Ψ) = (Π⁺Ψ, Π⁻Ψ)
+    fn bifurcate(&self, psi: &T) -> (T, T) {
+        (self.pi_plus(psi), self.pi_minus(psi))
+    }
+
+    /// Check J²Ψ = Ψ and Π⁺Ψ + Π⁻Ψ = Ψ within `tolerance` (without the
+    /// `strict-invariants` assertions)
+    fn verify(&self, psi: &T, tolerance: f64) -> bool {
+        let j = self.involution(psi);
+        let plus = psi.add(&j).scale(0.5);
+        let minus = psi.add(&j.scale(-1.0)).scale(0.5);
+        self.involution(&j).distance(psi) < tolerance && plus.add(&minus).distance(psi) < tolerance
+    }
+}
+
+/// The polarity involution JΨ = −Ψ, for every amplitude type
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Polarity;
+
+impl<T: Amplitude> Projector<T> for Polarity {
+    fn involution(&self, psi: &T) -> T {
+        psi.scale(-1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projectors::{pi_minus, pi_plus};
+
+    #[test]
+    fn test_polarity_matches_scalar_projectors() {
+        for psi in [-3.0, 0.0, 2.5] {
+            assert_eq!(Polarity.pi_plus(&psi), pi_plus(psi));
+            assert_eq!(Polarity.pi_minus(&psi), pi_minus(psi));
+        }
+
+        let psi = Complex::new(0.6, -0.8);
+        let (plus, minus) = Polarity.bifurcate(&psi);
+        assert_eq!((plus.re, plus.im), (0.0, 0.0));
+        assert_eq!((minus.re, minus.im), (0.6, -0.8));
+        assert!(Polarity.verify(&psi, 1e-12));
+    }
+
+    #[test]
+    fn test_operator_valued_projection() {
+        let rho = Matrix::new(vec![vec![0.5, 0.25], vec![0.25, 0.5]]).unwrap();
+        assert!(Polarity.verify(&rho, 1e-12));
+        assert_eq!(Polarity.pi_minus(&rho), rho);
+        assert_eq!(rho.multiply(&Matrix::identity(2)), rho);
+        assert!(Matrix::new(vec![vec![1.0, 0.0]]).is_none());
+    }
+}
@@ -0,0 +1,58 @@
+//! Strict Projector Laws
+//!
+//! With the `strict-invariants` feature, every call of Π⁺, Π⁻ or a
+//! bifurcation (scalar, custom-J, `Projector` and `DualityConfig` forms)
+//! first asserts at its argument Ψ that
+//! - J²Ψ = Ψ (involution)
+//! - Π⁺Ψ + Π⁻Ψ = Ψ (completeness)
+//! - Π±Π±Ψ = Π±Ψ (idempotence)
+//! - Π⁺Π⁻Ψ = Π⁻Π⁺Ψ = 0 (orthogonality)
+//!
+//! The checks are `debug_assert!`s: they run in debug builds of the crate
+//! that enables the feature and compile away in release builds. A NaN
+//! anywhere fails them. `Projector::verify` and `DualityConfig::verify`
+//! report violations instead of asserting.
+
+use super::projector::Amplitude;
+
+/// Allowed error of each law, relative to max |Ψ| (absolute below 1)
+pub const STRICT_TOLERANCE: f64 = 1e-9;
+
+/// Assert the projector laws induced by `j` at `psi`
+pub fn assert_projector_laws<T: Amplitude>(j: impl Fn(&T) -> T, psi: &T) {
+    let zero = psi.scale(0.0);
+    let tolerance = STRICT_TOLERANCE * (1.0 + psi.distance(&zero));
+    let plus = |x: &T| x.add(&j(x)).scale(0.5);
+    let minus = |x: &T| x.add(&j(x).scale(-1.0)).scale(0.5);
+    let (p, m) = (plus(psi), minus(psi));
+
+    let error = j(&j(psi)).distance(psi);
+    debug_assert!(error <= tolerance, "J² ≠ I: |J²Ψ − Ψ| = {:e}", error);
+    let error = p.add(&m).distance(psi);
+    debug_assert!(error <= tolerance, "Π⁺ + Π⁻ ≠ I: error {:e}", error);
+    let error = plus(&p).distance(&p).max(minus(&m).distance(&m));
+    debug_assert!(error <= tolerance, "Π± not idempotent: error {:e}", error);
+    let error = plus(&m).distance(&zero).max(minus(&p).distance(&zero));
+    debug_assert!(error <= tolerance, "Π⁺Π⁻ ≠ 0: error {:e}", error);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::projectors::{pi_plus, pi_plus_with_j, LinearInvolution, Matrix, Projector};
+
+    #[test]
+    fn test_lawful_projectors_pass() {
+        let swap =
+            LinearInvolution::new(Matrix::new(vec![vec![0.0, 1.0], vec![1.0, 0.0]]).unwrap())
+                .unwrap();
+        let (plus, minus) = swap.bifurcate(&vec![3.0, 1.0]);
+        assert_eq!((plus, minus), (vec![2.0, 2.0], vec![1.0, -1.0]));
+        assert_eq!(pi_plus(1e12), 0.0);
+    }
+
+    #[test]
+    #[cfg_attr(debug_assertions, should_panic(expected = "J² ≠ I"))]
+    fn test_non_involution_asserts() {
+        pi_plus_with_j(2.0, |x| 2.0 * x);
+    }
+}
@@ -0,0 +1,158 @@
+//! Rewind Buffer
+//!
+//! Frames of the evolving runtime state taken before each of the last K
+//! steps, so `DualRuntime::rewind` can go back without re-running from
+//! scratch. A frame holds Ψ, the CRSM7 state, coupled manifolds, the seal flag, the mesh
+//! weights, the step count, the noise generator, the perturbation cursor
+//! and pending inversions. The organism is not captured: genes removed by
+//! a perturbation stay removed after a rewind.
+//!
+//! The buffer is off by default (capacity 0). Only the capacity is
+//! serialized; a restored runtime starts with an empty buffer.
+
+use crate::certificate::SovereigntyCertificate;
+use crate::coupling::CoupledManifolds;
+use crate::dual_runtime::{DualRuntime, Z3MeshWeights};
+use crate::manifold::CRSM7State;
+use crate::perturbation::{Inversion, PerturbationSchedule};
+use crate::rng::SeededRng;
+use crate::wave::WaveFunction;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Runtime state before one step
+#[derive(Debug, Clone)]
+pub struct RewindFrame {
+    pub psi: WaveFunction,
+    pub state: CRSM7State,
+    pub coupled: CoupledManifolds,
+    pub sealed: bool,
+    pub mesh_weights: Z3MeshWeights,
+    pub steps: u64,
+    pub rng: SeededRng,
+    pub perturbations: PerturbationSchedule,
+    pub inversions: Vec<Inversion>,
+    pub trajectory_hash: u64,
+    pub certificate: Option<SovereigntyCertificate>,
+}
+
+impl RewindFrame {
+    pub fn capture(runtime: &DualRuntime) -> Self {
+        Self {
+            psi: runtime.psi.clone(),
+            state: runtime.state.clone(),
+            coupled: runtime.coupled.clone(),
+            sealed: runtime.sealed,
+            mesh_weights: runtime.mesh_weights.clone(),
+            steps: runtime.steps,
+            rng: runtime.rng.clone(),
+            perturbations: runtime.perturbations.clone(),
+            inversions: runtime.inversions.clone(),
+            trajectory_hash: runtime.trajectory_hash,
+            certificate: runtime.certificate.clone(),
+        }
+    }
+
+    pub fn restore(self, runtime: &mut DualRuntime) {
+        runtime.psi = self.psi;
+        runtime.state = self.state;
+        runtime.coupled = self.coupled;
+        runtime.sealed = self.sealed;
+        runtime.mesh_weights = self.mesh_weights;
+        runtime.steps = self.steps;
+        runtime.rng = self.rng;
+        runtime.perturbations = self.perturbations;
+        runtime.inversions = self.inversions;
+        runtime.trajectory_hash = self.trajectory_hash;
+        runtime.certificate = self.certificate;
+    }
+}
+
+/// The last `capacity` frames, oldest first
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RewindBuffer {
+    capacity: usize,
+    #[serde(skip)]
+    frames: VecDeque<RewindFrame>,
+}
+
+impl RewindBuffer {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            frames: VecDeque::new(),
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Steps that can currently be rewound
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    pub fn push(&mut self, frame: RewindFrame) {
+        if self.capacity == 0 {
+            return;
+        }
+        while self.frames.len() >= self.capacity {
+            self.frames.pop_front();
+        }
+        self.frames.push_back(frame);
+    }
+
+    /// Remove the newest `steps` frames and return the oldest of them
+    pub fn pop(&mut self, steps: usize) -> Option<RewindFrame> {
+        if steps == 0 {
+            return None;
+        }
+        let keep = self.frames.len().checked_sub(steps)?;
+        self.frames.drain(keep..).next()
+    }
+
+    pub fn clear(&mut self) {
+        self.frames.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profile::RuntimeProfile;
+
+    #[test]
+    fn test_rewind_restores_earlier_state() {
+        let mut runtime = DualRuntime::with_profile(RuntimeProfile {
+            history_capacity: 16,
+            ..RuntimeProfile::standard()
+        })
+        .with_rewind_capacity(8);
+        runtime.run(5, 0.01);
+        let at_five = (runtime.state.clone(), runtime.steps);
+        runtime.run(3, 0.01);
+
+        assert_eq!(runtime.rewind(3), 3);
+        assert!(runtime.state.approx_eq(&at_five.0, 0.0));
+        assert_eq!(runtime.steps, at_five.1);
+        assert_eq!(runtime.history.back().unwrap().tau, at_five.0.tau);
+
+        // Re-running the rewound steps reproduces them exactly
+        runtime.run(3, 0.01);
+        let replayed = runtime.state.clone();
+        runtime.rewind(3);
+        runtime.run(3, 0.01);
+        assert!(runtime.state.approx_eq(&replayed, 0.0));
+
+        // Only the last 8 steps are kept
+        runtime.run(20, 0.01);
+        assert_eq!(runtime.rewind(100), 0);
+        assert_eq!(runtime.rewind(8), 8);
+        assert_eq!(runtime.rewind(1), 0);
+    }
+}
@@ -0,0 +1,416 @@
+//! Scripting Hooks
+//!
+//! Embedded Rhai scripts attached to runtime events, enabled with the
+//! `scripting` feature. A script defines any of:
+//!
+//! ```text
+//! fn on_step(obs) { ... }       // after every DualRuntime::step
+//! fn on_collapse(obs) { ... }   // when a collapse rule fires (obs.rule)
+//! ```
+//!
+//! Hooks only see a copy of the observables (Ψ, the seven CRSM coordinates,
+//! Ω_sov, sealed) and may return a map of mutations restricted to
+//! `MUTABLE_KEYS`; `seal: true` requests Ω∞.seal(), which still requires the
+//! sovereignty conditions. Each script runs under its own `ScriptBudget`, and
+//! Rhai provides no file, network or process access.
+
+use crate::dual_runtime::DualRuntime;
+use rhai::{Dynamic, Engine, EvalAltResult, Map, Scope, AST};
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+/// Keys a hook may return in its mutation map
+pub const MUTABLE_KEYS: [&str; 5] = ["gamma", "lambda", "phi", "theta", "seal"];
+
+/// Runtime events scripts can attach to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptEvent {
+    Step,
+    Collapse,
+}
+
+impl ScriptEvent {
+    /// Name of the script function handling this event
+    pub fn hook_name(&self) -> &'static str {
+        match self {
+            ScriptEvent::Step => "on_step",
+            ScriptEvent::Collapse => "on_collapse",
+        }
+    }
+}
+
+/// Per-script execution limits, applied to every hook call
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScriptBudget {
+    /// Maximum Rhai operations per hook call
+    pub max_operations: u64,
+    /// Maximum function call nesting
+    pub max_call_levels: usize,
+}
+
+impl Default for ScriptBudget {
+    fn default() -> Self {
+        Self {
+            max_operations: 10_000,
+            max_call_levels: 16,
+        }
+    }
+}
+
+/// Errors raised while loading or running scripts
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScriptError {
+    Compile {
+        script: String,
+        message: String,
+    },
+    Runtime {
+        script: String,
+        hook: &'static str,
+        message: String,
+    },
+    BudgetExceeded {
+        script: String,
+        hook: &'static str,
+    },
+    Forbidden {
+        script: String,
+        key: String,
+    },
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScriptError::Compile { script, message } => {
+                write!(f, "script '{}' failed to compile: {}", script, message)
+            }
+            ScriptError::Runtime {
+                script,
+                hook,
+                message,
+            } => write!(f, "script '{}' failed in {}: {}", script, hook, message),
+            ScriptError::BudgetExceeded { script, hook } => {
+                write!(f, "script '{}' exceeded its budget in {}", script, hook)
+            }
+            ScriptError::Forbidden { script, key } => {
+                write!(f, "script '{}' may not mutate '{}'", script, key)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScriptError {}
+
+struct Script {
+    name: String,
+    ast: AST,
+    budget: ScriptBudget,
+}
+
+/// Host running scripts against a `DualRuntime`
+pub struct ScriptHost {
+    engine: Engine,
+    scripts: Vec<Script>,
+    log: Rc<RefCell<Vec<String>>>,
+}
+
+impl Default for ScriptHost {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScriptHost {
+    pub fn new() -> Self {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut engine = Engine::new();
+        engine.disable_symbol("eval");
+        engine.set_max_string_size(4096);
+        engine.set_max_array_size(1024);
+        engine.set_max_map_size(256);
+
+        let sink = Rc::clone(&log);
+        engine.on_print(move |s| sink.borrow_mut().push(s.to_string()));
+        let sink = Rc::clone(&log);
+        engine.on_debug(move |s, _, _| sink.borrow_mut().push(s.to_string()));
+
+        Self {
+            engine,
+            scripts: Vec::new(),
+            log,
+        }
+    }
+
+    /// Compile and attach a script with the default budget
+    pub fn load(&mut self, name: &str, source: &str) -> Result<(), ScriptError> {
+        self.load_with_budget(name, source, ScriptBudget::default())
+    }
+
+    /// Compile and attach a script with its own budget
+    pub fn load_with_budget(
+        &mut self,
+        name: &str,
+        source: &str,
+        budget: ScriptBudget,
+    ) -> Result<(), ScriptError> {
+        let ast = self
+            .engine
+            .compile(source)
+            .map_err(|e| ScriptError::Compile {
+                script: name.to_string(),
+                message: e.to_string(),
+            })?;
+        self.scripts.push(Script {
+            name: name.to_string(),
+            ast,
+            budget,
+        });
+        Ok(())
+    }
+
+    /// Number of attached scripts
+    pub fn len(&self) -> usize {
+        self.scripts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.scripts.is_empty()
+    }
+
+    /// Drain lines printed by scripts (`print`/`debug`)
+    pub fn take_log(&mut self) -> Vec<String> {
+        std::mem::take(&mut *self.log.borrow_mut())
+    }
+
+    /// Step the runtime and fire `on_step`, then `on_collapse` for each rule
+    pub fn step(&mut self, runtime: &mut DualRuntime, dt: f64) -> Result<(), ScriptError> {
+        if runtime.sealed {
+            return Ok(());
+        }
+
+        runtime.step(dt);
+        self.fire(ScriptEvent::Step, runtime, None)?;
+
+        for rule in runtime.fired_rules().to_vec() {
+            self.fire(ScriptEvent::Collapse, runtime, Some(&rule))?;
+        }
+        Ok(())
+    }
+
+    /// Run up to `steps` scripted steps, stopping once sealed
+    pub fn run(
+        &mut self,
+        runtime: &mut DualRuntime,
+        steps: usize,
+        dt: f64,
+    ) -> Result<(), ScriptError> {
+        for _ in 0..steps {
+            if runtime.sealed {
+                break;
+            }
+            self.step(runtime, dt)?;
+        }
+        Ok(())
+    }
+
+    /// Call the hook for `event` in every script that defines it
+    pub fn fire(
+        &mut self,
+        event: ScriptEvent,
+        runtime: &mut DualRuntime,
+        rule: Option<&str>,
+    ) -> Result<(), ScriptError> {
+        let hook = event.hook_name();
+        for script in &self.scripts {
+            if !script.ast.iter_functions().any(|f| f.name == hook) {
+                continue;
+            }
+
+            let mut obs = observables(runtime);
+            if let Some(rule) = rule {
+                obs.insert("rule".into(), rule.into());
+            }
+
+            self.engine
+                .set_max_operations(script.budget.max_operations)
+                .set_max_call_levels(script.budget.max_call_levels);
+            let result = self
+                .engine
+                .call_fn::<Dynamic>(&mut Scope::new(), &script.ast, hook, (obs,))
+                .map_err(|e| match *e {
+                    EvalAltResult::ErrorTooManyOperations(_) => ScriptError::BudgetExceeded {
+                        script: script.name.clone(),
+                        hook,
+                    },
+                    other => ScriptError::Runtime {
+                        script: script.name.clone(),
+                        hook,
+                        message: other.to_string(),
+                    },
+                })?;
+
+            apply_mutations(&script.name, hook, result, runtime)?;
+        }
+        Ok(())
+    }
+}
+
+/// Read-only snapshot handed to hooks
+fn observables(runtime: &DualRuntime) -> Map {
+    let state = &runtime.state;
+    let mut obs = Map::new();
+    for (key, value) in [
+        ("psi_re", runtime.psi.primary().re),
+        ("psi_im", runtime.psi.primary().im),
+        ("lambda", state.lambda),
+        ("gamma", state.gamma),
+        ("phi", state.phi),
+        ("xi", state.xi),
+        ("rho", state.rho),
+        ("theta", state.theta),
+        ("tau", state.tau),
+        ("sovereignty", runtime.compute_sovereignty()),
+    ] {
+        obs.insert(key.into(), value.into());
+    }
+    obs.insert("sealed".into(), runtime.sealed.into());
+    obs
+}
+
+/// Apply a hook's returned mutation map; `()` means no change
+fn apply_mutations(
+    script: &str,
+    hook: &'static str,
+    result: Dynamic,
+    runtime: &mut DualRuntime,
+) -> Result<(), ScriptError> {
+    if result.is_unit() {
+        return Ok(());
+    }
+    let invalid = |message: String| ScriptError::Runtime {
+        script: script.to_string(),
+        hook,
+        message,
+    };
+    let changes = result
+        .try_cast::<Map>()
+        .ok_or_else(|| invalid("hook must return a map or ()".to_string()))?;
+
+    // Validate every key before applying any, so a rejected map leaves
+    // the state untouched
+    let mut seal = false;
+    let mut values = Vec::with_capacity(changes.len());
+    for (key, value) in &changes {
+        let key = key.as_str();
+        if !MUTABLE_KEYS.contains(&key) {
+            return Err(ScriptError::Forbidden {
+                script: script.to_string(),
+                key: key.to_string(),
+            });
+        }
+
+        if key == "seal" {
+            seal = value.as_bool().unwrap_or(false);
+            continue;
+        }
+
+        let value = value
+            .as_float()
+            .or_else(|_| value.as_int().map(|i| i as f64))
+            .ok()
+            .filter(|v| v.is_finite())
+            .ok_or_else(|| invalid(format!("'{}' must be a finite number", key)))?;
+        values.push((key, value));
+    }
+
+    for (key, value) in values {
+        match key {
+            "gamma" => runtime.state.gamma = value.max(0.0),
+            "lambda" => runtime.state.lambda = value,
+            "phi" => runtime.state.phi = value,
+            _ => runtime.state.theta = value,
+        }
+    }
+    runtime.state.compute_emergence();
+    if seal {
+        runtime.seal();
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::collapse::{FnRule, GAMMA_TO_ZERO};
+
+    #[test]
+    fn test_on_step_mutates_state() {
+        let mut host = ScriptHost::new();
+        host.load("damp", "fn on_step(obs) { #{ gamma: obs.gamma * 0.5 } }")
+            .unwrap();
+
+        let mut runtime = DualRuntime::new();
+        let mut reference = runtime.clone();
+        host.step(&mut runtime, 0.1).unwrap();
+        reference.step(0.1);
+        assert!((runtime.state.gamma - reference.state.gamma * 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_on_collapse_receives_rule() {
+        let mut host = ScriptHost::new();
+        host.load("watch", "fn on_collapse(obs) { print(obs.rule); }")
+            .unwrap();
+
+        let mut runtime = DualRuntime::new();
+        runtime.state.gamma = 1e-12;
+        host.step(&mut runtime, 0.1).unwrap();
+        assert_eq!(host.take_log(), vec![GAMMA_TO_ZERO.to_string()]);
+
+        let mut runtime =
+            DualRuntime::new().with_collapse_rule(FnRule::new("always", |_| true, |_| {}));
+        host.step(&mut runtime, 0.1).unwrap();
+        assert_eq!(host.take_log(), vec!["always".to_string()]);
+    }
+
+    #[test]
+    fn test_forbidden_mutation() {
+        let mut host = ScriptHost::new();
+        host.load("bad", "fn on_step(obs) { #{ tau: 0.0 } }")
+            .unwrap();
+        let err = host.step(&mut DualRuntime::new(), 0.1).unwrap_err();
+        assert!(matches!(err, ScriptError::Forbidden { ref key, .. } if key == "tau"));
+
+        let mut host = ScriptHost::new();
+        host.load("partial", "fn on_step(obs) { #{ gamma: 0.5, tau: 0.0 } }")
+            .unwrap();
+        let mut runtime = DualRuntime::new();
+        assert!(host.step(&mut runtime, 0.1).is_err());
+        assert_ne!(runtime.state.gamma, 0.5);
+    }
+
+    #[test]
+    fn test_budget_exceeded() {
+        let mut host = ScriptHost::new();
+        let budget = ScriptBudget {
+            max_operations: 500,
+            ..ScriptBudget::default()
+        };
+        host.load_with_budget("spin", "fn on_step(obs) { loop {} }", budget)
+            .unwrap();
+        let err = host.step(&mut DualRuntime::new(), 0.1).unwrap_err();
+        assert!(matches!(err, ScriptError::BudgetExceeded { .. }));
+    }
+
+    #[test]
+    fn test_compile_error_and_eval_disabled() {
+        let mut host = ScriptHost::new();
+        assert!(host.load("broken", "fn on_step(obs) {").is_err());
+        assert!(host
+            .load("eval", "fn on_step(obs) { eval(\"1\") }")
+            .is_err());
+        assert!(host.is_empty());
+    }
+}
@@ -0,0 +1,318 @@
+//! Shadow-Run Verification
+//!
+//! Before upgrading a long-lived sovereign instance, a recording of the
+//! original run (a checkpoint of the `DualRuntime` plus the dt of every step
+//! and the observables it produced) is replayed by the new binary. The shadow
+//! run starts from the checkpoint, feeds the same inputs and compares its
+//! observables with the recorded ones step by step, reporting divergence.
+
+use crate::dual_runtime::DualRuntime;
+use crate::projectors::DualityConfig;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Observables compared between the recorded and the shadow run
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Observables {
+    pub lambda: f64,
+    pub gamma: f64,
+    pub phi: f64,
+    pub xi: f64,
+    pub rho: f64,
+    pub theta: f64,
+    pub tau: f64,
+    pub psi_re: f64,
+    pub psi_im: f64,
+    pub sealed: bool,
+}
+
+impl Observables {
+    /// Capture the observables of a runtime
+    pub fn capture(runtime: &DualRuntime) -> Self {
+        let state = &runtime.state;
+        Self {
+            lambda: state.lambda,
+            gamma: state.gamma,
+            phi: state.phi,
+            xi: state.xi,
+            rho: state.rho,
+            theta: state.theta,
+            tau: state.tau,
+            psi_re: runtime.psi.primary().re,
+            psi_im: runtime.psi.primary().im,
+            sealed: runtime.sealed,
+        }
+    }
+
+    /// Named values; the seal flag counts as 0 or 1
+    pub fn values(&self) -> [(&'static str, f64); 10] {
+        [
+            ("Λ", self.lambda),
+            ("Γ", self.gamma),
+            ("Φ", self.phi),
+            ("Ξ", self.xi),
+            ("ρ", self.rho),
+            ("θ", self.theta),
+            ("τ", self.tau),
+            ("Re Ψ", self.psi_re),
+            ("Im Ψ", self.psi_im),
+            ("sealed", if self.sealed { 1.0 } else { 0.0 }),
+        ]
+    }
+}
+
+/// One recorded step: its input and the observables after it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordedStep {
+    pub dt: f64,
+    pub observables: Observables,
+}
+
+/// Checkpoint plus recorded inputs and observables of the original run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Recording {
+    /// Runtime version that produced the recording
+    pub version: String,
+    pub checkpoint: DualRuntime,
+    pub steps: Vec<RecordedStep>,
+}
+
+impl Recording {
+    /// Start recording from the current runtime state
+    pub fn new(checkpoint: &DualRuntime) -> Self {
+        Self {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            checkpoint: checkpoint.clone(),
+            steps: Vec::new(),
+        }
+    }
+
+    /// Step the runtime and record the step
+    pub fn step(&mut self, runtime: &mut DualRuntime, dt: f64) {
+        runtime.step(dt);
+        self.steps.push(RecordedStep {
+            dt,
+            observables: Observables::capture(runtime),
+        });
+    }
+
+    /// Record `steps` steps of size dt
+    pub fn run(&mut self, runtime: &mut DualRuntime, steps: usize, dt: f64) {
+        for _ in 0..steps {
+            self.step(runtime, dt);
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_json()?)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self::from_json(&fs::read_to_string(path)?)?)
+    }
+
+    /// Load, refusing checkpoints made with a different duality configuration
+    pub fn load_checked(path: impl AsRef<Path>, duality: &DualityConfig) -> io::Result<Self> {
+        let recording = Self::load(path)?;
+        let recorded = &recording.checkpoint.duality;
+        if !recorded.matches(duality) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "checkpoint duality configuration {} does not match {}",
+                    recorded.fingerprint(),
+                    duality.fingerprint()
+                ),
+            ));
+        }
+        Ok(recording)
+    }
+}
+
+/// An observable that differs beyond tolerance
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Divergence {
+    pub step: usize,
+    pub observable: String,
+    pub recorded: f64,
+    pub shadow: f64,
+    /// |shadow − recorded| / max(1, |recorded|)
+    pub error: f64,
+}
+
+/// Outcome of a shadow run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShadowReport {
+    /// Version of the recording
+    pub recorded_version: String,
+    /// Version running the shadow
+    pub shadow_version: String,
+    pub steps: usize,
+    /// Largest error over all steps and observables
+    pub max_error: f64,
+    pub divergences: Vec<Divergence>,
+}
+
+impl ShadowReport {
+    /// No observable diverged
+    pub fn is_clean(&self) -> bool {
+        self.divergences.is_empty()
+    }
+
+    /// First diverging step, if any
+    pub fn first_divergence(&self) -> Option<&Divergence> {
+        self.divergences.first()
+    }
+}
+
+impl fmt::Display for ShadowReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "[SHADOW] {} → {}: {} steps, max error {:.3e}",
+            self.recorded_version, self.shadow_version, self.steps, self.max_error
+        )?;
+        match self.first_divergence() {
+            None => write!(f, "  no divergence"),
+            Some(d) => write!(
+                f,
+                "  {} divergences, first at step {}: {} recorded {} shadow {}",
+                self.divergences.len(),
+                d.step,
+                d.observable,
+                d.recorded,
+                d.shadow
+            ),
+        }
+    }
+}
+
+/// Replays a recording and compares observables
+#[derive(Debug, Clone)]
+pub struct ShadowRun {
+    /// Allowed error per observable (relative, absolute below 1)
+    pub tolerance: f64,
+}
+
+impl Default for ShadowRun {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ShadowRun {
+    pub fn new() -> Self {
+        Self { tolerance: 1e-9 }
+    }
+
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Run the current runtime from the recording's checkpoint
+    pub fn verify(&self, recording: &Recording) -> ShadowReport {
+        let mut runtime = recording.checkpoint.clone();
+        let mut report = ShadowReport {
+            recorded_version: recording.version.clone(),
+            shadow_version: env!("CARGO_PKG_VERSION").to_string(),
+            steps: recording.steps.len(),
+            max_error: 0.0,
+            divergences: Vec::new(),
+        };
+
+        for (step, recorded) in recording.steps.iter().enumerate() {
+            runtime.step(recorded.dt);
+            let shadow = Observables::capture(&runtime);
+            let pairs = recorded
+                .observables
+                .values()
+                .into_iter()
+                .zip(shadow.values());
+            for ((name, expected), (_, actual)) in pairs {
+                let error = if expected == actual {
+                    0.0
+                } else {
+                    (actual - expected).abs() / expected.abs().max(1.0)
+                };
+                // NaN errors always diverge
+                if error.is_nan() || error > self.tolerance {
+                    report.divergences.push(Divergence {
+                        step,
+                        observable: name.to_string(),
+                        recorded: expected,
+                        shadow: actual,
+                        error,
+                    });
+                }
+                if !error.is_nan() {
+                    report.max_error = report.max_error.max(error);
+                }
+            }
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::projectors::InvolutionSpec;
+
+    fn recording() -> Recording {
+        let mut runtime = DualRuntime::new();
+        runtime.run(5, 0.01);
+        let mut recording = Recording::new(&runtime);
+        recording.run(&mut runtime, 50, 0.01);
+        recording
+    }
+
+    #[test]
+    fn test_identical_replay_is_clean() {
+        let recording = Recording::from_json(&recording().to_json().unwrap()).unwrap();
+        let report = ShadowRun::new().verify(&recording);
+        assert!(report.is_clean(), "{}", report);
+        assert_eq!(report.steps, 50);
+        assert_eq!(report.max_error, 0.0);
+    }
+
+    #[test]
+    fn test_mismatched_duality_refused() {
+        let path = std::env::temp_dir().join(format!("shadow-{}.json", std::process::id()));
+        recording().save(&path).unwrap();
+
+        assert!(Recording::load_checked(&path, &DualityConfig::default()).is_ok());
+        let swap = DualityConfig::new(InvolutionSpec::Matrix {
+            rows: vec![vec![0.0, 1.0], vec![1.0, 0.0]],
+        });
+        let err = Recording::load_checked(&path, &swap).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_divergence_reported() {
+        let mut recording = recording();
+        recording.steps[10].observables.phi += 0.5;
+        let report = ShadowRun::new().verify(&recording);
+        let first = report.first_divergence().unwrap();
+        assert_eq!(first.step, 10);
+        assert_eq!(first.observable, "Φ");
+        assert!(report.max_error > 0.0);
+        assert!(ShadowRun::new()
+            .with_tolerance(1.0)
+            .verify(&recording)
+            .is_clean());
+    }
+}
@@ -0,0 +1,180 @@
+//! Emit Sinks
+//!
+//! Destinations for gene `emit` payloads. Each sink declares the encodings it
+//! accepts and the router negotiates one per payload (`Payload::negotiate`),
+//! so text-only sinks still receive binary and JSON payloads losslessly.
+
+use dnalang_compiler::ir::{GeneOpType, OmegaIR};
+use dnalang_compiler::{Encoded, Encoding, Payload};
+use std::io::{self, Write};
+
+/// A destination for emitted payloads
+pub trait EmitSink {
+    /// Encodings accepted; the first is the fallback when the payload's
+    /// natural encoding is not among them
+    fn accepts(&self) -> &[Encoding];
+
+    /// Deliver an encoded payload emitted by `gene`
+    fn write(&mut self, gene: &str, data: Encoded) -> io::Result<()>;
+}
+
+/// Sink collecting payloads in memory
+#[derive(Debug, Clone)]
+pub struct MemorySink {
+    pub accepted: Vec<Encoding>,
+    pub records: Vec<(String, Encoded)>,
+}
+
+impl Default for MemorySink {
+    fn default() -> Self {
+        Self::new(vec![Encoding::Binary, Encoding::Json, Encoding::Text])
+    }
+}
+
+impl MemorySink {
+    pub fn new(accepted: Vec<Encoding>) -> Self {
+        Self {
+            accepted,
+            records: Vec::new(),
+        }
+    }
+}
+
+impl EmitSink for MemorySink {
+    fn accepts(&self) -> &[Encoding] {
+        &self.accepted
+    }
+
+    fn write(&mut self, gene: &str, data: Encoded) -> io::Result<()> {
+        self.records.push((gene.to_string(), data));
+        Ok(())
+    }
+}
+
+/// Line-oriented text sink: `gene: payload`
+pub struct TextSink<W: Write> {
+    writer: W,
+}
+
+impl<W: Write> TextSink<W> {
+    pub fn new(writer: W) -> Self {
+        Self { writer }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+}
+
+impl<W: Write> EmitSink for TextSink<W> {
+    fn accepts(&self) -> &[Encoding] {
+        &[Encoding::Text]
+    }
+
+    fn write(&mut self, gene: &str, data: Encoded) -> io::Result<()> {
+        let text = match data {
+            Encoded::Text(text) => text,
+            Encoded::Json(value) => value.to_string(),
+            Encoded::Binary(bytes) => String::from_utf8_lossy(&bytes).into_owned(),
+        };
+        writeln!(self.writer, "{}: {}", gene, text)
+    }
+}
+
+/// Routes payloads to every registered sink
+#[derive(Default)]
+pub struct EmitRouter {
+    sinks: Vec<Box<dyn EmitSink>>,
+}
+
+impl EmitRouter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_sink(&mut self, sink: Box<dyn EmitSink>) {
+        self.sinks.push(sink);
+    }
+
+    /// Emit a payload; returns the number of sinks that received it
+    pub fn emit(&mut self, gene: &str, payload: &Payload) -> io::Result<usize> {
+        let mut delivered = 0;
+        for sink in &mut self.sinks {
+            // Sinks that accept no encoding are skipped
+            if let Some(data) = payload.negotiate(sink.accepts()) {
+                sink.write(gene, data)?;
+                delivered += 1;
+            }
+        }
+        Ok(delivered)
+    }
+
+    /// Emit every `Emit` gene op of an IR, in order
+    pub fn emit_ir(&mut self, ir: &OmegaIR) -> io::Result<usize> {
+        let mut delivered = 0;
+        for op in &ir.gene_ops {
+            if let GeneOpType::Emit(payload) = &op.op_type {
+                delivered += self.emit(&op.name, payload)?;
+            }
+        }
+        Ok(delivered)
+    }
+
+    /// Consume the router, returning its sinks
+    pub fn into_sinks(self) -> Vec<Box<dyn EmitSink>> {
+        self.sinks
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dnalang_compiler::ir::GeneOp;
+    use serde_json::json;
+
+    #[test]
+    fn test_memory_sink_keeps_bytes() {
+        let mut sink = MemorySink::default();
+        let payload = Payload::Bytes(vec![0, 0xff]);
+        let data = payload.negotiate(sink.accepts()).unwrap();
+        sink.write("g", data).unwrap();
+        assert_eq!(sink.records[0].1, Encoded::Binary(vec![0, 0xff]));
+    }
+
+    #[test]
+    fn test_text_sink_negotiates() {
+        let mut sink = TextSink::new(Vec::new());
+        for payload in [
+            Payload::from("hello"),
+            Payload::Bytes(vec![0xde, 0xad]),
+            Payload::Json(json!({ "xi": 8 })),
+        ] {
+            let data = payload.negotiate(sink.accepts()).unwrap();
+            sink.write("main", data).unwrap();
+        }
+        let out = String::from_utf8(sink.into_inner()).unwrap();
+        assert_eq!(out, "main: hello\nmain: dead\nmain: {\"xi\":8}\n");
+    }
+
+    #[test]
+    fn test_router_emits_ir_ops() {
+        let mut ir = OmegaIR::new();
+        ir.gene_ops.push(GeneOp {
+            name: "main".to_string(),
+            connection_index: 0,
+            op_type: GeneOpType::Emit(Payload::Json(json!([1, 2]))),
+            annotations: Default::default(),
+        });
+        ir.gene_ops.push(GeneOp {
+            name: "seal".to_string(),
+            connection_index: 1,
+            op_type: GeneOpType::Sovereign,
+            annotations: Default::default(),
+        });
+
+        let mut router = EmitRouter::new();
+        router.add_sink(Box::new(MemorySink::new(vec![Encoding::Json])));
+        router.add_sink(Box::new(MemorySink::new(Vec::new())));
+        assert_eq!(router.emit_ir(&ir).unwrap(), 1);
+    }
+}
@@ -0,0 +1,165 @@
+//! Trajectory Recording
+//!
+//! Samples (τ, Λ, Γ, Φ, Ξ, Ω_sov, |Ψ|) every N steps while a runtime runs
+//! and exports the samples as CSV or JSON Lines for plotting evolution
+//! curves. The starting state is sampled as step 0, and the step that seals
+//! the runtime is always sampled.
+
+use crate::dual_runtime::DualRuntime;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+/// CSV header matching `TrajectorySample` field order
+pub const CSV_HEADER: &str = "step,tau,lambda,gamma,phi,xi,sovereignty,psi_norm";
+
+/// One sampled point of a run
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TrajectorySample {
+    pub step: u64,
+    pub tau: f64,
+    pub lambda: f64,
+    pub gamma: f64,
+    pub phi: f64,
+    pub xi: f64,
+    /// Ω_sov
+    pub sovereignty: f64,
+    /// |Ψ|
+    pub psi_norm: f64,
+}
+
+impl TrajectorySample {
+    pub fn capture(runtime: &DualRuntime) -> Self {
+        let state = &runtime.state;
+        Self {
+            step: runtime.steps,
+            tau: state.tau,
+            lambda: state.lambda,
+            gamma: state.gamma,
+            phi: state.phi,
+            xi: state.xi,
+            sovereignty: runtime.compute_sovereignty(),
+            psi_norm: runtime.psi.norm(),
+        }
+    }
+
+    fn csv_row(&self) -> String {
+        format!(
+            "{},{},{},{},{},{},{},{}",
+            self.step,
+            self.tau,
+            self.lambda,
+            self.gamma,
+            self.phi,
+            self.xi,
+            self.sovereignty,
+            self.psi_norm
+        )
+    }
+}
+
+/// Samples a runtime every `every` steps
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrajectoryRecorder {
+    pub every: u64,
+    pub samples: Vec<TrajectorySample>,
+}
+
+impl Default for TrajectoryRecorder {
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+impl TrajectoryRecorder {
+    /// Recorder sampling every `every` steps (at least 1)
+    pub fn new(every: u64) -> Self {
+        Self {
+            every: every.max(1),
+            samples: Vec::new(),
+        }
+    }
+
+    /// Step the runtime and sample it if due
+    pub fn step(&mut self, runtime: &mut DualRuntime, dt: f64) {
+        if self.samples.is_empty() {
+            self.samples.push(TrajectorySample::capture(runtime));
+        }
+        let was_sealed = runtime.sealed;
+        runtime.step(dt);
+        if runtime.steps.is_multiple_of(self.every) || (runtime.sealed && !was_sealed) {
+            self.samples.push(TrajectorySample::capture(runtime));
+        }
+    }
+
+    /// Run up to `steps` steps, stopping as `DualRuntime::run` does
+    pub fn run(&mut self, runtime: &mut DualRuntime, steps: usize, dt: f64) {
+        for _ in 0..steps {
+            if runtime.sealed || runtime.observers.halted() {
+                break;
+            }
+            self.step(runtime, dt);
+        }
+    }
+
+    pub fn write_csv(&self, mut out: impl Write) -> io::Result<()> {
+        writeln!(out, "{}", CSV_HEADER)?;
+        for sample in &self.samples {
+            writeln!(out, "{}", sample.csv_row())?;
+        }
+        Ok(())
+    }
+
+    pub fn write_json_lines(&self, mut out: impl Write) -> io::Result<()> {
+        for sample in &self.samples {
+            serde_json::to_writer(&mut out, sample)?;
+            writeln!(out)?;
+        }
+        Ok(())
+    }
+
+    pub fn save_csv(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut out = BufWriter::new(File::create(path)?);
+        self.write_csv(&mut out)?;
+        out.flush()
+    }
+
+    pub fn save_json_lines(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut out = BufWriter::new(File::create(path)?);
+        self.write_json_lines(&mut out)?;
+        out.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_samples_every_n_steps_and_exports() {
+        let mut runtime = DualRuntime::new();
+        let mut recorder = TrajectoryRecorder::new(5);
+        recorder.run(&mut runtime, 20, 0.01);
+        let steps: Vec<u64> = recorder.samples.iter().map(|s| s.step).collect();
+        assert_eq!(steps, vec![0, 5, 10, 15, 20]);
+        assert!((recorder.samples[4].tau - 0.2).abs() < 1e-12);
+
+        let mut csv = Vec::new();
+        recorder.write_csv(&mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], CSV_HEADER);
+        assert_eq!(lines.len(), 6);
+        assert_eq!(lines[2].split(',').count(), 8);
+
+        let mut jsonl = Vec::new();
+        recorder.write_json_lines(&mut jsonl).unwrap();
+        let parsed: Vec<TrajectorySample> = String::from_utf8(jsonl)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(parsed, recorder.samples);
+    }
+}
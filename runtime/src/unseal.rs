@@ -0,0 +1,140 @@
+//! Unsealing
+//!
+//! Sealing is normally final. Experiments that need to evolve past
+//! sovereignty can set `RuntimeConfig::allow_unseal` and call
+//! `DualRuntime::unseal` with a reason. Each unseal revokes the certificate
+//! and appends an `UnsealRecord` to the runtime's audit trail, which is
+//! serialized with it. The record holds when it happened, why, and hashes
+//! of the observables before and after (FNV-1a, as in the trajectory hash).
+//!
+//! Collapse rules may seal the runtime again on the next step while the
+//! state stays sovereign.
+
+use crate::certificate::{extend_trajectory_hash, SovereigntyCertificate, TRAJECTORY_HASH_SEED};
+use crate::dual_runtime::DualRuntime;
+use crate::events::RuntimeEvent;
+use crate::shadow::Observables;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Why a runtime could not be unsealed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnsealError {
+    /// `RuntimeConfig::allow_unseal` is off
+    NotPermitted,
+    /// The runtime is not sealed
+    NotSealed,
+}
+
+impl fmt::Display for UnsealError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnsealError::NotPermitted => write!(f, "unsealing is not enabled for this runtime"),
+            UnsealError::NotSealed => write!(f, "runtime is not sealed"),
+        }
+    }
+}
+
+impl std::error::Error for UnsealError {}
+
+/// Audit entry for one unseal
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnsealRecord {
+    /// Steps completed when unsealed
+    pub step: u64,
+    pub tau: f64,
+    /// Wall-clock time, seconds since the Unix epoch
+    pub timestamp: u64,
+    pub reason: String,
+    /// Hash of the observables before and after, as 16 hex digits
+    pub before: String,
+    pub after: String,
+    /// Certificate revoked by the unseal
+    pub certificate: Option<SovereigntyCertificate>,
+}
+
+/// Hash of a runtime's current observables
+pub fn state_hash(runtime: &DualRuntime) -> u64 {
+    extend_trajectory_hash(TRAJECTORY_HASH_SEED, &Observables::capture(runtime))
+}
+
+impl DualRuntime {
+    /// Clear the seal so evolution can continue, recording `reason` in
+    /// `unseal_audit`
+    pub fn unseal(&mut self, reason: &str) -> Result<&UnsealRecord, UnsealError> {
+        if !self.config.allow_unseal {
+            return Err(UnsealError::NotPermitted);
+        }
+        if !self.sealed {
+            return Err(UnsealError::NotSealed);
+        }
+        let before = state_hash(self);
+        self.sealed = false;
+        let certificate = self.certificate.take();
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        self.unseal_audit.push(UnsealRecord {
+            step: self.steps,
+            tau: self.state.tau,
+            timestamp,
+            reason: reason.to_string(),
+            before: format!("{:016x}", before),
+            after: format!("{:016x}", state_hash(self)),
+            certificate,
+        });
+        self.events.record(RuntimeEvent::Unsealed {
+            step: self.steps,
+            tau: self.state.tau,
+            reason: reason.to_string(),
+        });
+        Ok(self.unseal_audit.last().expect("record just pushed"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RuntimeConfig;
+
+    fn sealed_runtime(config: RuntimeConfig) -> DualRuntime {
+        let mut runtime = DualRuntime::new().with_config(config);
+        runtime.state.gamma = runtime.config.gamma_tolerance;
+        runtime.state.compute_emergence();
+        runtime.seal();
+        assert!(runtime.sealed);
+        runtime
+    }
+
+    #[test]
+    fn test_unseal_requires_capability() {
+        let mut runtime = sealed_runtime(RuntimeConfig::default());
+        assert_eq!(
+            runtime.unseal("retry").unwrap_err(),
+            UnsealError::NotPermitted
+        );
+        assert!(runtime.sealed && runtime.unseal_audit.is_empty());
+    }
+
+    #[test]
+    fn test_unseal_records_audit_entry() {
+        let mut runtime = sealed_runtime(RuntimeConfig::default().with_unseal_allowed());
+        let tau = runtime.state.tau;
+        let record = runtime.unseal("probe past sovereignty").unwrap();
+        assert_eq!(record.reason, "probe past sovereignty");
+        assert_eq!(record.tau, tau);
+        assert_ne!(record.before, record.after);
+        assert!(record.certificate.is_some());
+        assert!(!runtime.sealed && runtime.certificate.is_none());
+        assert_eq!(runtime.unseal("again").unwrap_err(), UnsealError::NotSealed);
+
+        runtime.collapse_rules = crate::collapse::CollapseRules::empty();
+        runtime.step(0.01);
+        assert!(runtime.state.tau > tau);
+
+        let json = serde_json::to_string(&runtime).unwrap();
+        let restored: DualRuntime = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.unseal_audit.len(), 1);
+    }
+}
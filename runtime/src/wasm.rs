@@ -0,0 +1,230 @@
+//! WebAssembly Bindings
+//!
+//! wasm-bindgen exports for browser demos, enabled with the `wasm` feature.
+//! JavaScript sees `DualRuntime` and `CRSM7State` classes with camelCase
+//! getters, plus the scalar projectors. Build with:
+//!
+//! ```text
+//! wasm-pack build runtime --target web -- --features wasm
+//! ```
+//!
+//! Step counts cross the boundary as `number` rather than `BigInt`.
+
+use crate::dual_runtime::DualRuntime;
+use crate::manifold::CRSM7State;
+use crate::profile::RuntimeProfile;
+use crate::projectors;
+use wasm_bindgen::prelude::*;
+
+/// `DualRuntime` as seen from JavaScript
+#[wasm_bindgen(js_name = DualRuntime)]
+pub struct WasmRuntime {
+    inner: DualRuntime,
+}
+
+impl Default for WasmRuntime {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[wasm_bindgen(js_class = DualRuntime)]
+impl WasmRuntime {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: DualRuntime::new(),
+        }
+    }
+
+    /// Runtime with the lite profile (no event log, f32-rounded state)
+    pub fn lite() -> Self {
+        Self {
+            inner: DualRuntime::with_profile(RuntimeProfile::lite()),
+        }
+    }
+
+    /// Runtime whose evolution noise is seeded with `seed`
+    #[wasm_bindgen(js_name = withSeed)]
+    pub fn with_seed(seed: u32) -> Self {
+        Self {
+            inner: DualRuntime::new().with_seed(seed as u64),
+        }
+    }
+
+    pub fn step(&mut self, dt: f64) {
+        self.inner.step(dt);
+    }
+
+    pub fn run(&mut self, steps: usize, dt: f64) {
+        self.inner.run(steps, dt);
+    }
+
+    /// Step until sealed or `max_steps`; returns whether it sealed
+    #[wasm_bindgen(js_name = runToSovereignty)]
+    pub fn run_to_sovereignty(&mut self, max_steps: usize, dt: f64) -> bool {
+        self.inner.run_to_sovereignty(max_steps, dt)
+    }
+
+    /// Attempt Ω∞.seal(); succeeds only when sovereignty holds
+    pub fn seal(&mut self) -> bool {
+        self.inner.seal();
+        self.inner.sealed
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn sealed(&self) -> bool {
+        self.inner.sealed
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn steps(&self) -> f64 {
+        self.inner.steps as f64
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn sovereignty(&self) -> f64 {
+        self.inner.compute_sovereignty()
+    }
+
+    #[wasm_bindgen(getter, js_name = psiNorm)]
+    pub fn psi_norm(&self) -> f64 {
+        self.inner.psi.norm()
+    }
+
+    /// Copy of the current CRSM7 state
+    #[wasm_bindgen(getter)]
+    pub fn state(&self) -> WasmState {
+        WasmState {
+            inner: self.inner.state.clone(),
+        }
+    }
+
+    #[wasm_bindgen(getter, js_name = geneCount)]
+    pub fn gene_count(&self) -> usize {
+        self.inner.organism.genes.len()
+    }
+
+    /// Checkpoint-compatible JSON of the whole runtime
+    #[wasm_bindgen(js_name = toJson)]
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(&self.inner).expect("runtime serializes to JSON")
+    }
+}
+
+impl From<DualRuntime> for WasmRuntime {
+    fn from(inner: DualRuntime) -> Self {
+        Self { inner }
+    }
+}
+
+/// `CRSM7State` as seen from JavaScript
+#[wasm_bindgen(js_name = CRSM7State)]
+pub struct WasmState {
+    inner: CRSM7State,
+}
+
+#[wasm_bindgen(js_class = CRSM7State)]
+impl WasmState {
+    /// State with Ξ computed from Λ, Γ and Φ
+    #[wasm_bindgen(constructor)]
+    pub fn new(lambda: f64, gamma: f64, phi: f64, rho: f64, theta: f64, tau: f64) -> Self {
+        Self {
+            inner: CRSM7State::with_values(lambda, gamma, phi, rho, theta, tau),
+        }
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn lambda(&self) -> f64 {
+        self.inner.lambda
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn gamma(&self) -> f64 {
+        self.inner.gamma
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn phi(&self) -> f64 {
+        self.inner.phi
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn xi(&self) -> f64 {
+        self.inner.xi
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn rho(&self) -> f64 {
+        self.inner.rho
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn theta(&self) -> f64 {
+        self.inner.theta
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn tau(&self) -> f64 {
+        self.inner.tau
+    }
+
+    pub fn hamiltonian(&self) -> f64 {
+        self.inner.hamiltonian()
+    }
+
+    pub fn sovereignty(&self) -> f64 {
+        self.inner.compute_sovereignty()
+    }
+
+    #[wasm_bindgen(js_name = isSovereign)]
+    pub fn is_sovereign(&self) -> bool {
+        self.inner.check_sovereignty()
+    }
+}
+
+/// Π⁺Ψ = ½(Ψ + JΨ)
+#[wasm_bindgen(js_name = piPlus)]
+pub fn pi_plus(psi: f64) -> f64 {
+    projectors::pi_plus(psi)
+}
+
+/// Π⁻Ψ = ½(Ψ − JΨ)
+#[wasm_bindgen(js_name = piMinus)]
+pub fn pi_minus(psi: f64) -> f64 {
+    projectors::pi_minus(psi)
+}
+
+/// JΨ = −Ψ
+#[wasm_bindgen(js_name = involutionJ)]
+pub fn involution_j(psi: f64) -> f64 {
+    projectors::involution_j(psi)
+}
+
+/// [Π⁺Ψ, Π⁻Ψ] as a Float64Array
+#[wasm_bindgen]
+pub fn bifurcate(psi: f64) -> Vec<f64> {
+    let (plus, minus) = projectors::bifurcate(psi);
+    vec![plus, minus]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bindings_drive_runtime() {
+        let mut base = DualRuntime::new();
+        base.state.gamma = base.config.gamma_tolerance;
+        base.state.lambda = 0.999;
+        base.state.phi = 100.0;
+        base.state.compute_emergence();
+
+        let mut runtime = WasmRuntime::from(base);
+        assert!(runtime.run_to_sovereignty(10, 0.01));
+        assert!(runtime.sealed());
+        assert_eq!(runtime.steps(), 1.0);
+        assert_eq!(runtime.state().lambda(), runtime.inner.state.lambda);
+        assert_eq!(bifurcate(2.0), vec![pi_plus(2.0), pi_minus(2.0)]);
+    }
+}
@@ -0,0 +1,265 @@
+//! Watch Mode
+//!
+//! Support for `dna watch`: poll a source file, recompile it when it
+//! changes and hot-swap the result into a running `DualRuntime`. The
+//! runtime keeps Ψ and its state vector across swaps. A gene whose op is
+//! unchanged keeps its state. New or changed genes start from the state
+//! chosen by the `ResetPolicy`. `compile_source_with` can inline small
+//! gene-to-gene calls first (`dna watch --inline`), so fewer calls are
+//! dispatched at run time.
+
+use crate::manifold::CRSM7State;
+use dnalang_compiler::ir::OmegaIR;
+use dnalang_compiler::{
+    analyze, generate_omega_ir, generate_omega_ir_inlined, parse, Diagnostic, InlinePass,
+};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Initial state of genes that cannot keep theirs across a swap
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResetPolicy {
+    /// Start from the default CRSM7 state
+    #[default]
+    Fresh,
+    /// Start from the runtime's current state vector
+    Inherit,
+}
+
+impl ResetPolicy {
+    /// Policy by name (`fresh`, `inherit`)
+    pub fn named(name: &str) -> Option<Self> {
+        match name {
+            "fresh" => Some(ResetPolicy::Fresh),
+            "inherit" => Some(ResetPolicy::Inherit),
+            _ => None,
+        }
+    }
+
+    /// State given to a reset gene
+    pub fn initial_state(&self, runtime_state: &CRSM7State) -> CRSM7State {
+        match self {
+            ResetPolicy::Fresh => CRSM7State::new(),
+            ResetPolicy::Inherit => runtime_state.clone(),
+        }
+    }
+}
+
+/// What a hot swap did to each gene
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SwapReport {
+    /// Genes whose op is unchanged; their state was kept
+    pub preserved: Vec<String>,
+    /// New or changed genes, reset per policy
+    pub reset: Vec<String>,
+    /// Genes no longer in the program
+    pub removed: Vec<String>,
+    /// The runtime was sealed and has been unsealed; the new program
+    /// evolves from here
+    #[serde(default)]
+    pub unsealed: bool,
+    /// The runtime is sealed and unsealing is not enabled
+    /// (`RuntimeConfig::allow_unseal`), so the new program does not evolve
+    #[serde(default)]
+    pub still_sealed: bool,
+}
+
+/// Detects changes to a source file by modification time and length
+#[derive(Debug, Clone)]
+pub struct SourceWatcher {
+    path: PathBuf,
+    seen: Option<(SystemTime, u64)>,
+}
+
+impl SourceWatcher {
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            seen: None,
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The file's contents if it changed since the last poll (the first
+    /// poll always returns them)
+    pub fn poll(&mut self) -> io::Result<Option<String>> {
+        let metadata = fs::metadata(&self.path)?;
+        let stamp = (metadata.modified()?, metadata.len());
+        if self.seen == Some(stamp) {
+            return Ok(None);
+        }
+        self.seen = Some(stamp);
+        fs::read_to_string(&self.path).map(Some)
+    }
+}
+
+/// Parse, check and compile source; errors come back as diagnostics
+pub fn compile_source(source: &str) -> Result<OmegaIR, Vec<Diagnostic>> {
+    compile_source_with(source, false)
+}
+
+/// `compile_source`, running the inline pass first when `inline` is set
+pub fn compile_source_with(source: &str, inline: bool) -> Result<OmegaIR, Vec<Diagnostic>> {
+    let parsed = parse(source);
+    let mut diagnostics = parsed.diagnostics;
+    diagnostics.extend(analyze(&parsed.dna, &parsed.crsm));
+    if diagnostics.iter().any(Diagnostic::is_error) {
+        return Err(diagnostics);
+    }
+    Ok(if inline {
+        generate_omega_ir_inlined(&parsed.dna, &parsed.crsm, &mut InlinePass::new())
+    } else {
+        generate_omega_ir(&parsed.dna, &parsed.crsm)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::RuntimeConfig;
+    use crate::events::RuntimeEvent;
+    use crate::DualRuntime;
+    use dnalang_compiler::ir::GeneOpType;
+
+    const BEFORE: &str = r#"organism Demo {
+    gene keep { emit "a" }
+    gene change { emit "b" }
+    gene drop { sovereign }
+}"#;
+
+    const AFTER: &str = r#"organism Demo {
+    gene keep { emit "a" }
+    gene change { emit "c" }
+    gene added { sovereign }
+}"#;
+
+    #[test]
+    fn test_hot_swap_preserves_unchanged_genes() {
+        let mut runtime = DualRuntime::new();
+        runtime.load_ir(&compile_source(BEFORE).unwrap());
+        assert_eq!(runtime.organism.name, "Demo");
+        runtime.run(10, 0.01);
+        let kept = runtime.organism.genes[0].state.clone();
+        for gene in &mut runtime.organism.genes {
+            gene.state.tau = 42.0;
+        }
+        let psi = (runtime.psi.primary().re, runtime.psi.primary().im);
+
+        let report = runtime.hot_swap(&compile_source(AFTER).unwrap(), ResetPolicy::Fresh);
+        assert_eq!(report.preserved, vec!["keep"]);
+        assert_eq!(report.reset, vec!["change", "added"]);
+        assert_eq!(report.removed, vec!["drop"]);
+        assert_eq!(runtime.organism.genes[0].state.lambda, kept.lambda);
+        assert_eq!(runtime.organism.genes[0].state.tau, 42.0);
+        assert_eq!(runtime.organism.genes[1].state.tau, 0.0);
+        assert_eq!((runtime.psi.primary().re, runtime.psi.primary().im), psi);
+
+        let report = runtime.hot_swap(&compile_source(BEFORE).unwrap(), ResetPolicy::Inherit);
+        assert_eq!(report.reset, vec!["change", "drop"]);
+        assert_eq!(runtime.organism.genes[1].state.tau, runtime.state.tau);
+
+        let swaps: Vec<(&Vec<String>, &Vec<String>)> = runtime
+            .events
+            .iter()
+            .filter_map(|e| match e {
+                RuntimeEvent::OrganismSwapped { added, removed, .. } => Some((added, removed)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(swaps.len(), 2);
+        assert_eq!(
+            swaps[1],
+            (&vec!["drop".to_string()], &vec!["added".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_hot_swap_unseals() {
+        let mut runtime =
+            DualRuntime::new().with_config(RuntimeConfig::default().with_unseal_allowed());
+        runtime.load_ir(&compile_source(BEFORE).unwrap());
+        runtime.state.gamma = runtime.config.gamma_tolerance;
+        runtime.state.compute_emergence();
+        runtime.seal();
+        assert!(runtime.sealed);
+
+        let report = runtime.hot_swap(&compile_source(AFTER).unwrap(), ResetPolicy::Fresh);
+        assert!(report.unsealed && !report.still_sealed && !runtime.sealed);
+        assert!(runtime.certificate.is_none());
+        assert_eq!(runtime.unseal_audit[0].reason, "hot swap");
+        assert!(runtime
+            .events
+            .iter()
+            .any(|e| matches!(e, RuntimeEvent::Unsealed { reason, .. } if reason == "hot swap")));
+        let tau = runtime.state.tau;
+        runtime.step(0.01);
+        assert!(runtime.state.tau > tau);
+
+        let report = runtime.hot_swap(&compile_source(AFTER).unwrap(), ResetPolicy::Fresh);
+        assert!(!report.unsealed);
+    }
+
+    #[test]
+    fn test_hot_swap_keeps_seal_without_permission() {
+        let mut runtime = DualRuntime::new();
+        runtime.load_ir(&compile_source(BEFORE).unwrap());
+        runtime.state.gamma = runtime.config.gamma_tolerance;
+        runtime.state.compute_emergence();
+        runtime.seal();
+
+        let report = runtime.hot_swap(&compile_source(AFTER).unwrap(), ResetPolicy::Fresh);
+        assert!(report.still_sealed && !report.unsealed);
+        assert!(runtime.sealed && runtime.certificate.is_some());
+        assert!(runtime.unseal_audit.is_empty());
+    }
+
+    #[test]
+    fn test_runtime_runs_inlined_ir() {
+        const CHAIN: &str = r#"organism Chain {
+    gene main { step() }
+    gene step { tick() }
+    gene tick { emit "t" }
+}"#;
+        let calls = |ir: &OmegaIR| {
+            ir.gene_ops
+                .iter()
+                .filter(|op| matches!(op.op_type, GeneOpType::Call(..)))
+                .count()
+        };
+        let plain = compile_source(CHAIN).unwrap();
+        let inlined = compile_source_with(CHAIN, true).unwrap();
+        assert_eq!((calls(&plain), calls(&inlined)), (2, 0));
+
+        let payloads = |ir: &OmegaIR| {
+            let mut runtime = DualRuntime::new();
+            let trace = runtime.load_ir(ir);
+            assert!(trace.unresolved.is_empty());
+            runtime.run(10, 0.01);
+            assert_eq!(runtime.steps, 10);
+            trace.emits.into_iter().map(|(_, p)| p).collect::<Vec<_>>()
+        };
+        assert_eq!(payloads(&inlined), payloads(&plain));
+        assert_eq!(payloads(&inlined).len(), 3);
+    }
+
+    #[test]
+    fn test_watcher_reports_changes_and_errors() {
+        let path = std::env::temp_dir().join(format!("dna-watch-{}.dna", std::process::id()));
+        fs::write(&path, BEFORE).unwrap();
+        let mut watcher = SourceWatcher::new(&path);
+        assert_eq!(watcher.poll().unwrap().as_deref(), Some(BEFORE));
+        assert_eq!(watcher.poll().unwrap(), None);
+
+        fs::write(&path, "organism Broken {").unwrap();
+        let source = watcher.poll().unwrap().unwrap();
+        assert!(compile_source(&source).is_err());
+        fs::remove_file(&path).unwrap();
+    }
+}
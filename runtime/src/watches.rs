@@ -0,0 +1,536 @@
+//! Watch Expressions
+//!
+//! Small arithmetic expressions over the state vector (`lambda*phi/gamma`,
+//! `xi - 8`, `ln(gamma)`) evaluated after every step. Each watch keeps its
+//! last values in a bounded buffer and may carry thresholds: when the value
+//! crosses one, the runtime records a `WatchCrossed` event and calls every
+//! registered crossing callback. Breakpoints pause a run on a condition;
+//! watches only observe it.
+//!
+//! Expressions use `+ - * / ^`, parentheses, numbers, the fields of
+//! `StateField` by name or symbol, and the functions `abs`, `sqrt`, `ln`
+//! and `exp`. Callbacks are not serialized or cloned.
+
+use crate::debugger::StateField;
+use crate::manifold::CRSM7State;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fmt;
+
+/// Values kept per watch unless configured otherwise
+pub const WATCH_CAPACITY: usize = 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Function {
+    Abs,
+    Sqrt,
+    Ln,
+    Exp,
+}
+
+impl Function {
+    fn named(name: &str) -> Option<Self> {
+        match name {
+            "abs" => Some(Function::Abs),
+            "sqrt" => Some(Function::Sqrt),
+            "ln" => Some(Function::Ln),
+            "exp" => Some(Function::Exp),
+            _ => None,
+        }
+    }
+}
+
+/// A parsed watch expression
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Number(f64),
+    Field(StateField),
+    Neg(Box<Expr>),
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+    Call(Function, Box<Expr>),
+}
+
+/// Why an expression could not be parsed
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExprError {
+    /// Unexpected character or token at a byte offset
+    Unexpected {
+        position: usize,
+        found: String,
+    },
+    UnexpectedEnd,
+    /// Neither a state field nor a function
+    UnknownName(String),
+}
+
+impl fmt::Display for ExprError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExprError::Unexpected { position, found } => {
+                write!(f, "unexpected `{}` at offset {}", found, position)
+            }
+            ExprError::UnexpectedEnd => write!(f, "unexpected end of expression"),
+            ExprError::UnknownName(name) => write!(f, "unknown name `{}`", name),
+        }
+    }
+}
+
+impl std::error::Error for ExprError {}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Name(String),
+    Op(char),
+}
+
+fn tokenize(source: &str) -> Result<Vec<(usize, Token)>, ExprError> {
+    let mut tokens = Vec::new();
+    let mut chars = source.char_indices().peekable();
+    while let Some(&(position, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c.is_ascii_digit() || c == '.' {
+            let mut end = position;
+            let mut previous = ' ';
+            while let Some(&(i, c)) = chars.peek() {
+                let exponent_sign = (c == '-' || c == '+') && matches!(previous, 'e' | 'E');
+                if !(c.is_ascii_alphanumeric() || c == '.' || exponent_sign) {
+                    break;
+                }
+                end = i + c.len_utf8();
+                previous = c;
+                chars.next();
+            }
+            let text = &source[position..end];
+            let value = text.parse().map_err(|_| ExprError::Unexpected {
+                position,
+                found: text.to_string(),
+            })?;
+            tokens.push((position, Token::Number(value)));
+        } else if c.is_alphabetic() || c == '_' {
+            let mut end = position;
+            while let Some(&(i, c)) = chars.peek() {
+                if !(c.is_alphanumeric() || c == '_') {
+                    break;
+                }
+                end = i + c.len_utf8();
+                chars.next();
+            }
+            tokens.push((position, Token::Name(source[position..end].to_string())));
+        } else if "+-*/^()".contains(c) {
+            tokens.push((position, Token::Op(c)));
+            chars.next();
+        } else {
+            return Err(ExprError::Unexpected {
+                position,
+                found: c.to_string(),
+            });
+        }
+    }
+    Ok(tokens)
+}
+
+/// Recursive-descent parser over the token list
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    next: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.next).map(|(_, t)| t)
+    }
+
+    fn eat(&mut self, op: char) -> bool {
+        if self.peek() == Some(&Token::Op(op)) {
+            self.next += 1;
+            return true;
+        }
+        false
+    }
+
+    fn unexpected(&self) -> ExprError {
+        match self.tokens.get(self.next) {
+            Some((position, token)) => ExprError::Unexpected {
+                position: *position,
+                found: match token {
+                    Token::Number(n) => n.to_string(),
+                    Token::Name(name) => name.clone(),
+                    Token::Op(op) => op.to_string(),
+                },
+            },
+            None => ExprError::UnexpectedEnd,
+        }
+    }
+
+    /// sum := product (('+' | '-') product)*
+    fn sum(&mut self) -> Result<Expr, ExprError> {
+        let mut lhs = self.product()?;
+        loop {
+            let op = if self.eat('+') {
+                BinaryOp::Add
+            } else if self.eat('-') {
+                BinaryOp::Sub
+            } else {
+                return Ok(lhs);
+            };
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(self.product()?));
+        }
+    }
+
+    /// product := unary (('*' | '/') unary)*
+    fn product(&mut self) -> Result<Expr, ExprError> {
+        let mut lhs = self.unary()?;
+        loop {
+            let op = if self.eat('*') {
+                BinaryOp::Mul
+            } else if self.eat('/') {
+                BinaryOp::Div
+            } else {
+                return Ok(lhs);
+            };
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(self.unary()?));
+        }
+    }
+
+    /// unary := '-' unary | power
+    fn unary(&mut self) -> Result<Expr, ExprError> {
+        if self.eat('-') {
+            return Ok(Expr::Neg(Box::new(self.unary()?)));
+        }
+        self.power()
+    }
+
+    /// power := atom ('^' unary)?, right-associative
+    fn power(&mut self) -> Result<Expr, ExprError> {
+        let base = self.atom()?;
+        if self.eat('^') {
+            return Ok(Expr::Binary(
+                BinaryOp::Pow,
+                Box::new(base),
+                Box::new(self.unary()?),
+            ));
+        }
+        Ok(base)
+    }
+
+    /// atom := number | field | function '(' sum ')' | '(' sum ')'
+    fn atom(&mut self) -> Result<Expr, ExprError> {
+        let token = self.peek().cloned().ok_or(ExprError::UnexpectedEnd)?;
+        match token {
+            Token::Number(value) => {
+                self.next += 1;
+                Ok(Expr::Number(value))
+            }
+            Token::Name(name) => {
+                self.next += 1;
+                if let Some(field) = StateField::named(&name) {
+                    return Ok(Expr::Field(field));
+                }
+                let function =
+                    Function::named(&name).ok_or_else(|| ExprError::UnknownName(name.clone()))?;
+                if !self.eat('(') {
+                    return Err(self.unexpected());
+                }
+                let arg = self.sum()?;
+                if !self.eat(')') {
+                    return Err(self.unexpected());
+                }
+                Ok(Expr::Call(function, Box::new(arg)))
+            }
+            Token::Op('(') => {
+                self.next += 1;
+                let inner = self.sum()?;
+                if !self.eat(')') {
+                    return Err(self.unexpected());
+                }
+                Ok(inner)
+            }
+            Token::Op(_) => Err(self.unexpected()),
+        }
+    }
+}
+
+impl Expr {
+    pub fn parse(source: &str) -> Result<Self, ExprError> {
+        let mut parser = Parser {
+            tokens: tokenize(source)?,
+            next: 0,
+        };
+        let expr = parser.sum()?;
+        if parser.peek().is_some() {
+            return Err(parser.unexpected());
+        }
+        Ok(expr)
+    }
+
+    pub fn eval(&self, state: &CRSM7State) -> f64 {
+        match self {
+            Expr::Number(value) => *value,
+            Expr::Field(field) => field.of(state),
+            Expr::Neg(inner) => -inner.eval(state),
+            Expr::Binary(op, lhs, rhs) => {
+                let (a, b) = (lhs.eval(state), rhs.eval(state));
+                match op {
+                    BinaryOp::Add => a + b,
+                    BinaryOp::Sub => a - b,
+                    BinaryOp::Mul => a * b,
+                    BinaryOp::Div => a / b,
+                    BinaryOp::Pow => a.powf(b),
+                }
+            }
+            Expr::Call(function, arg) => {
+                let x = arg.eval(state);
+                match function {
+                    Function::Abs => x.abs(),
+                    Function::Sqrt => x.sqrt(),
+                    Function::Ln => x.ln(),
+                    Function::Exp => x.exp(),
+                }
+            }
+        }
+    }
+}
+
+/// Direction of a threshold crossing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Crossing {
+    Rising,
+    Falling,
+}
+
+/// A watch value crossing one of its thresholds
+#[derive(Debug, Clone, PartialEq)]
+pub struct WatchCrossing {
+    /// Id returned by `Watches::add`
+    pub watch: usize,
+    pub name: String,
+    pub threshold: f64,
+    pub direction: Crossing,
+    /// Steps completed, counting the one that crossed
+    pub step: u64,
+    pub value: f64,
+}
+
+/// An expression evaluated after every step
+#[derive(Debug, Clone)]
+pub struct Watch {
+    /// The source text, unless renamed
+    pub name: String,
+    pub expr: Expr,
+    pub thresholds: Vec<f64>,
+    capacity: usize,
+    /// (step, value), oldest first
+    values: VecDeque<(u64, f64)>,
+}
+
+impl Watch {
+    pub fn new(source: &str) -> Result<Self, ExprError> {
+        Ok(Self {
+            name: source.trim().to_string(),
+            expr: Expr::parse(source)?,
+            thresholds: Vec::new(),
+            capacity: WATCH_CAPACITY,
+            values: VecDeque::new(),
+        })
+    }
+
+    pub fn with_name(mut self, name: &str) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Report crossings of `threshold`
+    pub fn with_threshold(mut self, threshold: f64) -> Self {
+        self.thresholds.push(threshold);
+        self
+    }
+
+    /// Keep the last `capacity` values (0 records none)
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Recorded (step, value) pairs, oldest first
+    pub fn values(&self) -> &VecDeque<(u64, f64)> {
+        &self.values
+    }
+
+    pub fn last(&self) -> Option<f64> {
+        self.values.back().map(|&(_, value)| value)
+    }
+}
+
+/// Callback invoked for each threshold crossing
+pub type CrossingCallback = Box<dyn FnMut(&WatchCrossing) + Send>;
+
+/// Watches registered on a runtime, and their crossing callbacks
+#[derive(Default)]
+pub struct Watches {
+    watches: Vec<Watch>,
+    /// Value after the previous step, to detect crossings
+    previous: Vec<Option<f64>>,
+    callbacks: Vec<CrossingCallback>,
+}
+
+impl Clone for Watches {
+    fn clone(&self) -> Self {
+        Self {
+            watches: self.watches.clone(),
+            previous: self.previous.clone(),
+            callbacks: Vec::new(),
+        }
+    }
+}
+
+impl fmt::Debug for Watches {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Watches")
+            .field("watches", &self.watches)
+            .field("callbacks", &self.callbacks.len())
+            .finish()
+    }
+}
+
+impl Watches {
+    /// Register a watch; returns its id
+    pub fn add(&mut self, watch: Watch) -> usize {
+        self.watches.push(watch);
+        self.previous.push(None);
+        self.watches.len() - 1
+    }
+
+    /// Call `callback` on every threshold crossing
+    pub fn on_crossing(&mut self, callback: impl FnMut(&WatchCrossing) + Send + 'static) {
+        self.callbacks.push(Box::new(callback));
+    }
+
+    pub fn get(&self, id: usize) -> Option<&Watch> {
+        self.watches.get(id)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Watch> {
+        self.watches.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.watches.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.watches.is_empty()
+    }
+
+    /// Evaluate every watch after step `step`; returns the crossings
+    pub(crate) fn evaluate(&mut self, step: u64, state: &CRSM7State) -> Vec<WatchCrossing> {
+        let mut crossings = Vec::new();
+        for (id, (watch, previous)) in self.watches.iter_mut().zip(&mut self.previous).enumerate() {
+            let value = watch.expr.eval(state);
+            if let Some(before) = *previous {
+                for &threshold in &watch.thresholds {
+                    let direction = if before < threshold && value >= threshold {
+                        Crossing::Rising
+                    } else if before >= threshold && value < threshold {
+                        Crossing::Falling
+                    } else {
+                        continue;
+                    };
+                    crossings.push(WatchCrossing {
+                        watch: id,
+                        name: watch.name.clone(),
+                        threshold,
+                        direction,
+                        step,
+                        value,
+                    });
+                }
+            }
+            *previous = Some(value);
+            if watch.capacity > 0 {
+                while watch.values.len() >= watch.capacity {
+                    watch.values.pop_front();
+                }
+                watch.values.push_back((step, value));
+            }
+        }
+        for crossing in &crossings {
+            for callback in &mut self.callbacks {
+                callback(crossing);
+            }
+        }
+        crossings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dual_runtime::DualRuntime;
+    use crate::events::RuntimeEvent;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_parse_and_eval() {
+        let state = CRSM7State::new();
+        let xi = Expr::parse("lambda*phi/gamma").unwrap();
+        assert!((xi.eval(&state) - state.lambda * state.phi / state.gamma).abs() < 1e-9);
+        let expr = Expr::parse("-2^2 + abs(Ξ - 8) * (1e-3 + 1)").unwrap();
+        let expected = -4.0 + (state.xi - 8.0).abs() * 1.001;
+        assert!((expr.eval(&state) - expected).abs() < 1e-9);
+
+        assert_eq!(
+            Expr::parse("omega + 1"),
+            Err(ExprError::UnknownName("omega".to_string()))
+        );
+        assert_eq!(Expr::parse("xi -"), Err(ExprError::UnexpectedEnd));
+        assert_eq!(
+            Expr::parse("xi ) 2"),
+            Err(ExprError::Unexpected {
+                position: 3,
+                found: ")".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_watches_record_and_report_crossings() {
+        let mut runtime = DualRuntime::new();
+        let gamma = runtime.state.gamma;
+        let id = runtime.watch(
+            Watch::new("ln(gamma)")
+                .unwrap()
+                .with_threshold((gamma / 2.0).ln()),
+        );
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        runtime
+            .watches
+            .on_crossing(move |c| sink.lock().unwrap().push((c.step, c.direction)));
+
+        runtime.run(10, 0.1);
+        let watch = runtime.watches.get(id).unwrap();
+        assert_eq!(watch.values().len(), 10);
+        assert!((watch.last().unwrap() - runtime.state.gamma.ln()).abs() < 1e-12);
+        assert_eq!(*seen.lock().unwrap(), vec![(7, Crossing::Falling)]);
+        assert!(runtime.events.iter().any(|e| matches!(
+            e,
+            RuntimeEvent::WatchCrossed {
+                step: 7,
+                direction: Crossing::Falling,
+                ..
+            }
+        )));
+    }
+}
@@ -0,0 +1,177 @@
+//! Wave Functions
+//!
+//! Ψ as a state vector of complex amplitudes, one per mode. A single-mode
+//! wave function behaves exactly like the scalar Ψ the runtime used before.
+//!
+//! H_CRSM acts diagonally: mode k evolves with phase exp(i·(k+1)·H·dt), so
+//! the modes form an equally spaced ladder above the ground mode. The
+//! projectors Π± act on the real axis of each component, as they did on
+//! the scalar Ψ.
+
+use crate::dual_runtime::Complex;
+use serde::{Deserialize, Serialize};
+
+/// Normalized vector of complex amplitudes
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "WaveFunctionRepr", into = "WaveFunctionRepr")]
+pub struct WaveFunction {
+    components: Vec<Complex>,
+}
+
+/// Serialized form; checkpoints from before state vectors hold one Complex
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum WaveFunctionRepr {
+    Modes { components: Vec<Complex> },
+    Scalar(Complex),
+}
+
+impl From<WaveFunctionRepr> for WaveFunction {
+    fn from(repr: WaveFunctionRepr) -> Self {
+        match repr {
+            WaveFunctionRepr::Modes { components } => Self { components },
+            WaveFunctionRepr::Scalar(psi) => Self::single(psi),
+        }
+    }
+}
+
+impl From<WaveFunction> for WaveFunctionRepr {
+    fn from(wave: WaveFunction) -> Self {
+        WaveFunctionRepr::Modes {
+            components: wave.components,
+        }
+    }
+}
+
+impl Default for WaveFunction {
+    fn default() -> Self {
+        Self::single(Complex::default())
+    }
+}
+
+impl WaveFunction {
+    /// Wave function from amplitudes, normalized
+    pub fn new(components: Vec<Complex>) -> Self {
+        let mut wave = Self { components };
+        wave.normalize();
+        wave
+    }
+
+    /// Single-mode wave function holding `psi` as given
+    pub fn single(psi: Complex) -> Self {
+        Self {
+            components: vec![psi],
+        }
+    }
+
+    /// Equal superposition of `modes` modes
+    pub fn uniform(modes: usize) -> Self {
+        Self::new(vec![Complex::new(1.0, 0.0); modes.max(1)])
+    }
+
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+
+    pub fn components(&self) -> &[Complex] {
+        &self.components
+    }
+
+    pub fn components_mut(&mut self) -> &mut [Complex] {
+        &mut self.components
+    }
+
+    /// Amplitude of the ground mode
+    pub fn primary(&self) -> Complex {
+        self.components
+            .first()
+            .copied()
+            .unwrap_or(Complex::new(0.0, 0.0))
+    }
+
+    /// ‖Ψ‖
+    pub fn norm(&self) -> f64 {
+        self.inner(self).re.sqrt()
+    }
+
+    /// Scale to unit norm (a vanishing Ψ is left as is)
+    pub fn normalize(&mut self) {
+        let norm = self.norm();
+        if norm > 1e-10 {
+            for c in &mut self.components {
+                *c = c.scale(1.0 / norm);
+            }
+        }
+    }
+
+    /// ⟨self|other⟩ = Σ conj(a_k)·b_k over the shared modes
+    pub fn inner(&self, other: &WaveFunction) -> Complex {
+        self.components
+            .iter()
+            .zip(&other.components)
+            .fold(Complex::new(0.0, 0.0), |sum, (a, b)| {
+                sum.add(&a.conjugate().multiply(b))
+            })
+    }
+
+    /// Apply a projector to the real part of component `index`
+    pub fn project(&mut self, index: usize, projector: impl Fn(f64) -> f64) {
+        if let Some(c) = self.components.get_mut(index) {
+            c.re = projector(c.re);
+        }
+    }
+
+    /// Apply a projector to the real part of every component
+    pub fn project_all(&mut self, projector: impl Fn(f64) -> f64) {
+        for c in &mut self.components {
+            c.re = projector(c.re);
+        }
+    }
+
+    /// Evolve every mode under H for dt, then renormalize
+    pub fn evolve(&mut self, h: f64, dt: f64) {
+        for (k, c) in self.components.iter_mut().enumerate() {
+            *c = c.multiply(&Complex::exp_i((k + 1) as f64 * h * dt));
+        }
+        self.normalize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalization_and_inner_product() {
+        let wave = WaveFunction::new(vec![Complex::new(3.0, 0.0), Complex::new(0.0, 4.0)]);
+        assert!((wave.norm() - 1.0).abs() < 1e-12);
+        let other = WaveFunction::new(vec![Complex::new(0.0, 0.0), Complex::new(0.0, 1.0)]);
+        // ⟨Ψ|e₁⟩ = conj(0.8i)·i = 0.8
+        let overlap = wave.inner(&other);
+        assert!((overlap.re - 0.8).abs() < 1e-12 && overlap.im.abs() < 1e-12);
+
+        let mut evolved = WaveFunction::uniform(3);
+        evolved.evolve(1.7, 0.3);
+        assert!((evolved.norm() - 1.0).abs() < 1e-12);
+        let phase = evolved.components()[2];
+        assert!((phase.im.atan2(phase.re) - 3.0 * 1.7 * 0.3).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_scalar_checkpoints_load() {
+        let wave: WaveFunction = serde_json::from_str(r#"{"re": 0.6, "im": 0.8}"#).unwrap();
+        assert_eq!(wave.len(), 1);
+        assert_eq!(wave.primary().im, 0.8);
+
+        let mut two = WaveFunction::uniform(2);
+        two.project(1, |x| x * 0.0);
+        let json = serde_json::to_string(&two).unwrap();
+        let back: WaveFunction = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.components()[1].re, 0.0);
+        assert_eq!(back.len(), 2);
+    }
+}
@@ -0,0 +1,251 @@
+//! Canonical Workloads
+//!
+//! Reproducible workload generators shared by benchmarks, soak tests and the
+//! annealer, so performance numbers always refer to the same inputs:
+//! - Bifurcation tree: complete binary tree of bifurcating genes
+//! - Dense mesh: fully connected mesh of CRSM7 vertices (1k by default)
+//! - Large organism: one organism with 10k mixed genes
+//! - Emit fan-out: many payloads routed to many emit sinks (sink routing
+//!   only; messages between organisms go through the executor's `Mailbox`
+//!   and are not measured here)
+//!
+//! Every generator is deterministic in its seed.
+
+use crate::dual_runtime::Z3MeshWeights;
+use crate::manifold::CRSM7State;
+use crate::rng::SeededRng;
+use crate::sink::{EmitRouter, MemorySink};
+use dnalang_compiler::ast::{DnaProgram, Expr, Field, Gene, Organism};
+use dnalang_compiler::{generate_omega_ir, CrsmProgram, DualityPass, Payload};
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, Instant};
+
+/// A canonical workload and its size
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Workload {
+    BifurcationTree { depth: u32 },
+    DenseMesh { vertices: usize },
+    LargeOrganism { genes: usize },
+    EmitFanOut { sinks: usize, messages: usize },
+}
+
+/// Outcome of running a workload once
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkloadRun {
+    pub name: String,
+    /// Work items processed (ops, weights, deliveries)
+    pub units: usize,
+    pub elapsed: Duration,
+}
+
+impl Workload {
+    /// The shared canonical sizes
+    pub fn canonical() -> Vec<Workload> {
+        vec![
+            Workload::BifurcationTree { depth: 12 },
+            Workload::DenseMesh { vertices: 1_000 },
+            Workload::LargeOrganism { genes: 10_000 },
+            Workload::EmitFanOut {
+                sinks: 64,
+                messages: 1_000,
+            },
+        ]
+    }
+
+    pub fn name(&self) -> String {
+        match self {
+            Workload::BifurcationTree { depth } => format!("bifurcation_tree/{}", depth),
+            Workload::DenseMesh { vertices } => format!("dense_mesh/{}", vertices),
+            Workload::LargeOrganism { genes } => format!("large_organism/{}", genes),
+            Workload::EmitFanOut { sinks, messages } => {
+                format!("emit_fan_out/{}x{}", sinks, messages)
+            }
+        }
+    }
+
+    /// Generate the inputs and run the workload once
+    pub fn run(&self, seed: u64) -> WorkloadRun {
+        let start = Instant::now();
+        let units = match *self {
+            Workload::BifurcationTree { depth } => {
+                DualityPass::new().run(&bifurcation_tree(depth)).len()
+            }
+            Workload::DenseMesh { vertices } => dense_mesh(vertices, seed).weights().len(),
+            Workload::LargeOrganism { genes } => {
+                let program = large_organism(genes, seed);
+                generate_omega_ir(&program, &CrsmProgram::new())
+                    .gene_ops
+                    .len()
+            }
+            Workload::EmitFanOut { sinks, messages } => emit_fan_out(sinks, messages, seed).run(),
+        };
+        WorkloadRun {
+            name: self.name(),
+            units,
+            elapsed: start.elapsed(),
+        }
+    }
+}
+
+/// Complete binary tree of genes: inner genes bifurcate and call both
+/// children, leaves are sovereign (2^depth − 1 genes). Panics unless
+/// `depth` is below `usize::BITS`
+pub fn bifurcation_tree(depth: u32) -> DnaProgram {
+    assert!(
+        depth < usize::BITS,
+        "bifurcation tree depth {} is too large",
+        depth
+    );
+    let count = (1usize << depth) - 1;
+    let mut organism = Organism::new("BifurcationTree");
+    organism.fields.push(Field::new("psi", "state"));
+    for i in 0..count {
+        let mut gene = Gene::new(&format!("b{}", i));
+        let (left, right) = (2 * i + 1, 2 * i + 2);
+        if right < count {
+            gene.body.push(Expr::Bifurcate("psi".to_string()));
+            gene.body.push(Expr::Call(format!("b{}", left), vec![]));
+            gene.body.push(Expr::Call(format!("b{}", right), vec![]));
+        } else {
+            gene.body.push(Expr::Sovereign);
+        }
+        organism.genes.push(gene);
+    }
+    let mut program = DnaProgram::new();
+    program.add_organism(organism);
+    program
+}
+
+/// Fully connected mesh of vertices scattered around the default state
+#[derive(Debug, Clone)]
+pub struct MeshWorkload {
+    pub vertices: Vec<CRSM7State>,
+}
+
+impl MeshWorkload {
+    /// Z3 weight of every vertex pair (i < j)
+    pub fn weights(&self) -> Vec<f64> {
+        let n = self.vertices.len();
+        let mut weights = Vec::with_capacity(n * n.saturating_sub(1) / 2);
+        for (i, a) in self.vertices.iter().enumerate() {
+            for b in &self.vertices[i + 1..] {
+                weights.push(Z3MeshWeights::compute_weight(a, b));
+            }
+        }
+        weights
+    }
+}
+
+pub fn dense_mesh(vertices: usize, seed: u64) -> MeshWorkload {
+    let mut rng = SeededRng::new(seed);
+    let vertices = (0..vertices)
+        .map(|_| {
+            let mut state = CRSM7State::new();
+            state.lambda = rng.range(0.5, 1.0);
+            state.gamma = rng.range(0.001, 0.05);
+            state.phi = rng.range(6.0, 9.0);
+            state.rho = if rng.next_f64() < 0.5 { -1.0 } else { 1.0 };
+            state.compute_emergence();
+            state
+        })
+        .collect();
+    MeshWorkload { vertices }
+}
+
+/// One organism with `genes` genes mixing emits, builtin calls, calls to
+/// earlier genes and sovereign seals
+pub fn large_organism(genes: usize, seed: u64) -> DnaProgram {
+    let mut rng = SeededRng::new(seed);
+    let mut organism = Organism::new("LargeOrganism");
+    for name in ["lambda", "gamma", "phi"] {
+        organism.fields.push(Field::new(name, name));
+    }
+    for i in 0..genes {
+        let mut gene = Gene::new(&format!("g{}", i));
+        let expr = match rng.index(4) {
+            0 => Expr::Emit(Payload::from(format!("g{}", i))),
+            1 => Expr::Call("hamiltonian".to_string(), vec![]),
+            2 if i > 0 => Expr::Call(format!("g{}", rng.index(i)), vec![]),
+            _ => Expr::Sovereign,
+        };
+        gene.body.push(expr);
+        organism.genes.push(gene);
+    }
+    let mut program = DnaProgram::new();
+    program.add_organism(organism);
+    program
+}
+
+/// Payloads broadcast to every sink
+#[derive(Debug, Clone)]
+pub struct FanOutWorkload {
+    pub sinks: usize,
+    pub payloads: Vec<Payload>,
+}
+
+impl FanOutWorkload {
+    /// Route every payload to fresh memory sinks; returns deliveries
+    pub fn run(&self) -> usize {
+        let mut router = EmitRouter::new();
+        for _ in 0..self.sinks {
+            router.add_sink(Box::new(MemorySink::default()));
+        }
+        self.payloads
+            .iter()
+            .map(|payload| router.emit("fan_out", payload).unwrap_or(0))
+            .sum()
+    }
+}
+
+pub fn emit_fan_out(sinks: usize, messages: usize, seed: u64) -> FanOutWorkload {
+    let mut rng = SeededRng::new(seed);
+    let payloads = (0..messages)
+        .map(|i| match rng.index(3) {
+            0 => Payload::from(format!("message {}", i)),
+            1 => Payload::Bytes((0..16).map(|_| rng.next_u64() as u8).collect()),
+            _ => Payload::Json(serde_json::json!({ "seq": i, "xi": rng.range(0.0, 10.0) })),
+        })
+        .collect();
+    FanOutWorkload { sinks, payloads }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generators_are_deterministic() {
+        let a = large_organism(200, 7);
+        let b = large_organism(200, 7);
+        assert_eq!(
+            serde_json::to_string(&a).unwrap(),
+            serde_json::to_string(&b).unwrap()
+        );
+        assert_eq!(dense_mesh(20, 3).weights(), dense_mesh(20, 3).weights());
+        assert_eq!(bifurcation_tree(4).organisms[0].genes.len(), 15);
+    }
+
+    #[test]
+    fn test_small_workloads_run() {
+        let small = [
+            Workload::BifurcationTree { depth: 3 },
+            Workload::DenseMesh { vertices: 10 },
+            Workload::LargeOrganism { genes: 50 },
+            Workload::EmitFanOut {
+                sinks: 4,
+                messages: 5,
+            },
+        ];
+        let units: Vec<usize> = small.iter().map(|w| w.run(1).units).collect();
+        // 3 inner genes × 2 bifurcate ops + 3 × 2 calls + 4 sovereign leaves
+        assert_eq!(units, vec![16, 45, 50, 20]);
+        assert_eq!(Workload::canonical()[1].name(), "dense_mesh/1000");
+    }
+
+    #[test]
+    #[should_panic(expected = "too large")]
+    fn test_bifurcation_tree_rejects_oversized_depth() {
+        bifurcation_tree(usize::BITS);
+    }
+}